@@ -0,0 +1,31 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The extension point for writing bindings in other languages than the
+//! ones cbindgen supports, exported as `cbindgen::backend`.
+
+use std::fmt;
+use std::io::Write;
+use std::rc::Rc;
+
+use crate::bindgen::Bindings;
+
+pub use crate::bindgen::writer::SourceWriter;
+
+/// An output format implemented outside of cbindgen.
+pub trait LanguageBackend {
+    /// Writes all the `bindings` to `out`: the items, functions, constants
+    /// and globals, along with any header or trailer of the format.
+    fn write_bindings(&self, bindings: &Bindings, out: &mut SourceWriter<&mut dyn Write>);
+}
+
+/// A backend shared by a builder and the bindings it generates.
+#[derive(Clone)]
+pub(crate) struct SharedBackend(pub Rc<dyn LanguageBackend>);
+
+impl fmt::Debug for SharedBackend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("LanguageBackend")
+    }
+}
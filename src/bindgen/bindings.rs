@@ -4,17 +4,28 @@
 
 use std::borrow::Cow;
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::fs::File;
 use std::io::{Read, Write};
+use std::mem;
 use std::path;
 use std::rc::Rc;
 
-use crate::bindgen::config::{Config, Language};
+use log::Level;
+
+use crate::bindgen::backend::SharedBackend;
+use crate::bindgen::cdecl;
+use crate::bindgen::config::{CimportStyle, Config, Int128Style, Language, NullableReturnWrapper};
+use crate::bindgen::diagnostic::{self, DiagnosticKind};
+use crate::bindgen::includes::{cython_cimport_module, SystemIncludes};
 use crate::bindgen::ir::{
-    Constant, Function, ItemContainer, ItemMap, Path as BindgenPath, Static, Struct, Typedef,
+    Cfg, ConditionWrite, Constant, CppMethod, CppViewKind, CythonClasses, Field, Function,
+    ItemContainer, ItemMap, Path as BindgenPath, ReprAlign, Static, Struct, ToCondition, Type,
+    Typedef, VariantBody,
 };
+use crate::bindgen::layout::StructLayout;
+use crate::bindgen::parser::SourceLocation;
 use crate::bindgen::writer::{Source, SourceWriter};
 
 /// A bindings header that can be written.
@@ -29,9 +40,102 @@ pub struct Bindings {
     constants: Vec<Constant>,
     items: Vec<ItemContainer>,
     functions: Vec<Function>,
+    /// Every instantiation of a generic item, and the name of its monomorph.
+    monomorphs: Vec<(String, String)>,
+    /// The instantiations of the C++ templates to declare `extern`, for
+    /// `cpp.instantiations_file`.
+    template_instantiations: Vec<TemplateInstantiation>,
+    /// The path of the module that each type was declared in.
+    module_paths: HashMap<String, Vec<String>>,
+    /// How the items of each top-level module depend on the ones of the
+    /// others, for `split_by_module`.
+    module_dependencies: HashMap<String, ModuleDependencies>,
+    /// The items of other modules to declare before the items of a module.
+    forward_declarations: Vec<ItemContainer>,
+    /// The layout of the structs to check with static assertions.
+    struct_layouts: HashMap<BindgenPath, StructLayout>,
+    /// Where each item was declared in the Rust sources.
+    source_locations: HashMap<String, SourceLocation>,
+    /// The Rust sources and the config the bindings were generated from.
+    source_files: Vec<path::PathBuf>,
     /// Bindings are generated by a recursive call to cbindgen
     /// and shouldn't do anything when written anywhere.
     noop: bool,
+    /// The files which changed when written, once `record_changes` was
+    /// called. Shared with the bindings of each target or module.
+    changes: Rc<RefCell<Option<Vec<FileChange>>>>,
+    /// The backend writing the bindings instead of cbindgen, if any.
+    pub(crate) backend: Option<SharedBackend>,
+}
+
+/// A file which changed when writing the bindings.
+#[derive(Debug, Clone)]
+pub struct FileChange {
+    pub path: path::PathBuf,
+    /// The previous contents of the file, or `None` if it didn't exist.
+    pub old_contents: Option<Vec<u8>>,
+    pub new_contents: Vec<u8>,
+}
+
+/// How the items of a top-level module depend on the ones of other modules.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ModuleDependencies {
+    /// The modules whose file to include.
+    pub includes: BTreeSet<String>,
+    /// The structs and unions of the other modules which are only used behind
+    /// pointers, and can be declared instead of included.
+    pub forward_declarations: Vec<ItemContainer>,
+}
+
+/// An explicit instantiation of the C++ template of a generic struct, union or
+/// enum.
+#[derive(Debug, Clone)]
+pub(crate) struct TemplateInstantiation {
+    /// `struct` or `union`, as the template is declared.
+    pub class_key: &'static str,
+    /// The instantiated type, e.g. `Foo<int32_t>`.
+    pub ty: Type,
+    /// The cfg of the generic item.
+    pub cfg: Option<Cfg>,
+}
+
+/// The name of the file of the items at the root of the crate, with
+/// `split_by_module`. It can't clash with a module, as `crate` is a keyword.
+const ROOT_MODULE: &str = "crate";
+
+/// The top-level module the item named `name` was declared in.
+pub(crate) fn top_level_module<'a>(
+    module_paths: &'a HashMap<String, Vec<String>>,
+    name: &str,
+) -> &'a str {
+    module_paths
+        .get(name)
+        .and_then(|module_path| module_path.first())
+        .map_or(ROOT_MODULE, |module| module)
+}
+
+/// Returns the name of the Cython module declared by the `.pxd` file named
+/// `file_name`.
+fn cython_module(file_name: &str) -> String {
+    path::Path::new(file_name)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Returns the path of a file next to `path`, named after it with `suffix`,
+/// e.g. `bindings_linux.h`.
+fn sibling_path(path: &path::Path, suffix: &str) -> path::PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mut file_name = format!("{}_{}", stem, suffix);
+    if let Some(extension) = path.extension() {
+        file_name.push('.');
+        file_name.push_str(&extension.to_string_lossy());
+    }
+    path.with_file_name(file_name)
 }
 
 #[derive(PartialEq, Eq)]
@@ -50,6 +154,13 @@ impl Bindings {
         globals: Vec<Static>,
         items: Vec<ItemContainer>,
         functions: Vec<Function>,
+        monomorphs: Vec<(String, String)>,
+        template_instantiations: Vec<TemplateInstantiation>,
+        module_paths: HashMap<String, Vec<String>>,
+        module_dependencies: HashMap<String, ModuleDependencies>,
+        struct_layouts: HashMap<BindgenPath, StructLayout>,
+        source_locations: HashMap<String, SourceLocation>,
+        source_files: Vec<path::PathBuf>,
         noop: bool,
     ) -> Bindings {
         Bindings {
@@ -61,8 +172,72 @@ impl Bindings {
             constants,
             items,
             functions,
+            monomorphs,
+            template_instantiations,
+            module_paths,
+            module_dependencies,
+            forward_declarations: Vec::new(),
+            struct_layouts,
+            source_locations,
+            source_files,
             noop,
+            changes: Rc::default(),
+            backend: None,
+        }
+    }
+
+    /// Keeps the previous and new contents of the files which change when
+    /// writing the bindings from now on, e.g. to show how they differ.
+    pub fn record_changes(&self) {
+        *self.changes.borrow_mut() = Some(Vec::new());
+    }
+
+    /// Returns the files which changed since `record_changes` was called.
+    pub fn take_changes(&self) -> Vec<FileChange> {
+        self.changes
+            .borrow_mut()
+            .as_mut()
+            .map(mem::take)
+            .unwrap_or_default()
+    }
+
+    /// Writes a file with the contents `write` produces, unless it has them
+    /// already. Returns whether the file changed.
+    fn write_if_changed(&self, path: &path::Path, write: impl FnOnce(&mut Vec<u8>)) -> bool {
+        let mut new_file_contents = Vec::new();
+        write(&mut new_file_contents);
+
+        // Don't compare files if we've never written this file before
+        let old_file_contents = if path.is_file() {
+            let mut old_file_contents = Vec::new();
+            let mut old_file = File::open(path).unwrap();
+            old_file.read_to_end(&mut old_file_contents).unwrap();
+            if old_file_contents == new_file_contents {
+                return false;
+            }
+            Some(old_file_contents)
+        } else {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).unwrap();
+            }
+            None
+        };
+
+        let mut new_file = File::create(path).unwrap();
+        new_file.write_all(&new_file_contents).unwrap();
+
+        if let Some(ref mut changes) = *self.changes.borrow_mut() {
+            changes.push(FileChange {
+                path: path.to_path_buf(),
+                old_contents: old_file_contents,
+                new_contents: new_file_contents,
+            });
         }
+        true
+    }
+
+    pub(crate) fn struct_layout(&self, path: &BindgenPath) -> Option<&StructLayout> {
+        self.struct_layouts.get(path)
     }
 
     // FIXME(emilio): What to do when the configuration doesn't match?
@@ -128,67 +303,195 @@ impl Bindings {
         fields
     }
 
+    /// Writes the bindings to `path`, along with the files configured to be
+    /// written next to them, if their contents changed. Returns whether any
+    /// file changed.
+    ///
+    /// This writes a single file guarding the cfg'd items with #ifdef's, even
+    /// with `[targets]` or `split_by_module`: use `write_targets_to_files` or
+    /// `write_modules_to_files` to write their files instead.
+    ///
+    /// With a `LanguageBackend`, only the bindings and the symbol files are
+    /// written, as the other files are C, C++ or Cython.
     pub fn write_to_file<P: AsRef<path::Path>>(&self, path: P) -> bool {
         if self.noop {
             return false;
         }
 
-        // Don't compare files if we've never written this file before
-        if !path.as_ref().is_file() {
-            if let Some(parent) = path::Path::new(path.as_ref()).parent() {
-                fs::create_dir_all(parent).unwrap();
+        let path = path.as_ref();
+        let mut changed = self.write_if_changed(path, |out| self.write(out));
+        for (_, symbols_changed) in self.write_symbol_files(path) {
+            changed |= symbols_changed;
+        }
+        if self.backend.is_some() {
+            return changed;
+        }
+        if let Some(header) = self.config.export_macro.header.as_ref() {
+            if self.config.export_macro().is_some() {
+                let header_path = path.with_file_name(header);
+                changed |=
+                    self.write_if_changed(&header_path, |out| self.write_export_macro_header(out));
             }
-            self.write(File::create(path).unwrap());
-            return true;
         }
-
-        let mut new_file_contents = Vec::new();
-        self.write(&mut new_file_contents);
-
-        let mut old_file_contents = Vec::new();
-        {
-            let mut old_file = File::open(&path).unwrap();
-            old_file.read_to_end(&mut old_file_contents).unwrap();
+        if let Some(header) = self.config.export.umbrella.header.as_ref() {
+            if self.config.language != Language::Cython {
+                let file_name = path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                let header_path = path.with_file_name(header);
+                changed |= self.write_if_changed(&header_path, |out| {
+                    self.write_umbrella_header(out, &file_name)
+                });
+            }
         }
-
-        if old_file_contents != new_file_contents {
-            let mut new_file = File::create(&path).unwrap();
-            new_file.write_all(&new_file_contents).unwrap();
-            true
-        } else {
-            false
+        // The helpers of each target or module are written with the complete
+        // bindings.
+        if self.config.targets.is_empty() && !self.config.split_by_module() {
+            if let Some(helpers_file) = self.config.c_helpers_file() {
+                changed |= self
+                    .write_c_helpers_file(path, &path.with_file_name(helpers_file))
+                    .1;
+            }
+            if let Some(header) = self.config.cpp_exceptions_header() {
+                changed |= self
+                    .write_cpp_exceptions_file(path, &path.with_file_name(header))
+                    .1;
+            }
+            if let Some(source) = self.config.cpp_instantiations_file() {
+                changed |= self
+                    .write_cpp_instantiations_file(path, &path.with_file_name(source))
+                    .1;
+            }
+            if let Some(wrapper) = self.config.cython_wrapper_file() {
+                changed |= self
+                    .write_cython_wrapper_file(path, &path.with_file_name(wrapper))
+                    .1;
+            }
+            if let Some(enums) = self.config.cython_python_enums_file() {
+                changed |= self.write_python_enums_file(&path.with_file_name(enums)).1;
+            }
+            if let Some(stub) = self.config.cython_stub_file() {
+                changed |= self.write_python_stub_file(&path.with_file_name(stub)).1;
+            }
         }
+        changed
     }
 
-    pub fn write_headers<F: Write>(&self, out: &mut SourceWriter<F>) {
+    /// Writes the header of `cpp.exceptions_header` to `header_path`, for the
+    /// bindings at `path`. Returns its path, along with whether it changed.
+    fn write_cpp_exceptions_file(
+        &self,
+        path: &path::Path,
+        header_path: &path::Path,
+    ) -> (path::PathBuf, bool) {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let changed = self.write_if_changed(header_path, |out| {
+            self.write_cpp_exceptions_header(out, &file_name)
+        });
+        (header_path.to_owned(), changed)
+    }
+
+    /// Writes the exception type of `cpp.exception_name`, carrying an error
+    /// code, and the wrappers of the functions annotated with `error-code`
+    /// which throw it when they fail, for the bindings named `header_name`.
+    pub fn write_cpp_exceptions_header<F: Write>(&self, file: F, header_name: &str) {
         if self.noop {
             return;
         }
 
-        if let Some(ref f) = self.config.header {
-            out.new_line_if_not_start();
+        let mut out = SourceWriter::new(file, self);
+        if let Some(f) = self.config.file_header() {
             write!(out, "{}", f);
             out.new_line();
         }
-        if let Some(f) = self.config.include_guard() {
+        if let Some(ref f) = self.config.autogen_warning {
             out.new_line_if_not_start();
-            write!(out, "#ifndef {}", f);
-            out.new_line();
-            write!(out, "#define {}", f);
+            write!(out, "{}", f);
             out.new_line();
         }
-        if self.config.pragma_once && self.config.language != Language::Cython {
-            out.new_line_if_not_start();
-            write!(out, "#pragma once");
+        out.new_line_if_not_start();
+        out.write("#pragma once");
+        out.new_line();
+        out.new_line();
+        write!(out, "#include \"{}\"", header_name);
+        out.new_line();
+        out.write("#include <cstdint>");
+        out.new_line();
+        out.write("#include <stdexcept>");
+        out.new_line();
+        out.write("#include <string>");
+        out.new_line();
+
+        self.open_namespaces(&mut out);
+
+        let exception = &self.config.cpp.exception_name;
+        out.new_line();
+        write!(out, "struct {} : std::runtime_error", exception);
+        out.open_brace();
+        out.write("int64_t code;");
+        out.new_line();
+        out.new_line();
+        write!(out, "{}(const char *function, int64_t code)", exception);
+        out.new_line();
+        out.write("  : std::runtime_error(std::string(function) + \" failed with error code \" +");
+        out.new_line();
+        out.write("                       std::to_string(code)),");
+        out.new_line();
+        out.write("    code(code) {}");
+        out.close_brace(true);
+        out.new_line();
+
+        for function in &self.functions {
+            let success = match function.error_code_success() {
+                Some(success) => success,
+                None => continue,
+            };
+            let mut callee = self.module_path(&function.rust_name).join("::");
+            if !callee.is_empty() {
+                callee.push_str("::");
+            }
+            callee.push_str(function.path.name());
+            out.new_line();
+            function.write_throwing_wrapper(&callee, &success, &self.config, &mut out);
             out.new_line();
         }
-        if self.config.include_version {
-            out.new_line_if_not_start();
-            write!(
-                out,
-                "/* Generated with cbindgen:{} */",
-                crate::bindgen::config::VERSION
-            );
+
+        self.close_namespaces(&mut out);
+    }
+
+    /// Writes the source file of `cpp.instantiations_file` to `source_path`,
+    /// for the header at `path`. Returns its path, along with whether it
+    /// changed.
+    fn write_cpp_instantiations_file(
+        &self,
+        path: &path::Path,
+        source_path: &path::Path,
+    ) -> (path::PathBuf, bool) {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let changed = self.write_if_changed(source_path, |out| {
+            self.write_cpp_instantiations_source(out, &file_name)
+        });
+        (source_path.to_owned(), changed)
+    }
+
+    /// Writes the explicit instantiation definitions of the templates which
+    /// the header named `header_name` declares `extern` with
+    /// `cpp.instantiations_file`.
+    pub fn write_cpp_instantiations_source<F: Write>(&self, file: F, header_name: &str) {
+        if self.noop {
+            return;
+        }
+
+        let mut out = SourceWriter::new(file, self);
+        if let Some(f) = self.config.file_header() {
+            write!(out, "{}", f);
             out.new_line();
         }
         if let Some(ref f) = self.config.autogen_warning {
@@ -196,119 +499,1446 @@ impl Bindings {
             write!(out, "{}", f);
             out.new_line();
         }
+        out.new_line_if_not_start();
+        write!(out, "#include \"{}\"", header_name);
+        out.new_line();
 
-        if self.config.no_includes
-            && self.config.sys_includes().is_empty()
-            && self.config.includes().is_empty()
-            && (self.config.cython.cimports.is_empty() || self.config.language != Language::Cython)
-            && self.config.after_includes.is_none()
-        {
+        self.open_namespaces(&mut out);
+        self.write_template_instantiations("template", &mut out);
+        self.close_namespaces(&mut out);
+    }
+
+    /// Writes an explicit instantiation of each template the items use,
+    /// starting with `prefix`: `extern template` for declarations, and
+    /// `template` for definitions.
+    fn write_template_instantiations<F: Write>(&self, prefix: &str, out: &mut SourceWriter<F>) {
+        if self.template_instantiations.is_empty() {
+            return;
+        }
+        out.new_line_if_not_start();
+        for instantiation in &self.template_instantiations {
+            let condition = instantiation.cfg.to_condition(&self.config);
+            condition.write_before(&self.config, out);
+            write!(out, "{} {} ", prefix, instantiation.class_key);
+            cdecl::write_type(out, &instantiation.ty, &self.config);
+            out.write(";");
+            condition.write_after(&self.config, out);
+            out.new_line();
+        }
+    }
+
+    /// Writes the `.pyx` file of `cython.wrapper_file` to `wrapper_path`, for
+    /// the declarations at `path`. Returns its path, along with whether it
+    /// changed.
+    fn write_cython_wrapper_file(
+        &self,
+        path: &path::Path,
+        wrapper_path: &path::Path,
+    ) -> (path::PathBuf, bool) {
+        let module = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let changed =
+            self.write_if_changed(wrapper_path, |out| self.write_cython_wrapper(out, &module));
+        (wrapper_path.to_owned(), changed)
+    }
+
+    /// Writes the Python wrappers of `cython.wrapper_file` for the
+    /// declarations of the `.pxd` module `module`: a class holding a pointer
+    /// to each opaque type, which frees it with its destructor if it owns it,
+    /// a class holding each tagged enum, with helpers to read its variants,
+    /// and a Python function for each function which can be called with
+    /// Python objects.
+    pub fn write_cython_wrapper<F: Write>(&self, file: F, module: &str) {
+        if self.noop {
             return;
         }
 
+        let mut out = SourceWriter::new(file, self);
+        if let Some(f) = self.config.file_header() {
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
         out.new_line_if_not_start();
+        out.write("from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t");
+        out.new_line();
+        out.write("from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t");
+        out.new_line();
+        write!(out, "cimport {}", module);
 
-        if !self.config.no_includes {
-            match self.config.language {
-                Language::C => {
-                    out.write("#include <stdarg.h>");
-                    out.new_line();
-                    out.write("#include <stdbool.h>");
-                    out.new_line();
-                    if self.config.usize_is_size_t {
-                        out.write("#include <stddef.h>");
-                        out.new_line();
-                    }
-                    out.write("#include <stdint.h>");
-                    out.new_line();
-                    out.write("#include <stdlib.h>");
-                    out.new_line();
-                }
-                Language::Cxx => {
-                    out.write("#include <cstdarg>");
-                    out.new_line();
-                    if self.config.usize_is_size_t {
-                        out.write("#include <cstddef>");
-                        out.new_line();
-                    }
-                    out.write("#include <cstdint>");
-                    out.new_line();
-                    out.write("#include <cstdlib>");
-                    out.new_line();
-                    out.write("#include <ostream>");
-                    out.new_line();
-                    out.write("#include <new>");
-                    out.new_line();
-                    if self.config.enumeration.cast_assert_name.is_none()
-                        && (self.config.enumeration.derive_mut_casts
-                            || self.config.enumeration.derive_const_casts)
-                    {
-                        out.write("#include <cassert>");
-                        out.new_line();
-                    }
-                }
-                Language::Cython => {
-                    out.write(
-                        "from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t",
-                    );
-                    out.new_line();
-                    out.write(
-                        "from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t",
-                    );
+        let mut classes = CythonClasses::default();
+        let mut destructors = Vec::new();
+        for item in &self.items {
+            let opaque = match *item {
+                ItemContainer::OpaqueItem(ref x) if x.generic_params.is_empty() => x,
+                ItemContainer::Enum(ref x) if x.has_cython_class() => {
+                    classes.enums.push(x.export_name.as_str());
                     out.new_line();
-                    out.write("cdef extern from *");
-                    out.open_brace();
-                    out.write("ctypedef bint bool");
                     out.new_line();
-                    out.write("ctypedef struct va_list");
                     out.new_line();
-                    out.close_brace(false);
+                    x.write_cython_class(module, &self.config, &mut out);
+                    continue;
                 }
+                _ => continue,
+            };
+            let destructor = self.destructor(item);
+            classes.opaque.push(opaque.export_name.as_str());
+            destructors.extend(destructor.map(|function| function.path.name()));
+
+            let name = &opaque.export_name;
+            let condition = opaque.cfg.to_condition(&self.config);
+            out.new_line();
+            out.new_line();
+            out.new_line();
+            condition.write_before(&self.config, &mut out);
+            write!(out, "cdef class {}", name);
+            out.open_brace();
+            write!(out, "cdef {}.{} *ptr", module, name);
+            out.new_line();
+            out.write("cdef bint owned");
+            out.new_line();
+            out.write("cdef object _owner");
+            if let Some(destructor) = destructor {
+                out.new_line();
+                out.new_line();
+                out.write("def __dealloc__(self)");
+                out.open_brace();
+                out.write("if self.owned and self.ptr != NULL");
+                out.open_brace();
+                write!(out, "{}.{}(self.ptr)", module, destructor.path.name());
+                out.close_brace(false);
+                out.close_brace(false);
             }
+            out.new_line();
+            out.new_line();
+            out.write("@staticmethod");
+            out.new_line();
+            write!(
+                out,
+                "cdef {} from_ptr({}.{} *ptr, bint owned, object owner=None)",
+                name, module, name
+            );
+            out.open_brace();
+            write!(out, "cdef {} wrapper", name);
+            out.new_line();
+            out.write("if ptr == NULL");
+            out.open_brace();
+            out.write("return None");
+            out.close_brace(false);
+            out.new_line();
+            write!(out, "wrapper = {}.__new__({})", name, name);
+            out.new_line();
+            out.write("wrapper.ptr = ptr");
+            out.new_line();
+            out.write("wrapper.owned = owned");
+            out.new_line();
+            out.write("wrapper._owner = owner");
+            out.new_line();
+            out.write("return wrapper");
+            out.close_brace(false);
+            out.close_brace(false);
+            condition.write_after(&self.config, &mut out);
         }
 
-        for include in self.config.sys_includes() {
-            write!(out, "#include <{}>", include);
+        for function in &self.functions {
+            // Destructors are only called by the classes, which would free
+            // the pointer a second time otherwise.
+            if destructors.contains(&function.path.name()) {
+                continue;
+            }
+            if function.has_cython_wrapper(&classes, &self.config) {
+                out.new_line();
+                out.new_line();
+                out.new_line();
+                function.write_cython_wrapper(module, &classes, &self.config, &mut out);
+            }
+        }
+        out.new_line();
+    }
+
+    /// Writes the `.pyi` file of `cython.stub_file` to `stub_path`. Returns
+    /// its path, along with whether it changed.
+    fn write_python_stub_file(&self, stub_path: &path::Path) -> (path::PathBuf, bool) {
+        let changed = self.write_if_changed(stub_path, |out| self.write_python_stub(out));
+        (stub_path.to_owned(), changed)
+    }
+
+    /// Writes the type stubs of the Python wrappers of `cython.wrapper_file`,
+    /// for `cython.stub_file`: the classes holding the opaque types and the
+    /// tagged enums, and the signatures of the Python functions.
+    pub fn write_python_stub<F: Write>(&self, file: F) {
+        if self.noop {
+            return;
+        }
+
+        let mut out = SourceWriter::new(file, self);
+        if let Some(f) = self.config.file_header() {
+            write!(out, "{}", f);
             out.new_line();
         }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        out.new_line_if_not_start();
+        out.write("import typing");
 
-        for include in self.config.includes() {
-            write!(out, "#include \"{}\"", include);
+        let mut classes = CythonClasses::default();
+        let mut destructors = Vec::new();
+        for item in &self.items {
+            let opaque = match *item {
+                ItemContainer::OpaqueItem(ref x) if x.generic_params.is_empty() => x,
+                ItemContainer::Enum(ref x) if x.has_cython_class() => {
+                    classes.enums.push(x.export_name.as_str());
+                    out.new_line();
+                    out.new_line();
+                    out.new_line();
+                    x.write_python_stub(&self.config, &mut out);
+                    continue;
+                }
+                _ => continue,
+            };
+            classes.opaque.push(opaque.export_name.as_str());
+            destructors.extend(self.destructor(item).map(|function| function.path.name()));
+
+            out.new_line();
+            out.new_line();
             out.new_line();
+            write!(out, "class {}", opaque.export_name);
+            out.open_brace();
+            if !opaque.documentation.write_docstring(&mut out) {
+                out.write("...");
+            }
+            out.close_brace(false);
         }
 
-        if self.config.language == Language::Cython {
-            for (module, names) in &self.config.cython.cimports {
-                write!(out, "from {} cimport {}", module, names.join(", "));
+        for function in &self.functions {
+            if destructors.contains(&function.path.name()) {
+                continue;
+            }
+            if function.has_cython_wrapper(&classes, &self.config) {
+                out.new_line();
+                out.new_line();
                 out.new_line();
+                function.write_python_stub(&classes, &self.config, &mut out);
             }
         }
+        out.new_line();
+    }
+
+    /// Writes the Python file of `cython.python_enums_file` to `enums_path`.
+    /// Returns its path, along with whether it changed.
+    fn write_python_enums_file(&self, enums_path: &path::Path) -> (path::PathBuf, bool) {
+        let changed = self.write_if_changed(enums_path, |out| self.write_python_enums(out));
+        (enums_path.to_owned(), changed)
+    }
+
+    /// Writes an `enum.IntEnum`, or an `enum.IntFlag` for `flags` enums,
+    /// mirroring each enum and the tag of each enum with data, for
+    /// `cython.python_enums_file`.
+    pub fn write_python_enums<F: Write>(&self, file: F) {
+        if self.noop {
+            return;
+        }
+
+        let mut out = SourceWriter::new(file, self);
+        if let Some(f) = self.config.file_header() {
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        out.new_line_if_not_start();
+        out.write("import enum");
+
+        for item in &self.items {
+            let e = match *item {
+                ItemContainer::Enum(ref x) if x.generic_params.is_empty() => x,
+                _ => continue,
+            };
+            if !e.has_python_enum() {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::SkippedHelper,
+                    None,
+                    format_args!(
+                        "Skipping the Python enum of {}, as some of its discriminants can't be \
+                         written in Python.",
+                        e.export_name
+                    ),
+                );
+                continue;
+            }
+            out.new_line();
+            out.new_line();
+            out.new_line();
+            e.write_python_enum(&self.config, &mut out);
+        }
+        out.new_line();
+    }
+
+    /// header at `path`. Returns its path, along with whether it changed.
+    fn write_c_helpers_file(
+        &self,
+        path: &path::Path,
+        helpers_path: &path::Path,
+    ) -> (path::PathBuf, bool) {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let changed = self.write_if_changed(helpers_path, |out| {
+            self.write_c_helpers_source(out, &file_name)
+        });
+        (helpers_path.to_owned(), changed)
+    }
+
+    /// Writes the definitions of the C helper functions of tagged enums, which
+    /// the header named `header_name` declares with `c.helpers_file`.
+    pub fn write_c_helpers_source<F: Write>(&self, file: F, header_name: &str) {
+        if self.noop {
+            return;
+        }
+
+        let mut out = SourceWriter::new(file, self);
+        if let Some(f) = self.config.file_header() {
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        out.new_line_if_not_start();
+        write!(out, "#include \"{}\"", header_name);
+        for item in &self.items {
+            if let ItemContainer::Enum(ref x) = *item {
+                if x.has_c_helper_functions(&self.config) {
+                    x.write_c_helper_definitions(&self.config, &mut out);
+                    out.new_line();
+                }
+            }
+        }
+    }
+
+    /// Writes the version script and module-definition file of
+    /// `export.symbols` next to `path`. Returns the path of each file, along
+    /// with whether it changed.
+    fn write_symbol_files(&self, path: &path::Path) -> Vec<(path::PathBuf, bool)> {
+        let symbols = &self.config.export.symbols;
+        let mut files = Vec::new();
+        if let Some(ref version_script) = symbols.version_script {
+            let script_path = path.with_file_name(version_script);
+            let changed = self.write_if_changed(&script_path, |out| self.write_version_script(out));
+            files.push((script_path, changed));
+        }
+        if let Some(ref def_file) = symbols.def_file {
+            let def_path = path.with_file_name(def_file);
+            let changed = self.write_if_changed(&def_path, |out| self.write_def_file(out));
+            files.push((def_path, changed));
+        }
+        files
+    }
+
+    /// The symbols of the functions and globals defined by the library, along
+    /// with whether they are data, sorted by name.
+    fn exported_symbols(&self) -> Vec<(&str, bool)> {
+        let mut symbols: Vec<_> = self
+            .functions
+            .iter()
+            .filter(|function| !function.extern_decl)
+            .map(|function| (function.path.name(), false))
+            .chain(
+                self.globals
+                    .iter()
+                    .filter(|global| !global.extern_decl)
+                    .map(|global| (global.path.name(), true)),
+            )
+            .collect();
+        symbols.sort();
+        symbols.dedup();
+        symbols
+    }
+
+    /// Writes a GNU ld version script exporting the symbols of the functions
+    /// and globals, and hiding every other one.
+    pub fn write_version_script<F: Write>(&self, mut out: F) {
+        if self.noop {
+            return;
+        }
+
+        let symbols = self.exported_symbols();
+        match self.config.export.symbols.version {
+            Some(ref version) => writeln!(out, "{} {{", version).unwrap(),
+            None => writeln!(out, "{{").unwrap(),
+        }
+        if !symbols.is_empty() {
+            writeln!(out, "  global:").unwrap();
+            for (symbol, _) in symbols {
+                writeln!(out, "    {};", symbol).unwrap();
+            }
+        }
+        writeln!(out, "  local:").unwrap();
+        writeln!(out, "    *;").unwrap();
+        writeln!(out, "}};").unwrap();
+    }
+
+    /// Writes an MSVC module-definition file exporting the symbols of the
+    /// functions and globals.
+    pub fn write_def_file<F: Write>(&self, mut out: F) {
+        if self.noop {
+            return;
+        }
+
+        if let Some(ref library) = self.config.export.symbols.library {
+            writeln!(out, "LIBRARY {}", library).unwrap();
+        }
+        writeln!(out, "EXPORTS").unwrap();
+        for (symbol, is_data) in self.exported_symbols() {
+            if is_data {
+                writeln!(out, "    {} DATA", symbol).unwrap();
+            } else {
+                writeln!(out, "    {}", symbol).unwrap();
+            }
+        }
+    }
+
+    /// Writes the umbrella header of `export.umbrella`, which includes the
+    /// headers these bindings depend on, and then the bindings themselves
+    /// from `file_name`.
+    pub fn write_umbrella_header<F: Write>(&self, file: F, file_name: &str) {
+        let umbrella = &self.config.export.umbrella;
+        let mut out = SourceWriter::new(file, self);
+        if let Some(f) = self.config.file_header() {
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if let Some(ref guard) = umbrella.include_guard {
+            out.new_line_if_not_start();
+            write!(out, "#ifndef {}", guard);
+            out.new_line();
+            write!(out, "#define {}", guard);
+            out.new_line();
+        } else if self.config.pragma_once {
+            out.new_line_if_not_start();
+            out.write("#pragma once");
+            out.new_line();
+        }
+        out.new_line_if_not_start();
+        for include in umbrella
+            .includes
+            .iter()
+            .map(|s| s.as_str())
+            .chain(Some(file_name))
+        {
+            write!(out, "#include \"{}\"", include);
+            out.new_line();
+        }
+        if let Some(ref guard) = umbrella.include_guard {
+            out.new_line();
+            if self.config.language == Language::C {
+                write!(out, "#endif /* {} */", guard);
+            } else {
+                write!(out, "#endif // {}", guard);
+            }
+            out.new_line();
+        }
+    }
+
+    /// Writes one file per target of `[targets]`, next to `path` and named
+    /// after it and the target, e.g. `bindings_linux.h`. Returns the path of
+    /// each file, along with whether it changed.
+    pub fn write_targets_to_files<P: AsRef<path::Path>>(
+        &self,
+        path: P,
+    ) -> Vec<(path::PathBuf, bool)> {
+        let mut targets: Vec<_> = self.config.targets.iter().collect();
+        targets.sort_by(|a, b| a.0.cmp(b.0));
+
+        let path = path.as_ref();
+        let mut files = Vec::new();
+        for (name, enabled) in targets {
+            let target_path = sibling_path(path, name);
+            let target = self.for_target(enabled);
+            let changed = target.write_to_file(&target_path);
+            files.push((target_path.clone(), changed));
+            if self.backend.is_some() {
+                continue;
+            }
+            if let Some(helpers_file) = self.config.c_helpers_file() {
+                let helpers_path = sibling_path(&path.with_file_name(helpers_file), name);
+                files.push(target.write_c_helpers_file(&target_path, &helpers_path));
+            }
+            if let Some(header) = self.config.cpp_exceptions_header() {
+                let header_path = sibling_path(&path.with_file_name(header), name);
+                files.push(target.write_cpp_exceptions_file(&target_path, &header_path));
+            }
+            if let Some(source) = self.config.cpp_instantiations_file() {
+                let source_path = sibling_path(&path.with_file_name(source), name);
+                files.push(target.write_cpp_instantiations_file(&target_path, &source_path));
+            }
+            if let Some(wrapper) = self.config.cython_wrapper_file() {
+                let wrapper_path = sibling_path(&path.with_file_name(wrapper), name);
+                files.push(target.write_cython_wrapper_file(&target_path, &wrapper_path));
+            }
+            if let Some(enums) = self.config.cython_python_enums_file() {
+                let enums_path = sibling_path(&path.with_file_name(enums), name);
+                files.push(target.write_python_enums_file(&enums_path));
+            }
+            if let Some(stub) = self.config.cython_stub_file() {
+                let stub_path = sibling_path(&path.with_file_name(stub), name);
+                files.push(target.write_python_stub_file(&stub_path));
+            }
+        }
+        // The symbols of every target are exported.
+        files.extend(self.write_symbol_files(path));
+
+        files
+    }
+
+    /// Writes the items of each top-level module to a file next to `path`,
+    /// named after it and the module, e.g. `bindings_net.h`, and an umbrella
+    /// file including all of them to `path`. The items at the root of the
+    /// crate go to e.g. `bindings_crate.h`. Returns the path of each file,
+    /// along with whether it changed.
+    pub fn write_modules_to_files<P: AsRef<path::Path>>(
+        &self,
+        path: P,
+    ) -> Vec<(path::PathBuf, bool)> {
+        let path = path.as_ref();
+        let file_name = |module: &str| {
+            sibling_path(path, module)
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        };
+
+        let mut modules = BTreeSet::new();
+        let item_paths = self
+            .items
+            .iter()
+            .map(|item| item.deref().path())
+            .chain(self.functions.iter().map(|function| &function.path))
+            .chain(self.constants.iter().map(|constant| &constant.path))
+            .chain(self.globals.iter().map(|global| &global.path));
+        for item_path in item_paths {
+            modules.insert(top_level_module(&self.module_paths, item_path.name()));
+        }
+
+        let mut files: Vec<_> = modules
+            .iter()
+            .map(|module| {
+                let module_path = sibling_path(path, module);
+                let changed = self
+                    .for_module(module, &file_name)
+                    .write_to_file(&module_path);
+                (module_path, changed)
+            })
+            .collect();
+
+        let mut umbrella = self.with_items(Vec::new(), |_| false);
+        let includes = &mut umbrella.config.includes;
+        includes.extend(self.config.export.umbrella.includes.iter().cloned());
+        for module in self.modules_in_dependency_order(&modules) {
+            if self.config.language == Language::Cython {
+                umbrella
+                    .config
+                    .cython
+                    .cimports
+                    .insert(cython_module(&file_name(module)), vec!["*".to_owned()]);
+            } else {
+                umbrella.config.includes.push(file_name(module));
+            }
+        }
+        // The modules declare their items in the namespaces themselves.
+        umbrella.config.namespace = None;
+        umbrella.config.namespaces = None;
+        umbrella.config.inline_namespace = None;
+        let changed = umbrella.write_to_file(path);
+        files.push((path.to_owned(), changed));
+        files.extend(self.write_symbol_files(path));
+        if self.backend.is_some() {
+            return files;
+        }
+        if let Some(helpers_file) = self.config.c_helpers_file() {
+            files.push(self.write_c_helpers_file(path, &path.with_file_name(helpers_file)));
+        }
+        if let Some(header) = self.config.cpp_exceptions_header() {
+            files.push(self.write_cpp_exceptions_file(path, &path.with_file_name(header)));
+        }
+        if let Some(source) = self.config.cpp_instantiations_file() {
+            files.push(self.write_cpp_instantiations_file(path, &path.with_file_name(source)));
+        }
+        if let Some(wrapper) = self.config.cython_wrapper_file() {
+            files.push(self.write_cython_wrapper_file(path, &path.with_file_name(wrapper)));
+        }
+        if let Some(enums) = self.config.cython_python_enums_file() {
+            files.push(self.write_python_enums_file(&path.with_file_name(enums)));
+        }
+        if let Some(stub) = self.config.cython_stub_file() {
+            files.push(self.write_python_stub_file(&path.with_file_name(stub)));
+        }
+
+        files
+    }
+
+    /// Sorts `modules` so that each one comes after the modules it includes,
+    /// and by name otherwise. Modules in a cycle come in the order in which
+    /// they are reached.
+    fn modules_in_dependency_order<'a>(&self, modules: &BTreeSet<&'a str>) -> Vec<&'a str> {
+        fn visit<'a>(
+            module: &'a str,
+            modules: &BTreeSet<&'a str>,
+            dependencies: &HashMap<String, ModuleDependencies>,
+            visited: &mut HashSet<&'a str>,
+            order: &mut Vec<&'a str>,
+        ) {
+            if !visited.insert(module) {
+                return;
+            }
+            if let Some(dependencies_of_module) = dependencies.get(module) {
+                for dependency in &dependencies_of_module.includes {
+                    if let Some(dependency) = modules.get(dependency.as_str()) {
+                        visit(dependency, modules, dependencies, visited, order);
+                    }
+                }
+            }
+            order.push(module);
+        }
+
+        let mut visited = HashSet::new();
+        let mut order = Vec::with_capacity(modules.len());
+        for module in modules {
+            visit(
+                module,
+                modules,
+                &self.module_dependencies,
+                &mut visited,
+                &mut order,
+            );
+        }
+        order
+    }
+
+    /// Returns the bindings of the items of a top-level module, which include
+    /// the files of the modules they depend on.
+    fn for_module(&self, module: &str, file_name: &dyn Fn(&str) -> String) -> Bindings {
+        let in_module = |item_path: &BindgenPath| {
+            top_level_module(&self.module_paths, item_path.name()) == module
+        };
+        let items = self
+            .items
+            .iter()
+            .filter(|item| in_module(item.deref().path()))
+            .cloned()
+            .collect();
+        let mut bindings = self.with_items(items, in_module);
+
+        if let Some(dependencies) = self.module_dependencies.get(module) {
+            if self.config.language == Language::Cython {
+                // Cython has no forward declarations, but cimports can be
+                // circular.
+                let forward_declared = dependencies
+                    .forward_declarations
+                    .iter()
+                    .map(|item| top_level_module(&self.module_paths, item.deref().path().name()));
+                let dependencies: BTreeSet<_> = dependencies
+                    .includes
+                    .iter()
+                    .map(|dependency| dependency.as_str())
+                    .chain(forward_declared)
+                    .collect();
+                for dependency in dependencies {
+                    bindings
+                        .config
+                        .cython
+                        .cimports
+                        .insert(cython_module(&file_name(dependency)), vec!["*".to_owned()]);
+                }
+            } else {
+                bindings.config.includes.extend(
+                    dependencies
+                        .includes
+                        .iter()
+                        .map(|dependency| file_name(dependency)),
+                );
+                bindings.forward_declarations = dependencies.forward_declarations.clone();
+            }
+        }
+        // Each file needs its own guard, as they include each other.
+        let guard: String = file_name(module)
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_uppercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        bindings.config.include_guard = Some(guard);
+
+        bindings
+    }
+
+    /// Returns a copy of these bindings with `items`, and the functions,
+    /// constants and globals whose path satisfies `keep`.
+    fn with_items(
+        &self,
+        items: Vec<ItemContainer>,
+        keep: impl Fn(&BindgenPath) -> bool,
+    ) -> Bindings {
+        let mut config = self.config.clone();
+        // Only the complete bindings have an umbrella header and symbol files.
+        config.export.umbrella.header = None;
+        config.export.symbols.version_script = None;
+        config.export.symbols.def_file = None;
+        Bindings {
+            config,
+            struct_map: self.struct_map.clone(),
+            typedef_map: self.typedef_map.clone(),
+            struct_fileds_memo: Default::default(),
+            globals: self
+                .globals
+                .iter()
+                .filter(|global| keep(&global.path))
+                .cloned()
+                .collect(),
+            constants: self
+                .constants
+                .iter()
+                .filter(|constant| keep(&constant.path))
+                .cloned()
+                .collect(),
+            items,
+            functions: self
+                .functions
+                .iter()
+                .filter(|function| keep(&function.path))
+                .cloned()
+                .collect(),
+            monomorphs: self.monomorphs.clone(),
+            template_instantiations: self
+                .template_instantiations
+                .iter()
+                .filter(|instantiation| match instantiation.ty {
+                    Type::Path(ref generic) => keep(generic.path()),
+                    _ => false,
+                })
+                .cloned()
+                .collect(),
+            module_paths: self.module_paths.clone(),
+            module_dependencies: self.module_dependencies.clone(),
+            forward_declarations: self.forward_declarations.clone(),
+            struct_layouts: self.struct_layouts.clone(),
+            source_locations: self.source_locations.clone(),
+            source_files: self.source_files.clone(),
+            noop: self.noop,
+            changes: self.changes.clone(),
+            backend: self.backend.clone(),
+        }
+    }
+
+    /// Returns the bindings as seen by a target enabling exactly the
+    /// `enabled` cfgs: items whose cfg doesn't hold are dropped, and the
+    /// #ifdef's of the remaining ones are removed.
+    pub fn for_target(&self, enabled: &[String]) -> Bindings {
+        fn retain<T>(items: &mut Vec<T>, mut keep: impl FnMut(&mut T) -> bool) {
+            *items = mem::take(items)
+                .into_iter()
+                .filter_map(|mut item| if keep(&mut item) { Some(item) } else { None })
+                .collect();
+        }
+
+        fn resolve_fields(fields: &mut Vec<Field>, enabled: &[String]) {
+            retain(fields, |field| {
+                Cfg::resolve_for_target(&mut field.cfg, enabled)
+            });
+        }
+
+        fn resolve_struct(item: &mut Struct, enabled: &[String]) -> bool {
+            resolve_fields(&mut item.fields, enabled);
+            retain(&mut item.associated_constants, |constant| {
+                Cfg::resolve_for_target(&mut constant.cfg, enabled)
+            });
+            Cfg::resolve_for_target(&mut item.cfg, enabled)
+        }
+
+        let mut bindings = self.with_items(self.items.clone(), |_| true);
+
+        retain(&mut bindings.globals, |global| {
+            Cfg::resolve_for_target(&mut global.cfg, enabled)
+        });
+        retain(&mut bindings.constants, |constant| {
+            Cfg::resolve_for_target(&mut constant.cfg, enabled)
+        });
+        retain(&mut bindings.functions, |function| {
+            Cfg::resolve_for_target(&mut function.cfg, enabled)
+        });
+        retain(&mut bindings.template_instantiations, |instantiation| {
+            Cfg::resolve_for_target(&mut instantiation.cfg, enabled)
+        });
+        retain(&mut bindings.items, |item| match *item {
+            ItemContainer::Constant(ref mut x) => Cfg::resolve_for_target(&mut x.cfg, enabled),
+            ItemContainer::Static(ref mut x) => Cfg::resolve_for_target(&mut x.cfg, enabled),
+            ItemContainer::OpaqueItem(ref mut x) => Cfg::resolve_for_target(&mut x.cfg, enabled),
+            ItemContainer::Struct(ref mut x) => resolve_struct(x, enabled),
+            ItemContainer::Union(ref mut x) => {
+                resolve_fields(&mut x.fields, enabled);
+                Cfg::resolve_for_target(&mut x.cfg, enabled)
+            }
+            ItemContainer::Enum(ref mut x) => {
+                retain(&mut x.variants, |variant| {
+                    if let VariantBody::Body { ref mut body, .. } = variant.body {
+                        resolve_fields(&mut body.fields, enabled);
+                    }
+                    Cfg::resolve_for_target(&mut variant.cfg, enabled)
+                });
+                Cfg::resolve_for_target(&mut x.cfg, enabled)
+            }
+            ItemContainer::Typedef(ref mut x) => Cfg::resolve_for_target(&mut x.cfg, enabled),
+        });
+
+        bindings
+    }
+
+    /// Writes a report of every instantiation of a generic item, one per
+    /// line, in the form `Instantiation<Args> = MangledName`. Monomorphs are
+    /// only generated for C and Cython, so the report is empty for C++.
+    pub fn write_monomorph_report<F: Write>(&self, mut out: F) {
+        if self.noop {
+            return;
+        }
+
+        for (instantiation, name) in &self.monomorphs {
+            writeln!(out, "{} = {}", instantiation, name).unwrap();
+        }
+    }
+
+    /// The files the bindings were generated from: the Rust sources, the
+    /// manifests and sources of the expanded crates, and the config.
+    pub fn source_files(&self) -> &[path::PathBuf] {
+        &self.source_files
+    }
+
+    /// Writes a depfile, in the Makefile format that Make and Ninja read,
+    /// making `targets` depend on the `source_files`, so that build systems
+    /// only regenerate the bindings when one of them changes.
+    pub fn write_depfile<F: Write, P: AsRef<path::Path>>(&self, targets: &[P], mut out: F) {
+        fn escape(path: &path::Path) -> String {
+            let mut escaped = String::new();
+            for c in path.to_string_lossy().chars() {
+                match c {
+                    ' ' | '#' => escaped.push('\\'),
+                    '$' => escaped.push('$'),
+                    _ => {}
+                }
+                escaped.push(c);
+            }
+            escaped
+        }
+
+        let targets: Vec<_> = targets
+            .iter()
+            .map(|target| escape(target.as_ref()))
+            .collect();
+        write!(out, "{}:", targets.join(" ")).unwrap();
+        for file in &self.source_files {
+            write!(out, " \\\n  {}", escape(file)).unwrap();
+        }
+        writeln!(out).unwrap();
+    }
+
+    /// The standard headers to include, which are the ones the declarations
+    /// need with `minimal_includes`.
+    fn system_includes(&self) -> SystemIncludes {
+        let config = &self.config;
+        if !config.minimal_includes {
+            return SystemIncludes::all(config);
+        }
+
+        let mut includes = SystemIncludes::none(config);
+        for item in self.items() {
+            for ty in item.types() {
+                includes.add_type(ty, config);
+            }
+            match *item {
+                ItemContainer::Struct(ref x) => {
+                    for constant in &x.associated_constants {
+                        includes.add_literal(&constant.value, config);
+                    }
+                }
+                ItemContainer::Enum(ref x) => {
+                    if let Some(repr) = x.repr.ty {
+                        includes.add_primitive(&repr.to_primitive(), config);
+                    }
+                    // The C helper functions return `bool` and `NULL`.
+                    if x.has_c_helper_functions(config) {
+                        includes.stdbool = true;
+                        includes.stddef = true;
+                    }
+                }
+                _ => {}
+            }
+        }
+        for function in self.functions() {
+            for ty in function.types() {
+                includes.add_type(ty, config);
+            }
+        }
+        for constant in self.constants() {
+            includes.add_type(&constant.ty, config);
+            includes.add_literal(&constant.value, config);
+        }
+        for global in self.globals() {
+            includes.add_type(&global.ty, config);
+        }
+        includes
+    }
+
+    pub fn write_headers<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if self.noop {
+            return;
+        }
+
+        if let Some(f) = self.config.file_header() {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if let Some(f) = self.config.include_guard() {
+            out.new_line_if_not_start();
+            write!(out, "#ifndef {}", f);
+            out.new_line();
+            write!(out, "#define {}", f);
+            out.new_line();
+        }
+        if self.config.pragma_once
+            && self.config.language != Language::Cython
+            && self.config.cpp_module().is_none()
+        {
+            out.new_line_if_not_start();
+            write!(out, "#pragma once");
+            out.new_line();
+        }
+        if self.config.include_version {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "/* Generated with cbindgen:{} */",
+                crate::bindgen::config::VERSION
+            );
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if self.config.cpp_module().is_some() {
+            // The includes go to the global module fragment.
+            out.new_line_if_not_start();
+            out.write("module;");
+            out.new_line();
+        }
+
+        if self.config.no_includes
+            && self.config.sys_includes().is_empty()
+            && self.config.includes().is_empty()
+            && (self.config.cython.cimports.is_empty() || self.config.language != Language::Cython)
+            && self.config.after_includes.is_none()
+            && self.config.export_macro().is_none()
+            && !self.uses_alignas_macro()
+        {
+            return;
+        }
+
+        out.new_line_if_not_start();
+
+        if !self.config.no_includes {
+            match self.config.language {
+                Language::C => {
+                    let includes = self.system_includes();
+                    // `static_assert` and `alignof` are keywords since C23.
+                    if self.config.layout.static_asserts && !self.config.c23() {
+                        out.write("#include <assert.h>");
+                        out.new_line();
+                        out.write("#include <stdalign.h>");
+                        out.new_line();
+                    }
+                    if includes.stdarg {
+                        out.write("#include <stdarg.h>");
+                        out.new_line();
+                    }
+                    // `bool` is a keyword since C23.
+                    if includes.stdbool && !self.config.c23() {
+                        out.write("#include <stdbool.h>");
+                        out.new_line();
+                    }
+                    if includes.stddef {
+                        out.write("#include <stddef.h>");
+                        out.new_line();
+                    }
+                    if includes.stdint {
+                        out.write("#include <stdint.h>");
+                        out.new_line();
+                    }
+                    if includes.stdlib {
+                        out.write("#include <stdlib.h>");
+                        out.new_line();
+                    }
+                }
+                Language::Cxx => {
+                    let includes = self.system_includes();
+                    if includes.stdarg {
+                        out.write("#include <cstdarg>");
+                        out.new_line();
+                    }
+                    if includes.stddef {
+                        out.write("#include <cstddef>");
+                        out.new_line();
+                    }
+                    if includes.stdint {
+                        out.write("#include <cstdint>");
+                        out.new_line();
+                    }
+                    if includes.stdlib {
+                        out.write("#include <cstdlib>");
+                        out.new_line();
+                    }
+                    out.write("#include <ostream>");
+                    out.new_line();
+                    out.write("#include <new>");
+                    out.new_line();
+                    if self.config.layout.template_static_asserts {
+                        out.write("#include <type_traits>");
+                        out.new_line();
+                    }
+                    let uses_view = |kind| {
+                        self.functions
+                            .iter()
+                            .any(|f| f.cpp_views.iter().any(|view| view.kind == kind))
+                    };
+                    if uses_view(CppViewKind::Span) {
+                        out.write("#if __cplusplus >= 202002L");
+                        out.new_line();
+                        out.write("#include <span>");
+                        out.new_line();
+                        out.write("#endif");
+                        out.new_line();
+                    }
+                    if self.config.function.nullable_return_wrappers
+                        == NullableReturnWrapper::Optional
+                        && self
+                            .functions
+                            .iter()
+                            .any(|f| f.has_nullable_return_wrapper(&self.config))
+                    {
+                        out.write("#if __cplusplus >= 201703L");
+                        out.new_line();
+                        out.write("#include <optional>");
+                        out.new_line();
+                        out.write("#endif");
+                        out.new_line();
+                    }
+                    if uses_view(CppViewKind::StringView) {
+                        out.write("#include <string_view>");
+                        out.new_line();
+                    }
+                    if self
+                        .items
+                        .iter()
+                        .any(|item| item.deref().annotations().atom("destructor").is_some())
+                    {
+                        out.write("#include <memory>");
+                        out.new_line();
+                    }
+                    if self.items.iter().any(|item| match *item {
+                        ItemContainer::Struct(ref x) => {
+                            self.config.structure.derive_comparisons(&x.annotations)
+                                && x.can_derive_order()
+                        }
+                        _ => false,
+                    }) {
+                        out.write("#if __cplusplus >= 202002L");
+                        out.new_line();
+                        out.write("#include <compare>");
+                        out.new_line();
+                        out.write("#endif");
+                        out.new_line();
+                    }
+                    if self.items.iter().any(|item| match *item {
+                        ItemContainer::Enum(ref x) => x.has_variant_conversions(&self.config),
+                        _ => false,
+                    }) {
+                        out.write("#if __cplusplus >= 201703L");
+                        out.new_line();
+                        out.write("#include <variant>");
+                        out.new_line();
+                        out.write("#endif");
+                        out.new_line();
+                    }
+                    if self.config.enumeration.cast_assert_name.is_none()
+                        && (self.config.enumeration.derive_mut_casts
+                            || self.config.enumeration.derive_const_casts)
+                    {
+                        out.write("#include <cassert>");
+                        out.new_line();
+                    }
+                }
+                Language::Cython => {
+                    self.write_cython_libc_cimports(out);
+                    out.write("cdef extern from *");
+                    out.open_brace();
+                    out.write("ctypedef bint bool");
+                    out.new_line();
+                    out.write("ctypedef struct va_list");
+                    out.new_line();
+                    out.close_brace(false);
+                }
+            }
+        }
+
+        for include in self.config.sys_includes() {
+            write!(out, "#include <{}>", include);
+            out.new_line();
+        }
+
+        for include in self.config.includes() {
+            write!(out, "#include \"{}\"", include);
+            out.new_line();
+        }
+
+        if self.config.language == Language::Cython {
+            for (module, names) in &self.config.cython.cimports {
+                write!(out, "from {} cimport {}", module, names.join(", "));
+                out.new_line();
+            }
+        }
+
+        if self.config.export_macro().is_some() {
+            match self.config.export_macro.header {
+                Some(ref header) => {
+                    write!(out, "#include \"{}\"", header);
+                    out.new_line();
+                }
+                None => {
+                    out.new_line_if_not_start();
+                    self.write_export_macro_definition(out);
+                }
+            }
+        }
+
+        if self.uses_alignas_macro() {
+            out.new_line_if_not_start();
+            self.write_alignas_macro_definition(out);
+        }
+
+        if let Some(ref line) = self.config.after_includes {
+            write!(out, "{}", line);
+            out.new_line();
+        }
+    }
+
+    /// Writes the cimports of the types of `libc.stdint` and `libc.stddef`,
+    /// which are the ones the declarations use with `minimal_includes`.
+    fn write_cython_libc_cimports<F: Write>(&self, out: &mut SourceWriter<F>) {
+        let style = self.config.cython.libc_cimports;
+        if style == CimportStyle::From && !self.config.minimal_includes {
+            out.write("from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t");
+            out.new_line();
+            out.write("from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t");
+            out.new_line();
+            return;
+        }
+
+        let mut modules = BTreeMap::new();
+        for name in self.system_includes().cython_types {
+            if let Some(module) = cython_cimport_module(name) {
+                modules.entry(module).or_insert_with(Vec::new).push(name);
+            }
+        }
+        for (module, names) in modules {
+            match style {
+                CimportStyle::From => write!(out, "from {} cimport {}", module, names.join(", ")),
+                CimportStyle::Qualified => write!(out, "cimport {}", module),
+            }
+            out.new_line();
+        }
+    }
+
+    /// Whether a struct or union is aligned with the `layout.alignas` macro.
+    fn uses_alignas_macro(&self) -> bool {
+        if self.config.alignas_macro().is_none() {
+            return false;
+        }
+        self.config.int128 == Int128Style::Struct
+            || self.items.iter().any(|item| {
+                let alignment = match *item {
+                    ItemContainer::Struct(ref s) => s.alignment,
+                    ItemContainer::Union(ref u) => u.alignment,
+                    _ => None,
+                };
+                matches!(alignment, Some(ReprAlign::Align(_)))
+            })
+    }
+
+    /// Defines the alignment macro as the standard `_Alignas`, or as what C++
+    /// and MSVC understand instead.
+    fn write_alignas_macro_definition<F: Write>(&self, out: &mut SourceWriter<F>) {
+        let name = match self.config.alignas_macro() {
+            Some(name) => name,
+            None => return,
+        };
+        write!(out, "#ifndef {}", name);
+        out.new_line();
+        let mut directive = "#if";
+        if self.config.cpp_compat {
+            out.write("#if defined(__cplusplus)");
+            out.new_line();
+            write!(out, "#define {}(n) alignas(n)", name);
+            out.new_line();
+            directive = "#elif";
+        }
+        write!(out, "{} defined(_MSC_VER)", directive);
+        out.new_line();
+        write!(out, "#define {}(n) __declspec(align(n))", name);
+        out.new_line();
+        out.write("#else");
+        out.new_line();
+        write!(out, "#define {}(n) _Alignas(n)", name);
+        out.new_line();
+        out.write("#endif");
+        out.new_line();
+        write!(out, "#endif // {}", name);
+        out.new_line();
+    }
+
+    /// Writes the companion header defining the export macro, which the
+    /// bindings include with `export_macro.header`.
+    pub fn write_export_macro_header<F: Write>(&self, file: F) {
+        let mut out = SourceWriter::new(file, self);
+        if let Some(f) = self.config.file_header() {
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        if let Some(ref f) = self.config.autogen_warning {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+        out.new_line_if_not_start();
+        self.write_export_macro_definition(&mut out);
+    }
+
+    /// Defines the export macro as exporting symbols when building the library,
+    /// and as importing them otherwise.
+    fn write_export_macro_definition<F: Write>(&self, out: &mut SourceWriter<F>) {
+        let name = match self.config.export_macro() {
+            Some(name) => name,
+            None => return,
+        };
+        let build_define = self.config.export_macro.build_define();
+        write!(out, "#ifndef {}", name);
+        out.new_line();
+        let mut directive = "#if";
+        if let Some(ref static_define) = self.config.export_macro.static_define {
+            write!(out, "#if defined({})", static_define);
+            out.new_line();
+            write!(out, "#define {}", name);
+            out.new_line();
+            directive = "#elif";
+        }
+        write!(out, "{} defined(_WIN32) || defined(__CYGWIN__)", directive);
+        out.new_line();
+        write!(out, "#if defined({})", build_define);
+        out.new_line();
+        write!(out, "#define {} __declspec(dllexport)", name);
+        out.new_line();
+        out.write("#else");
+        out.new_line();
+        write!(out, "#define {} __declspec(dllimport)", name);
+        out.new_line();
+        out.write("#endif");
+        out.new_line();
+        out.write("#elif defined(__GNUC__) && __GNUC__ >= 4");
+        out.new_line();
+        write!(
+            out,
+            "#define {} __attribute__((visibility(\"default\")))",
+            name
+        );
+        out.new_line();
+        out.write("#else");
+        out.new_line();
+        write!(out, "#define {}", name);
+        out.new_line();
+        out.write("#endif");
+        out.new_line();
+        write!(out, "#endif // {}", name);
+        out.new_line();
+    }
+
+    /// Declares the types `i128` and `u128` are emitted as, unless they are
+    /// emitted as builtin types or not handled at all.
+    fn write_int128_types<F: Write>(&self, out: &mut SourceWriter<F>) {
+        match (self.config.int128, self.config.language) {
+            (Int128Style::None, _) => {}
+            (Int128Style::Builtin, Language::Cython) => {
+                out.new_line_if_not_start();
+                out.write("ctypedef long long cbindgen_int128_t \"__int128\"");
+                out.new_line();
+                out.write("ctypedef unsigned long long cbindgen_uint128_t \"unsigned __int128\"");
+                out.new_line();
+            }
+            (Int128Style::Builtin, _) => {}
+            (Int128Style::Struct, language) => {
+                // The halves are in little-endian order, as on every target with
+                // a 128-bit integer ABI that this is useful for.
+                for (name, hi) in &[
+                    ("cbindgen_int128_t", "int64_t"),
+                    ("cbindgen_uint128_t", "uint64_t"),
+                ] {
+                    out.new_line_if_not_start();
+                    if language == Language::Cython {
+                        write!(out, "ctypedef struct {}", name);
+                    } else {
+                        // Aligned like Rust's 128-bit integers, as a
+                        // `#[repr(align(16))]` struct would be.
+                        out.write("typedef struct");
+                        if let Some(ref anno) = self.config.layout.aligned_n {
+                            write!(out, " {}(16)", anno);
+                        } else if language == Language::Cxx && self.config.layout.alignas {
+                            out.write(" alignas(16)");
+                        }
+                        write!(out, " {}", name);
+                    }
+                    out.open_brace();
+                    if let Some(alignas) = self.config.alignas_macro() {
+                        write!(out, "{}(16) ", alignas);
+                    }
+                    out.write("uint64_t lo;");
+                    out.new_line();
+                    write!(out, "{} hi;", hi);
+                    out.close_brace(false);
+                    if language != Language::Cython {
+                        write!(out, " {};", name);
+                    }
+                    out.new_line();
+                }
+            }
+        }
+    }
+
+    /// Declares `char8_t` as `char`, which is also the type of `u8` string
+    /// literals, for C++ without it, when the functions use it.
+    fn write_char8_t_fallback<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if !self
+            .functions
+            .iter()
+            .any(|function| function.uses_char8_t(&self.config))
+        {
+            return;
+        }
+        out.new_line_if_not_start();
+        out.write("#if !defined(__cpp_char8_t)");
+        out.new_line();
+        out.write("typedef char char8_t;");
+        out.new_line();
+        out.write("#endif");
+        out.new_line();
+    }
+
+    /// The structs, unions, enums, opaque items and typedefs, in the order
+    /// they are written in.
+    pub fn items(&self) -> &[ItemContainer] {
+        &self.items
+    }
+
+    /// The functions, in the order they are written in.
+    pub fn functions(&self) -> &[Function] {
+        &self.functions
+    }
+
+    /// The constants, in the order they are written in.
+    pub fn constants(&self) -> &[Constant] {
+        &self.constants
+    }
 
-        if let Some(ref line) = self.config.after_includes {
-            write!(out, "{}", line);
-            out.new_line();
-        }
+    /// The globals, in the order they are written in.
+    pub fn globals(&self) -> &[Static] {
+        &self.globals
     }
 
-    pub fn write<F: Write>(&self, file: F) {
+    pub fn write<F: Write>(&self, mut file: F) {
         if self.noop {
             return;
         }
 
+        if let Some(SharedBackend(ref backend)) = self.backend {
+            let file: &mut dyn Write = &mut file;
+            backend.write_bindings(self, &mut SourceWriter::new(file, self));
+            return;
+        }
+
         let mut out = SourceWriter::new(file, self);
 
         self.write_headers(&mut out);
 
+        if let Some(module) = self.config.cpp_module() {
+            out.new_line_if_not_start();
+            write!(out, "export module {};", module);
+            out.new_line();
+            out.new_line();
+            out.write("export {");
+            out.new_line();
+        }
+
         self.open_namespaces(&mut out);
 
+        self.write_int128_types(&mut out);
+
+        self.write_char8_t_fallback(&mut out);
+
+        self.write_forward_declarations(&mut out);
+
+        self.write_doxygen_groups(&mut out);
+
+        let mut group = None;
+        let mut module_path: &[String] = &[];
         for constant in &self.constants {
             if constant.uses_only_primitive_types() {
+                let constant_module_path = self.module_path(constant.path.name());
+                self.switch_module_namespaces(
+                    &mut module_path,
+                    constant_module_path,
+                    &mut group,
+                    &mut out,
+                );
+                self.switch_doxygen_group(&mut group, constant.path.name(), &mut out);
                 out.new_line_if_not_start();
+                self.write_line_directive(constant.path.name(), &mut out);
                 constant.write(&self.config, &mut out, None);
+                self.write_source_location_comment(constant.path.name(), &mut out);
                 out.new_line();
             }
         }
-
         for item in &self.items {
             if item
                 .deref()
@@ -319,7 +1949,12 @@ impl Bindings {
                 continue;
             }
 
+            let item_module_path = self.module_path(item.deref().path().name());
+            self.switch_module_namespaces(&mut module_path, item_module_path, &mut group, &mut out);
+            self.switch_doxygen_group(&mut group, item.deref().path().name(), &mut out);
+
             out.new_line_if_not_start();
+            self.write_line_directive(item.deref().path().name(), &mut out);
             match *item {
                 ItemContainer::Constant(..) => unreachable!(),
                 ItemContainer::Static(..) => unreachable!(),
@@ -329,16 +1964,32 @@ impl Bindings {
                 ItemContainer::OpaqueItem(ref x) => x.write(&self.config, &mut out),
                 ItemContainer::Typedef(ref x) => x.write(&self.config, &mut out),
             }
+            self.write_source_location_comment(item.deref().path().name(), &mut out);
             out.new_line();
         }
-
         for constant in &self.constants {
             if !constant.uses_only_primitive_types() {
+                let constant_module_path = self.module_path(constant.path.name());
+                self.switch_module_namespaces(
+                    &mut module_path,
+                    constant_module_path,
+                    &mut group,
+                    &mut out,
+                );
+                self.switch_doxygen_group(&mut group, constant.path.name(), &mut out);
                 out.new_line_if_not_start();
+                self.write_line_directive(constant.path.name(), &mut out);
                 constant.write(&self.config, &mut out, None);
+                self.write_source_location_comment(constant.path.name(), &mut out);
                 out.new_line();
             }
         }
+        self.switch_module_namespaces(&mut module_path, &[], &mut group, &mut out);
+        self.close_doxygen_group(&mut group, &mut out);
+
+        if self.config.cpp_instantiations_file().is_some() {
+            self.write_template_instantiations("extern template", &mut out);
+        }
 
         if !self.functions.is_empty() || !self.globals.is_empty() {
             if self.config.cpp_compatible_c() {
@@ -368,16 +2019,38 @@ impl Bindings {
             }
 
             for global in &self.globals {
+                let global_module_path = self.module_path(global.path.name());
+                self.switch_module_namespaces(
+                    &mut module_path,
+                    global_module_path,
+                    &mut group,
+                    &mut out,
+                );
+                self.switch_doxygen_group(&mut group, global.path.name(), &mut out);
                 out.new_line_if_not_start();
+                self.write_line_directive(global.path.name(), &mut out);
                 global.write(&self.config, &mut out);
+                self.write_source_location_comment(global.path.name(), &mut out);
                 out.new_line();
             }
 
             for function in &self.functions {
+                let function_module_path = self.module_path(&function.rust_name);
+                self.switch_module_namespaces(
+                    &mut module_path,
+                    function_module_path,
+                    &mut group,
+                    &mut out,
+                );
+                self.switch_doxygen_group(&mut group, function.path.name(), &mut out);
                 out.new_line_if_not_start();
+                self.write_line_directive(function.path.name(), &mut out);
                 function.write(&self.config, &mut out);
+                self.write_source_location_comment(function.path.name(), &mut out);
                 out.new_line();
             }
+            self.switch_module_namespaces(&mut module_path, &[], &mut group, &mut out);
+            self.close_doxygen_group(&mut group, &mut out);
 
             if self.config.cpp_compatible_c() {
                 out.new_line();
@@ -390,13 +2063,68 @@ impl Bindings {
                 out.new_line();
             }
 
+            for function in &self.functions {
+                if !function.cpp_views.is_empty() && self.config.language == Language::Cxx {
+                    let function_module_path = self.module_path(&function.rust_name);
+                    self.switch_module_namespaces(
+                        &mut module_path,
+                        function_module_path,
+                        &mut group,
+                        &mut out,
+                    );
+                    out.new_line();
+                    function.write_cpp_view_overload(&self.config, &mut out);
+                    out.new_line();
+                }
+            }
+
+            for function in &self.functions {
+                if function.has_default_arg_overloads(&self.config) {
+                    out.new_line();
+                    function.write_default_arg_overloads(&self.config, &mut out);
+                    out.new_line();
+                }
+            }
+
+            for function in &self.functions {
+                if function.has_nullable_return_wrapper(&self.config) {
+                    let function_module_path = self.module_path(&function.rust_name);
+                    self.switch_module_namespaces(
+                        &mut module_path,
+                        function_module_path,
+                        &mut group,
+                        &mut out,
+                    );
+                    out.new_line();
+                    function.write_nullable_return_wrapper(&self.config, &mut out);
+                    out.new_line();
+                }
+            }
+            self.switch_module_namespaces(&mut module_path, &[], &mut group, &mut out);
+
+            self.write_cpp_deleters(&mut out);
+
+            self.write_cpp_method_definitions(&mut out);
+
+            self.write_generic_overloads(&mut out);
+
             if self.config.cpp_compatible_c() {
                 out.write("#endif // __cplusplus");
                 out.new_line();
             }
+
+            self.write_generic_macros(&mut out);
         }
 
-        if self.config.language == Language::Cython
+        if let Some(extra_defs) = self.config.cython_extra_defs() {
+            out.new_line_if_not_start();
+            for (i, line) in extra_defs.trim_matches('\n').lines().enumerate() {
+                if i != 0 {
+                    out.new_line();
+                }
+                write!(out, "{}", line);
+            }
+        } else if self.config.language == Language::Cython
             && self.globals.is_empty()
             && self.constants.is_empty()
             && self.items.is_empty()
@@ -407,6 +2135,12 @@ impl Bindings {
 
         self.close_namespaces(&mut out);
 
+        if self.config.cpp_module().is_some() {
+            out.new_line_if_not_start();
+            out.write("} // export");
+            out.new_line();
+        }
+
         if let Some(f) = self.config.include_guard() {
             out.new_line_if_not_start();
             if self.config.language == Language::C {
@@ -416,7 +2150,7 @@ impl Bindings {
             }
             out.new_line();
         }
-        if let Some(ref f) = self.config.trailer {
+        if let Some(f) = self.config.file_trailer() {
             out.new_line_if_not_start();
             write!(out, "{}", f);
             if !f.ends_with('\n') {
@@ -425,6 +2159,601 @@ impl Bindings {
         }
     }
 
+    /// Writes a `#line` directive pointing at where the item named `name` was
+    /// declared in the Rust sources, for `line_directives`.
+    fn write_line_directive<F: Write>(&self, name: &str, out: &mut SourceWriter<F>) {
+        if !self.config.line_directives || self.config.language == Language::Cython {
+            return;
+        }
+        if let Some(location) = self.source_locations.get(name) {
+            write!(
+                out,
+                "#line {} \"{}\"",
+                location.line,
+                location.file.replace('\\', "\\\\")
+            );
+            out.new_line();
+        }
+    }
+
+    /// Writes a comment giving where the item named `name` was declared in the
+    /// Rust sources on the line after it, for `documentation_source_location`.
+    fn write_source_location_comment<F: Write>(&self, name: &str, out: &mut SourceWriter<F>) {
+        if !self.config.documentation_source_location {
+            return;
+        }
+        if let Some(location) = self.source_locations.get(name) {
+            out.new_line();
+            if self.config.language == Language::Cython {
+                write!(out, "# defined in {}:{}", location.file, location.line);
+            } else {
+                write!(out, "/* defined in {}:{} */", location.file, location.line);
+            }
+        }
+    }
+
+    /// The function freeing the opaque type or struct `item`, as given by an
+    /// annotation like `cbindgen:destructor=foo_free`. Generic types and
+    /// functions which aren't exported have no deleter.
+    fn destructor(&self, item: &ItemContainer) -> Option<&Function> {
+        if self.config.language == Language::C {
+            return None;
+        }
+        let generic = match *item {
+            ItemContainer::OpaqueItem(ref x) => !x.generic_params.is_empty(),
+            ItemContainer::Struct(ref x) => !x.generic_params.is_empty(),
+            _ => return None,
+        };
+        let name = item.deref().annotations().atom("destructor")??;
+        let function = self
+            .functions
+            .iter()
+            .find(|function| function.path.name() == name);
+        if generic {
+            diagnostic::report(
+                Level::Warn,
+                DiagnosticKind::SkippedHelper,
+                None,
+                format_args!(
+                    "Skipping the deleter of the generic {}.",
+                    item.deref().export_name()
+                ),
+            );
+            return None;
+        }
+        let function = match function {
+            Some(function) => function,
+            None => {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::SkippedHelper,
+                    None,
+                    format_args!(
+                        "Skipping the deleter of {}, as its destructor {} isn't exported.",
+                        item.deref().export_name(),
+                        name
+                    ),
+                );
+                return None;
+            }
+        };
+        let frees_item = match function.args[..] {
+            [ref arg] => match arg.ty {
+                Type::Ptr {
+                    ref ty,
+                    is_const: false,
+                    ..
+                } => matches!(**ty, Type::Path(ref generic)
+                    if generic.path() == item.deref().path()),
+                _ => false,
+            },
+            _ => false,
+        };
+        if !frees_item {
+            diagnostic::report(
+                Level::Warn,
+                DiagnosticKind::SkippedHelper,
+                None,
+                format_args!(
+                    "Skipping the deleter of {}, as its destructor {} doesn't take a \
+                     mutable pointer to it.",
+                    item.deref().export_name(),
+                    name
+                ),
+            );
+            return None;
+        }
+        Some(function)
+    }
+
+    /// Writes a `<Name>Deleter` calling the destructor of each type which has
+    /// one, and a `<Name>Ptr` alias of `std::unique_ptr` using it, so that C++
+    /// code can own the pointers returned by the bindings.
+    fn write_cpp_deleters<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if self.config.language != Language::Cxx {
+            return;
+        }
+        for item in &self.items {
+            let function = match self.destructor(item) {
+                Some(function) => function,
+                None => continue,
+            };
+            let name = item.deref().export_name();
+            let condition = item
+                .deref()
+                .cfg()
+                .and_then(|cfg| cfg.to_condition(&self.config));
+            // The destructor usually has the same cfg as the type.
+            let function_condition = if function.cfg.as_ref() == item.deref().cfg() {
+                None
+            } else {
+                function.cfg.to_condition(&self.config)
+            };
+            // The deleter is declared along with the type, so the destructor
+            // is qualified by its own module.
+            let module_path = self.module_path(item.deref().path().name());
+            let mut destructor = self.module_path(&function.rust_name).join("::");
+            if !destructor.is_empty() {
+                destructor.push_str("::");
+            }
+            destructor.push_str(function.path.name());
+            self.open_module_namespaces(module_path, out);
+            out.new_line();
+            condition.write_before(&self.config, out);
+            function_condition.write_before(&self.config, out);
+            write!(out, "struct {}Deleter", name);
+            out.open_brace();
+            write!(out, "void operator()({} *ptr) const", name);
+            out.open_brace();
+            write!(out, "{}(ptr);", destructor);
+            out.close_brace(false);
+            out.close_brace(true);
+            out.new_line();
+            out.new_line();
+            write!(
+                out,
+                "using {}Ptr = std::unique_ptr<{}, {}Deleter>;",
+                name, name, name
+            );
+            function_condition.write_after(&self.config, out);
+            condition.write_after(&self.config, out);
+            out.new_line();
+            self.close_module_namespaces(module_path, out);
+        }
+    }
+
+    /// Whether the struct `s` can have C++ member functions. In C, the struct
+    /// needs a tag, as C++ doesn't allow member functions in unnamed structs.
+    fn can_have_cpp_methods(&self, s: &Struct) -> bool {
+        s.generic_params.is_empty()
+            && (self.config.language == Language::Cxx
+                || (self.config.cpp_compatible_c() && self.config.style.generate_tag()))
+    }
+
+    /// The C++ member functions of the struct `s`, as given by `method-of`
+    /// annotations on the functions taking a pointer to it first.
+    pub(crate) fn cpp_methods(&self, s: &Struct) -> Vec<CppMethod> {
+        if !self.can_have_cpp_methods(s) {
+            return Vec::new();
+        }
+        self.functions
+            .iter()
+            .filter_map(|function| function.cpp_method()?.ok())
+            .filter(|method| method.of == s.path.name() && method.this_type == s.export_name)
+            .collect()
+    }
+
+    /// Writes the definitions of the C++ member functions declared in the
+    /// bodies of the structs, after the functions they forward to.
+    fn write_cpp_method_definitions<F: Write>(&self, out: &mut SourceWriter<F>) {
+        for function in &self.functions {
+            let method = match function.cpp_method() {
+                Some(Ok(method)) => method,
+                Some(Err(msg)) => {
+                    diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::SkippedHelper,
+                        None,
+                        format_args!(
+                            "Skipping the member function of {}: {}",
+                            function.rust_name, msg
+                        ),
+                    );
+                    continue;
+                }
+                None => continue,
+            };
+            let s = self.items.iter().find_map(|item| match *item {
+                ItemContainer::Struct(ref s) if s.path.name() == method.of => Some(s),
+                _ => None,
+            });
+            let s = match s {
+                Some(s) if s.export_name == method.this_type && self.can_have_cpp_methods(s) => s,
+                Some(s) if s.export_name != method.this_type => {
+                    diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::SkippedHelper,
+                        None,
+                        format_args!(
+                            "Skipping the member function of {}, as it doesn't take a {} first.",
+                            function.rust_name, method.of
+                        ),
+                    );
+                    continue;
+                }
+                Some(_) => {
+                    diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::SkippedHelper,
+                        None,
+                        format_args!(
+                            "Skipping the member function of {}, as {} can't have any.",
+                            function.rust_name, method.of
+                        ),
+                    );
+                    continue;
+                }
+                None => {
+                    diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::SkippedHelper,
+                        None,
+                        format_args!(
+                            "Skipping the member function of {}, as {} isn't an exported struct.",
+                            function.rust_name, method.of
+                        ),
+                    );
+                    continue;
+                }
+            };
+            // The function is fully qualified, as a member function of the
+            // same name would hide it.
+            let module_path = self.module_path(s.path.name());
+            let mut call = String::new();
+            for namespace in self.all_namespaces().into_iter().chain(
+                self.module_path(&function.rust_name)
+                    .iter()
+                    .map(|x| x.as_str()),
+            ) {
+                call.push_str("::");
+                call.push_str(namespace);
+            }
+            call.push_str("::");
+            call.push_str(function.path.name());
+            let condition = s
+                .cfg
+                .as_ref()
+                .and_then(|cfg| cfg.to_condition(&self.config));
+            self.open_module_namespaces(module_path, out);
+            out.new_line();
+            condition.write_before(&self.config, out);
+            method.write_definition(&s.export_name, &call, &self.config, out);
+            condition.write_after(&self.config, out);
+            out.new_line();
+            self.close_module_namespaces(module_path, out);
+        }
+    }
+
+    /// The functions sharing a generic name, as given by `generic_name`, in
+    /// the order of their first function.
+    fn generic_groups<'a>(
+        &'a self,
+        generic_name: impl Fn(&'a Function) -> Option<Cow<'a, str>>,
+    ) -> Vec<(Cow<'a, str>, Vec<&'a Function>)> {
+        let mut groups: Vec<(Cow<str>, Vec<&Function>)> = Vec::new();
+        for function in &self.functions {
+            let name = match generic_name(function) {
+                Some(name) => name,
+                None => continue,
+            };
+            match groups.iter_mut().find(|(group, _)| *group == name) {
+                Some((_, functions)) => functions.push(function),
+                None => groups.push((name, vec![function])),
+            }
+        }
+        groups
+    }
+
+    /// Returns how `ty` is written, to tell the argument types apart.
+    fn type_name(&self, ty: &Type) -> String {
+        let mut name = Vec::new();
+        cdecl::write_type(&mut SourceWriter::new(&mut name, self), ty, &self.config);
+        String::from_utf8(name).unwrap()
+    }
+
+    /// Writes the inline C++ overloads sharing the name of a group of
+    /// functions, each of which forwards to one of them.
+    fn write_generic_overloads<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if self.config.language != Language::Cxx && !self.config.cpp_compatible_c() {
+            return;
+        }
+
+        let groups = self.generic_groups(|f| f.generic_overload(&self.config));
+        for (name, functions) in groups {
+            if functions.len() < 2 {
+                continue;
+            }
+            if self.functions.iter().any(|f| f.path.name() == name) {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::SkippedHelper,
+                    None,
+                    format_args!(
+                        "Skipping the overloads {}, which would hide the function of that name.",
+                        name
+                    ),
+                );
+                continue;
+            }
+            let signatures: Vec<Vec<String>> = functions
+                .iter()
+                .map(|f| f.args.iter().map(|arg| self.type_name(&arg.ty)).collect())
+                .collect();
+            if (1..signatures.len()).any(|i| signatures[..i].contains(&signatures[i])) {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::SkippedHelper,
+                    None,
+                    format_args!(
+                        "Skipping the overloads {}, as several of its functions take the same \
+                         arguments.",
+                        name
+                    ),
+                );
+                continue;
+            }
+            for function in functions {
+                // The overloads are declared outside of the namespaces of the
+                // modules of the functions.
+                let mut callee = self.module_path(&function.rust_name).join("::");
+                if !callee.is_empty() {
+                    callee.push_str("::");
+                }
+                callee.push_str(function.path.name());
+                out.new_line();
+                function.write_cpp_generic_overload(&name, &callee, &self.config, out);
+                out.new_line();
+            }
+        }
+    }
+
+    /// Writes the `_Generic` macros dispatching to the functions sharing a
+    /// generic macro name on the type of their first argument.
+    fn write_generic_macros<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if self.config.language != Language::C {
+            return;
+        }
+
+        let groups = self.generic_groups(|f| f.generic_macro(&self.config));
+        let type_name = |function: &Function| self.type_name(&function.args[0].ty);
+
+        let mut macros = Vec::new();
+        for (name, functions) in groups {
+            if functions.len() < 2 {
+                continue;
+            }
+            if self.functions.iter().any(|f| f.path.name() == name) {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::SkippedHelper,
+                    None,
+                    format_args!(
+                    "Skipping the _Generic macro {}, which would hide the function of that name.",
+                    name
+                ),
+                );
+                continue;
+            }
+            if let Some(function) = functions.iter().find(|f| f.cfg.is_some()) {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::SkippedHelper,
+                    None,
+                    format_args!(
+                        "Skipping the _Generic macro {}, as {} is only declared under a cfg.",
+                        name,
+                        function.path.name()
+                    ),
+                );
+                continue;
+            }
+            let arity = functions[0].args.len();
+            if arity == 0 || functions.iter().any(|f| f.args.len() != arity) {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::SkippedHelper,
+                    None,
+                    format_args!(
+                        "Skipping the _Generic macro {}, as its functions don't take the same \
+                         number of arguments.",
+                        name
+                    ),
+                );
+                continue;
+            }
+            let types: Vec<String> = functions.iter().map(|f| type_name(f)).collect();
+            if (1..types.len()).any(|i| types[..i].contains(&types[i])) {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::SkippedHelper,
+                    None,
+                    format_args!(
+                        "Skipping the _Generic macro {}, as several of its functions take the \
+                         same type of first argument.",
+                        name
+                    ),
+                );
+                continue;
+            }
+            macros.push((name, functions, types));
+        }
+
+        if macros.is_empty() {
+            return;
+        }
+
+        if self.config.cpp_compatible_c() {
+            out.new_line_if_not_start();
+            out.write("#ifndef __cplusplus");
+            out.new_line();
+        }
+
+        let indent = " ".repeat(self.config.tab_width);
+        for (name, functions, types) in macros {
+            let args: Vec<String> = functions[0]
+                .args
+                .iter()
+                .enumerate()
+                .map(|(i, arg)| arg.name.clone().unwrap_or_else(|| format!("arg{}", i)))
+                .collect();
+
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "#define {}({}) _Generic(({}), \\",
+                name,
+                args.join(", "),
+                args[0]
+            );
+            for (i, (function, ty)) in functions.iter().zip(&types).enumerate() {
+                out.new_line();
+                write!(out, "{}{}: {}", indent, ty, function.path.name());
+                if i + 1 != functions.len() {
+                    out.write(", \\");
+                }
+            }
+            write!(out, ")({})", args.join(", "));
+            out.new_line();
+        }
+
+        if self.config.cpp_compatible_c() {
+            out.new_line();
+            out.write("#endif // __cplusplus");
+            out.new_line();
+        }
+    }
+
+    /// The module path of the item named `name`, for the Doxygen group to put
+    /// it in.
+    fn doxygen_group(&self, name: &str) -> Option<&[String]> {
+        if !self.config.doxygen_groups() {
+            return None;
+        }
+        self.module_paths
+            .get(name)
+            .map(|module_path| &module_path[..])
+            .filter(|module_path| !module_path.is_empty())
+    }
+
+    /// Defines the groups of the modules of the items, nested in the groups
+    /// of their parent modules.
+    fn write_doxygen_groups<F: Write>(&self, out: &mut SourceWriter<F>) {
+        let names = self
+            .items
+            .iter()
+            .map(|item| item.deref().path())
+            .chain(self.functions.iter().map(|function| &function.path))
+            .chain(self.constants.iter().map(|constant| &constant.path))
+            .chain(self.globals.iter().map(|global| &global.path));
+        let mut groups = BTreeSet::new();
+        for name in names {
+            if let Some(module_path) = self.doxygen_group(name.name()) {
+                for len in 1..=module_path.len() {
+                    groups.insert(&module_path[..len]);
+                }
+            }
+        }
+
+        let doxygen = &self.config.doxygen;
+        for group in groups {
+            out.new_line_if_not_start();
+            out.write("/**");
+            out.new_line();
+            write!(
+                out,
+                " * \\defgroup {} {}",
+                doxygen.group_name(group),
+                doxygen.group_title(group)
+            );
+            out.new_line();
+            if group.len() > 1 {
+                write!(
+                    out,
+                    " * \\ingroup {}",
+                    doxygen.group_name(&group[..group.len() - 1])
+                );
+                out.new_line();
+            }
+            out.write(" */");
+            out.new_line();
+        }
+    }
+
+    /// Adds the item named `name` to its Doxygen group, closing the group of
+    /// the previous item if it's a different one.
+    fn switch_doxygen_group<'a, F: Write>(
+        &'a self,
+        current: &mut Option<&'a [String]>,
+        name: &str,
+        out: &mut SourceWriter<F>,
+    ) {
+        let group = self.doxygen_group(name);
+        if *current == group {
+            return;
+        }
+        self.close_doxygen_group(current, out);
+        if let Some(group) = group {
+            out.new_line_if_not_start();
+            write!(
+                out,
+                "/** \\addtogroup {}",
+                self.config.doxygen.group_name(group)
+            );
+            out.new_line();
+            out.write(" * @{");
+            out.new_line();
+            out.write(" */");
+            out.new_line();
+        }
+        *current = group;
+    }
+
+    fn close_doxygen_group<F: Write>(
+        &self,
+        current: &mut Option<&[String]>,
+        out: &mut SourceWriter<F>,
+    ) {
+        if current.take().is_some() {
+            out.new_line_if_not_start();
+            out.write("/** @} */");
+            out.new_line();
+        }
+    }
+
+    fn write_forward_declarations<F: Write>(&self, out: &mut SourceWriter<F>) {
+        if self.forward_declarations.is_empty() {
+            return;
+        }
+        out.new_line_if_not_start();
+        for item in &self.forward_declarations {
+            let module_path = self.module_path(item.deref().path().name());
+            for module in module_path {
+                write!(out, "namespace {} {{", module);
+                out.new_line();
+            }
+            let keyword = match *item {
+                ItemContainer::Union(..) => "union",
+                _ => "struct",
+            };
+            write!(out, "{} {};", keyword, item.deref().export_name());
+            out.new_line();
+            for module in module_path.iter().rev() {
+                write!(out, "}} // namespace {}", module);
+                out.new_line();
+            }
+        }
+    }
+
     fn all_namespaces(&self) -> Vec<&str> {
         if self.config.language != Language::Cxx && !self.config.cpp_compatible_c() {
             return vec![];
@@ -454,7 +2783,16 @@ impl Bindings {
             return;
         }
 
-        let mut namespaces = self.all_namespaces();
+        let mut namespaces: Vec<_> = self
+            .all_namespaces()
+            .into_iter()
+            .map(|namespace| ("", namespace))
+            .collect();
+        if let Some(ref namespace) = self.config.inline_namespace {
+            if self.config.language == Language::Cxx || self.config.cpp_compatible_c() {
+                namespaces.push(("inline ", namespace));
+            }
+        }
         if namespaces.is_empty() {
             return;
         }
@@ -468,10 +2806,10 @@ impl Bindings {
             out.write("#ifdef __cplusplus");
         }
 
-        for namespace in namespaces {
+        for (inline, namespace) in namespaces {
             out.new_line();
             match op {
-                NamespaceOperation::Open => write!(out, "namespace {} {{", namespace),
+                NamespaceOperation::Open => write!(out, "{}namespace {} {{", inline, namespace),
                 NamespaceOperation::Close => write!(out, "}} // namespace {}", namespace),
             }
         }
@@ -483,6 +2821,63 @@ impl Bindings {
         }
     }
 
+    /// The module path of the item named `name` in Rust, for the namespaces
+    /// to declare it in.
+    fn module_path(&self, name: &str) -> &[String] {
+        if !self.config.export.namespace_from_modules || self.config.language != Language::Cxx {
+            return &[];
+        }
+        self.module_paths
+            .get(name)
+            .map_or(&[], |module_path| module_path)
+    }
+
+    /// Leaves the namespaces of the module path `current` for the ones of
+    /// `next`, closing the current Doxygen group first so that they nest.
+    fn switch_module_namespaces<'a, F: Write>(
+        &'a self,
+        current: &mut &'a [String],
+        next: &'a [String],
+        group: &mut Option<&'a [String]>,
+        out: &mut SourceWriter<F>,
+    ) {
+        if *current == next {
+            return;
+        }
+        self.close_doxygen_group(group, out);
+        self.close_module_namespaces(current, out);
+        self.open_module_namespaces(next, out);
+        *current = next;
+    }
+
+    fn open_module_namespaces<F: Write>(&self, module_path: &[String], out: &mut SourceWriter<F>) {
+        if module_path.is_empty() {
+            return;
+        }
+        out.new_line_if_not_start();
+        for (i, module) in module_path.iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            write!(out, "namespace {} {{", module);
+        }
+        out.new_line();
+    }
+
+    fn close_module_namespaces<F: Write>(&self, module_path: &[String], out: &mut SourceWriter<F>) {
+        if module_path.is_empty() {
+            return;
+        }
+        out.new_line_if_not_start();
+        for (i, module) in module_path.iter().rev().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            write!(out, "}} // namespace {}", module);
+        }
+        out.new_line();
+    }
+
     pub(crate) fn open_namespaces<F: Write>(&self, out: &mut SourceWriter<F>) {
         self.open_close_namespaces(NamespaceOperation::Open, out);
     }
@@ -2,24 +2,34 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::cell::RefCell;
+use std::fmt;
 use std::path;
+use std::rc::Rc;
 
+use crate::bindgen::backend::{LanguageBackend, SharedBackend};
 use crate::bindgen::bindings::Bindings;
-use crate::bindgen::cargo::Cargo;
-use crate::bindgen::config::{Braces, Config, Language, Profile, Style};
+use crate::bindgen::cargo::{Cargo, MetadataFlags};
+use crate::bindgen::config::{Braces, Config, Language, Placeholders, Profile, Style};
+use crate::bindgen::diagnostic;
 use crate::bindgen::error::Error;
 use crate::bindgen::library::Library;
-use crate::bindgen::parser::{self, Parse};
+use crate::bindgen::parser::{self, Parse, ParseCache, ParsedItem};
 
 /// A builder for generating a bindings header.
 #[derive(Debug, Clone)]
 pub struct Builder {
     config: Config,
     srcs: Vec<path::PathBuf>,
+    src_strs: Vec<(path::PathBuf, String)>,
+    parsed_files: Vec<(path::PathBuf, syn::File)>,
     lib: Option<(path::PathBuf, Option<String>)>,
     lib_cargo: Option<Cargo>,
     std_types: bool,
     lockfile: Option<path::PathBuf>,
+    parse_cache: Rc<RefCell<ParseCache>>,
+    backend: Option<SharedBackend>,
+    rewriters: Rewriters,
 }
 
 impl Builder {
@@ -28,10 +38,15 @@ impl Builder {
         Builder {
             config: Config::default(),
             srcs: Vec::new(),
+            src_strs: Vec::new(),
+            parsed_files: Vec::new(),
             lib: None,
             lib_cargo: None,
             std_types: true,
             lockfile: None,
+            parse_cache: Rc::default(),
+            backend: None,
+            rewriters: Rewriters::default(),
         }
     }
 
@@ -207,6 +222,15 @@ impl Builder {
         self
     }
 
+    #[allow(unused)]
+    pub fn with_parse_extra_sources<S: AsRef<str>>(mut self, extra_sources: &[S]) -> Builder {
+        self.config.parse.extra_sources = extra_sources
+            .iter()
+            .map(|x| String::from(x.as_ref()))
+            .collect();
+        self
+    }
+
     #[allow(unused)]
     pub fn with_parse_expand<S: AsRef<str>>(mut self, expand: &[S]) -> Builder {
         self.config.parse.expand.crates = expand.iter().map(|x| String::from(x.as_ref())).collect();
@@ -299,6 +323,29 @@ impl Builder {
         self
     }
 
+    /// Adds a rust source file held in memory, e.g. generated by a build
+    /// script, parsed like a file named `name` with `with_src`.
+    #[allow(unused)]
+    pub fn with_src_str<P: AsRef<path::Path>, S: Into<String>>(
+        mut self,
+        name: P,
+        contents: S,
+    ) -> Builder {
+        self.src_strs
+            .push((name.as_ref().to_path_buf(), contents.into()));
+        self
+    }
+
+    /// Adds a rust source file already parsed with `syn`, e.g. by a procedural
+    /// macro or a code generator, handled like a file named `name` with
+    /// `with_src`. `file` must come from the same major version of `syn` as
+    /// cbindgen uses.
+    #[allow(unused)]
+    pub fn with_parsed_file<P: AsRef<path::Path>>(mut self, file: syn::File, name: P) -> Builder {
+        self.parsed_files.push((name.as_ref().to_path_buf(), file));
+        self
+    }
+
     #[allow(unused)]
     pub fn with_crate<P: AsRef<path::Path>>(mut self, lib_dir: P) -> Builder {
         debug_assert!(self.lib.is_none());
@@ -338,37 +385,146 @@ impl Builder {
         self
     }
 
-    pub fn generate(self) -> Result<Bindings, Error> {
-        let mut result = Parse::new();
+    /// Keeps the syntax trees of the sources in `cache`, so that the files
+    /// which didn't change aren't parsed again by the next builder given the
+    /// same cache, e.g. when regenerating the bindings after a change.
+    #[allow(unused)]
+    pub fn with_parse_cache(mut self, cache: Rc<RefCell<ParseCache>>) -> Builder {
+        self.parse_cache = cache;
+        self
+    }
 
-        if self.std_types {
-            result.add_std_types();
-        }
+    /// Writes the bindings with `backend` instead of in the language of the
+    /// config, which the items are still processed for.
+    #[allow(unused)]
+    pub fn with_backend<B: LanguageBackend + 'static>(mut self, backend: B) -> Builder {
+        self.backend = Some(SharedBackend(Rc::new(backend)));
+        self
+    }
 
-        for x in &self.srcs {
-            result.extend_with(&parser::parse_src(x, &self.config)?);
+    /// Leaves the items and functions for which `filter` returns false out of
+    /// the bindings, as if they were excluded in the config.
+    #[allow(unused)]
+    pub fn with_item_filter<F: Fn(ParsedItem) -> bool + 'static>(self, filter: F) -> Builder {
+        self.with_item_rewriter(move |parse| parse.retain(&filter))
+    }
+
+    /// Calls `rewrite` with the items parsed from all the sources, before
+    /// they're processed, to rename, retype or annotate them. Filters and
+    /// rewriters run in the order they're added.
+    ///
+    /// Setting the export name of an item renames it in the bindings, before
+    /// the rename rules and prefix of the config are applied.
+    #[allow(unused)]
+    pub fn with_item_rewriter<F: Fn(&mut Parse) + 'static>(mut self, rewrite: F) -> Builder {
+        self.rewriters.0.push(Rc::new(rewrite));
+        self
+    }
+
+    pub fn generate(self) -> Result<Bindings, Error> {
+        let cargo = self.load_cargo()?;
+        let cache = self.parse_cache.clone();
+        let mut cache = cache.borrow_mut();
+        self.generate_with(self.config.clone(), cargo, &mut cache)
+    }
+
+    /// Generates the bindings of each `[[output]]` of the config, along with
+    /// the file they should be written to.
+    ///
+    /// The crate metadata is only loaded once, and the syntax trees of the
+    /// sources are shared between the outputs, but the items are loaded and
+    /// processed for each language, as both depend on it.
+    pub fn generate_outputs(self) -> Result<Vec<(path::PathBuf, Bindings)>, Error> {
+        let cargo = self.load_cargo()?;
+        let cache = self.parse_cache.clone();
+        let mut cache = cache.borrow_mut();
+
+        let placeholders = placeholders(cargo.as_ref());
+        let mut outputs = Vec::with_capacity(self.config.outputs.len());
+        for output in &self.config.outputs {
+            let mut config = self.config.clone();
+            config.language = output.language;
+            config.outputs = Vec::new();
+            let bindings = self.generate_with(config, cargo.clone(), &mut cache)?;
+            let file = placeholders.interpolate(&output.file);
+            outputs.push((path::PathBuf::from(file), bindings));
         }
+        Ok(outputs)
+    }
 
-        if let Some((lib_dir, binding_lib_name)) = self.lib.clone() {
+    fn load_cargo(&self) -> Result<Option<Cargo>, Error> {
+        if let Some((ref lib_dir, ref binding_lib_name)) = self.lib {
             let lockfile = self.lockfile.as_ref().and_then(|p| p.to_str());
 
             let cargo = Cargo::load(
-                &lib_dir,
+                lib_dir,
                 lockfile,
                 binding_lib_name.as_deref(),
                 self.config.parse.parse_deps,
                 self.config.parse.clean,
                 self.config.only_target_dependencies,
                 /* existing_metadata = */ None,
+                MetadataFlags::default(),
             )?;
+            Ok(Some(cargo))
+        } else {
+            Ok(self.lib_cargo.clone())
+        }
+    }
 
-            result.extend_with(&parser::parse_lib(cargo, &self.config)?);
-        } else if let Some(cargo) = self.lib_cargo.clone() {
-            result.extend_with(&parser::parse_lib(cargo, &self.config)?);
+    fn generate_with(
+        &self,
+        mut config: Config,
+        cargo: Option<Cargo>,
+        cache: &mut ParseCache,
+    ) -> Result<Bindings, Error> {
+        let denied = diagnostic::Denied::new(&config.diagnostics.deny);
+        let mut result = Parse::new();
+        let mut crate_version = None;
+
+        if self.std_types {
+            result.add_std_types();
         }
 
-        Library::new(
-            self.config,
+        for x in &self.srcs {
+            result.extend_with(&parser::parse_src(x, &config, cache)?);
+        }
+
+        for (name, contents) in &self.src_strs {
+            result.extend_with(&parser::parse_src_str(name, contents, &config, cache)?);
+        }
+
+        for (name, file) in &self.parsed_files {
+            result.extend_with(&parser::parse_file(name, file, &config, cache)?);
+        }
+
+        config.interpolate(&placeholders(cargo.as_ref()));
+
+        // Extra sources are found relative to the binding crate, if any.
+        let mut root = path::PathBuf::from(".");
+        if let Some(cargo) = cargo {
+            crate_version = cargo.binding_crate_ref().version;
+            if let Some(crate_dir) = cargo.find_crate_dir(&cargo.binding_crate_ref()) {
+                root = crate_dir;
+            }
+            result.extend_with(&parser::parse_lib(cargo, &config, cache)?);
+        }
+
+        for x in config.parse.extra_source_files(&root) {
+            result.extend_with(&parser::parse_src(&x, &config, cache)?);
+        }
+        config.resolve_inline_namespace(crate_version.as_deref());
+        if let Some(ref config_path) = config.config_path {
+            result.source_files.push(config_path.clone());
+        }
+
+        result.add_view_types();
+        for rewrite in &self.rewriters.0 {
+            rewrite(&mut result);
+        }
+
+        let mut bindings = Library::new(
+            config,
             result.constants,
             result.globals,
             result.enums,
@@ -377,14 +533,55 @@ impl Builder {
             result.opaque_items,
             result.typedefs,
             result.functions,
+            result.module_paths,
+            result.crates,
+            result.source_order,
+            result.source_locations,
+            result.source_files,
         )
-        .generate()
+        .generate()?;
+        if denied.count() > 0 {
+            return Err(Error::DeniedDiagnostics(denied.count()));
+        }
+        bindings.backend = self.backend.clone();
+        Ok(bindings)
+    }
+}
+
+/// A hook of a builder that rewrites the parsed items.
+type Rewriter = dyn Fn(&mut Parse);
+
+/// The hooks of a builder that rewrite the parsed items.
+#[derive(Clone, Default)]
+struct Rewriters(Vec<Rc<Rewriter>>);
+
+impl fmt::Debug for Rewriters {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} item rewriters", self.0.len())
+    }
+}
+
+/// The values of the placeholders of the config for the binding crate, if
+/// any.
+fn placeholders(cargo: Option<&Cargo>) -> Placeholders {
+    match cargo {
+        Some(cargo) => {
+            let binding_crate = cargo.binding_crate_ref();
+            Placeholders::new(
+                Some(binding_crate.name.clone()),
+                binding_crate.version.clone(),
+                cargo.find_crate_dir(&binding_crate),
+            )
+        }
+        None => Placeholders::default(),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bindgen::config::OutputConfig;
+    use crate::bindgen::diagnostic::DiagnosticKind;
 
     #[test]
     fn with_style() {
@@ -393,4 +590,128 @@ mod tests {
             Builder::new().with_style(Style::Tag).config.style
         );
     }
+
+    #[test]
+    fn generate_outputs() {
+        let mut config = Config::default();
+        for (language, file) in &[(Language::C, "a.h"), (Language::Cython, "a.pxd")] {
+            config.outputs.push(OutputConfig {
+                language: *language,
+                file: file.to_string(),
+            });
+        }
+
+        let outputs = Builder::new()
+            .with_config(config)
+            .with_src("tests/rust/function_args.rs")
+            .generate_outputs()
+            .unwrap();
+        let outputs: Vec<_> = outputs
+            .iter()
+            .map(|(file, bindings)| (file.to_str().unwrap(), bindings.config.language))
+            .collect();
+        assert_eq!(outputs, [("a.h", Language::C), ("a.pxd", Language::Cython)]);
+    }
+
+    #[test]
+    fn with_src_str() {
+        let src = r#"
+            #[repr(C)]
+            pub struct Generated {
+                value: u32,
+            }
+
+            #[no_mangle]
+            pub extern "C" fn generated(x: Generated) {}
+        "#;
+        let bindings = Builder::new()
+            .with_language(Language::C)
+            .with_src_str("generated.rs", src)
+            .generate()
+            .unwrap();
+
+        let mut out = Vec::new();
+        bindings.write(&mut out);
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("uint32_t value;"));
+        assert!(out.contains("void generated(struct Generated x);"));
+    }
+
+    #[test]
+    fn deny_diagnostics() {
+        let src = r#"
+            pub struct Opaque {
+                value: Vec<u32>,
+            }
+
+            pub const NAME: &str = "name";
+        "#;
+        let generate = |deny: Vec<DiagnosticKind>| {
+            let mut config = Config::default();
+            config.diagnostics.deny = deny;
+            Builder::new()
+                .with_config(config)
+                .with_src_str("denied.rs", src)
+                .generate()
+        };
+
+        assert!(generate(vec![]).is_ok());
+        assert!(generate(vec![DiagnosticKind::SkippedItem]).is_ok());
+        match generate(vec![
+            DiagnosticKind::OpaqueType,
+            DiagnosticKind::UnsupportedLiteral,
+        ]) {
+            Err(Error::DeniedDiagnostics(count)) => assert_eq!(count, 2),
+            other => panic!("{:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn with_parsed_file() {
+        let file: syn::File = parse_quote! {
+            #[repr(C)]
+            pub struct Generated {
+                value: u32,
+            }
+
+            #[no_mangle]
+            pub extern "C" fn generated(x: Generated) {}
+        };
+        let bindings = Builder::new()
+            .with_language(Language::C)
+            .with_parsed_file(file, "generated.rs")
+            .generate()
+            .unwrap();
+
+        let mut out = Vec::new();
+        bindings.write(&mut out);
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("uint32_t value;"));
+        assert!(out.contains("void generated(struct Generated x);"));
+        assert!(bindings.source_files().is_empty());
+    }
+
+    #[test]
+    fn with_parse_cache() {
+        let src = std::env::temp_dir().join("cbindgen_with_parse_cache.rs");
+        let generate = |cache: &Rc<RefCell<ParseCache>>| {
+            let bindings = Builder::new()
+                .with_language(Language::C)
+                .with_src(&src)
+                .with_parse_cache(cache.clone())
+                .generate()
+                .unwrap();
+            let mut out = Vec::new();
+            bindings.write(&mut out);
+            String::from_utf8(out).unwrap()
+        };
+
+        let cache = Rc::default();
+        std::fs::write(&src, "#[no_mangle] pub extern \"C\" fn before() {}").unwrap();
+        assert!(generate(&cache).contains("void before(void);"));
+        std::fs::write(&src, "#[no_mangle] pub extern \"C\" fn after() {}").unwrap();
+        let out = generate(&cache);
+        assert!(out.contains("void after(void);"));
+        assert!(!out.contains("before"));
+    }
 }
@@ -2,14 +2,19 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::env;
 use std::path::{Path, PathBuf};
 
+use log::Level;
+
 use crate::bindgen::cargo::cargo_expand;
 use crate::bindgen::cargo::cargo_lock::{self, Lock};
+pub(crate) use crate::bindgen::cargo::cargo_metadata::MetadataFlags;
 pub(crate) use crate::bindgen::cargo::cargo_metadata::PackageRef;
 use crate::bindgen::cargo::cargo_metadata::{self, Metadata};
 use crate::bindgen::cargo::cargo_toml;
 use crate::bindgen::config::Profile;
+use crate::bindgen::diagnostic::{self, DiagnosticKind};
 use crate::bindgen::error::Error;
 use crate::bindgen::ir::Cfg;
 
@@ -34,6 +39,7 @@ impl Cargo {
     /// Gather metadata from cargo for a specific library and binding crate
     /// name. If dependency finding isn't needed then Cargo.lock files don't
     /// need to be parsed.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn load(
         crate_dir: &Path,
         lock_file: Option<&str>,
@@ -42,11 +48,16 @@ impl Cargo {
         clean: bool,
         only_target_dependencies: bool,
         existing_metadata_file: Option<&Path>,
+        metadata_flags: MetadataFlags,
     ) -> Result<Cargo, Error> {
         let toml_path = crate_dir.join("Cargo.toml");
-        let metadata =
-            cargo_metadata::metadata(&toml_path, existing_metadata_file, only_target_dependencies)
-                .map_err(|x| Error::CargoMetadata(toml_path.to_str().unwrap().to_owned(), x))?;
+        let metadata = cargo_metadata::metadata(
+            &toml_path,
+            existing_metadata_file,
+            only_target_dependencies,
+            metadata_flags,
+        )
+        .map_err(|x| Error::CargoMetadata(toml_path.to_str().unwrap().to_owned(), x))?;
         let lock_path = lock_file
             .map(PathBuf::from)
             .unwrap_or_else(|| Path::new(&metadata.workspace_root).join("Cargo.lock"));
@@ -55,7 +66,12 @@ impl Cargo {
             match cargo_lock::lock(&lock_path) {
                 Ok(lock) => Some(lock),
                 Err(x) => {
-                    warn!("Couldn't load lock file {:?}: {:?}", lock_path, x);
+                    diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::Environment,
+                        None,
+                        format_args!("Couldn't load lock file {:?}: {:?}", lock_path, x),
+                    );
                     None
                 }
             }
@@ -82,6 +98,19 @@ impl Cargo {
         })
     }
 
+    /// The root directory of the workspace of the crate.
+    pub(crate) fn workspace_root(&self) -> &Path {
+        Path::new(&self.metadata.workspace_root)
+    }
+
+    /// The target directory of the workspace, where cbindgen keeps its caches.
+    pub(crate) fn target_dir(&self) -> PathBuf {
+        match env::var_os("CARGO_TARGET_DIR") {
+            Some(dir) => PathBuf::from(dir),
+            None => self.workspace_root().join("target"),
+        }
+    }
+
     pub(crate) fn binding_crate_name(&self) -> &str {
         &self.binding_crate_name
     }
@@ -157,7 +186,16 @@ impl Cargo {
                     if versions.next().is_none() {
                         version
                     } else {
-                        warn!("when looking for a version for package {}, multiple versions where found", dep_name);
+                        diagnostic::report(
+                            Level::Warn,
+                            DiagnosticKind::Environment,
+                            None,
+                            format_args!(
+                                "when looking for a version for package {}, \
+                                 multiple versions where found",
+                                dep_name
+                            ),
+                        );
                         None
                     }
                 });
@@ -8,6 +8,7 @@ use std::io::Read;
 use std::path::Path;
 
 #[derive(Debug)]
+#[allow(dead_code)]
 /// Possible errors that can occur during Cargo.toml parsing.
 pub enum Error {
     /// Error during reading of Cargo.toml
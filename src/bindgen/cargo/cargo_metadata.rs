@@ -15,12 +15,18 @@ use std::collections::{HashMap, HashSet};
 use std::env;
 use std::error;
 use std::fmt;
+use std::fs;
 use std::hash::{Hash, Hasher};
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Output};
 use std::str::Utf8Error;
 
+use log::Level;
+
+use crate::bindgen::diagnostic::{self, DiagnosticKind};
+use crate::bindgen::utilities::StableHasher;
+
 #[derive(Clone, Deserialize, Debug)]
 /// Starting point for metadata returned by `cargo metadata`
 pub struct Metadata {
@@ -212,11 +218,54 @@ fn discover_target(manifest_path: &Path) -> Option<String> {
     })
 }
 
+/// How to run `cargo metadata`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MetadataFlags {
+    /// Whether to pass `--offline`, so that cargo doesn't access the network.
+    pub offline: bool,
+    /// Whether to pass `--frozen`, so that cargo neither accesses the network
+    /// nor updates `Cargo.lock`.
+    pub frozen: bool,
+    /// Whether to save the metadata in the target directory, and reuse it
+    /// while `Cargo.lock` and the manifest don't change.
+    pub cache: bool,
+}
+
+/// Returns the file the metadata of `manifest_path` is cached in, named after
+/// a hash of the manifest, of the `Cargo.lock` of its workspace and of the
+/// platform to filter the dependencies for. There's none without a
+/// `Cargo.lock`, as the metadata could then change at any time.
+fn cache_file(manifest_path: &Path, target: Option<&str>) -> Option<PathBuf> {
+    let manifest = fs::read(manifest_path).ok()?;
+    let lock_dir = manifest_path
+        .ancestors()
+        .skip(1)
+        .find(|dir| dir.join("Cargo.lock").is_file())?;
+    let lock = fs::read(lock_dir.join("Cargo.lock")).ok()?;
+
+    let mut hasher = StableHasher::new();
+    hasher.write_str(env!("CARGO_PKG_VERSION"));
+    hasher.write(&manifest);
+    hasher.write(&lock);
+    hasher.write_str(target.unwrap_or_default());
+
+    let target_dir = match env::var_os("CARGO_TARGET_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => lock_dir.join("target"),
+    };
+    Some(
+        target_dir
+            .join("cbindgen")
+            .join(format!("metadata-{:016x}.json", hasher.finish())),
+    )
+}
+
 /// The main entry point to obtaining metadata
 pub fn metadata(
     manifest_path: &Path,
     existing_metadata_file: Option<&Path>,
     only_target: bool,
+    flags: MetadataFlags,
 ) -> Result<Metadata, Error> {
     let output;
     let metadata = match existing_metadata_file {
@@ -225,9 +274,14 @@ pub fn metadata(
             let target = if only_target {
                 let target = discover_target(manifest_path);
                 if target.is_none() {
-                    warn!(
-                        "Failed to discover host platform for cargo metadata; \
-                        will fetch dependencies for all platforms."
+                    diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::Environment,
+                        None,
+                        format_args!(
+                            "Failed to discover host platform for cargo metadata; \
+                            will fetch dependencies for all platforms."
+                        ),
                     );
                 }
                 target
@@ -235,6 +289,20 @@ pub fn metadata(
                 None
             };
 
+            let cache_file = if flags.cache {
+                cache_file(manifest_path, target.as_deref())
+            } else {
+                None
+            };
+            if let Some(cached) = cache_file
+                .as_ref()
+                .and_then(|file| fs::read_to_string(file).ok())
+            {
+                debug!("Reusing the cached cargo metadata of {:?}", manifest_path);
+                let meta: Metadata = serde_json::from_str(&cached)?;
+                return Ok(meta);
+            }
+
             let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
             let mut cmd = Command::new(cargo);
             cmd.arg("metadata");
@@ -243,16 +311,38 @@ pub fn metadata(
             if let Some(target) = target {
                 cmd.arg("--filter-platform").arg(target);
             }
+            if flags.offline {
+                cmd.arg("--offline");
+            }
+            if flags.frozen {
+                cmd.arg("--frozen");
+            }
             cmd.arg("--manifest-path");
             cmd.arg(manifest_path);
             output = cmd.output()?;
             if !output.status.success() {
                 return Err(Error::Metadata(output));
             }
-            Cow::Borrowed(std::str::from_utf8(&output.stdout)?)
+
+            let metadata = std::str::from_utf8(&output.stdout)?;
+            if let Some(file) = cache_file {
+                // The cache is only an optimization, so failing to write it
+                // isn't an error.
+                let written = fs::create_dir_all(file.parent().unwrap())
+                    .and_then(|()| fs::write(&file, metadata));
+                if let Err(err) = written {
+                    diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::Environment,
+                        None,
+                        format_args!("Couldn't cache the cargo metadata in {:?}: {}", file, err),
+                    );
+                }
+            }
+            Cow::Borrowed(metadata)
         }
     };
 
-    let meta: Metadata = serde_json::from_str(&*metadata)?;
+    let meta: Metadata = serde_json::from_str(&metadata)?;
     Ok(meta)
 }
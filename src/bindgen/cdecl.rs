@@ -6,7 +6,7 @@ use std::io::Write;
 
 use crate::bindgen::config::Layout;
 use crate::bindgen::declarationtyperesolver::DeclarationType;
-use crate::bindgen::ir::{ConstExpr, Function, GenericArgument, Type};
+use crate::bindgen::ir::{ConstExpr, Function, FunctionArgument, GenericArgument, Type};
 use crate::bindgen::writer::{ListType, SourceWriter};
 use crate::bindgen::{Config, Language};
 
@@ -19,10 +19,13 @@ enum CDeclarator {
         is_const: bool,
         is_nullable: bool,
         is_ref: bool,
+        is_restrict: bool,
     },
     Array(String),
     Func {
         args: Vec<(Option<String>, CDecl)>,
+        /// The default values of the last arguments.
+        default_args: Vec<String>,
         layout: Layout,
         never_return: bool,
     },
@@ -62,11 +65,22 @@ impl CDecl {
         cdecl
     }
 
-    fn from_func_arg(t: &Type, array_length: Option<&str>, config: &Config) -> CDecl {
+    fn from_func_arg(arg: &FunctionArgument, config: &Config) -> CDecl {
+        let t = &arg.ty;
         let mut cdecl = CDecl::new();
-        let length = match array_length {
-            Some(l) => l,
-            None => return CDecl::from_type(t, config),
+        let length = match arg.array_length {
+            Some(ref l) => l,
+            None => {
+                cdecl.build_type(t, false, config);
+                if let Some(CDeclarator::Ptr {
+                    ref mut is_restrict,
+                    ..
+                }) = cdecl.declarators.first_mut()
+                {
+                    *is_restrict = arg.is_restrict;
+                }
+                return cdecl;
+            }
         };
         let (ty, is_const) = match t {
             Type::Ptr { ty, is_const, .. } => (ty, is_const),
@@ -90,15 +104,16 @@ impl CDecl {
         let args = f
             .args
             .iter()
-            .map(|arg| {
-                (
-                    arg.name.clone(),
-                    CDecl::from_func_arg(&arg.ty, arg.array_length.as_deref(), config),
-                )
-            })
+            .map(|arg| (arg.name.clone(), CDecl::from_func_arg(arg, config)))
             .collect();
+        let default_args = if config.language == Language::Cxx {
+            f.default_args.clone()
+        } else {
+            Vec::new()
+        };
         self.declarators.push(CDeclarator::Func {
             args,
+            default_args,
             layout,
             never_return: f.never_return,
         });
@@ -146,7 +161,7 @@ impl CDecl {
                     "error generating cdecl for {:?}",
                     t
                 );
-                self.type_name = p.to_repr_c(config).to_string();
+                self.type_name = p.to_repr_decl(config).into_owned();
             }
             Type::Ptr {
                 ref ty,
@@ -158,6 +173,7 @@ impl CDecl {
                     is_const,
                     is_nullable: *is_nullable,
                     is_ref: *is_ref,
+                    is_restrict: false,
                 });
                 self.build_type(ty, *ptr_is_const, config);
             }
@@ -180,9 +196,11 @@ impl CDecl {
                     is_const: false,
                     is_nullable: true,
                     is_ref: false,
+                    is_restrict: false,
                 });
                 self.declarators.push(CDeclarator::Func {
                     args,
+                    default_args: Vec::new(),
                     layout: config.function.args.clone(),
                     never_return: *never_return,
                 });
@@ -228,11 +246,17 @@ impl CDecl {
                     is_const,
                     is_nullable,
                     is_ref,
+                    is_restrict,
                 } => {
                     out.write(if is_ref { "&" } else { "*" });
                     if is_const {
                         out.write("const ");
                     }
+                    if is_restrict {
+                        if let Some(restrict) = config.restrict_keyword() {
+                            write!(out, "{} ", restrict);
+                        }
+                    }
                     if !is_nullable && !is_ref && config.language != Language::Cython {
                         if let Some(attr) = &config.pointer.non_null_attribute {
                             write!(out, "{} ", attr);
@@ -277,6 +301,7 @@ impl CDecl {
                 }
                 CDeclarator::Func {
                     ref args,
+                    ref default_args,
                     ref layout,
                     never_return,
                 } => {
@@ -289,14 +314,27 @@ impl CDecl {
                         out.write("void");
                     }
 
+                    /// Writes the default value of the `i`th argument, if any.
+                    fn write_default_arg<F: Write>(
+                        out: &mut SourceWriter<F>,
+                        arg_count: usize,
+                        default_args: &[String],
+                        i: usize,
+                    ) {
+                        if let Some(j) = (i + default_args.len()).checked_sub(arg_count) {
+                            write!(out, " = {}", default_args[j]);
+                        }
+                    }
+
                     fn write_vertical<F: Write>(
                         out: &mut SourceWriter<F>,
                         config: &Config,
-                        args: &Vec<(Option<String>, CDecl)>,
+                        args: &[(Option<String>, CDecl)],
+                        default_args: &[String],
                     ) {
                         let align_length = out.line_length_for_align();
                         out.push_set_spaces(align_length);
-                        for (i, &(ref arg_ident, ref arg_ty)) in args.iter().enumerate() {
+                        for (i, (arg_ident, arg_ty)) in args.iter().enumerate() {
                             if i != 0 {
                                 out.write(",");
                                 out.new_line();
@@ -306,6 +344,7 @@ impl CDecl {
                             let arg_ident = arg_ident.as_ref().map(|x| x.as_ref());
 
                             arg_ty.write(out, arg_ident, config);
+                            write_default_arg(out, args.len(), default_args, i);
                         }
                         out.pop_tab();
                     }
@@ -313,9 +352,10 @@ impl CDecl {
                     fn write_horizontal<F: Write>(
                         out: &mut SourceWriter<F>,
                         config: &Config,
-                        args: &Vec<(Option<String>, CDecl)>,
+                        args: &[(Option<String>, CDecl)],
+                        default_args: &[String],
                     ) {
-                        for (i, &(ref arg_ident, ref arg_ty)) in args.iter().enumerate() {
+                        for (i, (arg_ident, arg_ty)) in args.iter().enumerate() {
                             if i != 0 {
                                 out.write(", ");
                             }
@@ -324,20 +364,23 @@ impl CDecl {
                             let arg_ident = arg_ident.as_ref().map(|x| x.as_ref());
 
                             arg_ty.write(out, arg_ident, config);
+                            write_default_arg(out, args.len(), default_args, i);
                         }
                     }
 
                     match layout {
-                        Layout::Vertical => write_vertical(out, config, args),
-                        Layout::Horizontal => write_horizontal(out, config, args),
+                        Layout::Vertical => write_vertical(out, config, args, default_args),
+                        Layout::Horizontal => write_horizontal(out, config, args, default_args),
                         Layout::Auto => {
                             if out.line_length_for_align()
-                                + out.measure(|out| write_horizontal(out, config, args))
+                                + out.measure(|out| {
+                                    write_horizontal(out, config, args, default_args)
+                                })
                                 > config.line_length
                             {
-                                write_vertical(out, config, args)
+                                write_vertical(out, config, args, default_args)
                             } else {
-                                write_horizontal(out, config, args)
+                                write_horizontal(out, config, args, default_args)
                             }
                         }
                     }
@@ -369,6 +412,19 @@ pub fn write_field<F: Write>(out: &mut SourceWriter<F>, t: &Type, ident: &str, c
     CDecl::from_type(t, config).write(out, Some(ident), config);
 }
 
+/// Writes the declaration of a constant `ident` of type `t`, with the `const`
+/// qualifier on the declarator itself, so a pointer is declared `T *const`.
+pub fn write_const_field<F: Write>(
+    out: &mut SourceWriter<F>,
+    t: &Type,
+    ident: &str,
+    config: &Config,
+) {
+    let mut cdecl = CDecl::new();
+    cdecl.build_type(t, true, config);
+    cdecl.write(out, Some(ident), config);
+}
+
 pub fn write_type<F: Write>(out: &mut SourceWriter<F>, t: &Type, config: &Config) {
     CDecl::from_type(t, config).write(out, None, config);
 }
@@ -2,21 +2,36 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::borrow::Cow;
+use std::cell::RefCell;
 use std::collections::{BTreeMap, HashMap};
 use std::default::Default;
+use std::path::{Path as StdPath, PathBuf};
+use std::process::Command;
 use std::str::FromStr;
-use std::{fmt, fs, path::Path as StdPath};
+use std::{env, fmt, fs};
 
+use log::Level;
 use serde::de::value::{MapAccessDeserializer, SeqAccessDeserializer};
 use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
 
+use crate::bindgen::diagnostic::{self, DiagnosticKind};
 use crate::bindgen::ir::annotation::AnnotationSet;
 use crate::bindgen::ir::path::Path;
 use crate::bindgen::ir::repr::ReprAlign;
 pub use crate::bindgen::rename::RenameRule;
+use crate::bindgen::schema;
 
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// The names of the config files looked for in the root of a crate, in order.
+pub const CONFIG_FILE_NAMES: &[&str] = &[
+    "cbindgen.toml",
+    "cbindgen.json",
+    "cbindgen.yaml",
+    "cbindgen.yml",
+];
+
 /// A language type to generate bindings for.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Language {
@@ -47,7 +62,11 @@ impl FromStr for Language {
     }
 }
 
-deserialize_enum_str!(Language);
+deserialize_enum_str!(Language {
+    Cxx => "C++",
+    C => "C",
+    Cython => "Cython",
+});
 
 impl Language {
     pub(crate) fn typedef(self) -> &'static str {
@@ -58,6 +77,43 @@ impl Language {
     }
 }
 
+/// The format of a config file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Toml,
+    Json,
+    Yaml,
+}
+
+impl FromStr for ConfigFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<ConfigFormat, Self::Err> {
+        match s {
+            "toml" => Ok(ConfigFormat::Toml),
+            "TOML" => Ok(ConfigFormat::Toml),
+            "json" => Ok(ConfigFormat::Json),
+            "JSON" => Ok(ConfigFormat::Json),
+            "yaml" => Ok(ConfigFormat::Yaml),
+            "YAML" => Ok(ConfigFormat::Yaml),
+            "yml" => Ok(ConfigFormat::Yaml),
+            _ => Err(format!("Unrecognized ConfigFormat: '{}'.", s)),
+        }
+    }
+}
+
+impl ConfigFormat {
+    /// The format of a config file according to its extension, TOML unless it
+    /// is `.json`, `.yaml` or `.yml`.
+    pub fn from_path<P: AsRef<StdPath>>(path: P) -> ConfigFormat {
+        match path.as_ref().extension().and_then(|x| x.to_str()) {
+            Some("json") => ConfigFormat::Json,
+            Some("yaml") | Some("yml") => ConfigFormat::Yaml,
+            _ => ConfigFormat::Toml,
+        }
+    }
+}
+
 /// Controls what type of line endings are used in the generated code.
 #[derive(Debug, Clone, Copy)]
 #[allow(clippy::upper_case_acronyms)]
@@ -112,7 +168,12 @@ impl FromStr for LineEndingStyle {
     }
 }
 
-deserialize_enum_str!(LineEndingStyle);
+deserialize_enum_str!(LineEndingStyle {
+    LF => "lf",
+    CR => "cr",
+    CRLF => "crlf",
+    Native => "native",
+});
 
 /// A style of braces to use for generating code.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -135,7 +196,10 @@ impl FromStr for Braces {
     }
 }
 
-deserialize_enum_str!(Braces);
+deserialize_enum_str!(Braces {
+    SameLine => "SameLine",
+    NextLine => "NextLine",
+});
 
 /// A type of layout to use when generating long lines of code.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -161,7 +225,11 @@ impl FromStr for Layout {
     }
 }
 
-deserialize_enum_str!(Layout);
+deserialize_enum_str!(Layout {
+    Horizontal => "Horizontal",
+    Vertical => "Vertical",
+    Auto => "Auto",
+});
 
 /// How the comments containing documentation should be styled.
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
@@ -189,7 +257,13 @@ impl FromStr for DocumentationStyle {
     }
 }
 
-deserialize_enum_str!(DocumentationStyle);
+deserialize_enum_str!(DocumentationStyle {
+    C => "c",
+    C99 => "c99",
+    Doxy => "doxy",
+    Cxx => "c++",
+    Auto => "auto",
+});
 
 /// How much of the documentation to include in the header file.
 #[derive(Debug, Clone, Copy)]
@@ -210,7 +284,10 @@ impl FromStr for DocumentationLength {
     }
 }
 
-deserialize_enum_str!(DocumentationLength);
+deserialize_enum_str!(DocumentationLength {
+    Short => "short",
+    Full => "full",
+});
 
 /// A style of Style to use when generating structs and enums.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -267,7 +344,11 @@ impl FromStr for Style {
     }
 }
 
-deserialize_enum_str!(Style);
+deserialize_enum_str!(Style {
+    Both => "both",
+    Tag => "tag",
+    Type => "type",
+});
 
 /// Different item types that we can generate and filter.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -301,13 +382,24 @@ impl FromStr for ItemType {
     }
 }
 
-deserialize_enum_str!(ItemType);
-
-/// Type which specifies the sort order of functions
+deserialize_enum_str!(ItemType {
+    Constants => "constants",
+    Globals => "globals",
+    Enums => "enums",
+    Structs => "structs",
+    Unions => "unions",
+    Typedefs => "typedefs",
+    OpaqueItems => "opaque",
+    Functions => "functions",
+});
+
+/// Type which specifies the sort order of functions, constants and types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SortKey {
     Name,
     None,
+    Source,
+    Dependency,
 }
 
 impl FromStr for SortKey {
@@ -318,15 +410,269 @@ impl FromStr for SortKey {
         Ok(match &*s.to_lowercase() {
             "name" => Name,
             "none" => None,
+            "source" => Source,
+            "dependency" => Dependency,
             _ => return Err(format!("Unrecognized sort option: '{}'.", s)),
         })
     }
 }
 
-deserialize_enum_str!(SortKey);
+deserialize_enum_str!(SortKey {
+    Name => "name",
+    None => "none",
+    Source => "source",
+    Dependency => "dependency",
+});
+
+/// How to emit 128-bit integers, which have no portable C equivalent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Int128Style {
+    /// Emit `i128` and `u128` as is, with a warning.
+    None,
+    /// Use the `__int128` extension of GCC and Clang.
+    Builtin,
+    /// Use a struct of two 64-bit halves, for compilers without `__int128`.
+    Struct,
+}
+
+impl Default for Int128Style {
+    fn default() -> Self {
+        Int128Style::None
+    }
+}
+
+impl FromStr for Int128Style {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "none" => Ok(Int128Style::None),
+            "builtin" => Ok(Int128Style::Builtin),
+            "struct" => Ok(Int128Style::Struct),
+            _ => Err(format!("Unrecognized int128 style: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(Int128Style {
+    None => "none",
+    Builtin => "builtin",
+    Struct => "struct",
+});
+
+/// How Cython bindings cimport the types of `libc.stdint` and `libc.stddef`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CimportStyle {
+    /// `from libc.stdint cimport int32_t`, with the types used as is.
+    From,
+    /// `cimport libc.stdint`, with the types used as `libc.stdint.int32_t`.
+    Qualified,
+}
+
+impl Default for CimportStyle {
+    fn default() -> Self {
+        CimportStyle::From
+    }
+}
+
+impl FromStr for CimportStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "from" => Ok(CimportStyle::From),
+            "qualified" => Ok(CimportStyle::Qualified),
+            _ => Err(format!("Unrecognized cimport style: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(CimportStyle {
+    From => "from",
+    Qualified => "qualified",
+});
+
+/// The C type to emit `c_char` as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CCharStyle {
+    /// `char`, whose signedness depends on the target.
+    Char,
+    /// `signed char`, which matches `c_char` on most targets.
+    SignedChar,
+    /// `int8_t`.
+    Int8,
+}
+
+impl Default for CCharStyle {
+    fn default() -> Self {
+        CCharStyle::Char
+    }
+}
+
+impl FromStr for CCharStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "char" => Ok(CCharStyle::Char),
+            "signed char" => Ok(CCharStyle::SignedChar),
+            "int8_t" => Ok(CCharStyle::Int8),
+            _ => Err(format!("Unrecognized c_char style: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(CCharStyle {
+    Char => "char",
+    SignedChar => "signed char",
+    Int8 => "int8_t",
+});
+
+/// The C standard to target when `language = "C"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CStandard {
+    C99,
+    C11,
+    C23,
+}
+
+impl Default for CStandard {
+    fn default() -> Self {
+        CStandard::C99
+    }
+}
+
+impl FromStr for CStandard {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "c99" => Ok(CStandard::C99),
+            "c11" => Ok(CStandard::C11),
+            "c23" => Ok(CStandard::C23),
+            _ => Err(format!("Unrecognized C standard: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(CStandard {
+    C99 => "c99",
+    C11 => "c11",
+    C23 => "c23",
+});
+
+/// How constants are emitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstantStyle {
+    /// `static const` or `constexpr` in C++ as allowed by `allow_static_const`
+    /// and `allow_constexpr`, `static constexpr` in C23, and `#define`
+    /// otherwise.
+    Auto,
+    /// `#define NAME value`
+    Define,
+    /// `enum { NAME = value };`, for integers only.
+    Enum,
+    /// `static const T NAME = value;`
+    StaticConst,
+    /// `constexpr` in C++ and C23.
+    Constexpr,
+    /// `inline constexpr` in C++, which isn't duplicated in each translation
+    /// unit. Like `Constexpr` in C.
+    InlineConstexpr,
+}
+
+impl Default for ConstantStyle {
+    fn default() -> Self {
+        ConstantStyle::Auto
+    }
+}
+
+impl FromStr for ConstantStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "auto" => Ok(ConstantStyle::Auto),
+            "define" => Ok(ConstantStyle::Define),
+            "enum" => Ok(ConstantStyle::Enum),
+            "static_const" => Ok(ConstantStyle::StaticConst),
+            "constexpr" => Ok(ConstantStyle::Constexpr),
+            "inline_constexpr" => Ok(ConstantStyle::InlineConstexpr),
+            _ => Err(format!("Unrecognized constant style: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(ConstantStyle {
+    Auto => "auto",
+    Define => "define",
+    Enum => "enum",
+    StaticConst => "static_const",
+    Constexpr => "constexpr",
+    InlineConstexpr => "inline_constexpr",
+});
+
+/// How to declare additional names for an exported function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AliasStyle {
+    /// `#define alias symbol`
+    Define,
+    /// A second prototype using the alias as the function name.
+    Prototype,
+}
+
+impl FromStr for AliasStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use self::AliasStyle::*;
+        Ok(match &*s.to_lowercase() {
+            "define" => Define,
+            "prototype" => Prototype,
+            _ => return Err(format!("Unrecognized alias style: '{}'.", s)),
+        })
+    }
+}
+
+deserialize_enum_str!(AliasStyle {
+    Define => "define",
+    Prototype => "prototype",
+});
+
+/// How to wrap the functions which return nullable pointers in C++.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NullableReturnWrapper {
+    /// No wrapper.
+    None,
+    /// A `[[nodiscard]]` wrapper returning `std::optional<T *>`, which is
+    /// empty for null pointers.
+    Optional,
+    /// A `[[nodiscard]]` wrapper returning the pointer itself.
+    Pointer,
+}
+
+impl FromStr for NullableReturnWrapper {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use self::NullableReturnWrapper::*;
+        Ok(match &*s.to_lowercase() {
+            "none" => None,
+            "optional" => Optional,
+            "pointer" => Pointer,
+            _ => return Err(format!("Unrecognized nullable return wrapper: '{}'.", s)),
+        })
+    }
+}
+
+deserialize_enum_str!(NullableReturnWrapper {
+    None => "none",
+    Optional => "optional",
+    Pointer => "pointer",
+});
 
 /// Settings to apply when exporting items.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
@@ -350,10 +696,71 @@ pub struct ExportConfig {
     pub renaming_overrides_prefixing: bool,
     /// Mangling configuration.
     pub mangle: MangleConfig,
+    /// Table of generic items to the lists of generic arguments they should
+    /// always be instantiated with, even if no exported item uses them.
+    pub instantiations: HashMap<String, Vec<String>>,
+    /// Whether to reflect the Rust module of types, as nested namespaces in
+    /// C++ and name prefixes otherwise.
+    pub namespace_from_modules: bool,
+    /// Sort key for types
+    pub sort_by: Option<SortKey>,
+    /// The umbrella header including these bindings along with others.
+    pub umbrella: UmbrellaConfig,
+    /// The linker files listing the symbols of the exported functions and
+    /// globals.
+    pub symbols: SymbolsConfig,
+}
+
+/// Settings of an umbrella header, which includes several headers in the
+/// order they depend on each other.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct UmbrellaConfig {
+    /// The umbrella header to write next to the bindings, which includes them
+    /// last. With `split_by_module`, the output file is the umbrella header
+    /// already.
+    pub header: Option<String>,
+    /// The headers to include before the bindings, in order, e.g. the ones of
+    /// other crates they depend on.
+    pub includes: Vec<String>,
+    /// The include guard of the umbrella header written with `header`.
+    pub include_guard: Option<String>,
+}
+
+/// An output of a run generating bindings in several languages at once.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+pub struct OutputConfig {
+    /// The language of the bindings.
+    pub language: Language,
+    /// The file to write the bindings to.
+    pub file: String,
+}
+
+/// Settings of the linker files listing the symbols the bindings declare, so
+/// that they are the only ones a shared library exports.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct SymbolsConfig {
+    /// The GNU ld version script to write next to the bindings.
+    pub version_script: Option<String>,
+    /// The name of the version node of the version script. The symbols are
+    /// unversioned without it.
+    pub version: Option<String>,
+    /// The MSVC module-definition file to write next to the bindings.
+    pub def_file: Option<String>,
+    /// The name of the library in the `LIBRARY` statement of the
+    /// module-definition file.
+    pub library: Option<String>,
 }
 
 /// Mangling-specific configuration.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
@@ -362,6 +769,23 @@ pub struct MangleConfig {
     pub rename_types: RenameRule,
     /// Remove the underscores used for name mangling.
     pub remove_underscores: bool,
+    /// The separator to use instead of underscores for name mangling.
+    pub separator: Option<String>,
+    /// Table of shorter names to use for the types mangled.
+    pub abbreviations: HashMap<String, String>,
+}
+
+impl MangleConfig {
+    pub(crate) fn separator(&self) -> &str {
+        if self.remove_underscores {
+            return "";
+        }
+        self.separator.as_deref().unwrap_or("_")
+    }
+
+    pub(crate) fn abbreviate<'a>(&'a self, name: &'a str) -> &'a str {
+        self.abbreviations.get(name).map_or(name, |s| s.as_str())
+    }
 }
 
 impl ExportConfig {
@@ -377,6 +801,22 @@ impl ExportConfig {
         self.body.get(path.name()).map(|s| s.trim_matches('\n'))
     }
 
+    /// Returns the forced instantiations as `Name<Arguments>` type strings,
+    /// sorted so that the output doesn't depend on the table's order.
+    pub(crate) fn instantiations(&self) -> Vec<String> {
+        let mut instantiations: Vec<String> = self
+            .instantiations
+            .iter()
+            .flat_map(|(name, arguments)| {
+                arguments
+                    .iter()
+                    .map(move |arguments| format!("{}<{}>", name, arguments))
+            })
+            .collect();
+        instantiations.sort();
+        instantiations
+    }
+
     pub(crate) fn rename(&self, item_name: &mut String) {
         if let Some(name) = self.rename.get(item_name) {
             *item_name = name.clone();
@@ -385,13 +825,15 @@ impl ExportConfig {
             }
         }
         if let Some(ref prefix) = self.prefix {
-            item_name.insert_str(0, prefix);
+            // Prefix the name itself rather than the namespace it's in.
+            let start = item_name.rfind("::").map_or(0, |i| i + 2);
+            item_name.insert_str(start, prefix);
         }
     }
 }
 
 /// Settings to apply to generated types with layout modifiers.
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
@@ -401,20 +843,43 @@ pub struct LayoutConfig {
     /// The way to annotate C types as #[repr(align(...))]. This is assumed to be a functional
     /// macro which takes a single argument (the alignment).
     pub aligned_n: Option<String>,
+    /// Whether to annotate C types as #[repr(align(...))] with the standard
+    /// `alignas` specifier, when `aligned_n` isn't set.
+    pub alignas: bool,
+    /// Whether to surround C types as #[repr(packed)] with `#pragma pack`,
+    /// when `packed` isn't set.
+    pub pragma_pack: bool,
+    /// Whether to check the size, alignment and field offsets of structs with
+    /// static assertions.
+    pub static_asserts: bool,
+    /// Whether C++ templates check with static assertions that the type
+    /// parameters they hold by value are trivially copyable.
+    pub template_static_asserts: bool,
 }
 
 impl LayoutConfig {
     pub(crate) fn ensure_safe_to_represent(&self, align: &ReprAlign) -> Result<(), String> {
         match (align, &self.packed, &self.aligned_n) {
-            (ReprAlign::Packed, None, _) => Err("Cannot safely represent #[repr(packed)] type without configured 'packed' annotation.".to_string()),
-            (ReprAlign::Align(_), _, None) => Err("Cannot safely represent #[repr(aligned(...))] type without configured 'aligned_n' annotation.".to_string()),
+            (ReprAlign::Packed, None, _) if !self.pragma_pack => Err("Cannot safely represent #[repr(packed)] type without configured 'packed' annotation.".to_string()),
+            (ReprAlign::Align(_), _, None) if !self.alignas => Err("Cannot safely represent #[repr(aligned(...))] type without configured 'aligned_n' annotation.".to_string()),
             _ => Ok(()),
         }
     }
 }
 
+/// Settings for the diagnostics about the Rust sources.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct DiagnosticsConfig {
+    /// The kinds of diagnostics to report as errors, which make the
+    /// generation fail.
+    pub deny: Vec<DiagnosticKind>,
+}
+
 /// Settings to apply to generated functions.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
@@ -431,10 +896,44 @@ pub struct FunctionConfig {
     pub rename_args: RenameRule,
     /// An optional macro to use when generating Swift function name attributes
     pub swift_name_macro: Option<String>,
+    /// Whether to emit guessed Swift names as raw `swift_name` attributes when
+    /// there is no `swift_name_macro`
+    pub swift_name_attribute: bool,
     /// Sort key for functions
     pub sort_by: Option<SortKey>,
     /// Optional text to output after functions which return `!`.
     pub no_return: Option<String>,
+    /// Whether to declare the Rust name of functions whose `#[export_name]`
+    /// differs from it as an alias of the exported symbol.
+    pub export_name_aliases: bool,
+    /// Extra names to declare for exported functions, mapping each alias to
+    /// the symbol it refers to.
+    pub aliases: HashMap<String, String>,
+    /// How aliases are declared.
+    pub alias_style: AliasStyle,
+    /// Whether to emit `*const u8` and `*mut u8` parameters and return values
+    /// as pointers to `c_char`, for use with C string functions.
+    pub u8_ptr_as_char: bool,
+    /// Whether to emit the pointers of the `utf8` annotation as pointers to
+    /// `char8_t` in C++, rather than to `c_char`
+    pub utf8_as_char8_t: bool,
+    /// Whether to dispatch to functions named after a primitive type, like
+    /// `foo_u32` and `foo_f64`, with a C11 `_Generic` macro like `foo`.
+    pub generic_macros: bool,
+    /// Whether to declare inline C++ overloads named like these macros, which
+    /// forward to the functions.
+    pub generic_overloads: bool,
+    /// Whether to declare the function pointer types which appear inline in
+    /// declarations with typedefs, one for each signature.
+    pub fn_ptr_typedefs: bool,
+    /// The name of these typedefs, where `{ret}` and `{args}` are replaced by
+    /// the return type and the argument types of the signature.
+    pub fn_ptr_typedef_name: String,
+    /// The C++ wrapper to declare for functions returning nullable pointers.
+    pub nullable_return_wrappers: NullableReturnWrapper,
+    /// The name of these wrappers, where `{name}` is replaced by the name of
+    /// the function.
+    pub nullable_return_wrapper_name: String,
 }
 
 impl Default for FunctionConfig {
@@ -446,8 +945,20 @@ impl Default for FunctionConfig {
             args: Layout::Auto,
             rename_args: RenameRule::None,
             swift_name_macro: None,
+            swift_name_attribute: false,
             sort_by: None,
             no_return: None,
+            export_name_aliases: false,
+            aliases: HashMap::new(),
+            alias_style: AliasStyle::Define,
+            u8_ptr_as_char: false,
+            utf8_as_char8_t: false,
+            generic_macros: false,
+            generic_overloads: false,
+            fn_ptr_typedefs: false,
+            fn_ptr_typedef_name: "{ret}_fn_{args}".to_owned(),
+            nullable_return_wrappers: NullableReturnWrapper::None,
+            nullable_return_wrapper_name: "try_{name}".to_owned(),
         }
     }
 }
@@ -466,10 +977,22 @@ impl FunctionConfig {
         }
         self.postfix.clone()
     }
+
+    /// Returns the aliases configured in `aliases` for `symbol`, sorted by name.
+    pub(crate) fn aliases_for(&self, symbol: &str) -> Vec<&str> {
+        let mut aliases: Vec<&str> = self
+            .aliases
+            .iter()
+            .filter(|(_, target)| *target == symbol)
+            .map(|(alias, _)| alias.as_str())
+            .collect();
+        aliases.sort_unstable();
+        aliases
+    }
 }
 
 /// Settings to apply to generated structs.
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
@@ -491,6 +1014,9 @@ pub struct StructConfig {
     pub derive_gt: bool,
     /// Whether to generate a greater than or equal to operator on structs with one field
     pub derive_gte: bool,
+    /// Whether to generate all the comparison operators, defaulted in C++20
+    /// and piecewise before
+    pub derive_comparisons: bool,
     /// Whether to generate a ostream serializer for the struct
     pub derive_ostream: bool,
     /// Whether associated constants should be in the body. Only applicable to
@@ -498,9 +1024,30 @@ pub struct StructConfig {
     pub associated_constants_in_body: bool,
     /// The way to annotate this struct as #[must_use].
     pub must_use: Option<String>,
+    /// The prefix to put before the index of tuple struct fields to give them
+    /// a name. Defaults to `_`.
+    pub tuple_field_prefix: Option<String>,
+    /// Whether to leave a comment in place of the zero-sized fields, like
+    /// `PhantomData` ones, which are omitted.
+    pub comment_zero_sized_fields: bool,
+    /// The name of a `uint8_t` field to add to structs without any field in C,
+    /// where empty structs aren't valid.
+    pub empty_struct_dummy_field: Option<String>,
+    /// Whether to emit a `<Name>_INIT(...)` macro in C, which initializes the
+    /// struct with the default values of its fields followed by designated
+    /// initializers, and default member initializers in C++.
+    pub init_macro: bool,
 }
 
 impl StructConfig {
+    /// Prefixes the name of a tuple struct field, which is otherwise a bare
+    /// index and not a valid identifier.
+    pub(crate) fn name_tuple_field(&self, name: &mut String) {
+        if name.starts_with(|c: char| c.is_ascii_digit()) {
+            name.insert_str(0, self.tuple_field_prefix.as_deref().unwrap_or("_"));
+        }
+    }
+
     pub(crate) fn derive_constructor(&self, annotations: &AnnotationSet) -> bool {
         if let Some(x) = annotations.bool("derive-constructor") {
             return x;
@@ -543,16 +1090,28 @@ impl StructConfig {
         }
         self.derive_gte
     }
+    pub(crate) fn derive_comparisons(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("derive-comparisons") {
+            return x;
+        }
+        self.derive_comparisons
+    }
     pub(crate) fn derive_ostream(&self, annotations: &AnnotationSet) -> bool {
         if let Some(x) = annotations.bool("derive-ostream") {
             return x;
         }
         self.derive_ostream
     }
+    pub(crate) fn init_macro(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("init-macro") {
+            return x;
+        }
+        self.init_macro
+    }
 }
 
 /// Settings to apply to generated enums.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
@@ -571,10 +1130,17 @@ pub struct EnumConfig {
     /// Whether to generate static `::X(..)` constructors and `IsX()`
     /// methods for tagged enums.
     pub derive_helper_methods: bool,
+    /// Whether to generate `static inline` constructors, `is_x` checks and
+    /// `as_x` accessors for tagged enums in C, like the C++ helper methods.
+    pub derive_c_helper_functions: bool,
     /// Whether to generate `AsX() const` methods for tagged enums.
     pub derive_const_casts: bool,
     /// Whether to generate `AsX()` methods for tagged enums.
     pub derive_mut_casts: bool,
+    /// Whether to generate `to_variant()` and `from_variant()` methods for
+    /// tagged enums, converting them from and to a `std::variant` of their
+    /// variants.
+    pub derive_variant_conversions: bool,
     /// The name of the macro to use for `derive_{const,mut}casts`. If custom, you're
     /// responsible to provide the necessary header, otherwise `assert` will be
     /// used, and `<cassert>` will be included.
@@ -608,8 +1174,10 @@ impl Default for EnumConfig {
             add_sentinel: false,
             prefix_with_name: false,
             derive_helper_methods: false,
+            derive_c_helper_functions: false,
             derive_const_casts: false,
             derive_mut_casts: false,
+            derive_variant_conversions: false,
             cast_assert_name: None,
             must_use: None,
             derive_tagged_enum_destructor: false,
@@ -635,6 +1203,12 @@ impl EnumConfig {
         }
         self.derive_helper_methods
     }
+    pub(crate) fn derive_c_helper_functions(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("derive-c-helper-functions") {
+            return x;
+        }
+        self.derive_c_helper_functions
+    }
     pub(crate) fn derive_const_casts(&self, annotations: &AnnotationSet) -> bool {
         if let Some(x) = annotations.bool("derive-const-casts") {
             return x;
@@ -647,6 +1221,12 @@ impl EnumConfig {
         }
         self.derive_mut_casts
     }
+    pub(crate) fn derive_variant_conversions(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("derive-variant-conversions") {
+            return x;
+        }
+        self.derive_variant_conversions
+    }
     pub(crate) fn derive_tagged_enum_destructor(&self, annotations: &AnnotationSet) -> bool {
         if let Some(x) = annotations.bool("derive-tagged-enum-destructor") {
             return x;
@@ -689,7 +1269,7 @@ impl EnumConfig {
 }
 
 /// Settings to apply to generated constants.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
@@ -700,6 +1280,12 @@ pub struct ConstantConfig {
     pub allow_constexpr: bool,
     /// Sort key for constants
     pub sort_by: Option<SortKey>,
+    /// How constants are emitted, unless overridden by their `style`
+    /// annotation.
+    pub style: ConstantStyle,
+    /// Whether to define a constant with the length of each array typedef and
+    /// array field of a struct.
+    pub array_lengths: bool,
 }
 
 impl Default for ConstantConfig {
@@ -708,12 +1294,14 @@ impl Default for ConstantConfig {
             allow_static_const: true,
             allow_constexpr: true,
             sort_by: None,
+            style: ConstantStyle::Auto,
+            array_lengths: false,
         }
     }
 }
 
 /// Settings for custom macro expansion.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
@@ -741,10 +1329,13 @@ impl FromStr for Profile {
     }
 }
 
-deserialize_enum_str!(Profile);
+deserialize_enum_str!(Profile {
+    Debug => "Debug",
+    Release => "Release",
+});
 
 /// Settings to apply when running `rustc -Zunpretty=expanded`
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
@@ -760,6 +1351,9 @@ pub struct ParseExpandConfig {
     pub features: Option<Vec<String>>,
     /// Controls whether or not to pass `--release` when expanding.
     pub profile: Profile,
+    /// Whether to save the expanded crates in the target directory, and
+    /// reuse them until their sources or the options change.
+    pub cache: bool,
 }
 
 impl Default for ParseExpandConfig {
@@ -770,10 +1364,38 @@ impl Default for ParseExpandConfig {
             default_features: true,
             features: None,
             profile: Profile::Debug,
+            cache: false,
         }
     }
 }
 
+/// Settings to apply when parsing or expanding a single package.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct PackageParseConfig {
+    /// The features enabled in the package. Its `feature = "..."` cfgs are
+    /// resolved with them, and they are passed when expanding it instead of
+    /// `[parse.expand] features`.
+    pub features: Option<Vec<String>>,
+    /// Whether all the features of the package are enabled, like `features`.
+    pub all_features: Option<bool>,
+    /// Whether to use the default feature set when expanding the package.
+    pub default_features: Option<bool>,
+}
+
+impl PackageParseConfig {
+    /// Returns whether the `feature` cfgs of the package can be resolved,
+    /// along with the features it enables, or `None` if it enables all of them.
+    pub(crate) fn enabled_features(&self) -> Option<Option<&[String]>> {
+        if self.all_features == Some(true) {
+            return Some(None);
+        }
+        self.features.as_ref().map(|features| Some(&features[..]))
+    }
+}
+
 // Backwards-compatibility deserializer for ParseExpandConfig. This allows accepting both the
 // simple `expand = ["crate"]` and the more complex `expand = {"crates": ["crate"],
 // "default_features": false}` format for the `expand` key.
@@ -802,6 +1424,7 @@ fn retrocomp_parse_expand_config_deserialize<'de, D: Deserializer<'de>>(
                 default_features: true,
                 features: None,
                 profile: Profile::Debug,
+                cache: false,
             })
         }
 
@@ -814,7 +1437,7 @@ fn retrocomp_parse_expand_config_deserialize<'de, D: Deserializer<'de>>(
 }
 
 /// Settings to apply when parsing.
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
@@ -828,10 +1451,18 @@ pub struct ParseConfig {
     /// before parsing. A crate marked in `expand` doesn't need to be added to any
     /// whitelist.
     pub parse_deps: bool,
-    /// An optional whitelist of names of crates to parse
+    /// An optional whitelist of names of crates to parse, which can be glob
+    /// patterns like `ffi_*`
     pub include: Option<Vec<String>>,
-    /// The names of crates to not parse
+    /// The names of crates to not parse, which can be glob patterns
     pub exclude: Vec<String>,
+    /// Settings of single packages, overriding the others.
+    #[serde(rename = "package")]
+    pub packages: HashMap<String, PackageParseConfig>,
+    /// Glob patterns of Rust source files outside of the crate graph to parse
+    /// along with the crate, following their `mod` declarations. Relative
+    /// patterns are relative to the directory of the binding crate.
+    pub extra_sources: Vec<String>,
     /// The configuration options for `rustc -Zunpretty=expanded`
     #[serde(deserialize_with = "retrocomp_parse_expand_config_deserialize")]
     pub expand: ParseExpandConfig,
@@ -841,9 +1472,97 @@ pub struct ParseConfig {
     /// List of crate names which generate consts, statics, and fns. By default
     /// no dependent crates generate them.
     pub extra_bindings: Vec<String>,
+    /// Types to replace with another type wherever they are used. Keys are Rust
+    /// type paths, values are Rust types such as `u32`, `*const c_char` or the
+    /// name of another item.
+    pub type_map: BTreeMap<String, String>,
+    /// Whether to also emit the statics declared in `extern "C" { }` blocks,
+    /// like the functions declared there.
+    pub foreign_statics: bool,
+    /// Whether to save the items parsed from each crate in the target
+    /// directory, and reuse them until the sources of the crate, the config or
+    /// the toolchain change.
+    pub cache: bool,
+}
+
+/// Whether the crate `name` matches `pattern`, a name or a glob pattern.
+fn crate_matches(pattern: &str, name: &str) -> bool {
+    match glob::Pattern::new(pattern) {
+        Ok(pattern) => pattern.matches(name),
+        Err(_) => pattern == name,
+    }
 }
 
 impl ParseConfig {
+    /// Returns the `all_features`, `default_features` and `features` options to
+    /// expand the package `name` with.
+    pub(crate) fn expand_options(&self, name: &str) -> (bool, bool, Option<Vec<String>>) {
+        let package = self.packages.get(name);
+        let all_features = package
+            .and_then(|package| package.all_features)
+            .unwrap_or(self.expand.all_features);
+        let default_features = package
+            .and_then(|package| package.default_features)
+            .unwrap_or(self.expand.default_features);
+        let features = package
+            .and_then(|package| package.features.clone())
+            .or_else(|| self.expand.features.clone());
+        (all_features, default_features, features)
+    }
+
+    /// Whether the crate `name` is in the `include` whitelist, if any.
+    pub(crate) fn includes_crate(&self, name: &str) -> bool {
+        match self.include {
+            Some(ref include) => include.iter().any(|pattern| crate_matches(pattern, name)),
+            None => true,
+        }
+    }
+
+    /// Whether the crate `name` is in the `exclude` blacklist.
+    pub(crate) fn excludes_crate(&self, name: &str) -> bool {
+        self.exclude
+            .iter()
+            .any(|pattern| crate_matches(pattern, name))
+    }
+
+    /// Returns the files matching the `extra_sources` patterns, relative to
+    /// `root` unless they are absolute.
+    pub(crate) fn extra_source_files(&self, root: &StdPath) -> Vec<PathBuf> {
+        let mut files = Vec::new();
+        for pattern in &self.extra_sources {
+            let full_pattern = root.join(pattern);
+            let paths = match glob::glob(&full_pattern.to_string_lossy()) {
+                Ok(paths) => paths,
+                Err(err) => {
+                    diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::InvalidConfig,
+                        None,
+                        format_args!(
+                            "Invalid pattern in `parse.extra_sources`: {}: {}",
+                            pattern, err
+                        ),
+                    );
+                    continue;
+                }
+            };
+            let count = files.len();
+            files.extend(paths.filter_map(Result::ok).filter(|path| path.is_file()));
+            if files.len() == count {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::InvalidConfig,
+                    None,
+                    format_args!(
+                        "No source file matches `{}` in `parse.extra_sources`.",
+                        pattern
+                    ),
+                );
+            }
+        }
+        files
+    }
+
     pub(crate) fn should_generate_top_level_item(
         &self,
         crate_name: &str,
@@ -859,30 +1578,193 @@ impl ParseConfig {
 }
 
 /// Settings to apply to pointers
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
 pub struct PtrConfig {
     /// Optional attribute to apply to pointers that are required to not be null
     pub non_null_attribute: Option<String>,
+    /// Whether to declare functions with `__attribute__((nonnull(...)))`
+    /// listing their arguments which are required to not be null
+    pub non_null_args: bool,
+}
+
+impl PtrConfig {
+    pub(crate) fn non_null_args(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("non-null-args") {
+            return x;
+        }
+        self.non_null_args
+    }
+}
+
+/// Settings for organizing the documentation of the bindings with Doxygen.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct DoxygenConfig {
+    /// Put the items of each Rust module in a Doxygen group of their own.
+    pub groups: bool,
+    /// The name the groups are referred to with. `{id}` is replaced with the
+    /// module path joined with underscores, `{path}` with the module path
+    /// joined with `::`, and `{name}` with the name of the module.
+    pub group_name: String,
+    /// The title of the groups in the documentation, with the same
+    /// placeholders as `group_name`.
+    pub group_title: String,
+}
+
+impl Default for DoxygenConfig {
+    fn default() -> DoxygenConfig {
+        DoxygenConfig {
+            groups: false,
+            group_name: "{id}".to_owned(),
+            group_title: "{path}".to_owned(),
+        }
+    }
+}
+
+impl DoxygenConfig {
+    fn format_group(template: &str, module_path: &[String]) -> String {
+        template
+            .replace("{id}", &module_path.join("_"))
+            .replace("{path}", &module_path.join("::"))
+            .replace("{name}", module_path.last().map_or("", |name| name))
+    }
+
+    pub(crate) fn group_name(&self, module_path: &[String]) -> String {
+        Self::format_group(&self.group_name, module_path)
+    }
+
+    pub(crate) fn group_title(&self, module_path: &[String]) -> String {
+        Self::format_group(&self.group_title, module_path)
+    }
+}
+
+/// Settings of the macro to export functions and statics from a shared library
+/// with.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct ExportMacroConfig {
+    /// The name of the macro, e.g. `MYLIB_API`. Nothing is exported with a
+    /// macro without it.
+    pub name: Option<String>,
+    /// The define to export symbols with, instead of importing them.
+    /// Defaults to the name of the macro with a `_BUILD` suffix.
+    pub build_define: Option<String>,
+    /// The define to neither export nor import symbols with, when linking the
+    /// library statically.
+    pub static_define: Option<String>,
+    /// The companion header to define the macro in, next to the bindings.
+    /// The macro is defined in the bindings themselves without it.
+    pub header: Option<String>,
+}
+
+impl ExportMacroConfig {
+    pub(crate) fn build_define(&self) -> Cow<'_, str> {
+        match self.build_define {
+            Some(ref define) => Cow::Borrowed(define),
+            None => Cow::Owned(format!(
+                "{}_BUILD",
+                self.name.as_deref().unwrap_or_default()
+            )),
+        }
+    }
+}
+
+/// Settings specific to C bindings.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct CConfig {
+    /// The C standard to target.
+    pub standard: CStandard,
+    /// The source file to write next to the header with the definitions of
+    /// the helper functions, which the header then only declares.
+    pub helpers_file: Option<String>,
+}
+
+/// Settings specific to C++ bindings.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct CppConfig {
+    /// The name of the C++20 module to write a module interface unit for,
+    /// instead of a header.
+    pub module: Option<String>,
+    /// The name of a header to write next to the bindings, with wrappers of
+    /// the functions returning error codes which throw exceptions instead.
+    pub exceptions_header: Option<String>,
+    /// The name of the exception type these wrappers throw.
+    pub exception_name: String,
+    /// The name of these wrappers, where `{name}` is replaced by the name of
+    /// the function.
+    pub throwing_wrapper_name: String,
+    /// The name of a source file to write next to the bindings, with explicit
+    /// instantiations of the templates of the generic types they use, which
+    /// the bindings declare `extern`.
+    pub instantiations_file: Option<String>,
+}
+
+impl Default for CppConfig {
+    fn default() -> CppConfig {
+        CppConfig {
+            module: None,
+            exceptions_header: None,
+            exception_name: "Error".to_owned(),
+            throwing_wrapper_name: "{name}_or_throw".to_owned(),
+            instantiations_file: None,
+        }
+    }
 }
 
 /// Settings specific to Cython bindings.
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
 pub struct CythonConfig {
     /// Header specified in the top level `cdef extern from header:` declaration.
     pub header: Option<String>,
+    /// Optional text to output at the beginning of Cython files, instead of
+    /// `header`.
+    pub file_header: Option<String>,
+    /// Optional text to output at the end of Cython files, instead of
+    /// `trailer`.
+    pub trailer: Option<String>,
+    /// Verbatim declarations added at the end of the `cdef extern from`
+    /// block.
+    pub extra_defs: Option<String>,
     /// `from module cimport name1, name2, ...` declarations added in the same place
     /// where you'd get includes in C.
     pub cimports: BTreeMap<String, Vec<String>>,
+    /// The `.pxd` module declaring the types of each dependency crate, which
+    /// are cimported from it instead of being declared again.
+    pub crate_modules: BTreeMap<String, String>,
+    /// The name of a `.pyx` file to write next to the bindings, with Python
+    /// wrappers of their opaque types and functions.
+    pub wrapper_file: Option<String>,
+    /// The name of a Python file to write next to the bindings, with an
+    /// `enum.IntEnum` mirroring each enum.
+    pub python_enums_file: Option<String>,
+    /// The name of a `.pyi` file to write next to the bindings, with the type
+    /// stubs of the Python wrappers of `wrapper_file`.
+    pub stub_file: Option<String>,
+    /// How the types of `libc.stdint` and `libc.stddef` are cimported.
+    pub libc_cimports: CimportStyle,
+    /// Whether functions are declared `nogil` unless their `nogil` annotation
+    /// says otherwise.
+    pub nogil: bool,
 }
 
 /// A collection of settings to customize the generated bindings.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
@@ -906,6 +1788,8 @@ pub struct Config {
     /// This option is useful when using cbindgen with tools such as python's cffi which
     /// doesn't understand include directives
     pub no_includes: bool,
+    /// Only include the standard headers which the declarations need
+    pub minimal_includes: bool,
     /// Optional text to output at major sections to deter manual editing
     pub autogen_warning: Option<String>,
     /// Include a comment with the version of cbindgen used to generate the file
@@ -916,6 +1800,10 @@ pub struct Config {
     pub namespaces: Option<Vec<String>>,
     /// An optional list of namespaces to declare as using. Only applicable when language="C++"
     pub using_namespaces: Option<Vec<String>>,
+    /// An optional inline namespace to declare everything in, within the
+    /// other namespaces, where `{major}`, `{minor}` and `{patch}` are replaced
+    /// with the version of the crate. Only applicable when language="C++"
+    pub inline_namespace: Option<String>,
     /// The style to use for braces
     pub braces: Braces,
     /// The preferred length of a line, used for auto breaking function arguments
@@ -935,6 +1823,10 @@ pub struct Config {
     /// If this option is true `usize` and `isize` will be converted into `size_t` and `ptrdiff_t`
     /// instead of `uintptr_t` and `intptr_t` respectively.
     pub usize_is_size_t: bool,
+    /// How to emit `i128` and `u128`.
+    pub int128: Int128Style,
+    /// The C type to emit `c_char` as.
+    pub c_char: CCharStyle,
     /// The configuration options for parsing
     pub parse: ParseConfig,
     /// The configuration options for exporting
@@ -943,6 +1835,8 @@ pub struct Config {
     pub macro_expansion: MacroExpansionConfig,
     /// The configuration options for type layouts.
     pub layout: LayoutConfig,
+    /// The configuration options for diagnostics.
+    pub diagnostics: DiagnosticsConfig,
     /// The configuration options for functions
     #[serde(rename = "fn")]
     pub function: FunctionConfig,
@@ -957,12 +1851,36 @@ pub struct Config {
     pub constant: ConstantConfig,
     /// Preprocessor defines to use when generating #ifdef's for #[cfg]
     pub defines: HashMap<String, String>,
+    /// Table of target names to the cfgs they enable, to write a separate
+    /// output for each of them instead of #ifdef's
+    pub targets: HashMap<String, Vec<String>>,
+    /// The outputs to generate from a single parse of the sources, each in its
+    /// own language, instead of the one of `language`
+    #[serde(rename = "output")]
+    pub outputs: Vec<OutputConfig>,
+    /// Write the items of each top-level Rust module to their own file, next
+    /// to an umbrella file including all of them
+    pub split_by_module: bool,
+    /// Whether to precede each item with a `#line` directive pointing at its
+    /// declaration in the Rust sources
+    pub line_directives: bool,
+    /// Guarantee that the bindings are byte-identical wherever they are
+    /// generated, by writing the paths of the sources relative to their crate
+    pub deterministic: bool,
+    /// With `split_by_module`, declare the structs and unions of other modules
+    /// which are only used behind pointers instead of including their module
+    pub forward_declarations: bool,
     /// Include doc comments from Rust as documentation
     pub documentation: bool,
     /// How documentation comments should be styled.
     pub documentation_style: DocumentationStyle,
     /// How much of the documentation should be output for each item.
     pub documentation_length: DocumentationLength,
+    /// Whether to follow each item with a comment giving where it was
+    /// declared in the Rust sources
+    pub documentation_source_location: bool,
+    /// Configuration options for Doxygen
+    pub doxygen: DoxygenConfig,
     /// Configuration options for pointers
     #[serde(rename = "ptr")]
     pub pointer: PtrConfig,
@@ -1001,8 +1919,22 @@ pub struct Config {
     /// via the CLI, when ran from a build script cargo sets this variable
     /// appropriately).
     pub only_target_dependencies: bool,
+    /// The macro to export functions and statics with.
+    pub export_macro: ExportMacroConfig,
+    /// Configuration options specific to C.
+    pub c: CConfig,
+    /// Configuration options specific to C++.
+    pub cpp: CppConfig,
     /// Configuration options specific to Cython.
     pub cython: CythonConfig,
+    /// Named sets of options overriding the others when selected, which can
+    /// inherit the options of another profile.
+    #[serde(rename = "profile")]
+    pub profiles: BTreeMap<String, serde_json::Value>,
+    /// The file the config was loaded from, if any, which the bindings depend
+    /// on like the Rust sources.
+    #[serde(skip)]
+    pub config_path: Option<PathBuf>,
 }
 
 impl Default for Config {
@@ -1018,9 +1950,11 @@ impl Default for Config {
             autogen_warning: None,
             include_version: false,
             no_includes: false,
+            minimal_includes: false,
             namespace: None,
             namespaces: None,
             using_namespaces: None,
+            inline_namespace: None,
             braces: Braces::SameLine,
             line_length: 100,
             tab_width: 2,
@@ -1029,34 +1963,379 @@ impl Default for Config {
             cpp_compat: false,
             style: Style::default(),
             usize_is_size_t: false,
+            int128: Int128Style::default(),
+            c_char: CCharStyle::default(),
             sort_by: SortKey::None,
             macro_expansion: Default::default(),
             parse: ParseConfig::default(),
             export: ExportConfig::default(),
             layout: LayoutConfig::default(),
+            diagnostics: DiagnosticsConfig::default(),
             function: FunctionConfig::default(),
             structure: StructConfig::default(),
             enumeration: EnumConfig::default(),
             constant: ConstantConfig::default(),
             defines: HashMap::new(),
+            targets: HashMap::new(),
+            outputs: Vec::new(),
+            split_by_module: false,
+            line_directives: false,
+            deterministic: false,
+            forward_declarations: false,
             documentation: true,
             documentation_style: DocumentationStyle::Auto,
             documentation_length: DocumentationLength::Full,
+            documentation_source_location: false,
+            doxygen: DoxygenConfig::default(),
             pointer: PtrConfig::default(),
             only_target_dependencies: false,
+            export_macro: ExportMacroConfig::default(),
+            c: CConfig::default(),
+            cpp: CppConfig::default(),
             cython: CythonConfig::default(),
+            profiles: BTreeMap::new(),
+            config_path: None,
+        }
+    }
+}
+
+/// The values of the `${...}` placeholders of the config, which are replaced
+/// when generating the bindings. Other names are read from the environment.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Placeholders {
+    /// `${CRATE_NAME}`, the name of the binding crate.
+    crate_name: Option<String>,
+    /// `${CRATE_VERSION}`, the version of the binding crate.
+    crate_version: Option<String>,
+    /// The directory to find the commit of `${GIT_SHA}` from.
+    crate_dir: Option<PathBuf>,
+    git_sha: RefCell<Option<String>>,
+}
+
+impl Placeholders {
+    pub fn new(
+        crate_name: Option<String>,
+        crate_version: Option<String>,
+        crate_dir: Option<PathBuf>,
+    ) -> Placeholders {
+        Placeholders {
+            crate_name,
+            crate_version,
+            crate_dir,
+            git_sha: RefCell::new(None),
+        }
+    }
+
+    /// Returns `text` with its placeholders replaced. Unknown ones are
+    /// replaced with nothing, like unset variables in a shell.
+    pub fn interpolate(&self, text: &str) -> String {
+        let mut interpolated = String::new();
+        let mut rest = text;
+        while let Some(start) = rest.find("${") {
+            let end = match rest[start..].find('}') {
+                Some(end) => start + end,
+                None => break,
+            };
+            interpolated.push_str(&rest[..start]);
+            interpolated.push_str(&self.value(&rest[start + 2..end]));
+            rest = &rest[end + 1..];
+        }
+        interpolated.push_str(rest);
+        interpolated
+    }
+
+    fn value(&self, name: &str) -> String {
+        let value = match name {
+            "CRATE_NAME" => self.crate_name.clone(),
+            "CRATE_VERSION" => self.crate_version.clone(),
+            "GIT_SHA" => self.git_sha(),
+            _ => env::var(name).ok(),
+        };
+        value.unwrap_or_else(|| {
+            diagnostic::report(
+                Level::Warn,
+                DiagnosticKind::InvalidConfig,
+                None,
+                format_args!("Can't find the value of ${{{}}} in the config.", name),
+            );
+            String::new()
+        })
+    }
+
+    /// The commit checked out in the crate directory, which is only looked up
+    /// once, when needed.
+    fn git_sha(&self) -> Option<String> {
+        if let Some(ref sha) = *self.git_sha.borrow() {
+            return Some(sha.clone());
         }
+        let output = Command::new("git")
+            .args(&["rev-parse", "HEAD"])
+            .current_dir(
+                self.crate_dir
+                    .as_deref()
+                    .unwrap_or_else(|| StdPath::new(".")),
+            )
+            .output()
+            .ok()
+            .filter(|output| output.status.success())?;
+        let sha = String::from_utf8_lossy(&output.stdout).trim().to_owned();
+        *self.git_sha.borrow_mut() = Some(sha.clone());
+        Some(sha)
     }
 }
 
 impl Config {
+    /// Replaces the `${...}` placeholders in the texts written in the bindings
+    /// and in the names of the files they are written to.
+    pub(crate) fn interpolate(&mut self, placeholders: &Placeholders) {
+        let interpolate = |text: &mut String| *text = placeholders.interpolate(text);
+        let texts = self
+            .header
+            .iter_mut()
+            .chain(self.trailer.iter_mut())
+            .chain(self.after_includes.iter_mut())
+            .chain(self.autogen_warning.iter_mut())
+            .chain(self.include_guard.iter_mut())
+            .chain(self.namespace.iter_mut())
+            .chain(self.namespaces.iter_mut().flatten())
+            .chain(self.includes.iter_mut())
+            .chain(self.sys_includes.iter_mut())
+            .chain(self.cython.header.iter_mut())
+            .chain(self.cython.file_header.iter_mut())
+            .chain(self.cython.trailer.iter_mut())
+            .chain(self.export.symbols.version.iter_mut())
+            .chain(self.export.symbols.library.iter_mut())
+            .chain(self.outputs.iter_mut().map(|output| &mut output.file));
+        for text in texts {
+            interpolate(text);
+        }
+    }
+
+    /// Replaces `{major}`, `{minor}` and `{patch}` in `inline_namespace` with
+    /// the components of `version`, the version of the crate. The inline
+    /// namespace is dropped if it needs a version that isn't known.
+    pub(crate) fn resolve_inline_namespace(&mut self, version: Option<&str>) {
+        let namespace = match self.inline_namespace {
+            Some(ref namespace) if namespace.contains('{') => namespace,
+            _ => return,
+        };
+        let version = match version {
+            Some(version) => version,
+            None => {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::InvalidConfig,
+                    None,
+                    format_args!(
+                        "Can't derive the inline namespace {:?} without the version of a crate.",
+                        namespace
+                    ),
+                );
+                self.inline_namespace = None;
+                return;
+            }
+        };
+        // Pre-release and build metadata can't be part of an identifier.
+        let release = version.split(|c| c == '-' || c == '+').next().unwrap();
+        let mut components = release.split('.');
+        let mut resolved = namespace.clone();
+        for placeholder in &["{major}", "{minor}", "{patch}"] {
+            resolved = resolved.replace(placeholder, components.next().unwrap_or("0"));
+        }
+        self.inline_namespace = Some(resolved);
+    }
+
     pub(crate) fn cpp_compatible_c(&self) -> bool {
         self.language == Language::C && self.cpp_compat
     }
 
-    pub(crate) fn include_guard(&self) -> Option<&str> {
+    /// The macro to export functions and statics with, which Cython has no
+    /// use for.
+    pub(crate) fn export_macro(&self) -> Option<&str> {
         if self.language == Language::Cython {
             None
+        } else {
+            self.export_macro.name.as_deref()
+        }
+    }
+
+    /// Whether to put items in Doxygen groups per module, which needs their
+    /// documentation.
+    pub(crate) fn doxygen_groups(&self) -> bool {
+        self.doxygen.groups && self.documentation && self.language != Language::Cython
+    }
+
+    pub(crate) fn split_by_module(&self) -> bool {
+        self.split_by_module
+    }
+
+    /// The source file defining the C helper functions, if they aren't
+    /// `static inline` in the header.
+    pub(crate) fn c_helpers_file(&self) -> Option<&str> {
+        match self.language {
+            Language::C => self.c.helpers_file.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The header of the wrappers throwing exceptions for error codes.
+    pub(crate) fn cpp_exceptions_header(&self) -> Option<&str> {
+        match self.language {
+            Language::Cxx => self.cpp.exceptions_header.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The source file of the explicit instantiations of templates. Modules
+    /// can't export them, so there is none with `cpp.module`.
+    pub(crate) fn cpp_instantiations_file(&self) -> Option<&str> {
+        match self.language {
+            Language::Cxx if self.cpp.module.is_none() => self.cpp.instantiations_file.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The `.pyx` file of the Python wrappers of the Cython declarations.
+    pub(crate) fn cython_wrapper_file(&self) -> Option<&str> {
+        match self.language {
+            Language::Cython => self.cython.wrapper_file.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The Python file of the `enum.IntEnum`s mirroring the enums.
+    pub(crate) fn cython_python_enums_file(&self) -> Option<&str> {
+        match self.language {
+            Language::Cython => self.cython.python_enums_file.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The text to output at the beginning of files, which Cython files can
+    /// set apart.
+    pub(crate) fn file_header(&self) -> Option<&str> {
+        match self.language {
+            Language::Cython if self.cython.file_header.is_some() => {
+                self.cython.file_header.as_deref()
+            }
+            _ => self.header.as_deref(),
+        }
+    }
+
+    /// The text to output at the end of files, which Cython files can set
+    /// apart.
+    pub(crate) fn file_trailer(&self) -> Option<&str> {
+        match self.language {
+            Language::Cython if self.cython.trailer.is_some() => self.cython.trailer.as_deref(),
+            _ => self.trailer.as_deref(),
+        }
+    }
+
+    /// The verbatim declarations added at the end of the `cdef extern from`
+    /// block.
+    pub(crate) fn cython_extra_defs(&self) -> Option<&str> {
+        match self.language {
+            Language::Cython => self.cython.extra_defs.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The `.pyi` file of the type stubs of the Python wrappers.
+    pub(crate) fn cython_stub_file(&self) -> Option<&str> {
+        match self.language {
+            Language::Cython => self.cython.stub_file.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The name of the C++20 module the bindings are the interface of.
+    pub(crate) fn cpp_module(&self) -> Option<&str> {
+        match self.language {
+            Language::Cxx => self.cpp.module.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn c23(&self) -> bool {
+        self.language == Language::C && self.c.standard == CStandard::C23
+    }
+
+    /// The standard attribute to mark functions which never return with, in
+    /// front of their declaration. It isn't used when `fn.no_return` is
+    /// configured, and there is none before C11.
+    pub(crate) fn no_return_attribute(&self) -> Option<&'static str> {
+        if self.function.no_return.is_some() {
+            return None;
+        }
+        match self.language {
+            Language::Cxx => Some("[[noreturn]]"),
+            Language::C if self.c23() => Some("[[noreturn]]"),
+            Language::C if self.c.standard == CStandard::C11 => Some("_Noreturn"),
+            Language::C | Language::Cython => None,
+        }
+    }
+
+    /// The macro that C types as #[repr(align(...))] put in front of their
+    /// first field when `layout.alignas` is used. It expands to `_Alignas`,
+    /// or to `alignas` in C++ and `__declspec(align)` with MSVC. C++ output
+    /// uses `alignas` on the type itself instead.
+    pub(crate) fn alignas_macro(&self) -> Option<&'static str> {
+        if self.language != Language::C || !self.layout.alignas || self.layout.aligned_n.is_some() {
+            return None;
+        }
+        Some("CBINDGEN_ALIGNAS")
+    }
+
+    /// Whether types as #[repr(packed)] are surrounded with
+    /// `#pragma pack(push, 1)` and `#pragma pack(pop)`.
+    pub(crate) fn pragma_pack(&self, alignment: Option<ReprAlign>) -> bool {
+        self.language != Language::Cython
+            && self.layout.pragma_pack
+            && self.layout.packed.is_none()
+            && alignment == Some(ReprAlign::Packed)
+    }
+
+    /// The qualifier of the pointer arguments annotated as `restrict`. C++ has
+    /// no `restrict`, but its compilers understand `__restrict`.
+    pub(crate) fn restrict_keyword(&self) -> Option<&'static str> {
+        match self.language {
+            Language::C if !self.cpp_compat => Some("restrict"),
+            Language::C | Language::Cxx => Some("__restrict"),
+            Language::Cython => None,
+        }
+    }
+
+    /// Whether the length of array typedefs and fields is defined as a
+    /// constant, which isn't supported for Cython.
+    pub(crate) fn array_lengths(&self) -> bool {
+        self.constant.array_lengths && self.language != Language::Cython
+    }
+
+    /// The attribute to mark must_use items with, which defaults to
+    /// `[[nodiscard]]` in C23.
+    pub(crate) fn must_use_attribute<'a>(&self, configured: &'a Option<String>) -> Option<&'a str> {
+        match *configured {
+            Some(ref attribute) => Some(attribute),
+            None if self.c23() => Some("[[nodiscard]]"),
+            None => None,
+        }
+    }
+
+    /// The attribute giving an item the name `name` when imported into Swift,
+    /// through `fn.swift_name_macro` if there is one.
+    pub(crate) fn swift_name_attribute(&self, name: &str) -> Option<String> {
+        if self.language == Language::Cython {
+            return None;
+        }
+        Some(match self.function.swift_name_macro {
+            Some(ref swift_name_macro) => format!("{}({})", swift_name_macro, name),
+            None => format!("__attribute__((swift_name(\"{}\")))", name),
+        })
+    }
+
+    pub(crate) fn include_guard(&self) -> Option<&str> {
+        if self.language == Language::Cython || self.cpp_module().is_some() {
+            None
         } else {
             self.include_guard.as_deref()
         }
@@ -1078,27 +2357,277 @@ impl Config {
         }
     }
 
+    #[allow(unused)]
     pub fn from_file<P: AsRef<StdPath>>(file_name: P) -> Result<Config, String> {
-        let config_text = fs::read_to_string(file_name.as_ref()).map_err(|_| {
-            format!(
-                "Couldn't open config file: {}.",
-                file_name.as_ref().display()
-            )
-        })?;
+        let format = ConfigFormat::from_path(file_name.as_ref());
+        Config::from_file_with_format(file_name, format)
+    }
+
+    pub fn from_file_with_format<P: AsRef<StdPath>>(
+        file_name: P,
+        format: ConfigFormat,
+    ) -> Result<Config, String> {
+        let config_text = read_config_file(file_name.as_ref())?;
+        let mut config = Config::from_str_with_format(&config_text, format)?;
+        config.config_path = Some(file_name.as_ref().to_path_buf());
+        Ok(config)
+    }
+
+    /// Loads a config with the options of the `[profile.<name>]` table
+    /// overriding the others.
+    pub fn from_file_with_profile<P: AsRef<StdPath>>(
+        file_name: P,
+        format: ConfigFormat,
+        profile: &str,
+    ) -> Result<Config, String> {
+        let config_text = read_config_file(file_name.as_ref())?;
+        let mut config = Config::from_str_with_profile(&config_text, format, profile)?;
+        config.config_path = Some(file_name.as_ref().to_path_buf());
+        Ok(config)
+    }
+
+    /// Parses a config, e.g. one generated by a build system, in any of the
+    /// formats. The keys are the same in all of them.
+    pub fn from_str_with_format(config_text: &str, format: ConfigFormat) -> Result<Config, String> {
+        parse_config::<Config>(config_text, format)
+    }
 
-        match toml::from_str::<Config>(&config_text) {
-            Ok(x) => Ok(x),
-            Err(e) => Err(format!("Couldn't parse config file: {}.", e)),
+    /// Parses a config with the options of the `[profile.<name>]` table
+    /// overriding the others.
+    pub fn from_str_with_profile(
+        config_text: &str,
+        format: ConfigFormat,
+        profile: &str,
+    ) -> Result<Config, String> {
+        let mut config = parse_config::<serde_json::Value>(config_text, format)?;
+        apply_profile(&mut config, profile)?;
+        serde_json::from_value::<Config>(config)
+            .map_err(|e| format!("Couldn't parse config file: {}.", e))
+    }
+
+    /// The JSON schema of the config, with the type and the default value of
+    /// every key, e.g. for editors to complete and check configs.
+    #[allow(unused)]
+    pub fn schema() -> serde_json::Value {
+        schema::config_schema()
+    }
+
+    /// Checks a config in any of the formats, and returns all its unknown keys,
+    /// values of the wrong type and unknown values of enums like `language`,
+    /// along with their keys, where parsing stops at the first one. Other
+    /// invalid values are reported when there are no such problems.
+    #[allow(unused)]
+    pub fn check_str(config_text: &str, format: ConfigFormat) -> Vec<String> {
+        let config = match parse_config::<serde_json::Value>(config_text, format) {
+            Ok(config) => config,
+            Err(err) => return vec![err],
+        };
+        let mut problems = schema::check_config(&config);
+        if problems.is_empty() {
+            if let Err(err) = serde_json::from_value::<Config>(config) {
+                problems.push(err.to_string());
+            }
         }
+        problems
     }
 
-    pub fn from_root_or_default<P: AsRef<StdPath>>(root: P) -> Config {
-        let c = root.as_ref().join("cbindgen.toml");
+    /// Returns the first of `cbindgen.toml`, `cbindgen.json`, `cbindgen.yaml`
+    /// and `cbindgen.yml` which exists in `root`.
+    pub fn find_in_root<P: AsRef<StdPath>>(root: P) -> Option<PathBuf> {
+        CONFIG_FILE_NAMES
+            .iter()
+            .map(|name| root.as_ref().join(name))
+            .find(|c| c.exists())
+    }
 
-        if c.exists() {
+    /// Loads `cbindgen.toml`, `cbindgen.json`, `cbindgen.yaml` or
+    /// `cbindgen.yml` from `root`, whichever exists first, or the default
+    /// config.
+    #[allow(unused)]
+    pub fn from_root_or_default<P: AsRef<StdPath>>(root: P) -> Config {
+        if let Some(c) = Config::find_in_root(root) {
             Config::from_file(c).unwrap()
         } else {
             Config::default()
         }
     }
 }
+
+fn read_config_file(file_name: &StdPath) -> Result<String, String> {
+    fs::read_to_string(file_name)
+        .map_err(|_| format!("Couldn't open config file: {}.", file_name.display()))
+}
+
+fn parse_config<T: for<'de> Deserialize<'de>>(
+    config_text: &str,
+    format: ConfigFormat,
+) -> Result<T, String> {
+    let result = match format {
+        ConfigFormat::Toml => toml::from_str::<T>(config_text).map_err(|e| e.to_string()),
+        ConfigFormat::Json => serde_json::from_str::<T>(config_text).map_err(|e| e.to_string()),
+        ConfigFormat::Yaml => serde_yaml::from_str::<T>(config_text).map_err(|e| e.to_string()),
+    };
+    result.map_err(|e| format!("Couldn't parse config file: {}.", e))
+}
+
+/// Overrides the options of `config` with those of the profile `name`, after
+/// those of the profiles it inherits.
+fn apply_profile(config: &mut serde_json::Value, name: &str) -> Result<(), String> {
+    let profiles = config.get("profile").cloned().unwrap_or_default();
+
+    let mut chain = Vec::new();
+    let mut next = Some(name.to_owned());
+    while let Some(name) = next {
+        if chain.contains(&name) {
+            return Err(format!("Config profile '{}' inherits itself.", name));
+        }
+        let profile = profiles
+            .get(&name)
+            .ok_or_else(|| format!("Unknown config profile: '{}'.", name))?;
+        next = match profile.get("inherits") {
+            None => None,
+            Some(serde_json::Value::String(parent)) => Some(parent.clone()),
+            Some(_) => {
+                return Err(format!(
+                    "The `inherits` key of config profile '{}' must be a string.",
+                    name
+                ))
+            }
+        };
+        chain.push(name);
+    }
+
+    for name in chain.iter().rev() {
+        let mut profile = profiles[name].clone();
+        if let Some(profile) = profile.as_object_mut() {
+            profile.remove("inherits");
+        }
+        merge_config(config, profile);
+    }
+    Ok(())
+}
+
+/// Merges the tables of `overrides` into those of `config`, and replaces the
+/// other values.
+fn merge_config(config: &mut serde_json::Value, overrides: serde_json::Value) {
+    match (config, overrides) {
+        (serde_json::Value::Object(config), serde_json::Value::Object(overrides)) => {
+            for (key, value) in overrides {
+                match config.get_mut(&key) {
+                    Some(config) => merge_config(config, value),
+                    None => {
+                        config.insert(key, value);
+                    }
+                }
+            }
+        }
+        (config, overrides) => *config = overrides,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn profiles() {
+        let config = r#"
+            language = "C"
+            [export]
+            prefix = "Base"
+            [export.rename]
+            "Foo" = "Bar"
+
+            [profile.a.export]
+            prefix = "A"
+
+            [profile.b]
+            inherits = "a"
+            language = "C++"
+            [profile.b.export.rename]
+            "Baz" = "Qux"
+        "#;
+
+        let config = Config::from_str_with_profile(config, ConfigFormat::Toml, "b").unwrap();
+        assert_eq!(config.language, Language::Cxx);
+        assert_eq!(config.export.prefix.as_deref(), Some("A"));
+        assert_eq!(config.export.rename.len(), 2);
+
+        let error = Config::from_str_with_profile("", ConfigFormat::Toml, "c").unwrap_err();
+        assert_eq!(error, "Unknown config profile: 'c'.");
+    }
+
+    #[test]
+    fn placeholders() {
+        env::set_var("CBINDGEN_TEST_PLACEHOLDER", "from-env");
+        let placeholders = Placeholders::new(Some("my_crate".to_owned()), None, None);
+        assert_eq!(
+            placeholders.interpolate("${CRATE_NAME} ${CBINDGEN_TEST_PLACEHOLDER} ${CRATE_VERSION}"),
+            "my_crate from-env "
+        );
+        assert_eq!(placeholders.interpolate("${unterminated"), "${unterminated");
+    }
+    #[test]
+    fn schema() {
+        let schema = Config::schema();
+        let include_guard = &schema["properties"]["include_guard"];
+        assert_eq!(include_guard["type"], json!(["string", "null"]));
+        let prefix = &schema["properties"]["export"]["properties"]["prefix"];
+        assert_eq!(prefix["type"], json!(["string", "null"]));
+        let language = &schema["properties"]["language"];
+        assert_eq!(language["type"], "string");
+        assert_eq!(
+            language["enum"],
+            json!(["C++", "c++", "C", "c", "Cython", "cython"])
+        );
+        let rename_args = &schema["properties"]["fn"]["properties"]["rename_args"];
+        assert!(rename_args["enum"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("snake_case")));
+
+        // The defaults are valid values.
+        let default = serde_json::to_value(Config::default()).unwrap();
+        serde_json::from_value::<Config>(default).unwrap();
+    }
+
+    #[test]
+    fn check_str() {
+        let config = r#"
+            incldue_guard = "BINDINGS_H"
+            language = 3
+
+            [export.rename]
+            "Foo" = "Bar"
+            "Baz" = true
+        "#;
+        assert_eq!(
+            Config::check_str(config, ConfigFormat::Toml),
+            [
+                "`export.rename.Baz` should be of type string, not boolean.",
+                "Unknown key `incldue_guard`, did you mean `include_guard`?",
+                "`language` should be of type string, not integer.",
+            ]
+        );
+        let config = r#"
+            language = "Klingon"
+            sort_by = "Size"
+
+            [fn]
+            rename_args = "snake_case"
+
+            [enum]
+            rename_variants = "kebab-case"
+        "#;
+        assert_eq!(
+            Config::check_str(config, ConfigFormat::Toml),
+            [
+                "`enum.rename_variants`: Unrecognized RenameRule: 'kebab-case'.",
+                "`language`: Unrecognized Language: 'Klingon'.",
+                "`sort_by`: Unrecognized sort option: 'Size'.",
+            ]
+        );
+        assert!(Config::check_str("language = \"C\"", ConfigFormat::Toml).is_empty());
+    }
+}
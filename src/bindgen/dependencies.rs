@@ -2,10 +2,11 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
 
 use crate::bindgen::ir::{ItemContainer, Path};
+use crate::bindgen::library::Library;
 
 /// A dependency list is used for gathering what order to output the types.
 #[derive(Default)]
@@ -26,17 +27,15 @@ impl Dependencies {
         // Sort untagged enums and opaque structs into their own layers because they don't
         // depend on each other or anything else.
         let ordering = |a: &ItemContainer, b: &ItemContainer| match (a, b) {
-            (&ItemContainer::Enum(ref x), &ItemContainer::Enum(ref y))
+            (ItemContainer::Enum(x), ItemContainer::Enum(y))
                 if x.tag.is_none() && y.tag.is_none() =>
             {
                 x.path.cmp(&y.path)
             }
-            (&ItemContainer::Enum(ref x), _) if x.tag.is_none() => Ordering::Less,
-            (_, &ItemContainer::Enum(ref x)) if x.tag.is_none() => Ordering::Greater,
+            (ItemContainer::Enum(x), _) if x.tag.is_none() => Ordering::Less,
+            (_, ItemContainer::Enum(x)) if x.tag.is_none() => Ordering::Greater,
 
-            (&ItemContainer::OpaqueItem(ref x), &ItemContainer::OpaqueItem(ref y)) => {
-                x.path.cmp(&y.path)
-            }
+            (ItemContainer::OpaqueItem(x), ItemContainer::OpaqueItem(y)) => x.path.cmp(&y.path),
             (&ItemContainer::OpaqueItem(_), _) => Ordering::Less,
             (_, &ItemContainer::OpaqueItem(_)) => Ordering::Greater,
 
@@ -45,4 +44,83 @@ impl Dependencies {
 
         self.order.sort_by(ordering);
     }
+
+    /// Reorders the items by `compare`, as far as the items they depend on
+    /// still come first. Items depending on each other keep their order.
+    pub fn sort_topologically<F>(&mut self, library: &Library, compare: F)
+    where
+        F: Fn(&ItemContainer, &ItemContainer) -> Ordering,
+    {
+        let items: Vec<ItemContainer> = self.order.drain(..).collect();
+
+        // The rank of each item in the order of `compare`, ties keeping their
+        // order, so that the ready items can be kept in a heap.
+        let mut by_rank: Vec<usize> = (0..items.len()).collect();
+        by_rank.sort_by(|&a, &b| compare(&items[a], &items[b]));
+        let mut rank = vec![0; items.len()];
+        for (r, &i) in by_rank.iter().enumerate() {
+            rank[i] = r;
+        }
+
+        let mut indices_by_path: HashMap<&Path, Vec<usize>> = HashMap::new();
+        for (i, item) in items.iter().enumerate() {
+            indices_by_path
+                .entry(item.deref().path())
+                .or_default()
+                .push(i);
+        }
+
+        // Kahn's algorithm: count the items each item waits for, and release
+        // the items depending on it once it's written.
+        let mut in_degree = vec![0; items.len()];
+        let mut dependents = vec![Vec::new(); items.len()];
+        for (i, item) in items.iter().enumerate() {
+            let mut dependencies = Dependencies::new();
+            item.deref().add_dependencies(library, &mut dependencies);
+            dependencies.items.remove(item.deref().path());
+            for path in &dependencies.items {
+                for &j in indices_by_path.get(path).into_iter().flatten() {
+                    dependents[j].push(i);
+                    in_degree[i] += 1;
+                }
+            }
+        }
+
+        let mut ready: BinaryHeap<Reverse<(usize, usize)>> = (0..items.len())
+            .filter(|&i| in_degree[i] == 0)
+            .map(|i| Reverse((rank[i], i)))
+            .collect();
+        let mut done = vec![false; items.len()];
+        let mut order = Vec::with_capacity(items.len());
+        let mut first_pending = 0;
+        while order.len() < items.len() {
+            let next = match ready.pop() {
+                Some(Reverse((_, i))) => i,
+                None => {
+                    // Only cycles are left: break them in the original order.
+                    while done[first_pending] {
+                        first_pending += 1;
+                    }
+                    first_pending
+                }
+            };
+            if done[next] {
+                continue;
+            }
+            done[next] = true;
+            order.push(next);
+            for &dependent in &dependents[next] {
+                in_degree[dependent] -= 1;
+                if in_degree[dependent] == 0 && !done[dependent] {
+                    ready.push(Reverse((rank[dependent], dependent)));
+                }
+            }
+        }
+
+        let mut items: Vec<Option<ItemContainer>> = items.into_iter().map(Some).collect();
+        self.order = order
+            .into_iter()
+            .map(|i| items[i].take().unwrap())
+            .collect();
+    }
 }
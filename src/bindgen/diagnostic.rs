@@ -0,0 +1,225 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Structured warnings and errors about the Rust sources.
+//!
+//! The problems cbindgen finds in the sources, like items it skips, are logged
+//! as usual with the `log` crate, under a target naming their kind. While such
+//! a record is being logged, `current_span` returns where the problem is, so
+//! that a logger can report it to an IDE or a CI system, as
+//! `--message-format=json` does.
+//!
+//! The kinds listed in `[diagnostics] deny` are reported as errors instead,
+//! and make the generation fail once everything has been reported.
+
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::str::FromStr;
+
+use log::Level;
+
+/// What a diagnostic is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// An item which can't be exported, e.g. because it isn't `pub`.
+    SkippedItem,
+    /// An item whose type, or the type of one of its fields or parameters,
+    /// has no C equivalent.
+    UnsupportedType,
+    /// Two items which are declared with the same name after being renamed.
+    RenameCollision,
+    /// A source file which isn't valid Rust.
+    SyntaxError,
+    /// A type whose definition can't be exported, and which is declared as
+    /// opaque instead.
+    OpaqueType,
+    /// A constant whose value has no C equivalent.
+    UnsupportedLiteral,
+    /// An annotation, or an option of the config, which can't apply to an
+    /// item, and is ignored.
+    IgnoredAnnotation,
+    /// A helper which would be declared for an item, like a deleter, a member
+    /// function, an overload or a Python enum, but can't be.
+    SkippedHelper,
+    /// A type which isn't one of the parsed items.
+    UnknownType,
+    /// A cfg which has no entry in `[defines]`.
+    MissingDefine,
+    /// A module or a crate whose sources can't be found.
+    MissingSource,
+    /// A value of the config which can't be used.
+    InvalidConfig,
+    /// A problem with Cargo or with the files cbindgen caches its work in,
+    /// which doesn't change the bindings.
+    Environment,
+}
+
+impl DiagnosticKind {
+    const ALL: &'static [DiagnosticKind] = &[
+        DiagnosticKind::SkippedItem,
+        DiagnosticKind::UnsupportedType,
+        DiagnosticKind::RenameCollision,
+        DiagnosticKind::SyntaxError,
+        DiagnosticKind::OpaqueType,
+        DiagnosticKind::UnsupportedLiteral,
+        DiagnosticKind::IgnoredAnnotation,
+        DiagnosticKind::SkippedHelper,
+        DiagnosticKind::UnknownType,
+        DiagnosticKind::MissingDefine,
+        DiagnosticKind::MissingSource,
+        DiagnosticKind::InvalidConfig,
+        DiagnosticKind::Environment,
+    ];
+
+    /// The name of the kind, e.g. `skipped_item`.
+    pub fn code(self) -> &'static str {
+        match self {
+            DiagnosticKind::SkippedItem => "skipped_item",
+            DiagnosticKind::UnsupportedType => "unsupported_type",
+            DiagnosticKind::RenameCollision => "rename_collision",
+            DiagnosticKind::SyntaxError => "syntax_error",
+            DiagnosticKind::OpaqueType => "opaque_type",
+            DiagnosticKind::UnsupportedLiteral => "unsupported_literal",
+            DiagnosticKind::IgnoredAnnotation => "ignored_annotation",
+            DiagnosticKind::SkippedHelper => "skipped_helper",
+            DiagnosticKind::UnknownType => "unknown_type",
+            DiagnosticKind::MissingDefine => "missing_define",
+            DiagnosticKind::MissingSource => "missing_source",
+            DiagnosticKind::InvalidConfig => "invalid_config",
+            DiagnosticKind::Environment => "environment",
+        }
+    }
+
+    /// The target of the log records of the kind.
+    pub fn target(self) -> &'static str {
+        match self {
+            DiagnosticKind::SkippedItem => "cbindgen::skipped_item",
+            DiagnosticKind::UnsupportedType => "cbindgen::unsupported_type",
+            DiagnosticKind::RenameCollision => "cbindgen::rename_collision",
+            DiagnosticKind::SyntaxError => "cbindgen::syntax_error",
+            DiagnosticKind::OpaqueType => "cbindgen::opaque_type",
+            DiagnosticKind::UnsupportedLiteral => "cbindgen::unsupported_literal",
+            DiagnosticKind::IgnoredAnnotation => "cbindgen::ignored_annotation",
+            DiagnosticKind::SkippedHelper => "cbindgen::skipped_helper",
+            DiagnosticKind::UnknownType => "cbindgen::unknown_type",
+            DiagnosticKind::MissingDefine => "cbindgen::missing_define",
+            DiagnosticKind::MissingSource => "cbindgen::missing_source",
+            DiagnosticKind::InvalidConfig => "cbindgen::invalid_config",
+            DiagnosticKind::Environment => "cbindgen::environment",
+        }
+    }
+
+    /// The kind of the log records with `target`, if they're diagnostics.
+    pub fn from_target(target: &str) -> Option<DiagnosticKind> {
+        DiagnosticKind::ALL
+            .iter()
+            .copied()
+            .find(|kind| kind.target() == target)
+    }
+}
+
+impl FromStr for DiagnosticKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DiagnosticKind::ALL
+            .iter()
+            .copied()
+            .find(|kind| kind.code() == s)
+            .ok_or_else(|| format!("Unrecognized diagnostic kind: '{}'.", s))
+    }
+}
+
+deserialize_enum_str!(DiagnosticKind {
+    SkippedItem => "skipped_item",
+    UnsupportedType => "unsupported_type",
+    RenameCollision => "rename_collision",
+    SyntaxError => "syntax_error",
+    OpaqueType => "opaque_type",
+    UnsupportedLiteral => "unsupported_literal",
+    IgnoredAnnotation => "ignored_annotation",
+    SkippedHelper => "skipped_helper",
+    UnknownType => "unknown_type",
+    MissingDefine => "missing_define",
+    MissingSource => "missing_source",
+    InvalidConfig => "invalid_config",
+    Environment => "environment",
+});
+
+/// A place in the Rust sources.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    /// The source file, named as in the `#line` directives.
+    pub file: String,
+    /// The line, starting from 1.
+    pub line: usize,
+    /// The column, starting from 1, if known.
+    pub column: Option<usize>,
+}
+
+thread_local! {
+    static CURRENT_SPAN: RefCell<Option<Span>> = RefCell::new(None);
+    /// The kinds reported as errors on this thread, and how many diagnostics
+    /// of these kinds were reported.
+    static DENIED: RefCell<(Vec<DiagnosticKind>, usize)> = RefCell::new((Vec::new(), 0));
+    /// How many diagnostics were reported on this thread.
+    static REPORTED: Cell<usize> = Cell::new(0);
+}
+
+/// Where the problem of the diagnostic being logged on this thread is, if
+/// known.
+pub fn current_span() -> Option<Span> {
+    CURRENT_SPAN.with(|span| span.borrow().clone())
+}
+
+/// Logs a diagnostic of `kind` about `span`.
+pub(crate) fn report(level: Level, kind: DiagnosticKind, span: Option<Span>, args: fmt::Arguments) {
+    let level = DENIED.with(|denied| {
+        let mut denied = denied.borrow_mut();
+        if denied.0.contains(&kind) {
+            denied.1 += 1;
+            Level::Error
+        } else {
+            level
+        }
+    });
+    REPORTED.with(|reported| reported.set(reported.get() + 1));
+    CURRENT_SPAN.with(|current| *current.borrow_mut() = span);
+    log!(target: kind.target(), level, "{}", args);
+    CURRENT_SPAN.with(|current| current.borrow_mut().take());
+}
+
+/// How many diagnostics were reported on this thread so far, to tell whether
+/// some step reported any.
+pub(crate) fn reported() -> usize {
+    REPORTED.with(Cell::get)
+}
+
+/// Reports the diagnostics of the denied kinds on this thread as errors, and
+/// counts them, until dropped.
+pub(crate) struct Denied {
+    previous: Option<(Vec<DiagnosticKind>, usize)>,
+}
+
+impl Denied {
+    pub(crate) fn new(kinds: &[DiagnosticKind]) -> Denied {
+        let previous = DENIED.with(|denied| denied.replace((kinds.to_vec(), 0)));
+        Denied {
+            previous: Some(previous),
+        }
+    }
+
+    /// How many diagnostics of the denied kinds were reported so far.
+    pub(crate) fn count(&self) -> usize {
+        DENIED.with(|denied| denied.borrow().1)
+    }
+}
+
+impl Drop for Denied {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            DENIED.with(|denied| *denied.borrow_mut() = previous);
+        }
+    }
+}
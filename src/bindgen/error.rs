@@ -8,6 +8,7 @@ use std::fmt;
 pub use crate::bindgen::cargo::cargo_expand::Error as CargoExpandError;
 pub use crate::bindgen::cargo::cargo_metadata::Error as CargoMetadataError;
 pub use crate::bindgen::cargo::cargo_toml::Error as CargoTomlError;
+use crate::bindgen::diagnostic::Span;
 pub use syn::parse::Error as ParseError;
 
 #[derive(Debug)]
@@ -25,6 +26,30 @@ pub enum Error {
         crate_name: String,
         src_path: String,
     },
+    /// The number of diagnostics reported as errors because of
+    /// `[diagnostics] deny`.
+    DeniedDiagnostics(usize),
+}
+
+impl Error {
+    /// Where the error is in the Rust sources, if it's about them.
+    pub fn span(&self) -> Option<Span> {
+        match *self {
+            Error::ParseSyntaxError {
+                ref src_path,
+                ref error,
+                ..
+            } if !src_path.is_empty() => {
+                let start = error.span().start();
+                Some(Span {
+                    file: src_path.clone(),
+                    line: start.line,
+                    column: Some(start.column + 1),
+                })
+            }
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for Error {
@@ -71,6 +96,11 @@ impl fmt::Display for Error {
                 "Parsing crate `{}`: cannot open file `{}`.",
                 crate_name, src_path
             ),
+            Error::DeniedDiagnostics(count) => write!(
+                f,
+                "{} diagnostic(s) denied by the `[diagnostics]` config were reported.",
+                count
+            ),
         }
     }
 }
@@ -82,7 +112,7 @@ impl error::Error for Error {
             Error::CargoToml(_, ref error) => Some(error),
             Error::CargoExpand(_, ref error) => Some(error),
             Error::ParseSyntaxError { ref error, .. } => Some(error),
-            Error::ParseCannotOpenFile { .. } => None,
+            Error::ParseCannotOpenFile { .. } | Error::DeniedDiagnostics(_) => None,
         }
     }
 }
@@ -0,0 +1,137 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::BTreeSet;
+
+use crate::bindgen::config::{Config, Int128Style, Language};
+use crate::bindgen::ir::{to_known_assoc_constant, Literal, PrimitiveType, Type};
+
+/// The types which Cython bindings cimport from `libc.stdint`, unless
+/// `minimal_includes` is set.
+const CYTHON_STDINT_TYPES: &[&str] = &[
+    "int8_t",
+    "int16_t",
+    "int32_t",
+    "int64_t",
+    "intptr_t",
+    "uint8_t",
+    "uint16_t",
+    "uint32_t",
+    "uint64_t",
+    "uintptr_t",
+];
+
+/// The Cython module to cimport the type `name` from, if it isn't built into
+/// Cython.
+pub fn cython_cimport_module(name: &str) -> Option<&'static str> {
+    match name {
+        "ptrdiff_t" => Some("libc.stddef"),
+        _ if name.ends_with("_t") && (name.starts_with("int") || name.starts_with("uint")) => {
+            Some("libc.stdint")
+        }
+        _ => None,
+    }
+}
+
+/// The standard headers that bindings include, named after their C version.
+#[derive(Debug, Clone)]
+pub struct SystemIncludes {
+    pub stdarg: bool,
+    pub stdbool: bool,
+    pub stddef: bool,
+    pub stdint: bool,
+    pub stdlib: bool,
+    /// The types Cython bindings cimport, see `cython_cimport_module`.
+    pub cython_types: BTreeSet<&'static str>,
+}
+
+impl SystemIncludes {
+    /// The headers included unless `minimal_includes` is set.
+    pub fn all(config: &Config) -> Self {
+        SystemIncludes {
+            stdarg: true,
+            stdbool: true,
+            stddef: config.usize_is_size_t || config.layout.static_asserts,
+            stdint: true,
+            stdlib: true,
+            cython_types: CYTHON_STDINT_TYPES.iter().cloned().collect(),
+        }
+    }
+
+    /// No header, to add the ones the declarations need to.
+    pub fn none(config: &Config) -> Self {
+        SystemIncludes {
+            stdarg: false,
+            stdbool: false,
+            stddef: config.layout.static_asserts,
+            stdint: config.int128 == Int128Style::Struct,
+            stdlib: false,
+            cython_types: BTreeSet::new(),
+        }
+    }
+
+    pub fn add_type(&mut self, ty: &Type, config: &Config) {
+        ty.walk(false, &mut |ty, _| {
+            if let Type::Primitive(ref primitive) = *ty {
+                self.add_primitive(primitive, config);
+            }
+        });
+    }
+
+    pub fn add_primitive(&mut self, primitive: &PrimitiveType, config: &Config) {
+        let name = primitive.to_repr_c(config);
+        if cython_cimport_module(name).is_some() {
+            self.cython_types.insert(name);
+        }
+        match name {
+            "bool" => self.stdbool = true,
+            "va_list" => self.stdarg = true,
+            "size_t" | "ptrdiff_t" => self.stddef = true,
+            name if name.ends_with("_t")
+                && (name.starts_with("int") || name.starts_with("uint")) =>
+            {
+                self.stdint = true
+            }
+            _ => {}
+        }
+    }
+
+    pub fn add_literal(&mut self, literal: &Literal, config: &Config) {
+        match *literal {
+            Literal::Expr(ref value) => match value.as_str() {
+                "true" | "false" => self.stdbool = true,
+                "NULL" if config.language == Language::C && !config.c23() => self.stddef = true,
+                _ => {}
+            },
+            Literal::Path {
+                associated_to: Some((ref path, _)),
+                ref name,
+            } => {
+                if to_known_assoc_constant(path, name).is_some() {
+                    self.stdint = true;
+                }
+            }
+            Literal::Path { .. } => {}
+            Literal::PostfixUnaryOp { ref value, .. } => self.add_literal(value, config),
+            Literal::BinOp {
+                ref left,
+                ref right,
+                ..
+            } => {
+                self.add_literal(left, config);
+                self.add_literal(right, config);
+            }
+            Literal::FieldAccess { ref base, .. } => self.add_literal(base, config),
+            Literal::Struct { ref fields, .. } => {
+                for field in fields.values() {
+                    self.add_literal(field, config);
+                }
+            }
+            Literal::Cast { ref ty, ref value } => {
+                self.add_type(ty, config);
+                self.add_literal(value, config);
+            }
+        }
+    }
+}
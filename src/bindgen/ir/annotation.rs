@@ -2,6 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::borrow::Cow;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::str::FromStr;
@@ -23,7 +24,7 @@ use crate::bindgen::utilities::SynAttributeHelpers;
 //  * cbindgen:function-postfix=WR_DESTRUCTOR_SAFE
 
 /// A value specified by an annotation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum AnnotationValue {
     List(Vec<String>),
     Atom(Option<String>),
@@ -31,26 +32,102 @@ pub enum AnnotationValue {
 }
 
 /// A set of annotations specified by a document comment.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct AnnotationSet {
     annotations: HashMap<String, AnnotationValue>,
-    pub must_use: bool,
+    /// The reason of a `#[must_use]` attribute, empty if it has none.
+    pub must_use: Option<String>,
+    /// The note of a `#[deprecated]` attribute, empty if it has none.
+    pub deprecated: Option<String>,
 }
 
 impl AnnotationSet {
     pub fn new() -> AnnotationSet {
         AnnotationSet {
             annotations: HashMap::new(),
-            must_use: false,
+            must_use: None,
+            deprecated: None,
         }
     }
 
     pub fn is_empty(&self) -> bool {
-        self.annotations.is_empty() && !self.must_use
+        self.annotations.is_empty() && self.must_use.is_none() && self.deprecated.is_none()
     }
 
-    pub(crate) fn must_use(&self, config: &Config) -> bool {
-        self.must_use && config.language != Language::Cython
+    /// The attribute to mark a `#[must_use]` item with, if any: the
+    /// `configured` one, or else `[[nodiscard]]` in C23, or
+    /// `[[nodiscard("reason")]]` in C++ when the attribute has a reason.
+    pub(crate) fn must_use_attribute<'a>(
+        &self,
+        configured: &'a Option<String>,
+        config: &Config,
+    ) -> Option<Cow<'a, str>> {
+        let reason = self.must_use.as_ref()?;
+        if config.language == Language::Cython {
+            return None;
+        }
+        if let Some(attribute) = config.must_use_attribute(configured) {
+            return Some(Cow::Borrowed(attribute));
+        }
+        if config.language == Language::Cxx && !reason.is_empty() {
+            return Some(Cow::Owned(format!(
+                "[[nodiscard(\"{}\")]]",
+                escape_string(reason)
+            )));
+        }
+        None
+    }
+
+    /// The attribute renaming the item for Swift, from a `swift-name` annotation.
+    pub(crate) fn swift_name_attribute(&self, config: &Config) -> Option<String> {
+        let name = self.atom("swift-name")??;
+        config.swift_name_attribute(&name)
+    }
+
+    /// The C23 attribute to mark a `#[deprecated]` item with, if any.
+    pub(crate) fn deprecated_attribute(&self, config: &Config) -> Option<String> {
+        let note = self.deprecated.as_ref()?;
+        if !config.c23() {
+            return None;
+        }
+        if note.is_empty() {
+            return Some("[[deprecated]]".to_owned());
+        }
+        Some(format!("[[deprecated(\"{}\")]]", escape_string(note)))
+    }
+
+    /// The attributes to declare a function or static with: the well-known
+    /// `weak`, `used`, `constructor`, `destructor`, `visibility=V` and
+    /// `section=S` annotations as a GNU `__attribute__`, followed by the
+    /// `attribute` annotation verbatim.
+    pub(crate) fn declaration_attributes(&self, config: &Config) -> Option<String> {
+        if config.language == Language::Cython {
+            return None;
+        }
+        let mut gnu_attributes = vec![];
+        for flag in &["weak", "used", "constructor", "destructor"] {
+            if self.bool(flag).unwrap_or(false) {
+                gnu_attributes.push(flag.to_string());
+            }
+        }
+        for name in &["visibility", "section"] {
+            if let Some(Some(value)) = self.atom(name) {
+                gnu_attributes.push(format!("{}(\"{}\")", name, value.trim_matches('"')));
+            }
+        }
+
+        let mut attributes = vec![];
+        if !gnu_attributes.is_empty() {
+            attributes.push(format!("__attribute__(({}))", gnu_attributes.join(", ")));
+        }
+        if let Some(Some(attribute)) = self.atom("attribute") {
+            attributes.push(attribute);
+        }
+        if attributes.is_empty() {
+            None
+        } else {
+            Some(attributes.join(" "))
+        }
     }
 
     pub fn load(attrs: &[syn::Attribute]) -> Result<AnnotationSet, String> {
@@ -67,7 +144,8 @@ impl AnnotationSet {
             })
             .collect();
 
-        let must_use = attrs.has_attr_word("must_use");
+        let must_use = attrs.must_use_reason();
+        let deprecated = attrs.deprecated_note();
 
         let mut annotations = HashMap::new();
 
@@ -118,6 +196,7 @@ impl AnnotationSet {
         Ok(AnnotationSet {
             annotations,
             must_use,
+            deprecated,
         })
     }
 
@@ -130,19 +209,19 @@ impl AnnotationSet {
 
     pub fn list(&self, name: &str) -> Option<Vec<String>> {
         match self.annotations.get(name) {
-            Some(&AnnotationValue::List(ref x)) => Some(x.clone()),
+            Some(AnnotationValue::List(x)) => Some(x.clone()),
             _ => None,
         }
     }
     pub fn atom(&self, name: &str) -> Option<Option<String>> {
         match self.annotations.get(name) {
-            Some(&AnnotationValue::Atom(ref x)) => Some(x.clone()),
+            Some(AnnotationValue::Atom(x)) => Some(x.clone()),
             _ => None,
         }
     }
     pub fn bool(&self, name: &str) -> Option<bool> {
         match self.annotations.get(name) {
-            Some(&AnnotationValue::Bool(ref x)) => Some(*x),
+            Some(AnnotationValue::Bool(x)) => Some(*x),
             _ => None,
         }
     }
@@ -152,7 +231,7 @@ impl AnnotationSet {
         T: Default + FromStr,
     {
         match self.annotations.get(name) {
-            Some(&AnnotationValue::Atom(ref x)) => Some(
+            Some(AnnotationValue::Atom(x)) => Some(
                 x.as_ref()
                     .map_or(T::default(), |y| y.parse::<T>().ok().unwrap()),
             ),
@@ -162,6 +241,13 @@ impl AnnotationSet {
 }
 
 /// Parse lists like "[x, y, z]". This is not implemented efficiently or well.
+/// Escapes `s` to be written in a C string literal.
+fn escape_string(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
 fn parse_list(list: &str) -> Option<Vec<String>> {
     if list.len() < 2 {
         return None;
@@ -5,8 +5,11 @@
 use std::fmt;
 use std::io::Write;
 
+use log::Level;
+
 use crate::bindgen::cargo::cargo_metadata::Dependency;
 use crate::bindgen::config::{Config, Language};
+use crate::bindgen::diagnostic::{self, DiagnosticKind};
 use crate::bindgen::writer::SourceWriter;
 
 #[derive(PartialEq, Eq)]
@@ -16,7 +19,7 @@ enum DefineKey<'a> {
 }
 
 impl<'a> DefineKey<'a> {
-    fn load(key: &str) -> DefineKey {
+    fn load(key: &str) -> DefineKey<'_> {
         // TODO: dirty parser
         if !key.contains('=') {
             return DefineKey::Boolean(key);
@@ -42,7 +45,7 @@ impl<'a> DefineKey<'a> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Cfg {
     Boolean(String),
     Named(String, String),
@@ -182,6 +185,78 @@ impl Cfg {
         })
     }
 
+    /// Whether this cfg holds when exactly the `enabled` cfgs, written like
+    /// the keys of `[defines]`, are.
+    pub fn matches(&self, enabled: &[String]) -> bool {
+        match *self {
+            Cfg::Boolean(ref cfg_name) => enabled
+                .iter()
+                .any(|key| DefineKey::Boolean(cfg_name) == DefineKey::load(key)),
+            Cfg::Named(ref cfg_name, ref cfg_value) => enabled
+                .iter()
+                .any(|key| DefineKey::Named(cfg_name, cfg_value) == DefineKey::load(key)),
+            Cfg::Any(ref children) => children.iter().any(|x| x.matches(enabled)),
+            Cfg::All(ref children) => children.iter().all(|x| x.matches(enabled)),
+            Cfg::Not(ref child) => !child.matches(enabled),
+        }
+    }
+
+    /// Resolves an optional cfg for a target enabling the `enabled` cfgs,
+    /// returning whether the item it applies to exists for that target. The
+    /// cfg is removed, since it needs no #ifdef in the target's output.
+    pub fn resolve_for_target(cfg: &mut Option<Cfg>, enabled: &[String]) -> bool {
+        match cfg.take() {
+            Some(cfg) => cfg.matches(enabled),
+            None => true,
+        }
+    }
+
+    /// Evaluates the `feature = "..."` cfgs of a package with the `enabled`
+    /// features, or with all of them if `enabled` is `None`. Returns whether
+    /// the cfg always holds or never does, or the cfg left to check.
+    pub fn resolve_features(self, enabled: Option<&[String]>) -> Result<Cfg, bool> {
+        match self {
+            Cfg::Named(ref name, ref value) if name == "feature" => {
+                Err(enabled.map_or(true, |enabled| enabled.contains(value)))
+            }
+            Cfg::Boolean(..) | Cfg::Named(..) => Ok(self),
+            Cfg::Any(children) => {
+                let mut unknown = Vec::new();
+                for child in children {
+                    match child.resolve_features(enabled) {
+                        Ok(child) => unknown.push(child),
+                        Err(true) => return Err(true),
+                        Err(false) => {}
+                    }
+                }
+                match unknown.len() {
+                    0 => Err(false),
+                    1 => Ok(unknown.pop().unwrap()),
+                    _ => Ok(Cfg::Any(unknown)),
+                }
+            }
+            Cfg::All(children) => {
+                let mut unknown = Vec::new();
+                for child in children {
+                    match child.resolve_features(enabled) {
+                        Ok(child) => unknown.push(child),
+                        Err(false) => return Err(false),
+                        Err(true) => {}
+                    }
+                }
+                match unknown.len() {
+                    0 => Err(true),
+                    1 => Ok(unknown.pop().unwrap()),
+                    _ => Ok(Cfg::All(unknown)),
+                }
+            }
+            Cfg::Not(child) => match child.resolve_features(enabled) {
+                Ok(child) => Ok(Cfg::Not(Box::new(child))),
+                Err(holds) => Err(!holds),
+            },
+        }
+    }
+
     fn load_list<'a, I: Iterator<Item = &'a syn::NestedMeta>>(attrs: I) -> Option<Vec<Cfg>> {
         let mut configs = Vec::new();
 
@@ -211,30 +286,47 @@ impl ToCondition for Cfg {
     fn to_condition(&self, config: &Config) -> Option<Condition> {
         match *self {
             Cfg::Boolean(ref cfg_name) => {
+                // Several keys can match, e.g. `a=b` and `a = b`, in which case
+                // the smallest one is used, whatever the order of the map.
                 let define = config
                     .defines
                     .iter()
-                    .find(|(key, ..)| DefineKey::Boolean(cfg_name) == DefineKey::load(key));
+                    .filter(|(key, ..)| DefineKey::Boolean(cfg_name) == DefineKey::load(key))
+                    .min();
                 if let Some((_, define)) = define {
                     Some(Condition::Define(define.to_owned()))
                 } else {
-                    warn!(
-                        "Missing `[defines]` entry for `{}` in cbindgen config.",
-                        self,
+                    diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::MissingDefine,
+                        None,
+                        format_args!(
+                            "Missing `[defines]` entry for `{}` in cbindgen config.",
+                            self,
+                        ),
                     );
                     None
                 }
             }
             Cfg::Named(ref cfg_name, ref cfg_value) => {
-                let define = config.defines.iter().find(|(key, ..)| {
-                    DefineKey::Named(cfg_name, cfg_value) == DefineKey::load(key)
-                });
+                let define = config
+                    .defines
+                    .iter()
+                    .filter(|(key, ..)| {
+                        DefineKey::Named(cfg_name, cfg_value) == DefineKey::load(key)
+                    })
+                    .min();
                 if let Some((_, define)) = define {
                     Some(Condition::Define(define.to_owned()))
                 } else {
-                    warn!(
-                        "Missing `[defines]` entry for `{}` in cbindgen config.",
-                        self,
+                    diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::MissingDefine,
+                        None,
+                        format_args!(
+                            "Missing `[defines]` entry for `{}` in cbindgen config.",
+                            self,
+                        ),
                     );
                     None
                 }
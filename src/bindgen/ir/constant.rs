@@ -6,29 +6,46 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use std::io::Write;
 
+use log::Level;
 use syn::ext::IdentExt;
 use syn::{self, UnOp};
 
-use crate::bindgen::config::{Config, Language};
+use crate::bindgen::cdecl;
+use crate::bindgen::config::{Config, ConstantStyle, Language};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
+use crate::bindgen::diagnostic::{self, DiagnosticKind};
 use crate::bindgen::ir::{
     AnnotationSet, Cfg, ConditionWrite, Documentation, GenericParams, Item, ItemContainer, Path,
-    Struct, ToCondition, Type,
+    PrimitiveType, Struct, ToCondition, Type,
 };
 use crate::bindgen::library::Library;
+use crate::bindgen::reserved;
 use crate::bindgen::writer::{Source, SourceWriter};
 use crate::bindgen::Bindings;
 
 fn member_to_ident(member: &syn::Member) -> String {
     match member {
         syn::Member::Named(ref name) => name.unraw().to_string(),
-        syn::Member::Unnamed(ref index) => format!("_{}", index.index),
+        syn::Member::Unnamed(ref index) => index.index.to_string(),
     }
 }
 
+/// Whether `func` is `ptr::null` or `ptr::null_mut`.
+fn is_null_ptr_fn(func: &syn::Expr) -> bool {
+    let path = match *func {
+        syn::Expr::Path(syn::ExprPath { ref path, .. }) => path,
+        _ => return false,
+    };
+    let mut segments = path.segments.iter().rev();
+    let is_null = segments
+        .next()
+        .map_or(false, |s| s.ident == "null" || s.ident == "null_mut");
+    is_null && segments.next().map_or(true, |s| s.ident == "ptr")
+}
+
 // TODO: Maybe add support to more std associated constants.
-fn to_known_assoc_constant(associated_to: &Path, name: &str) -> Option<String> {
+pub(crate) fn to_known_assoc_constant(associated_to: &Path, name: &str) -> Option<String> {
     use crate::bindgen::ir::{IntKind, PrimitiveType};
 
     if name != "MAX" && name != "MIN" {
@@ -77,7 +94,7 @@ fn to_known_assoc_constant(associated_to: &Path, name: &str) -> Option<String> {
     Some(format!("{}_{}", prefix, name))
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Literal {
     Expr(String),
     Path {
@@ -85,12 +102,12 @@ pub enum Literal {
         name: String,
     },
     PostfixUnaryOp {
-        op: &'static str,
+        op: String,
         value: Box<Literal>,
     },
     BinOp {
         left: Box<Literal>,
-        op: &'static str,
+        op: String,
         right: Box<Literal>,
     },
     FieldAccess {
@@ -212,6 +229,25 @@ impl Literal {
         }
     }
 
+    /// Whether this literal refers to the free constant `constant`.
+    pub fn refers_to(&self, constant: &Constant) -> bool {
+        if constant.associated_to.is_some() {
+            return false;
+        }
+        let mut refers_to = false;
+        self.visit(&mut |lit| {
+            if let Literal::Path {
+                associated_to: None,
+                ref name,
+            } = *lit
+            {
+                refers_to = name == constant.path.name() || *name == constant.export_name;
+            }
+            !refers_to
+        });
+        refers_to
+    }
+
     fn has_pointer_casts(&self) -> bool {
         let mut has_pointer_casts = false;
         self.visit(&mut |lit| {
@@ -240,6 +276,64 @@ impl Literal {
 }
 
 impl Literal {
+    /// Writes the literal as a Python expression, for the Python code
+    /// cbindgen writes along with Cython bindings, if it can be.
+    pub fn to_python(&self) -> Option<String> {
+        match *self {
+            Literal::Expr(ref value) => match value.as_str() {
+                "true" => Some("True".to_owned()),
+                "false" => Some("False".to_owned()),
+                // Integers may have a C suffix, like `ull`.
+                _ => {
+                    let digits = value.trim_end_matches(|c| c == 'u' || c == 'l');
+                    if digits.parse::<i128>().is_ok() {
+                        Some(digits.to_owned())
+                    } else {
+                        None
+                    }
+                }
+            },
+            Literal::Path {
+                associated_to: None,
+                ref name,
+            } => {
+                let mut name = name.clone();
+                reserved::escape_python(&mut name);
+                Some(name)
+            }
+            Literal::PostfixUnaryOp { ref op, ref value } => match op.as_str() {
+                "-" | "~" => {
+                    let value = value.to_python()?;
+                    // Folds negative integers, so they can be added to.
+                    if op == "-" && value.parse::<i128>().is_ok() {
+                        Some(format!("-{}", value))
+                    } else {
+                        Some(format!("{}({})", op, value))
+                    }
+                }
+                _ => None,
+            },
+            Literal::BinOp {
+                ref left,
+                ref op,
+                ref right,
+            } => {
+                let op = match op.as_str() {
+                    "+" | "-" | "*" | "%" | "<<" | ">>" | "&" | "|" | "^" => op,
+                    "/" => "//",
+                    _ => return None,
+                };
+                Some(format!(
+                    "({} {} {})",
+                    left.to_python()?,
+                    op,
+                    right.to_python()?
+                ))
+            }
+            _ => None,
+        }
+    }
+
     pub fn rename_for_config(&mut self, config: &Config) {
         match self {
             Literal::Struct {
@@ -248,9 +342,14 @@ impl Literal {
                 ..
             } => {
                 config.export.rename(export_name);
-                for lit in fields.values_mut() {
-                    lit.rename_for_config(config);
-                }
+                *fields = std::mem::take(fields)
+                    .into_iter()
+                    .map(|(mut name, mut lit)| {
+                        config.structure.name_tuple_field(&mut name);
+                        lit.rename_for_config(config);
+                        (name, lit)
+                    })
+                    .collect();
             }
             Literal::FieldAccess { ref mut base, .. } => {
                 base.rename_for_config(config);
@@ -326,7 +425,7 @@ impl Literal {
                 };
                 Ok(Literal::BinOp {
                     left: Box::new(l),
-                    op,
+                    op: op.to_owned(),
                     right: Box::new(r),
                 })
             }
@@ -374,6 +473,9 @@ impl Literal {
             syn::Expr::Call(syn::ExprCall {
                 ref func, ref args, ..
             }) => {
+                if args.is_empty() && is_null_ptr_fn(func) {
+                    return Ok(Literal::Expr("NULL".to_owned()));
+                }
                 let struct_name = match Literal::load(func)? {
                     Literal::Path {
                         associated_to: None,
@@ -420,14 +522,14 @@ impl Literal {
                 UnOp::Not(_) => {
                     let val = Self::load(expr)?;
                     Ok(Literal::PostfixUnaryOp {
-                        op: "~",
+                        op: "~".to_owned(),
                         value: Box::new(val),
                     })
                 }
                 UnOp::Neg(_) => {
                     let val = Self::load(expr)?;
                     Ok(Literal::PostfixUnaryOp {
-                        op: "-",
+                        op: "-".to_owned(),
                         value: Box::new(val),
                     })
                 }
@@ -478,6 +580,8 @@ impl Literal {
             Literal::Expr(v) => match (&**v, config.language) {
                 ("true", Language::Cython) => write!(out, "True"),
                 ("false", Language::Cython) => write!(out, "False"),
+                ("NULL", Language::Cxx) => write!(out, "nullptr"),
+                ("NULL", Language::C) if config.c23() => write!(out, "nullptr"),
                 (v, _) => write!(out, "{}", v),
             },
             Literal::Path {
@@ -510,13 +614,13 @@ impl Literal {
                 base.write(config, out);
                 write!(out, ").{}", field);
             }
-            Literal::PostfixUnaryOp { op, ref value } => {
+            Literal::PostfixUnaryOp { ref op, ref value } => {
                 write!(out, "{}", op);
                 value.write(config, out);
             }
             Literal::BinOp {
                 ref left,
-                op,
+                ref op,
                 ref right,
             } => {
                 write!(out, "(");
@@ -545,6 +649,9 @@ impl Literal {
                 path,
             } => {
                 match config.language {
+                    Language::C if !config.style.generate_typedef() => {
+                        write!(out, "(struct {})", export_name)
+                    }
                     Language::C => write!(out, "({})", export_name),
                     Language::Cxx => write!(out, "{}", export_name),
                     Language::Cython => write!(out, "<{}>", export_name),
@@ -575,7 +682,7 @@ impl Literal {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Constant {
     pub path: Path,
     pub export_name: String,
@@ -692,6 +799,45 @@ impl Item for Constant {
 }
 
 impl Constant {
+    /// How the constant is emitted, from its `style` annotation or the
+    /// `[const]` config. Styles which can't express the constant fall back:
+    /// `enum` to `auto` for non-integers, and `constexpr` to `static_const`
+    /// when the value can't be `constexpr` or before C23.
+    fn style(&self, config: &Config) -> ConstantStyle {
+        let style = match self.annotations.atom("style") {
+            Some(Some(style)) => style.parse().unwrap_or_else(|e| {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::IgnoredAnnotation,
+                    None,
+                    format_args!("{} for constant {}.", e, self.export_name()),
+                );
+                config.constant.style
+            }),
+            _ => config.constant.style,
+        };
+        match style {
+            ConstantStyle::Enum => match self.ty {
+                Type::Primitive(PrimitiveType::Integer { .. }) => style,
+                _ => ConstantStyle::Auto,
+            },
+            ConstantStyle::InlineConstexpr if config.language == Language::Cxx => style,
+            ConstantStyle::Constexpr | ConstantStyle::InlineConstexpr => {
+                let can_be_constexpr = match config.language {
+                    Language::Cxx => self.value.can_be_constexpr(),
+                    Language::C => config.c23() && matches!(self.ty, Type::Primitive(..)),
+                    Language::Cython => false,
+                };
+                if can_be_constexpr {
+                    ConstantStyle::Constexpr
+                } else {
+                    ConstantStyle::StaticConst
+                }
+            }
+            _ => style,
+        }
+    }
+
     pub fn write_declaration<F: Write>(
         &self,
         config: &Config,
@@ -766,21 +912,92 @@ impl Constant {
                 ref fields,
                 ref path,
                 ..
-            } if out.bindings().struct_is_transparent(path) => fields.iter().next().unwrap().1,
+            } if out.bindings().struct_is_transparent(path) => {
+                // Only the non-zero-sized field of the struct is represented.
+                let field_names = out.bindings().struct_field_names(path);
+                field_names
+                    .first()
+                    .and_then(|name| fields.get(name))
+                    .unwrap_or(&self.value)
+            }
             _ => &self.value,
         };
 
         self.documentation.write(config, out);
 
+        // Constants in the body of their struct are always declared there.
+        let style = if in_body {
+            ConstantStyle::Auto
+        } else {
+            self.style(config)
+        };
         let allow_constexpr = config.constant.allow_constexpr && self.value.can_be_constexpr();
         match config.language {
+            Language::Cython => {
+                // The pointee of a pointer keeps its own qualifier.
+                cdecl::write_const_field(out, &self.ty, &name, config);
+                // For extern Cython declarations the initializer is ignored,
+                // but still useful as documentation, so we write it as a comment.
+                out.write(" # = ");
+                value.write(config, out);
+            }
+            _ if style == ConstantStyle::Define => {
+                write!(out, "#define {} ", name);
+                value.write(config, out);
+            }
+            _ if style == ConstantStyle::Enum => {
+                write!(out, "enum {{ {} = ", name);
+                value.write(config, out);
+                out.write(" };");
+            }
+            _ if style == ConstantStyle::InlineConstexpr => {
+                // Pointer casts can't be constant expressions.
+                if self.value.can_be_constexpr() {
+                    out.write("inline constexpr ");
+                } else if let Type::Ptr { is_const: true, .. } = self.ty {
+                    out.write("inline ");
+                } else {
+                    out.write("inline const ");
+                }
+                self.ty.write(config, out);
+                write!(out, " {} = ", name);
+                value.write(config, out);
+                write!(out, ";");
+            }
+            _ if style == ConstantStyle::StaticConst || style == ConstantStyle::Constexpr => {
+                // Exported names can't have internal linkage.
+                let storage = if config.cpp_module().is_some() {
+                    "inline "
+                } else {
+                    "static "
+                };
+                match (style, config.language) {
+                    (ConstantStyle::Constexpr, Language::Cxx) => {
+                        out.write("constexpr ");
+                        out.write(storage);
+                    }
+                    (ConstantStyle::Constexpr, _) => out.write("static constexpr "),
+                    _ => out.write(storage),
+                }
+                if let Type::Ptr { is_const: true, .. } = self.ty {
+                    // Nothing.
+                } else if style == ConstantStyle::StaticConst || config.language == Language::Cxx {
+                    out.write("const ");
+                }
+                self.ty.write(config, out);
+                write!(out, " {} = ", name);
+                value.write(config, out);
+                write!(out, ";");
+            }
             Language::Cxx if config.constant.allow_static_const || allow_constexpr => {
                 if allow_constexpr {
                     out.write("constexpr ")
                 }
 
                 if config.constant.allow_static_const {
-                    out.write(if in_body { "inline " } else { "static " });
+                    // Exported names can't have internal linkage.
+                    let inline = in_body || config.cpp_module().is_some();
+                    out.write(if inline { "inline " } else { "static " });
                 }
 
                 if let Type::Ptr { is_const: true, .. } = self.ty {
@@ -794,16 +1011,17 @@ impl Constant {
                 value.write(config, out);
                 write!(out, ";");
             }
-            Language::Cxx | Language::C => {
-                write!(out, "#define {} ", name);
+            Language::C
+                if config.c23() && allow_constexpr && matches!(self.ty, Type::Primitive(..)) =>
+            {
+                out.write("static constexpr ");
+                self.ty.write(config, out);
+                write!(out, " {} = ", name);
                 value.write(config, out);
+                write!(out, ";");
             }
-            Language::Cython => {
-                out.write("const ");
-                self.ty.write(config, out);
-                // For extern Cython declarations the initializer is ignored,
-                // but still useful as documentation, so we write it as a comment.
-                write!(out, " {} # = ", name);
+            Language::Cxx | Language::C => {
+                write!(out, "#define {} ", name);
                 value.write(config, out);
             }
         }
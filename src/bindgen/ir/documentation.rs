@@ -8,7 +8,7 @@ use crate::bindgen::config::{Config, DocumentationLength, DocumentationStyle, La
 use crate::bindgen::utilities::SynAttributeHelpers;
 use crate::bindgen::writer::{Source, SourceWriter};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Documentation {
     pub doc_comment: Vec<String>,
 }
@@ -35,6 +35,31 @@ impl Documentation {
             doc_comment: Vec::new(),
         }
     }
+
+    /// Writes the documentation as a Python docstring, for the Python code
+    /// cbindgen writes along with Cython bindings. Returns whether there was
+    /// any to write.
+    pub fn write_docstring<F: Write>(&self, out: &mut SourceWriter<F>) -> bool {
+        let lines = &self.doc_comment;
+        let len = lines.len()
+            - lines
+                .iter()
+                .rev()
+                .take_while(|line| line.trim().is_empty())
+                .count();
+        if len == 0 {
+            return false;
+        }
+        out.write("\"\"\"");
+        for (i, line) in lines[..len].iter().enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            write!(out, "{}", line.trim().replace("\"\"\"", "\\\"\"\""));
+        }
+        out.write("\"\"\"");
+        true
+    }
 }
 
 impl Source for Documentation {
@@ -4,15 +4,18 @@
 
 use std::io::Write;
 
+use log::Level;
 use syn::ext::IdentExt;
 
+use crate::bindgen::cdecl;
 use crate::bindgen::config::{Config, Language};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
+use crate::bindgen::diagnostic::{self, DiagnosticKind};
 use crate::bindgen::ir::{
-    AnnotationSet, AnnotationValue, Cfg, ConditionWrite, Documentation, Field, GenericArgument,
-    GenericParams, GenericPath, Item, ItemContainer, Literal, Path, Repr, ReprStyle, Struct,
-    ToCondition, Type,
+    AnnotationSet, AnnotationValue, Cfg, ConditionWrite, CythonClasses, CythonValue, Documentation,
+    Field, GenericArgument, GenericParams, GenericPath, Item, ItemContainer, Literal, Path, Repr,
+    ReprStyle, Struct, ToCondition, Type,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::mangle;
@@ -22,7 +25,7 @@ use crate::bindgen::reserved;
 use crate::bindgen::writer::{ListType, Source, SourceWriter};
 
 #[allow(clippy::large_enum_variant)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum VariantBody {
     Empty(AnnotationSet),
     Body {
@@ -83,7 +86,7 @@ impl VariantBody {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EnumVariant {
     pub name: String,
     pub export_name: String,
@@ -317,7 +320,7 @@ impl Source for EnumVariant {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Enum {
     pub path: Path,
     pub export_name: String,
@@ -341,8 +344,19 @@ impl Enum {
         repr.style != ReprStyle::C
     }
 
+    /// Whether the enum is written as a `struct` or a `union` in C++, if it
+    /// has data.
+    pub(crate) fn class_key(&self) -> Option<&'static str> {
+        self.tag.as_ref()?;
+        Some(if Self::inline_tag_field(&self.repr) {
+            "union"
+        } else {
+            "struct"
+        })
+    }
+
     pub fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
-        if self.generic_params.len() > 0 {
+        if !self.generic_params.is_empty() {
             return;
         }
 
@@ -608,6 +622,13 @@ impl Item for Enum {
                 })
                 .collect();
         }
+
+        // A variant's own name is used verbatim, and overrides every rule.
+        for variant in &mut self.variants {
+            if let Some(Some(name)) = variant.body.annotations().atom("rename") {
+                variant.export_name = name;
+            }
+        }
     }
 
     fn instantiate_monomorph(
@@ -654,9 +675,33 @@ impl Item for Enum {
     }
 }
 
+/// How the C helper functions of a tagged enum are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HelperKind {
+    /// `static inline` definitions in the header.
+    Inline,
+    /// Declarations in the header, for `c.helpers_file`.
+    Declaration,
+    /// Definitions in the source file of `c.helpers_file`.
+    Definition,
+}
+
+impl HelperKind {
+    fn specifiers(self) -> &'static str {
+        match self {
+            HelperKind::Inline => "static inline ",
+            HelperKind::Declaration | HelperKind::Definition => "",
+        }
+    }
+}
+
 impl Source for Enum {
     fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
-        let size = self.repr.ty.map(|ty| ty.to_primitive().to_repr_c(config));
+        let size = self
+            .repr
+            .ty
+            .map(|ty| ty.to_primitive().to_repr_decl(config));
+        let size = size.as_deref();
         let has_data = self.tag.is_some();
         let inline_tag_field = Self::inline_tag_field(&self.repr);
         let tag_name = self.tag_name();
@@ -713,8 +758,17 @@ impl Source for Enum {
                 out.close_brace(true);
             }
 
+            // Check the payloads the variants hold by value.
+            let payload_types = self.variants.iter().flat_map(|variant| match variant.body {
+                VariantBody::Body { ref body, .. } => body.fields.as_slice(),
+                VariantBody::Empty(..) => &[],
+            });
+            self.generic_params
+                .write_static_asserts(payload_types.map(|f| &f.ty), config, out);
+
             // Emit convenience methods for the struct or enum for the data.
             self.write_derived_functions_data(config, out, tag_name);
+            self.write_variant_conversions(config, out, tag_name);
 
             // Emit the post_body section, if relevant.
             if let Some(body) = config.export.post_body(&self.path) {
@@ -729,6 +783,21 @@ impl Source for Enum {
             } else {
                 out.close_brace(true);
             }
+
+            if self.has_c_helper_functions(config) {
+                if config.c_helpers_file().is_some() {
+                    self.write_c_helper_declarations(config, out, inline_tag_field);
+                } else {
+                    self.write_c_helper_functions(
+                        config,
+                        out,
+                        inline_tag_field,
+                        HelperKind::Inline,
+                    );
+                }
+            }
+        } else if self.annotations.bool("flags").unwrap_or(false) {
+            self.write_flag_operators(config, out, size, tag_name);
         }
 
         condition.write_after(config, out);
@@ -736,6 +805,604 @@ impl Source for Enum {
 }
 
 impl Enum {
+    /// The Python name and value of each variant, for the Python file of
+    /// `cython.python_enums_file`, unless some discriminant can't be written
+    /// in Python.
+    fn python_variants(&self) -> Option<Vec<(String, String)>> {
+        // Python enums need the value of every member, which is the one of the
+        // previous member plus one when Rust leaves it implicit.
+        let mut next_value = Some(0i128);
+        let mut previous = String::new();
+        let mut variants = Vec::with_capacity(self.variants.len());
+        for variant in &self.variants {
+            let mut name = variant.export_name.clone();
+            reserved::escape_python(&mut name);
+            let value = match (&variant.discriminant, next_value) {
+                (Some(discriminant), _) => {
+                    let value = discriminant.to_python()?;
+                    next_value = value.parse::<i128>().ok().map(|value| value + 1);
+                    value
+                }
+                (None, Some(value)) => {
+                    next_value = Some(value + 1);
+                    value.to_string()
+                }
+                (None, None) => format!("{} + 1", previous),
+            };
+            previous = name.clone();
+            variants.push((name, value));
+        }
+        Some(variants)
+    }
+
+    /// Whether the enum gets a Python enum in the file of
+    /// `cython.python_enums_file`.
+    pub fn has_python_enum(&self) -> bool {
+        self.generic_params.is_empty() && self.python_variants().is_some()
+    }
+
+    /// Writes an `enum.IntEnum` class mirroring the enum, or the tag of an
+    /// enum with data, for the Python file of `cython.python_enums_file`.
+    /// `flags` enums get an `enum.IntFlag` instead.
+    pub fn write_python_enum<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        let variants = match self.python_variants() {
+            Some(variants) => variants,
+            None => return,
+        };
+        let flags = self.tag.is_none() && self.annotations.bool("flags").unwrap_or(false);
+        write!(
+            out,
+            "class {}(enum.{})",
+            self.tag_name(),
+            if flags { "IntFlag" } else { "IntEnum" }
+        );
+        out.open_brace();
+        let has_docstring = self.documentation.write_docstring(out);
+        if has_docstring {
+            out.new_line();
+        }
+        if variants.is_empty() {
+            if has_docstring {
+                out.new_line();
+            }
+            out.write("pass");
+        }
+        for (i, (variant, (name, value))) in self.variants.iter().zip(variants).enumerate() {
+            if i != 0 || has_docstring {
+                out.new_line();
+            }
+            variant.documentation.write(config, out);
+            write!(out, "{} = {}", name, value);
+        }
+        out.close_brace(false);
+    }
+
+    /// Whether the enum gets a class in the `.pyx` file of
+    /// `cython.wrapper_file`, which only tagged enums do.
+    pub fn has_cython_class(&self) -> bool {
+        self.tag.is_some() && self.generic_params.is_empty()
+    }
+
+    /// The accessors of the wrapper class of the enum, for each variant: the
+    /// name of its helpers and, if its fields can be returned to Python code,
+    /// the member holding them and their Python type.
+    fn cython_accessors(&self, config: &Config) -> Vec<(String, Option<(String, String)>)> {
+        let classes = CythonClasses::default();
+        self.variants
+            .iter()
+            .map(|variant| {
+                let helper_name =
+                    RenameRule::SnakeCase.apply(&variant.name, IdentifierType::FunctionArg);
+                let (name, body, inline_casts) = match variant.body {
+                    VariantBody::Body {
+                        ref name,
+                        ref body,
+                        inline_casts,
+                        ..
+                    } => (name, body, inline_casts),
+                    VariantBody::Empty(..) => return (helper_name.into_owned(), None),
+                };
+                let skip_fields = if body.has_tag_field { 1 } else { 0 };
+                let fields = &body.fields[skip_fields..];
+                let values = fields
+                    .iter()
+                    .map(
+                        |field| match CythonValue::load(&field.ty, &classes, config)? {
+                            value @ CythonValue::Primitive(..) => Some(value),
+                            _ => None,
+                        },
+                    )
+                    .collect::<Option<Vec<_>>>();
+                // Cython converts structs of numbers to dicts.
+                let accessor = match values {
+                    Some(ref values) if inline_casts && values.len() == 1 => {
+                        let field = &fields[0];
+                        let member = match variant.body {
+                            VariantBody::Body { inline: false, .. } => {
+                                format!("{}.{}", name, field.name)
+                            }
+                            _ => field.name.clone(),
+                        };
+                        Some((member, values[0].python_type().to_owned()))
+                    }
+                    Some(ref values) if !values.is_empty() => {
+                        Some((name.clone(), "dict".to_owned()))
+                    }
+                    _ => None,
+                };
+                (helper_name.into_owned(), accessor)
+            })
+            .collect()
+    }
+
+    /// Writes the class wrapping the enum by value, for the `.pyx` file of
+    /// `cython.wrapper_file`, which cimports its declaration from `module`:
+    /// a `tag` property and, like the C++ helper methods, an `is_<variant>`
+    /// check for each variant and an `as_<variant>` accessor for the ones
+    /// with numbers or booleans, which raises `ValueError` when the enum
+    /// holds another variant.
+    pub fn write_cython_class<F: Write>(
+        &self,
+        module: &str,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+    ) {
+        let name = &self.export_name;
+        let condition = self.cfg.to_condition(config);
+        condition.write_before(config, out);
+        write!(out, "cdef class {}", name);
+        out.open_brace();
+        if self.documentation.write_docstring(out) {
+            out.new_line();
+            out.new_line();
+        }
+        write!(out, "cdef {}.{} value", module, name);
+        out.new_line();
+        out.new_line();
+        out.write("@staticmethod");
+        out.new_line();
+        write!(out, "cdef {} from_value({}.{} value)", name, module, name);
+        out.open_brace();
+        write!(out, "cdef {} wrapper", name);
+        out.new_line();
+        write!(out, "wrapper = {}.__new__({})", name, name);
+        out.new_line();
+        out.write("wrapper.value = value");
+        out.new_line();
+        out.write("return wrapper");
+        out.close_brace(false);
+        out.new_line();
+        out.new_line();
+        out.write("@property");
+        out.new_line();
+        out.write("def tag(self)");
+        out.open_brace();
+        out.write("return self.value.tag");
+        out.close_brace(false);
+
+        for (variant, (helper_name, accessor)) in
+            self.variants.iter().zip(self.cython_accessors(config))
+        {
+            let tag = format!("{}.{}", module, variant.export_name);
+            let condition = variant.cfg.to_condition(config);
+            out.new_line();
+            out.new_line();
+            condition.write_before(config, out);
+            write!(out, "def is_{}(self)", helper_name);
+            out.open_brace();
+            write!(out, "return self.value.tag == {}", tag);
+            out.close_brace(false);
+            if let Some((member, _)) = accessor {
+                out.new_line();
+                out.new_line();
+                write!(out, "def as_{}(self)", helper_name);
+                out.open_brace();
+                write!(out, "if self.value.tag != {}", tag);
+                out.open_brace();
+                write!(
+                    out,
+                    "raise ValueError(\"{} is not {}\")",
+                    name, variant.export_name
+                );
+                out.close_brace(false);
+                out.new_line();
+                write!(out, "return self.value.{}", member);
+                out.close_brace(false);
+            }
+            condition.write_after(config, out);
+        }
+        out.close_brace(false);
+        condition.write_after(config, out);
+    }
+
+    /// Writes the type stub of the wrapper class of the enum, for the `.pyi`
+    /// file of `cython.stub_file`.
+    pub fn write_python_stub<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        write!(out, "class {}", self.export_name);
+        out.open_brace();
+        if self.documentation.write_docstring(out) {
+            out.new_line();
+            out.new_line();
+        }
+        out.write("@property");
+        out.new_line();
+        out.write("def tag(self) -> int");
+        out.open_brace();
+        out.write("...");
+        out.close_brace(false);
+        for (helper_name, accessor) in self.cython_accessors(config) {
+            out.new_line();
+            out.new_line();
+            write!(out, "def is_{}(self) -> bool", helper_name);
+            out.open_brace();
+            out.write("...");
+            out.close_brace(false);
+            if let Some((_, python_type)) = accessor {
+                out.new_line();
+                out.new_line();
+                write!(out, "def as_{}(self) -> {}", helper_name, python_type);
+                out.open_brace();
+                out.write("...");
+                out.close_brace(false);
+            }
+        }
+        out.close_brace(false);
+    }
+
+    /// Whether helper functions are emitted for the enum, which only tagged
+    /// enums in C get.
+    pub fn has_c_helper_functions(&self, config: &Config) -> bool {
+        config.language == Language::C
+            && self.tag.is_some()
+            && config
+                .enumeration
+                .derive_c_helper_functions(&self.annotations)
+    }
+
+    /// Writes the definitions of the helper functions of the enum, for the
+    /// source file of `c.helpers_file`.
+    pub fn write_c_helper_definitions<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        let condition = self.cfg.to_condition(config);
+        condition.write_before(config, out);
+        self.write_c_helper_functions(
+            config,
+            out,
+            Self::inline_tag_field(&self.repr),
+            HelperKind::Definition,
+        );
+        condition.write_after(config, out);
+    }
+
+    /// Declares the helper functions of the enum, which are defined in the
+    /// source file of `c.helpers_file`.
+    fn write_c_helper_declarations<F: Write>(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+        inline_tag_field: bool,
+    ) {
+        if config.cpp_compatible_c() {
+            out.new_line();
+            out.new_line();
+            out.write("#ifdef __cplusplus");
+            out.new_line();
+            out.write("extern \"C\" {");
+            out.new_line();
+            out.write("#endif // __cplusplus");
+        }
+        self.write_c_helper_functions(config, out, inline_tag_field, HelperKind::Declaration);
+        if config.cpp_compatible_c() {
+            out.new_line();
+            out.new_line();
+            out.write("#ifdef __cplusplus");
+            out.new_line();
+            out.write("} // extern \"C\"");
+            out.new_line();
+            out.write("#endif // __cplusplus");
+        }
+    }
+
+    /// Emit the C counterparts of the C++ helper methods of a tagged enum:
+    /// a `<Name>_<Variant>(..)` constructor and a `<Name>_is_<variant>` check
+    /// for each variant, and `<Name>_as_<variant>` and `<Name>_as_<variant>_mut`
+    /// accessors for the variants with data, which return `NULL` when the enum
+    /// holds another variant.
+    fn write_c_helper_functions<F: Write>(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+        inline_tag_field: bool,
+        kind: HelperKind,
+    ) {
+        let ty = if config.style.generate_typedef() {
+            self.export_name.clone()
+        } else if inline_tag_field {
+            format!("union {}", self.export_name)
+        } else {
+            format!("struct {}", self.export_name)
+        };
+        let arg_renamer = |name: &str| {
+            config
+                .function
+                .rename_args
+                .apply(name, IdentifierType::FunctionArg)
+                .into_owned()
+        };
+        let this = arg_renamer("self");
+
+        for variant in &self.variants {
+            let helper_name =
+                RenameRule::SnakeCase.apply(&variant.name, IdentifierType::FunctionArg);
+            let condition = variant.cfg.to_condition(config);
+            out.new_line();
+            condition.write_before(config, out);
+            out.new_line();
+
+            let fields = match variant.body {
+                VariantBody::Body { ref body, .. } => {
+                    let skip_fields = if body.has_tag_field { 1 } else { 0 };
+                    &body.fields[skip_fields..]
+                }
+                VariantBody::Empty(..) => &[],
+            };
+            write!(
+                out,
+                "{}{} {}_{}(",
+                kind.specifiers(),
+                ty,
+                self.export_name,
+                variant.name
+            );
+            if fields.is_empty() {
+                out.write("void");
+            } else {
+                let args: Vec<_> = fields
+                    .iter()
+                    .map(|field| {
+                        Field::from_name_and_type(arg_renamer(&field.name), field.ty.clone())
+                    })
+                    .collect();
+                out.write_vertical_source_list(&args[..], ListType::Join(","));
+            }
+            out.write(")");
+            // The fields of inlined variants are members of an unnamed struct.
+            let member_prefix = match variant.body {
+                VariantBody::Body {
+                    ref name,
+                    inline: false,
+                    ..
+                } => format!("{}.", name),
+                _ => String::new(),
+            };
+            if kind == HelperKind::Declaration {
+                out.write(";");
+            } else {
+                self.write_c_constructor_body(config, out, variant, fields, &ty, &member_prefix);
+            }
+
+            out.new_line();
+            out.new_line();
+            write!(
+                out,
+                "{}bool {}_is_{}(const {} *{})",
+                kind.specifiers(),
+                self.export_name,
+                helper_name,
+                ty,
+                this
+            );
+            if kind == HelperKind::Declaration {
+                out.write(";");
+            } else {
+                out.open_brace();
+                write!(out, "return {}->tag == {};", this, variant.export_name);
+                out.close_brace(false);
+            }
+
+            if let VariantBody::Body {
+                ref name,
+                ref body,
+                inline_casts,
+                ..
+            } = variant.body
+            {
+                if fields.is_empty() {
+                    condition.write_after(config, out);
+                    continue;
+                }
+                for &is_const in &[true, false] {
+                    let (constness, suffix) = if is_const {
+                        ("const ", "")
+                    } else {
+                        ("", "_mut")
+                    };
+                    let declarator = format!(
+                        "{}_as_{}{}({}{} *{})",
+                        self.export_name, helper_name, suffix, constness, ty, this
+                    );
+                    out.new_line();
+                    out.new_line();
+                    out.write(kind.specifiers());
+                    // Casts to the only field of a variant return a pointer to
+                    // it, and other casts a pointer to the body of the variant.
+                    let member = if inline_casts {
+                        let field = fields.last().unwrap();
+                        let return_ty = Type::Ptr {
+                            ty: Box::new(field.ty.clone()),
+                            is_const,
+                            is_nullable: true,
+                            is_ref: false,
+                        };
+                        cdecl::write_field(out, &return_ty, &declarator, config);
+                        format!("{}{}", member_prefix, field.name)
+                    } else {
+                        out.write(constness);
+                        if !config.style.generate_typedef() {
+                            out.write("struct ");
+                        }
+                        write!(out, "{} *{}", body.export_name(), declarator);
+                        name.clone()
+                    };
+                    if kind == HelperKind::Declaration {
+                        out.write(";");
+                        continue;
+                    }
+                    out.open_brace();
+                    write!(
+                        out,
+                        "return {}->tag == {} ? &{}->{} : NULL;",
+                        this, variant.export_name, this, member
+                    );
+                    out.close_brace(false);
+                }
+            }
+
+            condition.write_after(config, out);
+        }
+    }
+
+    /// Writes the body of the constructor of `variant` out of its `fields`.
+    fn write_c_constructor_body<F: Write>(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+        variant: &EnumVariant,
+        fields: &[Field],
+        ty: &str,
+        member_prefix: &str,
+    ) {
+        let arg_renamer = |name: &str| {
+            config
+                .function
+                .rename_args
+                .apply(name, IdentifierType::FunctionArg)
+                .into_owned()
+        };
+        out.open_brace();
+        write!(out, "{} result;", ty);
+        for field in fields {
+            out.new_line();
+            let arg = arg_renamer(&field.name);
+            match field.ty {
+                // Arrays are not assignable in C, so copy their elements.
+                Type::Array(_, ref length) => write!(
+                    out,
+                    "for (int i = 0; i < {}; i++) result.{}{}[i] = {}[i];",
+                    length.as_str(),
+                    member_prefix,
+                    field.name,
+                    arg
+                ),
+                _ => write!(out, "result.{}{} = {};", member_prefix, field.name, arg),
+            }
+        }
+        out.new_line();
+        write!(out, "result.tag = {};", variant.export_name);
+        out.new_line();
+        out.write("return result;");
+        out.close_brace(false);
+    }
+
+    /// Emit the bitwise operators of an enum marked as flags: `<Name>_OR`,
+    /// `<Name>_AND`, `<Name>_XOR` and `<Name>_NOT` macros in C, and operator
+    /// overloads along with a `has_flag` check in C++.
+    fn write_flag_operators<F: Write>(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+        size: Option<&str>,
+        tag_name: &str,
+    ) {
+        match config.language {
+            Language::C => {
+                let ty = if size.is_none() && !config.style.generate_typedef() {
+                    format!("enum {}", tag_name)
+                } else {
+                    tag_name.to_owned()
+                };
+                for (name, op) in &[("OR", "|"), ("AND", "&"), ("XOR", "^")] {
+                    out.new_line();
+                    write!(
+                        out,
+                        "#define {}_{}(a, b) (({})((a) {} (b)))",
+                        tag_name, name, ty, op
+                    );
+                }
+                out.new_line();
+                write!(out, "#define {}_NOT(a) (({})~(a))", tag_name, ty);
+            }
+            Language::Cxx => {
+                let rename = |name: &'static str| {
+                    config
+                        .function
+                        .rename_args
+                        .apply(name, IdentifierType::FunctionArg)
+                };
+                let (lhs, rhs) = (rename("lhs"), rename("rhs"));
+                // Without a repr, the underlying type of an `enum class` is `int`.
+                let prim = size.unwrap_or("int");
+                let cast = |value: &str| format!("static_cast<{}>({})", prim, value);
+
+                for op in &["|", "&", "^"] {
+                    out.new_line();
+                    out.new_line();
+                    write!(
+                        out,
+                        "constexpr {} operator{}({} {}, {} {})",
+                        tag_name, op, tag_name, lhs, tag_name, rhs
+                    );
+                    out.open_brace();
+                    write!(
+                        out,
+                        "return static_cast<{}>({} {} {});",
+                        tag_name,
+                        cast(&lhs),
+                        op,
+                        cast(&rhs)
+                    );
+                    out.close_brace(false);
+                }
+
+                out.new_line();
+                out.new_line();
+                write!(
+                    out,
+                    "constexpr {} operator~({} {})",
+                    tag_name, tag_name, lhs
+                );
+                out.open_brace();
+                write!(out, "return static_cast<{}>(~{});", tag_name, cast(&lhs));
+                out.close_brace(false);
+
+                let (value, flag) = (rename("value"), rename("flag"));
+                out.new_line();
+                out.new_line();
+                write!(
+                    out,
+                    "constexpr bool has_flag({} {}, {} {})",
+                    tag_name, value, tag_name, flag
+                );
+                out.open_brace();
+                write!(out, "return ({} & {}) == {};", value, flag, flag);
+                out.close_brace(false);
+
+                for op in &["|", "&", "^"] {
+                    out.new_line();
+                    out.new_line();
+                    write!(
+                        out,
+                        "inline {}& operator{}=({}& {}, {} {})",
+                        tag_name, op, tag_name, lhs, tag_name, rhs
+                    );
+                    out.open_brace();
+                    write!(out, "return {} = {} {} {};", lhs, lhs, op, rhs);
+                    out.close_brace(false);
+                }
+            }
+            Language::Cython => {}
+        }
+    }
+
     /// Emit the tag enum and convenience methods for it.
     /// For enums with data this is only a part of the output,
     /// but for enums without data it's the whole output (modulo doc comments etc.).
@@ -747,15 +1414,30 @@ impl Enum {
         has_data: bool,
         tag_name: &str,
     ) {
+        // Enums with data carry the Swift name on the outer struct instead.
+        let swift_name = if has_data {
+            None
+        } else {
+            self.annotations.swift_name_attribute(config)
+        };
+
         // Open the tag enum.
         match config.language {
             Language::C => {
                 if let Some(prim) = size {
                     // If we need to specify size, then we have no choice but to create a typedef,
                     // so `config.style` is not respected.
-                    write!(out, "enum {}", tag_name);
+                    out.write(if config.c23() { "typedef enum" } else { "enum" });
+                    if let Some(ref swift_name) = swift_name {
+                        write!(out, " {}", swift_name);
+                    }
+                    write!(out, " {}", tag_name);
+                    if config.c23() {
+                        // C23 enums can have a fixed underlying type, like in C++.
+                        write!(out, " : {}", prim);
+                    }
 
-                    if config.cpp_compatible_c() {
+                    if config.cpp_compatible_c() && !config.c23() {
                         out.new_line();
                         out.write("#ifdef __cplusplus");
                         out.new_line();
@@ -769,27 +1451,38 @@ impl Enum {
                         out.write("typedef ");
                     }
                     out.write("enum");
+                    if let Some(ref swift_name) = swift_name {
+                        write!(out, " {}", swift_name);
+                    }
                     if config.style.generate_tag() {
                         write!(out, " {}", tag_name);
                     }
                 }
             }
             Language::Cxx => {
-                if config.enumeration.enum_class(&self.annotations) {
+                // Flags are scoped, with an explicit underlying type for their operators.
+                let flags = !has_data && self.annotations.bool("flags").unwrap_or(false);
+                if flags || config.enumeration.enum_class(&self.annotations) {
                     out.write("enum class");
                 } else {
                     out.write("enum");
                 }
 
-                if self.annotations.must_use(config) {
-                    if let Some(ref anno) = config.enumeration.must_use {
-                        write!(out, " {}", anno)
-                    }
+                if let Some(anno) = self
+                    .annotations
+                    .must_use_attribute(&config.enumeration.must_use, config)
+                {
+                    write!(out, " {}", anno)
+                }
+                if let Some(ref swift_name) = swift_name {
+                    write!(out, " {}", swift_name);
                 }
 
                 write!(out, " {}", tag_name);
                 if let Some(prim) = size {
                     write!(out, " : {}", prim);
+                } else if flags {
+                    out.write(" : int");
                 }
             }
             Language::Cython => {
@@ -813,7 +1506,10 @@ impl Enum {
         }
 
         // Close the tag enum.
-        if config.language == Language::C && size.is_none() && config.style.generate_typedef() {
+        let c23_sized = config.c23() && size.is_some();
+        if config.language == Language::C
+            && (size.is_none() && config.style.generate_typedef() || c23_sized)
+        {
             out.close_brace(false);
             write!(out, " {};", tag_name);
         } else {
@@ -822,8 +1518,8 @@ impl Enum {
 
         // Emit typedef specifying the tag enum's size if necessary.
         // In C++ enums can "inherit" from numeric types (`enum E: uint8_t { ... }`),
-        // but in C `typedef uint8_t E` is the only way to give a fixed size to `E`.
-        if let Some(prim) = size {
+        // but before C23 `typedef uint8_t E` is the only way to give a fixed size to `E`.
+        if let Some(prim) = size.filter(|_| !c23_sized) {
             if config.cpp_compatible_c() {
                 out.new_line_if_not_start();
                 out.write("#ifndef __cplusplus");
@@ -859,10 +1555,15 @@ impl Enum {
 
         out.write(if inline_tag_field { "union" } else { "struct" });
 
-        if self.annotations.must_use(config) {
-            if let Some(ref anno) = config.structure.must_use {
-                write!(out, " {}", anno);
-            }
+        if let Some(anno) = self
+            .annotations
+            .must_use_attribute(&config.structure.must_use, config)
+        {
+            write!(out, " {}", anno);
+        }
+
+        if let Some(swift_name) = self.annotations.swift_name_attribute(config) {
+            write!(out, " {}", swift_name);
         }
 
         if config.language != Language::C || config.style.generate_tag() {
@@ -1131,6 +1832,145 @@ impl Enum {
         }
     }
 
+    /// Whether the enum gets `std::variant` conversions, per
+    /// `enum.derive_variant_conversions`.
+    pub fn has_variant_conversions(&self, config: &Config) -> bool {
+        config.language == Language::Cxx
+            && self.tag.is_some()
+            && config
+                .enumeration
+                .derive_variant_conversions(&self.annotations)
+    }
+
+    /// Writes a `Variant` alias of the `std::variant` of the bodies of the
+    /// variants of a tagged enum, with `std::monostate` for the ones without
+    /// data, along with `to_variant()` and `from_variant()` methods converting
+    /// the enum to it and back, so that C++ code can use `std::visit`. The
+    /// alternatives are accessed by index, since several variants may have
+    /// the same type.
+    fn write_variant_conversions<F: Write>(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+        tag_name: &str,
+    ) {
+        if !self.has_variant_conversions(config) {
+            return;
+        }
+        // Conditional variants would change the indices of the others.
+        if self.variants.iter().any(|variant| variant.cfg.is_some()) {
+            diagnostic::report(
+                Level::Warn,
+                DiagnosticKind::SkippedHelper,
+                None,
+                format_args!(
+                    "Skipping the variant conversions of {}, as some of its variants are \
+                     conditional.",
+                    self.export_name
+                ),
+            );
+            return;
+        }
+        // The alternative and the member holding the data of each variant.
+        let (alternatives, members): (Vec<_>, Vec<_>) = self
+            .variants
+            .iter()
+            .map(|variant| match variant.body {
+                VariantBody::Empty(..) => ("std::monostate", None),
+                VariantBody::Body {
+                    ref name, ref body, ..
+                } => (body.export_name(), Some(name)),
+            })
+            .unzip();
+        let variant_arg = config
+            .function
+            .rename_args
+            .apply("variant", IdentifierType::FunctionArg);
+
+        out.new_line();
+        out.new_line();
+        out.write("#if __cplusplus >= 201703L");
+        out.new_line();
+        write!(
+            out,
+            "using Variant = std::variant<{}>;",
+            alternatives.join(", ")
+        );
+        out.new_line();
+        out.new_line();
+
+        out.write("Variant to_variant() const");
+        out.open_brace();
+        out.write("switch (tag)");
+        out.open_brace();
+        let last = self.variants.len() - 1;
+        for (i, (variant, member)) in self.variants.iter().zip(&members).enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            write!(out, "case {}::{}: ", tag_name, variant.export_name);
+            if i == last {
+                out.write("break;");
+                continue;
+            }
+            match *member {
+                Some(member) => write!(
+                    out,
+                    "return Variant(std::in_place_index<{}>, {});",
+                    i, member
+                ),
+                None => write!(out, "return Variant(std::in_place_index<{}>);", i),
+            }
+        }
+        out.close_brace(false);
+        out.new_line();
+        match members[last] {
+            Some(member) => write!(
+                out,
+                "return Variant(std::in_place_index<{}>, {});",
+                last, member
+            ),
+            None => write!(out, "return Variant(std::in_place_index<{}>);", last),
+        }
+        out.close_brace(false);
+        out.new_line();
+        out.new_line();
+
+        write!(
+            out,
+            "static {} from_variant(const Variant& {})",
+            self.export_name, variant_arg
+        );
+        out.open_brace();
+        write!(out, "{} result;", self.export_name);
+        out.new_line();
+        write!(out, "switch ({}.index())", variant_arg);
+        out.open_brace();
+        for (i, (variant, member)) in self.variants.iter().zip(&members).enumerate() {
+            if i != 0 {
+                out.new_line();
+            }
+            write!(out, "case {}:", i);
+            out.push_tab();
+            if let Some(member) = *member {
+                out.new_line();
+                write!(out, "result.{} = std::get<{}>({});", member, i, variant_arg);
+            }
+            // The tag goes last, since the body of the variant may have one.
+            out.new_line();
+            write!(out, "result.tag = {}::{};", tag_name, variant.export_name);
+            out.new_line();
+            out.write("break;");
+            out.pop_tab();
+        }
+        out.close_brace(false);
+        out.new_line();
+        out.write("return result;");
+        out.close_brace(false);
+        out.new_line();
+        out.write("#endif");
+    }
+
     // Emit convenience methods for structs or unions produced for enums with data.
     fn write_derived_functions_data<F: Write>(
         &self,
@@ -1296,7 +2136,7 @@ impl Enum {
                     out.new_line();
                     write!(out, "return {}", member_name);
                     if inline_casts {
-                        write!(out, "._0");
+                        write!(out, ".{}", body.fields.last().unwrap().name);
                     }
                     write!(out, ";");
                     out.close_brace(false);
@@ -1526,4 +2366,12 @@ impl Enum {
             variant.simplify_standard_types(config);
         }
     }
+
+    pub fn name_fn_ptrs(&mut self, name: &mut dyn FnMut(Type) -> Type) {
+        for variant in &mut self.variants {
+            if let VariantBody::Body { ref mut body, .. } = variant.body {
+                body.name_fn_ptrs(name);
+            }
+        }
+    }
 }
@@ -1,14 +1,16 @@
 use std::io::Write;
 
+use log::Level;
 use syn::ext::IdentExt;
 
 use crate::bindgen::cdecl;
 use crate::bindgen::config::{Config, Language};
+use crate::bindgen::diagnostic::{self, DiagnosticKind};
 use crate::bindgen::ir::{AnnotationSet, Cfg, ConditionWrite};
-use crate::bindgen::ir::{Documentation, Path, ToCondition, Type};
+use crate::bindgen::ir::{Documentation, Path, ReprAlign, ToCondition, Type};
 use crate::bindgen::writer::{Source, SourceWriter};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Field {
     pub name: String,
     pub ty: Type,
@@ -47,23 +49,77 @@ impl Field {
             None
         })
     }
-}
 
-impl Source for Field {
-    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+    /// Applies the `rename` annotation of the field, if any. The name it gives
+    /// is used verbatim, and overrides every renaming rule.
+    pub fn apply_rename_annotation(&mut self) {
+        if let Some(Some(name)) = self.annotations.atom("rename") {
+            self.name = name;
+        }
+    }
+
+    /// The default value of the field given with the `default` annotation,
+    /// which the struct initializes it with when it has an `init-macro`.
+    pub fn default_value(&self) -> Option<String> {
+        if let Some(x) = self.annotations.bool("default") {
+            return Some(x.to_string());
+        }
+        self.annotations.atom("default").flatten()
+    }
+
+    /// Writes the field, along with its default value when `with_default` is
+    /// set: as a note in its documentation, and as a default member
+    /// initializer in C++. With `alignas`, the field is declared with the
+    /// alignment macro, which gives that alignment to its struct or union.
+    fn write_declaration<F: Write>(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+        with_default: bool,
+        alignas: Option<u64>,
+    ) {
         // Cython doesn't support conditional fields.
         let condition = self.cfg.to_condition(config);
         if config.language != Language::Cython {
             condition.write_before(config, out);
         }
 
-        self.documentation.write(config, out);
+        let default = if with_default && self.cfg.is_none() {
+            self.default_value()
+        } else {
+            None
+        };
+        match default {
+            Some(ref default) => {
+                let mut documentation = self.documentation.clone();
+                if let Some(last) = documentation.doc_comment.last() {
+                    if !last.trim().is_empty() {
+                        documentation.doc_comment.push(String::new());
+                    }
+                }
+                documentation
+                    .doc_comment
+                    .push(format!(" Defaults to `{}`.", default));
+                documentation.write(config, out);
+            }
+            None => self.documentation.write(config, out),
+        }
+        if let (Some(align), Some(alignas)) = (alignas, config.alignas_macro()) {
+            write!(out, "{}({}) ", alignas, align);
+        }
         cdecl::write_field(out, &self.ty, &self.name, config);
         // Cython extern declarations don't manage layouts, layouts are defined entierly by the
         // corresponding C code. So we can omit bitfield sizes which are not supported by Cython.
+        let bitfield = self.annotations.atom("bitfield");
         if config.language != Language::Cython {
-            if let Some(bitfield) = self.annotations.atom("bitfield") {
-                write!(out, ": {}", bitfield.unwrap_or_default());
+            if let Some(ref bitfield) = bitfield {
+                write!(out, ": {}", bitfield.as_deref().unwrap_or_default());
+            }
+        }
+        // Bitfields can only have default member initializers since C++20.
+        if let Some(ref default) = default {
+            if config.language == Language::Cxx && bitfield.is_none() {
+                write!(out, " = {}", default);
             }
         }
 
@@ -78,3 +134,67 @@ impl Source for Field {
         }
     }
 }
+
+impl Source for Field {
+    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        self.write_declaration(config, out, false, None);
+    }
+}
+
+/// A field written along with what its struct or union adds to it: its
+/// default value, and the alignment of the type.
+pub struct FieldDeclaration<'a> {
+    field: &'a Field,
+    with_default: bool,
+    alignas: Option<u64>,
+}
+
+impl<'a> FieldDeclaration<'a> {
+    /// Declares the `fields` of `path`, with their default values when
+    /// `with_default` is set. When the type is aligned with the alignment
+    /// macro, its first field is declared with that alignment, which gives it
+    /// to the whole type without moving any field.
+    pub fn list(
+        config: &Config,
+        path: &Path,
+        fields: &'a [Field],
+        with_default: bool,
+        alignment: Option<ReprAlign>,
+    ) -> Vec<Self> {
+        let mut alignas = match alignment {
+            Some(ReprAlign::Align(n)) if config.alignas_macro().is_some() => Some(n),
+            _ => None,
+        };
+        if let (Some(_), Some(first)) = (alignas, fields.first()) {
+            if first.cfg.is_some() || first.annotations.atom("bitfield").is_some() {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::IgnoredAnnotation,
+                    None,
+                    format_args!(
+                        "Can't align {} with `alignas`, since its first field `{}` is a bitfield \
+                         or has a cfg.",
+                        path, first.name
+                    ),
+                );
+                alignas = None;
+            }
+        }
+        fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| FieldDeclaration {
+                field,
+                with_default,
+                alignas: if i == 0 { alignas } else { None },
+            })
+            .collect()
+    }
+}
+
+impl<'a> Source for FieldDeclaration<'a> {
+    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        self.field
+            .write_declaration(config, out, self.with_default, self.alignas);
+    }
+}
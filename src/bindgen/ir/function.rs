@@ -2,17 +2,22 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::io::Write;
+use std::str::FromStr;
 
+use log::Level;
 use syn::ext::IdentExt;
 
 use crate::bindgen::cdecl;
-use crate::bindgen::config::{Config, Language, Layout};
+use crate::bindgen::config::{AliasStyle, Config, Language, Layout, NullableReturnWrapper};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
+use crate::bindgen::diagnostic::{self, DiagnosticKind};
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Documentation, GenericPath, Path, ToCondition, Type,
+    AnnotationSet, Cfg, ConditionWrite, Documentation, GenericPath, IntKind, Path, PrimitiveType,
+    ToCondition, Type,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::monomorph::Monomorphs;
@@ -21,16 +26,306 @@ use crate::bindgen::reserved;
 use crate::bindgen::utilities::IterHelpers;
 use crate::bindgen::writer::{Source, SourceWriter};
 
+/// Who owns the value behind a pointer passed to or returned from a function,
+/// as specified with the `cbindgen:ownership` annotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Ownership {
+    /// Ownership is transferred: to the callee for arguments, and to the
+    /// caller for return values.
+    Owned,
+    /// Ownership stays with the side that provided the value.
+    Borrowed,
+}
+
+impl FromStr for Ownership {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "owned" => Ok(Ownership::Owned),
+            "borrowed" => Ok(Ownership::Borrowed),
+            _ => Err(format!("Unrecognized ownership: '{}'.", s)),
+        }
+    }
+}
+
+/// The C++ view type that a C++ overload of a function takes a pointer and
+/// length argument pair as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CppViewKind {
+    /// A `std::span`, as specified with the `cbindgen:span` annotation.
+    Span,
+    /// A `std::string_view`, as specified with the `cbindgen:string-view`
+    /// annotation.
+    StringView,
+}
+
+/// A pointer and length argument pair that a C++ overload of a function takes
+/// as a single view argument.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CppView {
+    pub kind: CppViewKind,
+    /// The index of the pointer argument, which the view replaces.
+    pub ptr: usize,
+    /// The index of the length argument, which the overload omits.
+    pub len: usize,
+}
+
+/// A C++ member function of a struct which forwards to a function taking a
+/// pointer or reference to the struct first, as specified with the
+/// `cbindgen:method-of=Foo` annotation.
 #[derive(Debug, Clone)]
+pub struct CppMethod {
+    /// The Rust name of the struct.
+    pub of: String,
+    /// The name of the type the first argument points to.
+    pub this_type: String,
+    /// The member function, without the first argument of the function.
+    pub method: Function,
+    /// Whether the first argument points to a const struct.
+    pub is_const: bool,
+    /// Whether the first argument is a reference.
+    pub is_ref: bool,
+}
+
+impl CppMethod {
+    /// Writes the declaration of the member function in the body of its
+    /// struct.
+    pub fn write_declaration<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        let condition = self.method.cfg.to_condition(config);
+        condition.write_before(config, out);
+        let horizontal = out.measure(|out| {
+            cdecl::write_func(out, &self.method, Layout::Horizontal, config);
+        });
+        let layout = if (config.function.args == Layout::Auto && horizontal <= config.line_length)
+            || config.function.args == Layout::Horizontal
+        {
+            Layout::Horizontal
+        } else {
+            Layout::Vertical
+        };
+        cdecl::write_func(out, &self.method, layout, config);
+        if self.is_const {
+            out.write(" const");
+        }
+        out.write(";");
+        condition.write_after(config, out);
+    }
+
+    /// Writes the inline definition of the member function of the struct
+    /// `struct_name`, which passes `this` to the function, called as `call`.
+    pub fn write_definition<F: Write>(
+        &self,
+        struct_name: &str,
+        call: &str,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+    ) {
+        let mut definition = self.method.clone();
+        definition.path = Path::new(format!("{}::{}", struct_name, self.method.path.name()));
+        definition.default_args.clear();
+        // References are only written as such in C++.
+        let this = if self.is_ref && config.language == Language::Cxx {
+            "*this"
+        } else {
+            "this"
+        };
+        let mut call_args = vec![this];
+        call_args.extend(
+            definition
+                .args
+                .iter()
+                .map(|arg| arg.name.as_deref().unwrap()),
+        );
+
+        let condition = self.method.cfg.to_condition(config);
+        condition.write_before(config, out);
+        let horizontal = out.measure(|out| {
+            out.write("inline ");
+            cdecl::write_func(out, &definition, Layout::Horizontal, config);
+        });
+        let layout = if (config.function.args == Layout::Auto && horizontal <= config.line_length)
+            || config.function.args == Layout::Horizontal
+        {
+            Layout::Horizontal
+        } else {
+            Layout::Vertical
+        };
+        out.write("inline ");
+        cdecl::write_func(out, &definition, layout, config);
+        if self.is_const {
+            out.write(" const");
+        }
+        out.open_brace();
+        write!(out, "return {}({});", call, call_args.join(", "));
+        out.close_brace(false);
+        condition.write_after(config, out);
+    }
+}
+
+/// The classes of the `.pyx` wrapper of `cython.wrapper_file`, by the name of
+/// the type they wrap.
+#[derive(Debug, Default)]
+pub struct CythonClasses<'a> {
+    /// The opaque types, which the classes hold pointers to.
+    pub opaque: Vec<&'a str>,
+    /// The tagged enums, which the classes hold by value.
+    pub enums: Vec<&'a str>,
+}
+
+/// How a value is passed between Python code and a function wrapped by the
+/// `.pyx` wrapper of `cython.wrapper_file`.
+pub(crate) enum CythonValue {
+    /// A number or a boolean, which Cython converts, of the given type.
+    Primitive(String),
+    /// A pointer to an opaque type, passed as its wrapper class.
+    Class(String),
+    /// A tagged enum, passed as its wrapper class.
+    Enum(String),
+}
+
+impl CythonValue {
+    pub(crate) fn load(ty: &Type, classes: &CythonClasses, config: &Config) -> Option<Self> {
+        match *ty {
+            Type::Primitive(ref primitive) => match *primitive {
+                PrimitiveType::Bool => Some(CythonValue::Primitive("bint".to_owned())),
+                PrimitiveType::Float | PrimitiveType::Double | PrimitiveType::Integer { .. }
+                    if !matches!(
+                        *primitive,
+                        PrimitiveType::Integer {
+                            kind: IntKind::B128,
+                            ..
+                        }
+                    ) =>
+                {
+                    Some(CythonValue::Primitive(
+                        primitive.to_repr_c(config).to_owned(),
+                    ))
+                }
+                _ => None,
+            },
+            Type::Path(ref generic)
+                if generic.generics().is_empty()
+                    && classes.enums.contains(&generic.export_name()) =>
+            {
+                Some(CythonValue::Enum(generic.export_name().to_owned()))
+            }
+            Type::Ptr { ref ty, .. } => match **ty {
+                Type::Path(ref generic)
+                    if generic.generics().is_empty()
+                        && classes.opaque.contains(&generic.export_name()) =>
+                {
+                    Some(CythonValue::Class(generic.export_name().to_owned()))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// The Python type of the value, for the `.pyi` stub of
+    /// `cython.stub_file`.
+    pub(crate) fn python_type(&self) -> &str {
+        match *self {
+            CythonValue::Primitive(ref ty) => match ty.as_str() {
+                "bint" => "bool",
+                "float" | "double" => "float",
+                _ => "int",
+            },
+            CythonValue::Class(ref class) | CythonValue::Enum(ref class) => class,
+        }
+    }
+}
+
+impl CppView {
+    /// Loads the pairs of the `span=[[ptr; len], ...]` and
+    /// `string-view=[[ptr; len], ...]` annotations.
+    fn load(annotations: &AnnotationSet, args: &[FunctionArgument]) -> Result<Vec<Self>, String> {
+        let mut views = Vec::new();
+        for &(kind, name) in &[
+            (CppViewKind::Span, "span"),
+            (CppViewKind::StringView, "string-view"),
+        ] {
+            let pairs = match annotations.list(name) {
+                Some(pairs) => pairs,
+                None => continue,
+            };
+            for pair in pairs {
+                let parts: Vec<&str> = pair
+                    .trim_start_matches('[')
+                    .trim_end_matches(']')
+                    .split(';')
+                    .map(|x| x.trim())
+                    .collect();
+                if parts.len() != 2 {
+                    return Err(format!("Couldn't parse the {} {:?}.", name, pair));
+                }
+                let position = |arg_name: &str| {
+                    args.iter()
+                        .position(|arg| arg.name.as_deref() == Some(arg_name))
+                        .ok_or_else(|| format!("No argument named {:?}.", arg_name))
+                };
+                let (ptr, len) = (position(parts[0])?, position(parts[1])?);
+                let valid_ptr = match args[ptr].ty {
+                    Type::Ptr {
+                        ref ty,
+                        is_const,
+                        is_ref: false,
+                        ..
+                    } => match kind {
+                        CppViewKind::Span => true,
+                        CppViewKind::StringView => {
+                            is_const
+                                && matches!(
+                                    **ty,
+                                    Type::Primitive(PrimitiveType::Char)
+                                        | Type::Primitive(PrimitiveType::SChar)
+                                        | Type::Primitive(PrimitiveType::UChar)
+                                        | Type::Primitive(PrimitiveType::Integer {
+                                            kind: IntKind::B8,
+                                            ..
+                                        })
+                                )
+                        }
+                    },
+                    _ => false,
+                };
+                if !valid_ptr {
+                    return Err(format!(
+                        "Argument {:?} isn't a pointer to take as a {}.",
+                        parts[0], name
+                    ));
+                }
+                if !matches!(args[len].ty, Type::Primitive(PrimitiveType::Integer { .. })) {
+                    return Err(format!("Argument {:?} isn't an integer length.", parts[1]));
+                }
+                views.push(CppView { kind, ptr, len });
+            }
+        }
+        if !views.is_empty() && args.iter().any(|arg| arg.name.is_none()) {
+            return Err("Functions with views must name all their arguments.".to_owned());
+        }
+        Ok(views)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FunctionArgument {
     pub name: Option<String>,
     pub ty: Type,
     pub array_length: Option<String>,
+    pub ownership: Option<Ownership>,
+    /// Whether the pointer is declared `restrict`, as the only way to access
+    /// what it points to in the function.
+    pub is_restrict: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Function {
     pub path: Path,
+    /// The name of the function in Rust, which differs from `path` when the
+    /// symbol is exported under another name with `#[export_name]`.
+    pub rust_name: String,
     /// Path to the self-type of the function
     /// If the function is a method, this will contain the path of the type in the impl block
     pub self_type_path: Option<Path>,
@@ -41,6 +336,19 @@ pub struct Function {
     pub annotations: AnnotationSet,
     pub documentation: Documentation,
     pub never_return: bool,
+    pub ret_ownership: Option<Ownership>,
+    /// Indices of the arguments that the returned value borrows from,
+    /// according to the lifetimes in the Rust signature.
+    pub ret_borrows_from: Vec<usize>,
+    /// The pointer and length argument pairs that a C++ overload of the
+    /// function takes as views.
+    pub cpp_views: Vec<CppView>,
+    /// The default values of the last arguments in C++, from the
+    /// `default-args` annotation.
+    pub default_args: Vec<String>,
+    /// The index of the argument that the function writes its result to,
+    /// from the `result-out-param` annotation.
+    pub result_out: Option<usize>,
 }
 
 impl Function {
@@ -52,7 +360,65 @@ impl Function {
         attrs: &[syn::Attribute],
         mod_cfg: Option<&Cfg>,
     ) -> Result<Function, String> {
-        let mut args = sig.inputs.iter().try_skip_map(|x| x.as_argument())?;
+        let annotations = AnnotationSet::load(attrs)?;
+
+        let mut documentation = Documentation::load(attrs);
+
+        let mut sig = Cow::Borrowed(sig);
+        let view_structs = ViewStructs::load(&annotations);
+        if view_structs.any() {
+            let sig = sig.to_mut();
+            for input in &mut sig.inputs {
+                if let syn::FnArg::Typed(ref mut arg) = *input {
+                    view_structs.replace(&mut arg.ty);
+                }
+            }
+            if let syn::ReturnType::Type(_, ref mut ty) = sig.output {
+                let returns_vec = view_structs.vecs && is_vec(ty);
+                view_structs.replace(ty);
+                if returns_vec {
+                    if let Some(last) = documentation.doc_comment.last() {
+                        if !last.trim().is_empty() {
+                            documentation.doc_comment.push(String::new());
+                        }
+                    }
+                    documentation.doc_comment.push(
+                        " The returned vector is owned by the caller, and must be given back \
+                         to Rust to be freed."
+                            .to_owned(),
+                    );
+                }
+            }
+        }
+
+        let mut args = sig.inputs.iter().try_skip_map(|x| {
+            let arg = x.as_argument();
+            if let Ok(None) = arg {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::UnsupportedType,
+                    None,
+                    format_args!(
+                        "Omitting a zero-sized parameter of {}, which has no C equivalent.",
+                        path
+                    ),
+                );
+            }
+            arg
+        })?;
+
+        let result_out_name = result_out_param(&annotations);
+        if let (Some(ref name), syn::ReturnType::Type(_, ref ty)) = (&result_out_name, &sig.output)
+        {
+            if is_result(ty) {
+                return Err(format!(
+                    "Results have no C equivalent, so functions annotated with \
+                     result-out-param must return an error code, and write the result to \
+                     their `{}: *mut T` argument instead.",
+                    name
+                ));
+            }
+        }
 
         let (mut ret, never_return) = Type::load_from_output(&sig.output)?;
 
@@ -63,16 +429,126 @@ impl Function {
             ret.replace_self_with(self_path);
         }
 
+        if annotations.bool("return-const").unwrap_or(false) {
+            match ret {
+                Type::Ptr {
+                    ref mut is_const, ..
+                } => *is_const = true,
+                _ => {
+                    return Err(
+                        "Functions annotated with return-const must return a pointer.".to_owned(),
+                    )
+                }
+            }
+        }
+
+        let mut ret_ownership = None;
+        if let Some(list) = annotations.list("ownership") {
+            for item in list {
+                let parts: Vec<&str> = item
+                    .trim_start_matches('[')
+                    .trim_end_matches(']')
+                    .split(';')
+                    .map(|x| x.trim())
+                    .collect();
+                if parts.len() != 2 {
+                    return Err(format!("Couldn't parse ownership of {:?}.", item));
+                }
+                let ownership = parts[1].parse()?;
+                if parts[0] == "return" {
+                    ret_ownership = Some(ownership);
+                    continue;
+                }
+                match args
+                    .iter_mut()
+                    .find(|arg| arg.name.as_deref() == Some(parts[0]))
+                {
+                    Some(arg) => arg.ownership = Some(ownership),
+                    None => return Err(format!("No argument named {:?}.", parts[0])),
+                }
+            }
+        } else if let Some(Some(ownership)) = annotations.atom("ownership") {
+            ret_ownership = Some(ownership.parse()?);
+        }
+
+        if let Some(names) = annotations.list("restrict") {
+            for name in names {
+                let arg = args
+                    .iter_mut()
+                    .find(|arg| arg.name.as_ref() == Some(&name))
+                    .ok_or_else(|| format!("No argument named {:?}.", name))?;
+                if !matches!(arg.ty, Type::Ptr { is_ref: false, .. }) {
+                    return Err(format!("Argument {:?} isn't a pointer to restrict.", name));
+                }
+                arg.is_restrict = true;
+            }
+        } else if annotations.bool("restrict").unwrap_or(false) {
+            for arg in &mut args {
+                arg.is_restrict = matches!(arg.ty, Type::Ptr { is_ref: false, .. });
+            }
+        }
+
+        let result_out = match result_out_name {
+            Some(name) => {
+                let i = args
+                    .iter()
+                    .position(|arg| arg.name.as_ref() == Some(&name))
+                    .ok_or_else(|| {
+                        format!("No argument named {:?} to write the result to.", name)
+                    })?;
+                let valid_ptr = match args[i].ty {
+                    Type::Ptr {
+                        ref ty,
+                        is_const: false,
+                        ..
+                    } => **ty != Type::Primitive(PrimitiveType::Void),
+                    _ => false,
+                };
+                if !valid_ptr {
+                    return Err(format!(
+                        "Argument {:?} isn't a mutable pointer to write the result to.",
+                        name
+                    ));
+                }
+                if !matches!(
+                    ret,
+                    Type::Primitive(PrimitiveType::Integer { .. }) | Type::Path(..)
+                ) {
+                    return Err(
+                        "Functions annotated with result-out-param must return an integer or \
+                         an enum error code."
+                            .to_owned(),
+                    );
+                }
+                Some(i)
+            }
+            None => None,
+        };
+
+        let cpp_views = CppView::load(&annotations, &args)?;
+        let default_args = load_default_args(&annotations, &args)?;
+
+        let ret_borrows_from = borrowed_args(&sig)
+            .into_iter()
+            .filter_map(|name| args.iter().position(|arg| arg.name.as_ref() == Some(&name)))
+            .collect();
+
         Ok(Function {
             path,
+            rust_name: sig.ident.unraw().to_string(),
             self_type_path: self_type_path.cloned(),
             ret,
             args,
             extern_decl,
             cfg: Cfg::append(mod_cfg, Cfg::load(attrs)),
-            annotations: AnnotationSet::load(attrs)?,
-            documentation: Documentation::load(attrs),
+            annotations,
+            documentation,
             never_return,
+            ret_ownership,
+            ret_borrows_from,
+            cpp_views,
+            default_args,
+            result_out,
         })
     }
 
@@ -109,15 +585,241 @@ impl Function {
         Some(format!("{}{}({})", type_prefix, item_name, item_args))
     }
 
+    /// The `swift_name` attribute to write after the declaration, either from
+    /// a `swift-name` annotation or guessed by `swift_name`.
+    fn swift_name_attribute(&self, config: &Config) -> Option<String> {
+        if let Some(attribute) = self.annotations.swift_name_attribute(config) {
+            return Some(attribute);
+        }
+        if config.function.swift_name_macro.is_none() && !config.function.swift_name_attribute {
+            return None;
+        }
+        config.swift_name_attribute(&self.swift_name(config)?)
+    }
+
     pub fn path(&self) -> &Path {
         &self.path
     }
 
+    /// The documentation of the function, followed by notes about the
+    /// ownership of its arguments and return value, if specified.
+    fn documentation(&self) -> Cow<'_, Documentation> {
+        let mut notes = vec![];
+        for arg in &self.args {
+            let name = match arg.name {
+                Some(ref name) => name,
+                None => continue,
+            };
+            match arg.ownership {
+                Some(Ownership::Owned) => notes.push(format!(
+                    " Ownership: `{}` is owned, ownership is transferred to the callee.",
+                    name
+                )),
+                Some(Ownership::Borrowed) => notes.push(format!(
+                    " Ownership: `{}` is borrowed, the caller keeps ownership of it.",
+                    name
+                )),
+                None => {}
+            }
+        }
+        let borrowed: Vec<_> = self
+            .ret_borrows_from
+            .iter()
+            .filter_map(|i| self.args[*i].name.as_ref())
+            .map(|name| format!("`{}`", name))
+            .collect();
+        if !borrowed.is_empty() {
+            notes.push(format!(
+                " The returned value borrows from {}, which must outlive it.",
+                borrowed.join(" and ")
+            ));
+        }
+        match self.ret_ownership {
+            Some(Ownership::Owned) => notes.push(
+                " Ownership: the returned value is owned, the caller takes ownership of it."
+                    .to_owned(),
+            ),
+            Some(Ownership::Borrowed) => notes.push(
+                " Ownership: the returned value is borrowed, the caller must not free it."
+                    .to_owned(),
+            ),
+            None => {}
+        }
+
+        if notes.is_empty() {
+            return Cow::Borrowed(&self.documentation);
+        }
+
+        let mut documentation = self.documentation.clone();
+        if let Some(last) = documentation.doc_comment.last() {
+            if !last.trim().is_empty() {
+                documentation.doc_comment.push(String::new());
+            }
+        }
+        documentation.doc_comment.extend(notes);
+        Cow::Owned(documentation)
+    }
+
+    /// Additional names the exported symbol should be declared under.
+    pub fn aliases<'a>(&'a self, config: &'a Config) -> Vec<&'a str> {
+        let mut aliases = config.function.aliases_for(self.path.name());
+        if config.function.export_name_aliases
+            && self.rust_name != self.path.name()
+            && !aliases.contains(&self.rust_name.as_str())
+        {
+            aliases.insert(0, &self.rust_name);
+        }
+        aliases
+    }
+
+    /// The name of the `_Generic` macro dispatching to this function on the
+    /// type of its first argument, from the `generic-macro` annotation, or
+    /// from the primitive type its name ends with when `fn.generic_macros` is
+    /// enabled.
+    pub fn generic_macro<'a>(&'a self, config: &Config) -> Option<Cow<'a, str>> {
+        self.generic_name(config.function.generic_macros)
+    }
+
+    /// The name of the C++ overload forwarding to this function, likewise
+    /// when `fn.generic_overloads` is enabled.
+    pub fn generic_overload<'a>(&'a self, config: &Config) -> Option<Cow<'a, str>> {
+        if !config.function.generic_overloads {
+            return None;
+        }
+        self.generic_name(true)
+    }
+
+    fn generic_name(&self, from_suffix: bool) -> Option<Cow<'_, str>> {
+        if let Some(Some(name)) = self.annotations.atom("generic-macro") {
+            return Some(Cow::Owned(name));
+        }
+        if !from_suffix {
+            return None;
+        }
+        const SUFFIXES: &[&str] = &[
+            "u8", "u16", "u32", "u64", "usize", "i8", "i16", "i32", "i64", "isize", "f32", "f64",
+            "bool", "char",
+        ];
+        let name = self.path.name();
+        let underscore = name.rfind('_')?;
+        if underscore == 0 || !SUFFIXES.contains(&&name[underscore + 1..]) {
+            return None;
+        }
+        Some(Cow::Borrowed(&name[..underscore]))
+    }
+
+    fn write_aliases<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        let aliases = self.aliases(config);
+        if aliases.is_empty() {
+            return;
+        }
+
+        for alias in aliases {
+            out.new_line();
+            if config.language == Language::Cython {
+                // Cython can declare a function under a different name than
+                // its C symbol, so the alias maps directly to the symbol.
+                let mut func = self.clone();
+                func.documentation = Documentation::none();
+                func.path = Path::new(format!("{} \"{}\"", alias, self.path.name()));
+                func.write_declaration(config, out);
+                continue;
+            }
+            match config.function.alias_style {
+                AliasStyle::Define => {
+                    write!(out, "#define {} {}", alias, self.path.name());
+                }
+                AliasStyle::Prototype => {
+                    let mut func = self.clone();
+                    func.documentation = Documentation::none();
+                    func.path = Path::new(alias);
+                    func.write_declaration(config, out);
+                }
+            }
+        }
+    }
+
     pub fn simplify_standard_types(&mut self, config: &Config) {
         self.ret.simplify_standard_types(config);
         for arg in &mut self.args {
             arg.ty.simplify_standard_types(config);
         }
+        if config.function.u8_ptr_as_char {
+            u8_ptr_to(&mut self.ret, PrimitiveType::Char);
+            for arg in &mut self.args {
+                u8_ptr_to(&mut arg.ty, PrimitiveType::Char);
+            }
+        }
+        if let Some(names) = self.annotations.list("utf8") {
+            let utf8_char = if self.uses_char8_t(config) {
+                PrimitiveType::Char8
+            } else {
+                PrimitiveType::Char
+            };
+            for name in names {
+                let ty = if name == "return" {
+                    Some(&mut self.ret)
+                } else {
+                    self.args
+                        .iter_mut()
+                        .find(|arg| arg.name.as_deref() == Some(&name))
+                        .map(|arg| &mut arg.ty)
+                };
+                match ty {
+                    Some(ty) => {
+                        if !u8_ptr_to(ty, utf8_char.clone()) {
+                            diagnostic::report(
+                                Level::Warn,
+                                DiagnosticKind::IgnoredAnnotation,
+                                None,
+                                format_args!(
+                                    "{:?} of {} is annotated as UTF-8, but isn't a pointer to u8.",
+                                    name, self.path
+                                ),
+                            );
+                        }
+                    }
+                    None => diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::IgnoredAnnotation,
+                        None,
+                        format_args!("{} has no argument named {:?}.", self.path, name),
+                    ),
+                }
+            }
+        }
+    }
+
+    /// Whether the function is declared `nogil` in Cython, so that it can be
+    /// called without the GIL, as given by its `nogil` annotation or else by
+    /// `cython.nogil`.
+    pub fn is_nogil(&self, config: &Config) -> bool {
+        config.language == Language::Cython
+            && self
+                .annotations
+                .bool("nogil")
+                .unwrap_or(config.cython.nogil)
+    }
+
+    /// Whether the pointers of the `utf8` annotation point to `char8_t`.
+    pub fn uses_char8_t(&self, config: &Config) -> bool {
+        config.language == Language::Cxx
+            && config.function.utf8_as_char8_t
+            && self.annotations.list("utf8").is_some()
+    }
+
+    pub fn name_fn_ptrs(&mut self, name: &mut dyn FnMut(Type) -> Type) {
+        self.ret.name_fn_ptrs(true, name);
+        for arg in &mut self.args {
+            arg.ty.name_fn_ptrs(true, name);
+        }
+    }
+
+    /// The types that the declaration of this function is made of.
+    pub fn types(&self) -> Vec<&Type> {
+        std::iter::once(&self.ret)
+            .chain(self.args.iter().map(|arg| &arg.ty))
+            .collect()
     }
 
     pub fn add_dependencies(&self, library: &Library, out: &mut Dependencies) {
@@ -171,6 +873,8 @@ impl Function {
                         name,
                         ty: arg.ty,
                         array_length: None,
+                        ownership: arg.ownership,
+                        is_restrict: arg.is_restrict,
                     }
                 })
                 .collect()
@@ -195,9 +899,15 @@ impl Function {
                     .map(|x| x.trim())
                     .collect();
                 if parts.len() != 2 {
-                    warn!(
-                        "{:?} does not follow the correct syntax, so the annotation is being ignored",
-                        parts
+                    diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::IgnoredAnnotation,
+                        None,
+                        format_args!(
+                            "{:?} does not follow the correct syntax, \
+                             so the annotation is being ignored",
+                            parts
+                        ),
                     );
                     continue;
                 }
@@ -219,30 +929,887 @@ impl Function {
     }
 }
 
-impl Source for Function {
-    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+impl Function {
+    /// Writes an inline C++ overload of the function which takes its
+    /// `cpp_views` as `std::span`s and `std::string_view`s, and forwards them
+    /// to it as pointers and lengths. `std::span` needs C++20, so overloads
+    /// with spans are only declared then.
+    pub fn write_cpp_view_overload<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        if self.cpp_views.is_empty() || config.language != Language::Cxx {
+            return;
+        }
+
+        let mut overload = self.clone();
+        overload.args.clear();
+        overload.default_args.clear();
+        let mut call_args = Vec::new();
+        for (i, arg) in self.args.iter().enumerate() {
+            // Functions with views have named arguments.
+            let name = arg.name.clone().unwrap();
+            if let Some(view) = self.cpp_views.iter().find(|view| view.len == i) {
+                let ptr_name = self.args[view.ptr].name.as_ref().unwrap();
+                call_args.push(format!("{}.size()", ptr_name));
+                continue;
+            }
+            let view = match self.cpp_views.iter().find(|view| view.ptr == i) {
+                Some(view) => view,
+                None => {
+                    call_args.push(name);
+                    overload.args.push(arg.clone());
+                    continue;
+                }
+            };
+            let (pointee, is_const) = match arg.ty {
+                Type::Ptr {
+                    ref ty, is_const, ..
+                } => (&**ty, is_const),
+                _ => unreachable!(),
+            };
+            let view_ty = match view.kind {
+                CppViewKind::Span => {
+                    let element = type_string(out, pointee, config);
+                    call_args.push(format!("{}.data()", name));
+                    if !is_const {
+                        format!("std::span<{}>", element)
+                    } else if pointee.is_ptr() {
+                        format!("std::span<{} const>", element)
+                    } else {
+                        format!("std::span<const {}>", element)
+                    }
+                }
+                CppViewKind::StringView => {
+                    if let Type::Primitive(PrimitiveType::Char) = *pointee {
+                        call_args.push(format!("{}.data()", name));
+                    } else {
+                        call_args.push(format!(
+                            "reinterpret_cast<const {} *>({}.data())",
+                            type_string(out, pointee, config),
+                            name
+                        ));
+                    }
+                    "std::string_view".to_owned()
+                }
+            };
+            overload.args.push(FunctionArgument {
+                name: Some(name),
+                ty: Type::Path(GenericPath::new(Path::new(view_ty), vec![])),
+                array_length: None,
+                ownership: None,
+                is_restrict: false,
+            });
+        }
+
+        let condition = self.cfg.to_condition(config);
+        condition.write_before(config, out);
+        let uses_span = self
+            .cpp_views
+            .iter()
+            .any(|view| view.kind == CppViewKind::Span);
+        if uses_span {
+            out.write("#if __cplusplus >= 202002L");
+            out.new_line();
+        }
+        let horizontal = out.measure(|out| {
+            out.write("inline ");
+            cdecl::write_func(out, &overload, Layout::Horizontal, config);
+        });
+        let layout = if (config.function.args == Layout::Auto && horizontal <= config.line_length)
+            || config.function.args == Layout::Horizontal
+        {
+            Layout::Horizontal
+        } else {
+            Layout::Vertical
+        };
+        out.write("inline ");
+        cdecl::write_func(out, &overload, layout, config);
+        out.open_brace();
+        write!(
+            out,
+            "return {}({});",
+            self.path.name(),
+            call_args.join(", ")
+        );
+        out.close_brace(false);
+        if uses_span {
+            out.new_line();
+            out.write("#endif");
+        }
+        condition.write_after(config, out);
+    }
+
+    /// The value that the function returns on success, if it returns an error
+    /// code per its `error-code` or `result-out-param` annotation, which is
+    /// `0` when it isn't given.
+    pub fn error_code_success(&self) -> Option<String> {
+        let success = match self.annotations.bool("error-code") {
+            Some(true) => None,
+            Some(false) => return None,
+            None if self.result_out.is_some() => self.annotations.atom("error-code").flatten(),
+            None => self.annotations.atom("error-code")?,
+        };
+        match self.ret {
+            Type::Primitive(PrimitiveType::Integer { .. }) | Type::Path(..) => {}
+            _ => {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::IgnoredAnnotation,
+                    None,
+                    format_args!(
+                        "Ignoring the error-code annotation of {}, which doesn't return an \
+                         integer or an enum.",
+                        self.path.name()
+                    ),
+                );
+                return None;
+            }
+        }
+        Some(success.unwrap_or_else(|| "0".to_owned()))
+    }
+
+    /// Writes an inline C++ wrapper of a function returning an error code,
+    /// which calls it as `callee` and throws `cpp.exception_name` when the
+    /// code isn't `success`. The wrapper of a function annotated with
+    /// `result-out-param` returns the result instead of taking a pointer to it.
+    pub fn write_throwing_wrapper<F: Write>(
+        &self,
+        callee: &str,
+        success: &str,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+    ) {
+        let mut wrapper = self.clone();
+        wrapper.path = Path::new(
+            config
+                .cpp
+                .throwing_wrapper_name
+                .replace("{name}", self.path.name()),
+        );
+        wrapper.ret = Type::Primitive(PrimitiveType::Void);
+        for (i, arg) in wrapper.args.iter_mut().enumerate() {
+            if arg.name.is_none() {
+                arg.name = Some(format!("arg{}", i));
+            }
+        }
+        let mut call_args: Vec<String> = wrapper
+            .args
+            .iter()
+            .map(|arg| arg.name.clone().unwrap())
+            .collect();
+        let result = self.result_out.map(|i| {
+            let arg = wrapper.args.remove(i);
+            call_args[i] = format!("&{}", arg.name.as_ref().unwrap());
+            if let Type::Ptr { ty, .. } = arg.ty {
+                wrapper.ret = *ty;
+            }
+            arg.name.unwrap()
+        });
+
+        let condition = self.cfg.to_condition(config);
+        condition.write_before(config, out);
+        let horizontal = out.measure(|out| {
+            out.write("inline ");
+            cdecl::write_func(out, &wrapper, Layout::Horizontal, config);
+        });
+        let layout = if (config.function.args == Layout::Auto && horizontal <= config.line_length)
+            || config.function.args == Layout::Horizontal
+        {
+            Layout::Horizontal
+        } else {
+            Layout::Vertical
+        };
+        out.write("inline ");
+        cdecl::write_func(out, &wrapper, layout, config);
+        out.open_brace();
+        if let Some(ref result) = result {
+            cdecl::write_field(out, &wrapper.ret, result, config);
+            out.write(";");
+            out.new_line();
+        }
+        write!(out, "auto code = {}({});", callee, call_args.join(", "));
+        out.new_line();
+        write!(out, "if (code != {})", success);
+        out.open_brace();
+        write!(
+            out,
+            "throw {}(\"{}\", static_cast<int64_t>(code));",
+            config.cpp.exception_name,
+            self.path.name()
+        );
+        out.close_brace(false);
+        if let Some(ref result) = result {
+            out.new_line();
+            write!(out, "return {};", result);
+        }
+        out.close_brace(false);
+        condition.write_after(config, out);
+    }
+
+    /// Writes an inline C++ overload named `name`, which forwards its
+    /// arguments to the function as `callee`.
+    pub fn write_cpp_generic_overload<F: Write>(
+        &self,
+        name: &str,
+        callee: &str,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+    ) {
+        let mut overload = self.clone();
+        overload.path = Path::new(name);
+        overload.default_args.clear();
+        for (i, arg) in overload.args.iter_mut().enumerate() {
+            if arg.name.is_none() {
+                arg.name = Some(format!("arg{}", i));
+            }
+        }
+        let call_args: Vec<String> = overload
+            .args
+            .iter()
+            .map(|arg| arg.name.clone().unwrap())
+            .collect();
+
+        let condition = self.cfg.to_condition(config);
+        condition.write_before(config, out);
+        let horizontal = out.measure(|out| {
+            out.write("inline ");
+            cdecl::write_func(out, &overload, Layout::Horizontal, config);
+        });
+        let layout = if (config.function.args == Layout::Auto && horizontal <= config.line_length)
+            || config.function.args == Layout::Horizontal
+        {
+            Layout::Horizontal
+        } else {
+            Layout::Vertical
+        };
+        out.write("inline ");
+        cdecl::write_func(out, &overload, layout, config);
+        out.open_brace();
+        write!(out, "return {}({});", callee, call_args.join(", "));
+        out.close_brace(false);
+        condition.write_after(config, out);
+    }
+
+    /// The C++ member function the function is wrapped as, given by a
+    /// `method-of` annotation, or an error if it can't be. The method is named
+    /// after the function without the snake case name of the struct and an
+    /// underscore at its start, so `foo_bar` becomes `Foo::bar`.
+    pub fn cpp_method(&self) -> Option<Result<CppMethod, String>> {
+        let of = match self.annotations.atom("method-of")? {
+            Some(of) => of,
+            None => return Some(Err("method-of needs the name of a struct.".to_owned())),
+        };
+        let (this_type, is_const, is_ref) = match self.args.first().map(|arg| &arg.ty) {
+            Some(&Type::Ptr {
+                ref ty,
+                is_const,
+                is_ref,
+                ..
+            }) => match **ty {
+                Type::Path(ref generic) if generic.generics().is_empty() => {
+                    (generic.path().name().to_owned(), is_const, is_ref)
+                }
+                _ => {
+                    return Some(Err(format!(
+                        "{} doesn't take a {} first.",
+                        self.rust_name, of
+                    )))
+                }
+            },
+            _ => {
+                return Some(Err(format!(
+                    "{} doesn't take a pointer to {} first.",
+                    self.rust_name, of
+                )))
+            }
+        };
+
+        let prefix = format!(
+            "{}_",
+            RenameRule::SnakeCase.apply(&of, IdentifierType::Type)
+        );
+        let name = if self.rust_name.starts_with(&prefix) && self.rust_name.len() > prefix.len() {
+            &self.rust_name[prefix.len()..]
+        } else {
+            &self.rust_name
+        };
+        let mut method = self.clone();
+        method.path = Path::new(name);
+        method.args.remove(0);
+        for (i, arg) in method.args.iter_mut().enumerate() {
+            if arg.name.is_none() {
+                arg.name = Some(format!("arg{}", i));
+            }
+        }
+        Some(Ok(CppMethod {
+            of,
+            this_type,
+            method,
+            is_const,
+            is_ref,
+        }))
+    }
+
+    /// How the return value and the arguments of the function are passed to
+    /// and from Python code, if they all can be, along with the value it
+    /// returns on success if it returns an error code. Such a function returns
+    /// what it writes to its `result-out-param` argument, which isn't passed,
+    /// if any.
+    fn cython_values(
+        &self,
+        classes: &CythonClasses,
+        config: &Config,
+    ) -> Option<(Option<CythonValue>, Vec<CythonValue>, Option<String>)> {
+        let success = self.error_code_success();
+        if let Some(ref success) = success {
+            // Python code compares the code with a number or a constant.
+            let valid_ret = match self.ret {
+                Type::Primitive(PrimitiveType::Integer { .. }) => true,
+                Type::Path(ref generic) => generic.generics().is_empty(),
+                _ => false,
+            };
+            if !valid_ret || cython_success(success).is_none() {
+                return None;
+            }
+        }
+        let ret = match self.result_out {
+            Some(i) => match self.args[i].ty {
+                Type::Ptr { ref ty, .. } => ty,
+                _ => return None,
+            },
+            None if success.is_some() => &Type::Primitive(PrimitiveType::Void),
+            None => &self.ret,
+        };
+        let ret = match *ret {
+            Type::Primitive(PrimitiveType::Void) => None,
+            ref ty => Some(CythonValue::load(ty, classes, config)?),
+        };
+        let args = self
+            .cython_args()
+            .map(|(_, arg)| CythonValue::load(&arg.ty, classes, config))
+            .collect::<Option<_>>()?;
+        Some((ret, args, success))
+    }
+
+    /// The arguments passed from Python code, with their index, which are all
+    /// but the `result-out-param` one.
+    fn cython_args(&self) -> impl Iterator<Item = (usize, &FunctionArgument)> {
+        let result_out = self.result_out;
+        self.args
+            .iter()
+            .enumerate()
+            .filter(move |&(i, _)| Some(i) != result_out)
+    }
+
+    /// Whether the function has a Python wrapper in the `.pyx` file of
+    /// `cython.wrapper_file`, as its arguments and return value are numbers,
+    /// booleans, pointers to the opaque types of `classes` or its tagged
+    /// enums.
+    pub fn has_cython_wrapper(&self, classes: &CythonClasses, config: &Config) -> bool {
+        self.cython_values(classes, config).is_some()
+    }
+
+    /// Writes the Python function of the same name wrapping the function, for
+    /// the `.pyx` file of `cython.wrapper_file`, which calls it from the
+    /// cimported module `module`. Pointers to the opaque types of `classes`
+    /// and its tagged enums are taken and returned as their wrapper class.
+    /// When the function returns an error code, the Python function raises a
+    /// `RuntimeError` if it fails, and returns what it wrote to its
+    /// `result-out-param` argument otherwise.
+    pub fn write_cython_wrapper<F: Write>(
+        &self,
+        module: &str,
+        classes: &CythonClasses,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+    ) {
+        let (ret, args, success) = match self.cython_values(classes, config) {
+            Some(values) => values,
+            None => return,
+        };
+        let mut params = Vec::new();
+        let mut call_args = Vec::new();
+        // The classes given away to the function, which mustn't free them.
+        let mut consumed = Vec::new();
+        // The classes the return value borrows from, which it keeps alive.
+        let mut owners = Vec::new();
+        for ((i, arg), value) in self.cython_args().zip(args) {
+            let name = arg.name.clone().unwrap_or_else(|| format!("arg{}", i));
+            match value {
+                CythonValue::Primitive(ty) => {
+                    params.push(format!("{} {}", ty, name));
+                    call_args.push(name);
+                }
+                CythonValue::Class(class) => {
+                    params.push(format!("{} {} not None", class, name));
+                    call_args.push(format!("{}.ptr", name));
+                    if arg.ownership == Some(Ownership::Owned)
+                        && matches!(arg.ty, Type::Ptr { is_ref: false, .. })
+                    {
+                        consumed.push(name.clone());
+                    }
+                    if self.ret_borrows_from.contains(&i) {
+                        owners.push(name);
+                    }
+                }
+                CythonValue::Enum(class) => {
+                    params.push(format!("{} {} not None", class, name));
+                    call_args.push(format!("{}.value", name));
+                }
+            }
+        }
+        // The variable the function writes its result to.
+        let result_out = self.result_out.map(|i| {
+            let name = self.args[i].name.clone().unwrap();
+            call_args.insert(i, format!("&{}", name));
+            name
+        });
+        // The C type of the value returned to Python code.
+        let ret_ty = match self.result_out {
+            Some(i) => match self.args[i].ty {
+                Type::Ptr { ref ty, .. } => &**ty,
+                _ => &self.ret,
+            },
+            None => &self.ret,
+        };
+        let ret_is_const = matches!(*ret_ty, Type::Ptr { is_const: true, .. });
+        let mut call = format!("{}.{}({})", module, self.path.name(), call_args.join(", "));
+        if let (Some(CythonValue::Class(ref class)), None, true) = (&ret, &result_out, ret_is_const)
+        {
+            // The classes hold mutable pointers.
+            call = format!("<{}.{} *>{}", module, class, call);
+        }
+        let returned = |result: &str| match ret {
+            Some(CythonValue::Class(ref class)) => {
+                let result = if result_out.is_some() && ret_is_const {
+                    format!("<{}.{} *>{}", module, class, result)
+                } else {
+                    result.to_owned()
+                };
+                // Returned pointers are borrowed unless the function gives
+                // them away, which references and const pointers can't be.
+                let owned = self.ret_ownership == Some(Ownership::Owned)
+                    && matches!(
+                        *ret_ty,
+                        Type::Ptr {
+                            is_const: false,
+                            is_ref: false,
+                            ..
+                        }
+                    );
+                let owned = if owned { "True" } else { "False" };
+                match owners.len() {
+                    0 => format!("return {}.from_ptr({}, {})", class, result, owned),
+                    1 => format!(
+                        "return {}.from_ptr({}, {}, {})",
+                        class, result, owned, owners[0]
+                    ),
+                    _ => format!(
+                        "return {}.from_ptr({}, {}, ({}))",
+                        class,
+                        result,
+                        owned,
+                        owners.join(", ")
+                    ),
+                }
+            }
+            Some(CythonValue::Enum(ref class)) => {
+                format!("return {}.from_value({})", class, result)
+            }
+            _ => format!("return {}", result),
+        };
+        let declare = |out: &mut SourceWriter<F>, value: &CythonValue, name: &str| {
+            match *value {
+                CythonValue::Primitive(ref ty) => write!(out, "cdef {} {}", ty, name),
+                CythonValue::Class(ref class) => {
+                    // The function writes to the variable through a pointer
+                    // of the same type.
+                    let is_const = result_out.is_some() && ret_is_const;
+                    let qualifier = if is_const { "const " } else { "" };
+                    write!(out, "cdef {}{}.{} *{}", qualifier, module, class, name)
+                }
+                CythonValue::Enum(ref class) => write!(out, "cdef {}.{} {}", module, class, name),
+            }
+            out.new_line();
+        };
+
+        let condition = self.cfg.to_condition(config);
+        condition.write_before(config, out);
+        write!(out, "def {}({})", self.path.name(), params.join(", "));
+        out.open_brace();
+        if self.documentation.write_docstring(out) {
+            out.new_line();
+        }
+        let nogil = self.is_nogil(config);
+        // The result is kept in a variable while the GIL is released or the
+        // classes given away are emptied, and so is the error code.
+        let stored = ret.is_some() && (nogil || !consumed.is_empty()) && success.is_none();
+        if success.is_some() {
+            match self.ret {
+                Type::Path(ref generic) => {
+                    write!(out, "cdef {}.{} code", module, generic.export_name())
+                }
+                Type::Primitive(ref primitive) => {
+                    write!(out, "cdef {} code", primitive.to_repr_c(config))
+                }
+                _ => {}
+            }
+            out.new_line();
+        }
+        if let Some(ref value) = ret {
+            match result_out {
+                Some(ref name) => declare(out, value, name),
+                None if stored => declare(out, value, "result"),
+                None => {}
+            }
+        }
+        for name in &consumed {
+            write!(out, "if not {}.owned", name);
+            out.open_brace();
+            write!(
+                out,
+                "raise ValueError(\"{} is borrowed, so it can't be given away\")",
+                name
+            );
+            out.close_brace(false);
+            out.new_line();
+        }
+        let statement = if success.is_some() {
+            format!("code = {}", call)
+        } else if stored {
+            format!("result = {}", call)
+        } else if ret.is_some() {
+            returned(&call)
+        } else {
+            call
+        };
+        if nogil {
+            out.write("with nogil");
+            out.open_brace();
+            write!(out, "{}", statement);
+            out.close_brace(false);
+        } else {
+            write!(out, "{}", statement);
+        }
+        for name in &consumed {
+            out.new_line();
+            write!(out, "{}.owned = False", name);
+            out.new_line();
+            write!(out, "{}.ptr = NULL", name);
+        }
+        if let Some(ref success) = success {
+            let success = match cython_success(success).unwrap() {
+                Some(name) => format!("{}.{}", module, name),
+                None => success.clone(),
+            };
+            out.new_line();
+            write!(out, "if code != {}", success);
+            out.open_brace();
+            write!(
+                out,
+                "raise RuntimeError(\"{} failed with error code %d\" % code)",
+                self.path.name()
+            );
+            out.close_brace(false);
+            if let Some(ref name) = result_out {
+                out.new_line();
+                write!(out, "{}", returned(name));
+            }
+        } else if stored {
+            out.new_line();
+            write!(out, "{}", returned("result"));
+        }
+        out.close_brace(false);
+        condition.write_after(config, out);
+    }
+
+    /// Writes the signature of the Python function wrapping the function, for
+    /// the `.pyi` stub of `cython.stub_file`. Wrapped pointers can be null, so
+    /// they are returned as optional classes.
+    pub fn write_python_stub<F: Write>(
+        &self,
+        classes: &CythonClasses,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+    ) {
+        let (ret, args, _) = match self.cython_values(classes, config) {
+            Some(values) => values,
+            None => return,
+        };
+        let params = self
+            .cython_args()
+            .zip(args)
+            .map(|((i, arg), value)| {
+                let name = arg.name.clone().unwrap_or_else(|| format!("arg{}", i));
+                format!("{}: {}", name, value.python_type())
+            })
+            .collect::<Vec<_>>();
+        let ret = match ret {
+            None => "None".to_owned(),
+            Some(CythonValue::Class(ref class)) => format!("typing.Optional[{}]", class),
+            Some(ref value) => value.python_type().to_owned(),
+        };
+        write!(
+            out,
+            "def {}({}) -> {}",
+            self.path.name(),
+            params.join(", "),
+            ret
+        );
+        out.open_brace();
+        if !self.documentation.write_docstring(out) {
+            out.write("...");
+        }
+        out.close_brace(false);
+    }
+
+    /// Whether the function gets C++ overloads omitting its `default_args`,
+    /// which C declarations can't have.
+    pub fn has_default_arg_overloads(&self, config: &Config) -> bool {
+        !self.default_args.is_empty() && config.cpp_compatible_c()
+    }
+
+    /// Writes an inline C++ overload of the function without each of its
+    /// arguments with a default value, from the last one, which passes the
+    /// default values to it.
+    pub fn write_default_arg_overloads<F: Write>(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+    ) {
+        let condition = self.cfg.to_condition(config);
+        condition.write_before(config, out);
+        let mut overload = self.clone();
+        overload.default_args.clear();
+        for (i, arg) in overload.args.iter_mut().enumerate() {
+            if arg.name.is_none() {
+                arg.name = Some(format!("arg{}", i));
+            }
+        }
+        let names: Vec<String> = overload
+            .args
+            .iter()
+            .map(|arg| arg.name.clone().unwrap())
+            .collect();
+        for omitted in 1..=self.default_args.len() {
+            if omitted != 1 {
+                out.new_line();
+                out.new_line();
+            }
+            let kept = names.len() - omitted;
+            overload.args.truncate(kept);
+            let call_args: Vec<&str> = names[..kept]
+                .iter()
+                .chain(&self.default_args[self.default_args.len() - omitted..])
+                .map(|arg| arg.as_str())
+                .collect();
+            let horizontal = out.measure(|out| {
+                out.write("inline ");
+                cdecl::write_func(out, &overload, Layout::Horizontal, config);
+            });
+            let layout = if (config.function.args == Layout::Auto
+                && horizontal <= config.line_length)
+                || config.function.args == Layout::Horizontal
+            {
+                Layout::Horizontal
+            } else {
+                Layout::Vertical
+            };
+            out.write("inline ");
+            cdecl::write_func(out, &overload, layout, config);
+            out.open_brace();
+            write!(
+                out,
+                "return {}({});",
+                self.path.name(),
+                call_args.join(", ")
+            );
+            out.close_brace(false);
+        }
+        condition.write_after(config, out);
+    }
+
+    /// Whether the function gets a C++ wrapper per
+    /// `[fn] nullable_return_wrappers`, as it returns a nullable pointer.
+    pub fn has_nullable_return_wrapper(&self, config: &Config) -> bool {
+        config.language == Language::Cxx
+            && config.function.nullable_return_wrappers != NullableReturnWrapper::None
+            && matches!(
+                self.ret,
+                Type::Ptr {
+                    is_nullable: true,
+                    is_ref: false,
+                    ..
+                }
+            )
+    }
+
+    /// Writes an inline `[[nodiscard]]` C++ wrapper of a function returning a
+    /// nullable pointer, which returns either the pointer or a
+    /// `std::optional` of it which is empty when it is null.
+    pub fn write_nullable_return_wrapper<F: Write>(
+        &self,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+    ) {
+        if !self.has_nullable_return_wrapper(config) {
+            return;
+        }
+
+        let mut wrapper = self.clone();
+        wrapper.path = Path::new(
+            config
+                .function
+                .nullable_return_wrapper_name
+                .replace("{name}", self.path.name()),
+        );
+        for (i, arg) in wrapper.args.iter_mut().enumerate() {
+            if arg.name.is_none() {
+                arg.name = Some(format!("arg{}", i));
+            }
+        }
+        let call = format!(
+            "{}({})",
+            self.path.name(),
+            wrapper
+                .args
+                .iter()
+                .map(|arg| arg.name.clone().unwrap())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        let optional = config.function.nullable_return_wrappers == NullableReturnWrapper::Optional;
+        if optional {
+            let ret = format!("std::optional<{}>", type_string(out, &self.ret, config));
+            wrapper.ret = Type::Path(GenericPath::new(Path::new(ret), vec![]));
+        }
+
+        let condition = self.cfg.to_condition(config);
+        condition.write_before(config, out);
+        out.write("#if __cplusplus >= 201703L");
+        out.new_line();
+        let horizontal = out.measure(|out| {
+            out.write("[[nodiscard]] inline ");
+            cdecl::write_func(out, &wrapper, Layout::Horizontal, config);
+        });
+        let layout = if (config.function.args == Layout::Auto && horizontal <= config.line_length)
+            || config.function.args == Layout::Horizontal
+        {
+            Layout::Horizontal
+        } else {
+            Layout::Vertical
+        };
+        out.write("[[nodiscard]] inline ");
+        cdecl::write_func(out, &wrapper, layout, config);
+        out.open_brace();
+        if optional {
+            write!(out, "auto result = {};", call);
+            out.new_line();
+            out.write("if (result == nullptr)");
+            out.open_brace();
+            out.write("return std::nullopt;");
+            out.close_brace(false);
+            out.new_line();
+            out.write("return result;");
+        } else {
+            write!(out, "return {};", call);
+        }
+        out.close_brace(false);
+        out.new_line();
+        out.write("#endif");
+        condition.write_after(config, out);
+    }
+
+    /// The attributes which come in front of the declaration of the function:
+    /// the GNU `nonnull` attribute listing the position of its arguments which
+    /// are required to not be null, and the ones of its annotations.
+    fn declaration_attributes(&self, config: &Config) -> Option<String> {
+        let mut non_null = vec![];
+        if config.language != Language::Cython && config.pointer.non_null_args(&self.annotations) {
+            for (i, arg) in self.args.iter().enumerate() {
+                match arg.ty {
+                    Type::Ptr {
+                        is_nullable: false,
+                        is_ref: false,
+                        ..
+                    }
+                    | Type::FuncPtr {
+                        is_nullable: false, ..
+                    } => non_null.push((i + 1).to_string()),
+                    _ => {}
+                }
+            }
+        }
+        let annotated = self.annotations.declaration_attributes(config);
+        if non_null.is_empty() {
+            return annotated;
+        }
+        let non_null = format!("__attribute__((nonnull({})))", non_null.join(", "));
+        Some(match annotated {
+            Some(annotated) => format!("{} {}", non_null, annotated),
+            None => non_null,
+        })
+    }
+
+    /// Writes the attribute of a function which never returns, which comes
+    /// first in its declaration.
+    fn write_no_return_attribute<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        if !self.never_return {
+            return;
+        }
+        let attribute = match config.no_return_attribute() {
+            Some(attribute) => attribute,
+            None => return,
+        };
+        // `_Noreturn` is C-only, while C++ has had `[[noreturn]]` since C++11.
+        if attribute == "_Noreturn" && config.cpp_compatible_c() {
+            out.write("#ifdef __cplusplus");
+            out.new_line();
+            out.write("[[noreturn]]");
+            out.new_line();
+            out.write("#else");
+            out.new_line();
+            out.write(attribute);
+            out.new_line();
+            out.write("#endif // __cplusplus");
+            out.new_line();
+        } else {
+            write!(out, "{} ", attribute);
+        }
+    }
+
+    fn write_declaration<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
         fn write_1<W: Write>(func: &Function, config: &Config, out: &mut SourceWriter<W>) {
             let prefix = config.function.prefix(&func.annotations);
             let postfix = config.function.postfix(&func.annotations);
 
-            let condition = func.cfg.to_condition(config);
-            condition.write_before(config, out);
-
-            func.documentation.write(config, out);
+            func.documentation().write(config, out);
 
+            func.write_no_return_attribute(config, out);
+            if let Some(ref attributes) = func.declaration_attributes(config) {
+                write!(out, "{} ", attributes);
+            }
             if func.extern_decl {
                 out.write("extern ");
             } else {
+                if let Some(ref deprecated) = func.annotations.deprecated_attribute(config) {
+                    write!(out, "{} ", deprecated);
+                }
                 if let Some(ref prefix) = prefix {
                     write!(out, "{} ", prefix);
                 }
-                if func.annotations.must_use(config) {
-                    if let Some(ref anno) = config.function.must_use {
-                        write!(out, "{} ", anno);
-                    }
+                if let Some(anno) = func
+                    .annotations
+                    .must_use_attribute(&config.function.must_use, config)
+                {
+                    write!(out, "{} ", anno);
+                }
+                if let Some(export_macro) = config.export_macro() {
+                    write!(out, "{} ", export_macro);
                 }
             }
             cdecl::write_func(out, func, Layout::Horizontal, config);
+            if func.is_nogil(config) {
+                out.write(" nogil");
+            }
 
             if !func.extern_decl {
                 if let Some(ref postfix) = postfix {
@@ -250,42 +1817,50 @@ impl Source for Function {
                 }
             }
 
-            if let Some(ref swift_name_macro) = config.function.swift_name_macro {
-                if let Some(swift_name) = func.swift_name(config) {
-                    write!(out, " {}({})", swift_name_macro, swift_name);
-                }
+            if let Some(swift_name) = func.swift_name_attribute(config) {
+                write!(out, " {}", swift_name);
             }
 
             out.write(";");
-
-            condition.write_after(config, out);
         }
 
         fn write_2<W: Write>(func: &Function, config: &Config, out: &mut SourceWriter<W>) {
             let prefix = config.function.prefix(&func.annotations);
             let postfix = config.function.postfix(&func.annotations);
 
-            let condition = func.cfg.to_condition(config);
-
-            condition.write_before(config, out);
-
-            func.documentation.write(config, out);
+            func.documentation().write(config, out);
 
+            func.write_no_return_attribute(config, out);
+            if let Some(ref attributes) = func.declaration_attributes(config) {
+                write!(out, "{}", attributes);
+                out.new_line();
+            }
             if func.extern_decl {
                 out.write("extern ");
             } else {
+                if let Some(ref deprecated) = func.annotations.deprecated_attribute(config) {
+                    write!(out, "{}", deprecated);
+                    out.new_line();
+                }
                 if let Some(ref prefix) = prefix {
                     write!(out, "{}", prefix);
                     out.new_line();
                 }
-                if func.annotations.must_use(config) {
-                    if let Some(ref anno) = config.function.must_use {
-                        write!(out, "{}", anno);
-                        out.new_line();
-                    }
+                if let Some(anno) = func
+                    .annotations
+                    .must_use_attribute(&config.function.must_use, config)
+                {
+                    write!(out, "{}", anno);
+                    out.new_line();
+                }
+                if let Some(export_macro) = config.export_macro() {
+                    write!(out, "{} ", export_macro);
                 }
             }
             cdecl::write_func(out, func, Layout::Vertical, config);
+            if func.is_nogil(config) {
+                out.write(" nogil");
+            }
             if !func.extern_decl {
                 if let Some(ref postfix) = postfix {
                     out.new_line();
@@ -293,15 +1868,11 @@ impl Source for Function {
                 }
             }
 
-            if let Some(ref swift_name_macro) = config.function.swift_name_macro {
-                if let Some(swift_name) = func.swift_name(config) {
-                    write!(out, " {}({})", swift_name_macro, swift_name);
-                }
+            if let Some(swift_name) = func.swift_name_attribute(config) {
+                write!(out, " {}", swift_name);
             }
 
             out.write(";");
-
-            condition.write_after(config, out);
         }
 
         let option_1 = out.measure(|out| write_1(self, config, out));
@@ -316,6 +1887,295 @@ impl Source for Function {
     }
 }
 
+impl Source for Function {
+    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        let condition = self.cfg.to_condition(config);
+        condition.write_before(config, out);
+
+        self.write_declaration(config, out);
+        self.write_aliases(config, out);
+
+        condition.write_after(config, out);
+    }
+}
+
+/// Returns how `ty` is written, e.g. as the argument of a template.
+fn type_string<F: Write>(out: &SourceWriter<F>, ty: &Type, config: &Config) -> String {
+    let mut written = Vec::new();
+    cdecl::write_type(
+        &mut SourceWriter::new(&mut written, out.bindings()),
+        ty,
+        config,
+    );
+    String::from_utf8(written).unwrap()
+}
+
+/// The default values of the last arguments of a function, from its
+/// `default-args=[[name; value], ...]` annotation.
+fn load_default_args(
+    annotations: &AnnotationSet,
+    args: &[FunctionArgument],
+) -> Result<Vec<String>, String> {
+    let pairs = match annotations.list("default-args") {
+        Some(pairs) => pairs,
+        None => return Ok(vec![]),
+    };
+    let mut defaults = vec![None; args.len()];
+    for pair in pairs {
+        let parts: Vec<&str> = pair
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .split(';')
+            .map(|x| x.trim())
+            .collect();
+        if parts.len() != 2 || parts[1].is_empty() {
+            return Err(format!("Couldn't parse the default-args {:?}.", pair));
+        }
+        let i = args
+            .iter()
+            .position(|arg| arg.name.as_deref() == Some(parts[0]))
+            .ok_or_else(|| format!("No argument named {:?}.", parts[0]))?;
+        defaults[i] = Some(parts[1].to_owned());
+    }
+    let first = defaults
+        .iter()
+        .position(Option::is_some)
+        .unwrap_or(args.len());
+    defaults
+        .drain(first..)
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| "Only the last arguments can have default values.".to_owned())
+}
+
+/// Returns how Python code refers to the value `success` given to the
+/// `error-code` annotation: `Some(None)` for a number, `Some(Some(name))` for
+/// a constant of the cimported module, which is the variant for a path like
+/// `Status::Ok`, and `None` otherwise.
+fn cython_success(success: &str) -> Option<Option<&str>> {
+    if success.parse::<i64>().is_ok() {
+        return Some(None);
+    }
+    let path = syn::parse_str::<syn::Path>(success).ok()?;
+    if path.leading_colon.is_some() || path.segments.len() > 2 {
+        return None;
+    }
+    let name = success.rsplit("::").next().unwrap().trim();
+    Some(Some(name))
+}
+
+/// Returns the name of the out parameter that a function annotated with
+/// `cbindgen:result-out-param` writes its result to.
+fn result_out_param(annotations: &AnnotationSet) -> Option<String> {
+    if let Some(Some(name)) = annotations.atom("result-out-param") {
+        return Some(name);
+    }
+    match annotations.bool("result-out-param") {
+        Some(true) => Some("out".to_owned()),
+        _ => None,
+    }
+}
+
+/// Returns whether `ty` is a `Vec<T>`.
+fn is_vec(ty: &syn::Type) -> bool {
+    match *ty {
+        syn::Type::Path(ref path) if path.qself.is_none() => path
+            .path
+            .segments
+            .last()
+            .map_or(false, |segment| segment.ident == "Vec"),
+        _ => false,
+    }
+}
+
+/// Returns whether `ty` is a `Result<T, E>`.
+fn is_result(ty: &syn::Type) -> bool {
+    match *ty {
+        syn::Type::Path(ref path) if path.qself.is_none() => path
+            .path
+            .segments
+            .last()
+            .map_or(false, |segment| segment.ident == "Result"),
+        _ => false,
+    }
+}
+
+/// Collects the lifetimes appearing in `ty` into `named`, and returns whether
+/// it contains a reference with an elided lifetime.
+fn collect_lifetimes(ty: &syn::Type, named: &mut Vec<String>) -> bool {
+    match *ty {
+        syn::Type::Reference(ref reference) => {
+            let elided = match reference.lifetime {
+                Some(ref lifetime) => {
+                    named.push(lifetime.ident.to_string());
+                    false
+                }
+                None => true,
+            };
+            collect_lifetimes(&reference.elem, named) || elided
+        }
+        syn::Type::Ptr(ref pointer) => collect_lifetimes(&pointer.elem, named),
+        syn::Type::Array(ref array) => collect_lifetimes(&array.elem, named),
+        syn::Type::Slice(ref slice) => collect_lifetimes(&slice.elem, named),
+        syn::Type::Paren(ref paren) => collect_lifetimes(&paren.elem, named),
+        syn::Type::Group(ref group) => collect_lifetimes(&group.elem, named),
+        syn::Type::Path(ref path) => {
+            let mut elided = false;
+            for segment in &path.path.segments {
+                if let syn::PathArguments::AngleBracketed(ref args) = segment.arguments {
+                    for arg in &args.args {
+                        match *arg {
+                            syn::GenericArgument::Lifetime(ref lifetime) => {
+                                named.push(lifetime.ident.to_string())
+                            }
+                            syn::GenericArgument::Type(ref ty) => {
+                                elided |= collect_lifetimes(ty, named)
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            elided
+        }
+        _ => false,
+    }
+}
+
+/// Returns the names of the arguments that the return value of `sig` borrows
+/// from, based on the lifetimes it shares with them and on the lifetime
+/// elision rules.
+fn borrowed_args(sig: &syn::Signature) -> Vec<String> {
+    let ret = match sig.output {
+        syn::ReturnType::Type(_, ref ty) => ty,
+        syn::ReturnType::Default => return vec![],
+    };
+    let mut ret_lifetimes = vec![];
+    let ret_elided = collect_lifetimes(ret, &mut ret_lifetimes);
+    ret_lifetimes.retain(|lifetime| lifetime != "static");
+    if ret_lifetimes.is_empty() && !ret_elided {
+        return vec![];
+    }
+
+    let mut borrowed = vec![];
+    let mut inputs_with_lifetimes = vec![];
+    let mut self_ref = false;
+    for input in &sig.inputs {
+        let mut lifetimes = vec![];
+        let (name, elided) = match *input {
+            syn::FnArg::Receiver(ref receiver) => match receiver.reference {
+                Some((_, ref lifetime)) => {
+                    self_ref = true;
+                    if let Some(ref lifetime) = *lifetime {
+                        lifetimes.push(lifetime.ident.to_string());
+                    }
+                    ("self".to_owned(), lifetime.is_none())
+                }
+                None => continue,
+            },
+            syn::FnArg::Typed(ref arg) => match *arg.pat {
+                syn::Pat::Ident(ref ident) => (
+                    ident.ident.unraw().to_string(),
+                    collect_lifetimes(&arg.ty, &mut lifetimes),
+                ),
+                _ => continue,
+            },
+        };
+        if lifetimes
+            .iter()
+            .any(|lifetime| ret_lifetimes.contains(lifetime))
+        {
+            borrowed.push(name.clone());
+        }
+        if elided || !lifetimes.is_empty() {
+            inputs_with_lifetimes.push(name);
+        }
+    }
+
+    if ret_elided && borrowed.is_empty() {
+        if self_ref {
+            borrowed.push("self".to_owned());
+        } else if inputs_with_lifetimes.len() == 1 {
+            borrowed.append(&mut inputs_with_lifetimes);
+        }
+    }
+    borrowed
+}
+
+/// The standard types that are replaced with the structs added by
+/// `Parse::add_view_types` in the signature of a function, as requested by
+/// its annotations.
+struct ViewStructs {
+    /// `&[T]` and `&mut [T]` become `SliceRef<T>` and `SliceMut<T>`.
+    slices: bool,
+    /// `&str` and `String` become `StrRef`.
+    strs: bool,
+    /// `Vec<T>` becomes `RawVec<T>`.
+    vecs: bool,
+}
+
+impl ViewStructs {
+    fn load(annotations: &AnnotationSet) -> Self {
+        ViewStructs {
+            slices: annotations.bool("slices-as-structs").unwrap_or(false),
+            strs: annotations.bool("strings-as-structs").unwrap_or(false),
+            vecs: annotations.bool("vecs-as-structs").unwrap_or(false),
+        }
+    }
+
+    fn any(&self) -> bool {
+        self.slices || self.strs || self.vecs
+    }
+
+    fn replace(&self, ty: &mut syn::Type) {
+        match *ty {
+            syn::Type::Reference(ref mut reference) => match *reference.elem {
+                syn::Type::Slice(ref mut slice) if self.slices => {
+                    self.replace(&mut slice.elem);
+                    let elem = &slice.elem;
+                    *ty = if reference.mutability.is_some() {
+                        syn::parse_quote!(SliceMut<#elem>)
+                    } else {
+                        syn::parse_quote!(SliceRef<#elem>)
+                    };
+                }
+                syn::Type::Path(ref path) if self.strs && path.path.is_ident("str") => {
+                    *ty = syn::parse_quote!(StrRef);
+                }
+                ref mut elem => self.replace(elem),
+            },
+            syn::Type::Ptr(ref mut pointer) => self.replace(&mut pointer.elem),
+            syn::Type::Array(ref mut array) => self.replace(&mut array.elem),
+            syn::Type::Paren(ref mut paren) => self.replace(&mut paren.elem),
+            syn::Type::Group(ref mut group) => self.replace(&mut group.elem),
+            syn::Type::Path(ref mut path) => {
+                if self.strs && path.qself.is_none() && path.path.is_ident("String") {
+                    *ty = syn::parse_quote!(StrRef);
+                    return;
+                }
+                for segment in &mut path.path.segments {
+                    if let syn::PathArguments::AngleBracketed(ref mut args) = segment.arguments {
+                        for arg in &mut args.args {
+                            if let syn::GenericArgument::Type(ref mut ty) = *arg {
+                                self.replace(ty);
+                            }
+                        }
+                    }
+                }
+                if self.vecs && path.qself.is_none() {
+                    let segment = path.path.segments.last().unwrap();
+                    if segment.ident == "Vec" {
+                        if let syn::PathArguments::AngleBracketed(ref args) = segment.arguments {
+                            let args = &args.args;
+                            *ty = syn::parse_quote!(RawVec<#args>);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 trait SynFnArgHelpers {
     fn as_argument(&self) -> Result<Option<FunctionArgument>, String>;
 }
@@ -364,13 +2224,35 @@ impl SynFnArgHelpers for syn::FnArg {
                     name,
                     ty,
                     array_length: None,
+                    ownership: None,
+                    is_restrict: false,
                 }))
             }
             syn::FnArg::Receiver(ref receiver) => Ok(Some(FunctionArgument {
                 name: Some("self".to_string()),
                 ty: gen_self_type(receiver),
                 array_length: None,
+                ownership: None,
+                is_restrict: false,
             })),
         }
     }
 }
+
+/// Turns a pointer to `u8` into a pointer to `c_char`.
+/// Replaces the pointee of a pointer to `u8` with `char_type`. Returns whether
+/// `ty` is such a pointer.
+fn u8_ptr_to(ty: &mut Type, char_type: PrimitiveType) -> bool {
+    if let Type::Ptr { ref mut ty, .. } = *ty {
+        if let Type::Primitive(PrimitiveType::Integer {
+            kind: IntKind::B8,
+            signed: false,
+            ..
+        }) = **ty
+        {
+            **ty = Type::Primitive(char_type);
+            return true;
+        }
+    }
+    false
+}
@@ -1,3 +1,4 @@
+use std::fmt;
 use std::io::Write;
 use std::ops::Deref;
 
@@ -10,13 +11,13 @@ use crate::bindgen::ir::{ConstExpr, Path, Type};
 use crate::bindgen::utilities::IterHelpers;
 use crate::bindgen::writer::{Source, SourceWriter};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum GenericParamType {
     Type,
     Const(Type),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GenericParam {
     name: Path,
     ty: GenericParamType,
@@ -59,7 +60,7 @@ impl GenericParam {
     }
 }
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct GenericParams(pub Vec<GenericParam>);
 
 impl GenericParams {
@@ -80,7 +81,7 @@ impl GenericParams {
         item_name: &str,
         arguments: &'out [GenericArgument],
     ) -> Vec<(&'out Path, &'out GenericArgument)> {
-        assert!(self.len() > 0, "{} is not generic", item_name);
+        assert!(!self.is_empty(), "{} is not generic", item_name);
         assert!(
             self.len() == arguments.len(),
             "{} has {} params but is being instantiated with {} values",
@@ -129,6 +130,52 @@ impl GenericParams {
     pub fn write_with_default<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
         self.write_internal(config, out, true);
     }
+
+    /// Writes, on lines of their own after a blank one, the static assertions
+    /// that the type parameters held by value in `types` are trivially
+    /// copyable, if `layout.template_static_asserts` is set.
+    pub fn write_static_asserts<'a, F: Write>(
+        &self,
+        types: impl IntoIterator<Item = &'a Type>,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+    ) {
+        if !config.layout.template_static_asserts || config.language != Language::Cxx {
+            return;
+        }
+
+        fn held_by_value<'a>(ty: &'a Type, params: &mut Vec<&'a Path>) {
+            match *ty {
+                Type::Path(ref generic) if generic.generics().is_empty() => {
+                    params.push(generic.path())
+                }
+                Type::Array(ref ty, _) => held_by_value(ty, params),
+                _ => {}
+            }
+        }
+
+        let mut held = Vec::new();
+        for ty in types {
+            held_by_value(ty, &mut held);
+        }
+
+        let mut wrote_start_newline = false;
+        for param in self.iter() {
+            if !matches!(param.ty, GenericParamType::Type) || !held.contains(&&param.name) {
+                continue;
+            }
+            if !wrote_start_newline {
+                wrote_start_newline = true;
+                out.new_line();
+            }
+            out.new_line();
+            write!(
+                out,
+                "static_assert(std::is_trivially_copyable<{0}>::value, \"{0} must be trivially copyable to cross the FFI boundary\");",
+                param.name
+            );
+        }
+    }
 }
 
 impl Deref for GenericParams {
@@ -150,7 +197,7 @@ impl Source for GenericParams {
 /// Note: Both arguments in a type like `Array<T, N>` are represented as
 /// `GenericArgument::Type`s, even if `N` is actually the name of a const. This
 /// is a consequence of `syn::GenericArgument` doing the same thing.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum GenericArgument {
     Type(Type),
     Const(ConstExpr),
@@ -185,6 +232,15 @@ impl GenericArgument {
     }
 }
 
+impl fmt::Display for GenericArgument {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GenericArgument::Type(ref ty) => ty.fmt(f),
+            GenericArgument::Const(ref expr) => f.write_str(expr.as_str()),
+        }
+    }
+}
+
 impl Source for GenericArgument {
     fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
         match *self {
@@ -194,7 +250,7 @@ impl Source for GenericArgument {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct GenericPath {
     path: Path,
     export_name: String,
@@ -202,6 +258,23 @@ pub struct GenericPath {
     ctype: Option<DeclarationType>,
 }
 
+impl fmt::Display for GenericPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.path.fmt(f)?;
+        if !self.generics.is_empty() {
+            f.write_str("<")?;
+            for (i, generic) in self.generics.iter().enumerate() {
+                if i != 0 {
+                    f.write_str(", ")?;
+                }
+                generic.fmt(f)?;
+            }
+            f.write_str(">")?;
+        }
+        Ok(())
+    }
+}
+
 impl GenericPath {
     pub fn new(path: Path, generics: Vec<GenericArgument>) -> Self {
         let export_name = path.name().to_owned();
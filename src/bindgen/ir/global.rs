@@ -12,16 +12,22 @@ use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{AnnotationSet, Cfg, Documentation, Item, ItemContainer, Path, Type};
 use crate::bindgen::library::Library;
+use crate::bindgen::monomorph::Monomorphs;
+use crate::bindgen::utilities::SynForeignItemHelpers;
 use crate::bindgen::writer::{Source, SourceWriter};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Static {
     pub path: Path,
     pub export_name: String,
     pub ty: Type,
     pub mutable: bool,
+    /// Whether the static is declared in an `extern "C" { }` block, so that
+    /// it's defined elsewhere.
+    pub extern_decl: bool,
     pub cfg: Option<Cfg>,
     pub annotations: AnnotationSet,
+    #[allow(dead_code)]
     pub documentation: Documentation,
 }
 
@@ -37,6 +43,30 @@ impl Static {
             Path::new(item.ident.unraw().to_string()),
             ty.unwrap(),
             item.mutability.is_some(),
+            false,
+            Cfg::append(mod_cfg, Cfg::load(&item.attrs)),
+            AnnotationSet::load(&item.attrs)?,
+            Documentation::load(&item.attrs),
+        ))
+    }
+
+    /// Loads a static declared in an `extern "C" { }` block, under the name of
+    /// its symbol.
+    pub fn load_foreign(
+        item: &syn::ForeignItemStatic,
+        mod_cfg: Option<&Cfg>,
+    ) -> Result<Static, String> {
+        let ty = Type::load(&item.ty)?;
+
+        if ty.is_none() {
+            return Err("Cannot have a zero sized static declaration.".to_owned());
+        }
+
+        Ok(Static::new(
+            Path::new(item.link_name()),
+            ty.unwrap(),
+            item.mutability.is_some(),
+            true,
             Cfg::append(mod_cfg, Cfg::load(&item.attrs)),
             AnnotationSet::load(&item.attrs)?,
             Documentation::load(&item.attrs),
@@ -47,6 +77,7 @@ impl Static {
         path: Path,
         ty: Type,
         mutable: bool,
+        extern_decl: bool,
         cfg: Option<Cfg>,
         annotations: AnnotationSet,
         documentation: Documentation,
@@ -57,6 +88,7 @@ impl Static {
             export_name,
             ty,
             mutable,
+            extern_decl,
             cfg,
             annotations,
             documentation,
@@ -66,6 +98,18 @@ impl Static {
     pub fn simplify_standard_types(&mut self, config: &Config) {
         self.ty.simplify_standard_types(config);
     }
+
+    pub fn name_fn_ptrs(&mut self, name: &mut dyn FnMut(Type) -> Type) {
+        self.ty.name_fn_ptrs(true, name);
+    }
+
+    pub fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
+        self.ty.add_monomorphs(library, out);
+    }
+
+    pub fn mangle_paths(&mut self, monomorphs: &Monomorphs) {
+        self.ty.mangle_paths(monomorphs);
+    }
 }
 
 impl Item for Static {
@@ -108,7 +152,13 @@ impl Item for Static {
 
 impl Source for Static {
     fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        if let Some(ref attributes) = self.annotations.declaration_attributes(config) {
+            write!(out, "{} ", attributes);
+        }
         out.write("extern ");
+        if let Some(export_macro) = config.export_macro() {
+            write!(out, "{} ", export_macro);
+        }
         if let Type::Ptr { is_const: true, .. } = self.ty {
         } else if !self.mutable {
             out.write("const ");
@@ -3,14 +3,15 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use indexmap::IndexMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::mem;
 
 use crate::bindgen::config::Config;
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, Constant, Enum, GenericArgument, OpaqueItem, Path, Static, Struct, Typedef,
-    Union,
+    AnnotationSet, Cfg, Constant, Enum, Field, GenericArgument, OpaqueItem, Path, Static, Struct,
+    Type, Typedef, Union, VariantBody,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::monomorph::Monomorphs;
@@ -60,6 +61,7 @@ pub enum ItemContainer {
 }
 
 impl ItemContainer {
+    #[allow(clippy::should_implement_trait)]
     pub fn deref(&self) -> &dyn Item {
         match *self {
             ItemContainer::Constant(ref x) => x,
@@ -71,9 +73,35 @@ impl ItemContainer {
             ItemContainer::Typedef(ref x) => x,
         }
     }
+
+    /// The types that the declaration of this item is made of.
+    pub fn types(&self) -> Vec<&Type> {
+        fn field_types(fields: &[Field]) -> impl Iterator<Item = &Type> {
+            fields.iter().map(|field| &field.ty)
+        }
+
+        match *self {
+            ItemContainer::Constant(ref x) => vec![&x.ty],
+            ItemContainer::Static(ref x) => vec![&x.ty],
+            ItemContainer::OpaqueItem(..) => vec![],
+            ItemContainer::Struct(ref x) => field_types(&x.fields)
+                .chain(x.associated_constants.iter().map(|constant| &constant.ty))
+                .collect(),
+            ItemContainer::Union(ref x) => field_types(&x.fields).collect(),
+            ItemContainer::Enum(ref x) => x
+                .variants
+                .iter()
+                .flat_map(|variant| match variant.body {
+                    VariantBody::Body { ref body, .. } => field_types(&body.fields).collect(),
+                    VariantBody::Empty(..) => vec![],
+                })
+                .collect(),
+            ItemContainer::Typedef(ref x) => vec![&x.aliased],
+        }
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ItemValue<T: Item> {
     Cfg(Vec<T>),
     Single(T),
@@ -92,6 +120,22 @@ impl<T: Item> Default for ItemMap<T> {
     }
 }
 
+// The items are saved as a list of paths and values, in their order.
+impl<T: Item + Serialize> Serialize for ItemMap<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.data.iter())
+    }
+}
+
+impl<'de, T: Item + Deserialize<'de>> Deserialize<'de> for ItemMap<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<ItemMap<T>, D::Error> {
+        let data = Vec::<(Path, ItemValue<T>)>::deserialize(deserializer)?;
+        Ok(ItemMap {
+            data: data.into_iter().collect(),
+        })
+    }
+}
+
 impl<T: Item + Clone> ItemMap<T> {
     pub fn rebuild(&mut self) {
         let old = mem::take(self);
@@ -147,6 +191,16 @@ impl<T: Item + Clone> ItemMap<T> {
         result
     }
 
+    /// Returns the item for `path`, unless there are several of them behind
+    /// different cfgs.
+    pub fn get_single(&self, path: &Path) -> Option<&T> {
+        match *self.data.get(path)? {
+            ItemValue::Single(ref item) => Some(item),
+            ItemValue::Cfg(ref items) if items.len() == 1 => items.first(),
+            ItemValue::Cfg(..) => None,
+        }
+    }
+
     pub fn get_items(&self, path: &Path) -> Option<Vec<ItemContainer>> {
         Some(match *self.data.get(path)? {
             ItemValue::Cfg(ref items) => items.iter().map(|x| x.container()).collect(),
@@ -219,12 +273,12 @@ impl<T: Item + Clone> ItemMap<T> {
         F: FnMut(&T),
     {
         match self.data.get(path) {
-            Some(&ItemValue::Cfg(ref items)) => {
+            Some(ItemValue::Cfg(items)) => {
                 for item in items {
                     callback(item);
                 }
             }
-            Some(&ItemValue::Single(ref item)) => {
+            Some(ItemValue::Single(item)) => {
                 callback(item);
             }
             None => {}
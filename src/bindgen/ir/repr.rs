@@ -6,7 +6,7 @@ use syn::ext::IdentExt;
 
 use crate::bindgen::ir::ty::{IntKind, PrimitiveType};
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ReprStyle {
     Rust,
     C,
@@ -19,7 +19,7 @@ impl Default for ReprStyle {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ReprType {
     kind: IntKind,
     signed: bool,
@@ -35,13 +35,13 @@ impl ReprType {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ReprAlign {
     Packed,
     Align(u64),
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub struct Repr {
     pub style: ReprStyle,
     pub ty: Option<ReprType>,
@@ -96,11 +96,13 @@ impl Repr {
                 ("u16", None) => (IntKind::B16, false),
                 ("u32", None) => (IntKind::B32, false),
                 ("u64", None) => (IntKind::B64, false),
+                ("u128", None) => (IntKind::B128, false),
                 ("usize", None) => (IntKind::Size, false),
                 ("i8", None) => (IntKind::B8, true),
                 ("i16", None) => (IntKind::B16, true),
                 ("i32", None) => (IntKind::B32, true),
                 ("i64", None) => (IntKind::B64, true),
+                ("i128", None) => (IntKind::B128, true),
                 ("isize", None) => (IntKind::Size, true),
                 ("C", None) => {
                     repr.style = ReprStyle::C;
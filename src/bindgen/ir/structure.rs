@@ -2,27 +2,29 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::borrow::Cow;
 use std::io::Write;
 
+use log::Level;
 use syn::ext::IdentExt;
 
-use crate::bindgen::config::{Config, Language, LayoutConfig};
+use crate::bindgen::config::{Config, Language};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
+use crate::bindgen::diagnostic::{self, DiagnosticKind};
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Constant, Documentation, Field, GenericArgument,
-    GenericParams, Item, ItemContainer, Path, Repr, ReprAlign, ReprStyle, ToCondition, Type,
-    Typedef,
+    AnnotationSet, Cfg, ConditionWrite, Constant, Documentation, Field, FieldDeclaration,
+    GenericArgument, GenericParams, IntKind, Item, ItemContainer, Path, PrimitiveType, Repr,
+    ReprAlign, ReprStyle, ToCondition, Type, Typedef,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::mangle;
 use crate::bindgen::monomorph::Monomorphs;
 use crate::bindgen::rename::{IdentifierType, RenameRule};
 use crate::bindgen::reserved;
-use crate::bindgen::utilities::IterHelpers;
 use crate::bindgen::writer::{ListType, Source, SourceWriter};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Struct {
     pub path: Path,
     pub export_name: String,
@@ -39,6 +41,8 @@ pub struct Struct {
     pub annotations: AnnotationSet,
     pub documentation: Documentation,
     pub associated_constants: Vec<Constant>,
+    /// The names of the zero-sized fields, which aren't represented.
+    pub omitted_fields: Vec<String>,
 }
 
 impl Struct {
@@ -47,12 +51,88 @@ impl Struct {
         !self.fields.is_empty() && self.fields.iter().all(|x| x.ty.can_cmp_eq())
     }
 
+    /// Whether this struct can derive operator< and the other ordering
+    /// operators, from all its fields.
+    pub fn can_derive_order(&self) -> bool {
+        self.can_derive_eq() && self.fields.iter().all(|x| x.ty.can_cmp_order())
+    }
+
+    /// Writes the comparison operators of the struct: the defaulted
+    /// `operator==` and `operator<=>` in C++20, and piecewise ones otherwise,
+    /// comparing the fields from the first to the last. The ordering operators
+    /// are only written when all the fields can be ordered.
+    fn write_comparisons<F: Write>(
+        &self,
+        other: &str,
+        skip_fields: usize,
+        out: &mut SourceWriter<F>,
+    ) {
+        let name = self.export_name();
+        let fields = &self.fields[skip_fields..];
+        let ordered = self.can_derive_order();
+
+        out.new_line();
+        out.write("#if __cplusplus >= 202002L");
+        out.new_line();
+        write!(out, "bool operator==(const {}&) const = default;", name);
+        if ordered {
+            out.new_line();
+            write!(out, "auto operator<=>(const {}&) const = default;", name);
+        }
+        out.new_line();
+        out.write("#else");
+        out.new_line();
+        write!(out, "bool operator==(const {}& {}) const", name, other);
+        out.open_brace();
+        out.write("return ");
+        let vec: Vec<_> = fields
+            .iter()
+            .map(|field| format!("{} == {}.{}", field.name, other, field.name))
+            .collect();
+        out.write_vertical_source_list(&vec[..], ListType::Join(" &&"));
+        out.write(";");
+        out.close_brace(false);
+        out.new_line();
+        write!(out, "bool operator!=(const {}& {}) const", name, other);
+        out.open_brace();
+        write!(out, "return !(*this == {});", other);
+        out.close_brace(false);
+        if ordered {
+            out.new_line();
+            write!(out, "bool operator<(const {}& {}) const", name, other);
+            out.open_brace();
+            for field in &fields[..fields.len() - 1] {
+                write!(out, "if ({} != {}.{})", field.name, other, field.name);
+                out.open_brace();
+                write!(out, "return {} < {}.{};", field.name, other, field.name);
+                out.close_brace(false);
+                out.new_line();
+            }
+            let last = &fields[fields.len() - 1];
+            write!(out, "return {} < {}.{};", last.name, other, last.name);
+            out.close_brace(false);
+            for (op, body) in &[
+                ("<=", "!({} < *this)"),
+                (">", "{} < *this"),
+                (">=", "!(*this < {})"),
+            ] {
+                out.new_line();
+                write!(out, "bool operator{}(const {}& {}) const", op, name, other);
+                out.open_brace();
+                write!(out, "return {};", body.replace("{}", other));
+                out.close_brace(false);
+            }
+        }
+        out.new_line();
+        out.write("#endif");
+    }
+
     pub fn add_associated_constant(&mut self, c: Constant) {
         self.associated_constants.push(c);
     }
 
     pub fn load(
-        layout_config: &LayoutConfig,
+        config: &Config,
         item: &syn::ItemStruct,
         mod_cfg: Option<&Cfg>,
     ) -> Result<Self, String> {
@@ -68,20 +148,32 @@ impl Struct {
         let path = Path::new(item.ident.unraw().to_string());
 
         // Ensure we can safely represent the struct given the configuration.
+        // Cython extern declarations leave the layout to the C declaration.
         if let Some(align) = repr.align {
-            layout_config.ensure_safe_to_represent(&align)?;
+            if config.language != Language::Cython {
+                config.layout.ensure_safe_to_represent(&align)?;
+            }
         }
 
+        let mut omitted_fields = Vec::new();
         let fields = match item.fields {
             syn::Fields::Unit => Vec::new(),
-            syn::Fields::Named(ref fields) => fields
-                .named
-                .iter()
-                .try_skip_map(|field| Field::load(field, &path))?,
+            syn::Fields::Named(ref fields) => {
+                let mut out = Vec::new();
+                for field in fields.named.iter() {
+                    match Field::load(field, &path)? {
+                        Some(field) => out.push(field),
+                        None => {
+                            omitted_fields.push(field.ident.as_ref().unwrap().unraw().to_string())
+                        }
+                    }
+                }
+                out
+            }
             syn::Fields::Unnamed(ref fields) => {
                 let mut out = Vec::new();
                 let mut current = 0;
-                for field in fields.unnamed.iter() {
+                for (index, field) in fields.unnamed.iter().enumerate() {
                     if let Some(mut ty) = Type::load(&field.ty)? {
                         ty.replace_self_with(&path);
                         out.push(Field {
@@ -92,16 +184,30 @@ impl Struct {
                             documentation: Documentation::load(&field.attrs),
                         });
                         current += 1;
+                    } else {
+                        omitted_fields.push(format!(".{}", index));
                     }
                 }
                 out
             }
         };
 
+        if fields.is_empty() && !omitted_fields.is_empty() {
+            diagnostic::report(
+                Level::Warn,
+                DiagnosticKind::UnsupportedType,
+                None,
+                format_args!(
+                    "Struct {} only has zero-sized fields, which are omitted, and is empty.",
+                    path
+                ),
+            );
+        }
+
         let has_tag_field = false;
         let is_enum_variant_body = false;
 
-        Ok(Struct::new(
+        let mut result = Struct::new(
             path,
             GenericParams::load(&item.generics)?,
             fields,
@@ -112,7 +218,9 @@ impl Struct {
             Cfg::append(mod_cfg, Cfg::load(&item.attrs)),
             AnnotationSet::load(&item.attrs)?,
             Documentation::load(&item.attrs),
-        ))
+        );
+        result.omitted_fields = omitted_fields;
+        Ok(result)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -142,6 +250,7 @@ impl Struct {
             annotations,
             documentation,
             associated_constants: vec![],
+            omitted_fields: vec![],
         }
     }
 
@@ -151,8 +260,70 @@ impl Struct {
         }
     }
 
+    pub fn name_fn_ptrs(&mut self, name: &mut dyn FnMut(Type) -> Type) {
+        for field in &mut self.fields {
+            field.ty.name_fn_ptrs(true, name);
+        }
+    }
+
     pub fn is_generic(&self) -> bool {
-        self.generic_params.len() > 0
+        !self.generic_params.is_empty()
+    }
+
+    /// Whether all the fields of this struct, if any, are zero-sized.
+    pub fn is_zero_sized(&self, is_zero_sized: &dyn Fn(&Type) -> bool) -> bool {
+        if self.is_transparent {
+            return self.fields.len() == 1 && is_zero_sized(&self.fields[0].ty);
+        }
+        self.fields.iter().all(|field| is_zero_sized(&field.ty))
+    }
+
+    /// Keeps only the non-zero-sized field of a transparent struct, which may
+    /// have other zero-sized fields like markers, so that it's the one the
+    /// struct is declared as.
+    pub fn resolve_transparent_field(&mut self, is_zero_sized: &dyn Fn(&Type) -> bool) {
+        if !self.is_transparent || self.fields.len() == 1 {
+            return;
+        }
+
+        let mut non_zero_sized = self
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| !is_zero_sized(&field.ty))
+            .map(|(i, _)| i);
+        match (non_zero_sized.next(), non_zero_sized.next()) {
+            (Some(i), None) => {
+                let field = self.fields.remove(i);
+                self.omitted_fields
+                    .extend(self.fields.drain(..).map(|field| field.name));
+                self.fields.push(field);
+            }
+            (None, _) => {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::UnsupportedType,
+                    None,
+                    format_args!(
+                        "Transparent struct {} is zero-sized, declaring it as an empty struct.",
+                        self.path
+                    ),
+                );
+                self.is_transparent = false;
+            }
+            (Some(_), Some(_)) => {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::UnsupportedType,
+                    None,
+                    format_args!(
+                        "Transparent struct {} has several fields that may not be zero-sized, \
+                         declaring it as its first field.",
+                        self.path
+                    ),
+                );
+            }
+        }
     }
 
     pub fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
@@ -180,7 +351,7 @@ impl Struct {
         config: &Config,
     ) -> Self {
         let mangled_path = mangle::mangle_path(&self.path, generic_values, &config.export.mangle);
-        Struct::new(
+        let mut specialized = Struct::new(
             mangled_path,
             GenericParams::default(),
             self.fields
@@ -200,7 +371,9 @@ impl Struct {
             self.cfg.clone(),
             self.annotations.clone(),
             self.documentation.clone(),
-        )
+        );
+        specialized.omitted_fields = self.omitted_fields.clone();
+        specialized
     }
 
     fn emit_bitflags_binop<F: Write>(
@@ -307,10 +480,11 @@ impl Item for Struct {
         //   1. `cbindgen::field-names` annotation
         //   2. `cbindgen::rename-all` annotation
         //   3. config struct rename rule
-        // If the struct is a tuple struct and we have not renamed the
-        // fields, then prefix each of them with an underscore.
+        // If the struct is a tuple struct, then prefix the remaining field
+        // indices with `struct.tuple_field_prefix` so they are identifiers.
         // If any field is a reserved keyword, then postfix it with an
-        // underscore.
+        // underscore. Finally, fields with a `cbindgen::rename` annotation
+        // get the name it gives.
 
         // Scope for mutable borrow of fields
         {
@@ -329,19 +503,16 @@ impl Item for Struct {
                 for name in names {
                     *name = r.apply(name, IdentifierType::StructMember).into_owned();
                 }
-            } else {
-                // If we don't have any rules for a tuple struct, prefix them with
-                // an underscore so it still compiles.
-                for name in names {
-                    if name.starts_with(|c: char| c.is_ascii_digit()) {
-                        name.insert(0, '_');
-                    }
-                }
             }
         }
 
+        for field in &mut self.fields {
+            config.structure.name_tuple_field(&mut field.name);
+        }
+
         for field in &mut self.fields {
             reserved::escape(&mut field.name);
+            field.apply_rename_annotation();
         }
 
         for c in self.associated_constants.iter_mut() {
@@ -380,6 +551,81 @@ impl Item for Struct {
     }
 }
 
+impl Struct {
+    /// Whether the struct initializes its fields with their default values,
+    /// which the bodies of enum variants can't.
+    fn has_init_macro(&self, config: &Config) -> bool {
+        !self.is_enum_variant_body
+            && config.language != Language::Cython
+            && config.structure.init_macro(&self.annotations)
+    }
+
+    /// The name of the struct as a type, which needs the `struct` keyword in C
+    /// when no typedef is generated.
+    fn type_name(&self, config: &Config) -> Cow<'_, str> {
+        if config.language == Language::C && !config.style.generate_typedef() {
+            Cow::Owned(format!("struct {}", self.export_name()))
+        } else {
+            Cow::Borrowed(self.export_name())
+        }
+    }
+
+    /// Writes the `<Name>_INIT(...)` macro, a compound literal of the struct
+    /// with the default values of its fields, followed by the designated
+    /// initializers passed to the macro, which override them.
+    /// Defines the length of each array field as `STRUCT_FIELD_LEN`.
+    fn write_array_lengths<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        for field in &self.fields {
+            let len = match field.ty {
+                Type::Array(_, ref len) => len,
+                _ => continue,
+            };
+            out.new_line();
+            let condition = field.cfg.to_condition(config);
+            condition.write_before(config, out);
+            write!(
+                out,
+                "#define {}_{}_LEN {}",
+                self.export_name().to_uppercase(),
+                field.name.to_uppercase(),
+                len.as_str()
+            );
+            condition.write_after(config, out);
+        }
+    }
+
+    fn write_init_macro<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        let defaults: Vec<_> = self
+            .fields
+            .iter()
+            .filter(|field| field.cfg.is_none())
+            .filter_map(|field| Some((&field.name, field.default_value()?)))
+            .collect();
+
+        out.new_line();
+        write!(
+            out,
+            "#define {}_INIT(...) (({}){{",
+            self.export_name(),
+            self.type_name(config)
+        );
+        if defaults.is_empty() {
+            out.write(" __VA_ARGS__ })");
+            return;
+        }
+        let indent = " ".repeat(config.tab_width);
+        out.write(" \\");
+        for (name, default) in defaults {
+            out.new_line();
+            write!(out, "{}.{} = {}, \\", indent, name, default);
+        }
+        out.new_line();
+        write!(out, "{}__VA_ARGS__ \\", indent);
+        out.new_line();
+        out.write("})");
+    }
+}
+
 impl Source for Struct {
     fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
         if self.is_transparent {
@@ -403,8 +649,21 @@ impl Source for Struct {
         let condition = self.cfg.to_condition(config);
         condition.write_before(config, out);
 
+        let pragma_pack = config.pragma_pack(self.alignment);
+        if pragma_pack {
+            out.write("#pragma pack(push, 1)");
+            out.new_line();
+        }
+
         self.documentation.write(config, out);
 
+        if let Some(ReprAlign::Align(n)) = self.alignment {
+            if config.language == Language::Cython {
+                write!(out, "# #[repr(align({}))]", n);
+                out.new_line();
+            }
+        }
+
         if !self.is_enum_variant_body {
             self.generic_params.write(config, out);
         }
@@ -423,14 +682,11 @@ impl Source for Struct {
         }
 
         // Cython extern declarations don't manage layouts, layouts are defined entierly by the
-        // corresponding C code. So this `packed` is only for documentation, and missing
-        // `aligned(n)` is also not a problem.
+        // corresponding C code. So this `packed` is only for documentation, like the comment
+        // written for `aligned(n)`, which Cython has no syntax for.
         if config.language == Language::Cython {
-            if let Some(align) = self.alignment {
-                match align {
-                    ReprAlign::Packed => out.write("packed "),
-                    ReprAlign::Align(_) => {} // Not supported
-                }
+            if let Some(ReprAlign::Packed) = self.alignment {
+                out.write("packed ");
             }
         }
 
@@ -447,16 +703,23 @@ impl Source for Struct {
                     ReprAlign::Align(n) => {
                         if let Some(ref anno) = config.layout.aligned_n {
                             write!(out, " {}({})", anno, n);
+                        } else if config.language == Language::Cxx && config.layout.alignas {
+                            write!(out, " alignas({})", n);
                         }
                     }
                 }
             }
         }
 
-        if self.annotations.must_use(config) {
-            if let Some(ref anno) = config.structure.must_use {
-                write!(out, " {}", anno);
-            }
+        if let Some(anno) = self
+            .annotations
+            .must_use_attribute(&config.structure.must_use, config)
+        {
+            write!(out, " {}", anno);
+        }
+
+        if let Some(swift_name) = self.annotations.swift_name_attribute(config) {
+            write!(out, " {}", swift_name);
         }
 
         if config.language != Language::C || config.style.generate_tag() {
@@ -471,9 +734,70 @@ impl Source for Struct {
             out.new_line();
         }
 
-        out.write_vertical_source_list(&self.fields, ListType::Cap(";"));
-        if config.language == Language::Cython && self.fields.is_empty() {
-            out.write("pass");
+        let fields = FieldDeclaration::list(
+            config,
+            &self.path,
+            &self.fields,
+            self.has_init_macro(config),
+            self.alignment,
+        );
+        out.write_vertical_source_list(&fields, ListType::Cap(";"));
+        if config.structure.comment_zero_sized_fields {
+            for (i, name) in self.omitted_fields.iter().enumerate() {
+                if i != 0 || !self.fields.is_empty() {
+                    out.new_line();
+                }
+                if config.language == Language::Cython {
+                    write!(out, "# `{}` is zero-sized and omitted.", name);
+                } else {
+                    write!(out, "/* `{}` is zero-sized and omitted. */", name);
+                }
+            }
+        }
+        if self.fields.is_empty() {
+            let has_comments =
+                config.structure.comment_zero_sized_fields && !self.omitted_fields.is_empty();
+            match config.language {
+                Language::Cython => {
+                    if has_comments {
+                        out.new_line();
+                    }
+                    out.write("pass");
+                }
+                Language::C => {
+                    if let Some(ref dummy) = config.structure.empty_struct_dummy_field {
+                        if has_comments {
+                            out.new_line();
+                        }
+                        write!(out, "uint8_t {};", dummy);
+                    }
+                }
+                Language::Cxx => {}
+            }
+        }
+
+        self.generic_params
+            .write_static_asserts(self.fields.iter().map(|f| &f.ty), config, out);
+
+        let methods = out.bindings().cpp_methods(self);
+        if !methods.is_empty() {
+            out.new_line();
+            if config.language == Language::C {
+                out.new_line();
+                out.push_set_spaces(0);
+                out.write("#ifdef __cplusplus");
+                out.pop_set_spaces();
+            }
+            for method in &methods {
+                out.new_line();
+                method.write_declaration(config, out);
+            }
+            if config.language == Language::C {
+                out.new_line();
+                out.push_set_spaces(0);
+                out.write("#endif // __cplusplus");
+                out.pop_set_spaces();
+            }
         }
 
         if config.language == Language::Cxx {
@@ -597,7 +921,16 @@ impl Source for Struct {
                 let vec: Vec<_> = self
                     .fields
                     .iter()
-                    .map(|x| format!(" << \"{}=\" << {}.{}", x.name, instance, x.name))
+                    .map(|x| {
+                        // `uint8_t` and `int8_t` would be printed as characters.
+                        let promote = match x.ty {
+                            Type::Primitive(PrimitiveType::Integer {
+                                kind: IntKind::B8, ..
+                            }) => "+",
+                            _ => "",
+                        };
+                        format!(" << \"{}=\" << {}{}.{}", x.name, promote, instance, x.name)
+                    })
                     .collect();
                 out.write_vertical_source_list(&vec[..], ListType::Join(" << \", \""));
                 out.write(" << \" }\";");
@@ -648,31 +981,50 @@ impl Source for Struct {
                 }};
             }
 
-            if config.structure.derive_eq(&self.annotations) && self.can_derive_eq() {
+            let comparisons = config.structure.derive_comparisons(&self.annotations)
+                && self.can_derive_eq()
+                && self.fields.len() > skip_fields;
+            if comparisons {
+                if !wrote_start_newline {
+                    wrote_start_newline = true;
+                    out.new_line();
+                }
+                self.write_comparisons(&other, skip_fields, out);
+            }
+
+            if !comparisons && config.structure.derive_eq(&self.annotations) && self.can_derive_eq()
+            {
                 emit_op!("eq", "==", "&&");
             }
-            if config.structure.derive_neq(&self.annotations) && self.can_derive_eq() {
+            if !comparisons
+                && config.structure.derive_neq(&self.annotations)
+                && self.can_derive_eq()
+            {
                 emit_op!("neq", "!=", "||");
             }
-            if config.structure.derive_lt(&self.annotations)
+            if !comparisons
+                && config.structure.derive_lt(&self.annotations)
                 && self.fields.len() == 1
                 && self.fields[0].ty.can_cmp_order()
             {
                 emit_op!("lt", "<", "&&");
             }
-            if config.structure.derive_lte(&self.annotations)
+            if !comparisons
+                && config.structure.derive_lte(&self.annotations)
                 && self.fields.len() == 1
                 && self.fields[0].ty.can_cmp_order()
             {
                 emit_op!("lte", "<=", "&&");
             }
-            if config.structure.derive_gt(&self.annotations)
+            if !comparisons
+                && config.structure.derive_gt(&self.annotations)
                 && self.fields.len() == 1
                 && self.fields[0].ty.can_cmp_order()
             {
                 emit_op!("gt", ">", "&&");
             }
-            if config.structure.derive_gte(&self.annotations)
+            if !comparisons
+                && config.structure.derive_gte(&self.annotations)
                 && self.fields.len() == 1
                 && self.fields[0].ty.can_cmp_order()
             {
@@ -703,11 +1055,66 @@ impl Source for Struct {
             out.close_brace(true);
         }
 
+        if pragma_pack {
+            out.new_line();
+            out.write("#pragma pack(pop)");
+        }
+
         for constant in &self.associated_constants {
             out.new_line();
             constant.write(config, out, Some(self));
         }
 
+        if config.language == Language::C && self.has_init_macro(config) {
+            self.write_init_macro(config, out);
+        }
+
+        if config.array_lengths() && self.generic_params.is_empty() {
+            self.write_array_lengths(config, out);
+        }
+
+        if config.layout.static_asserts && config.language != Language::Cython {
+            if let Some(layout) = out.bindings().struct_layout(&self.path).cloned() {
+                let name = self.type_name(config);
+                if layout.layout.only_64_bit {
+                    out.new_line();
+                    out.write("#if UINTPTR_MAX == UINT64_MAX");
+                }
+                out.new_line();
+                write!(
+                    out,
+                    "static_assert(sizeof({}) == {}, \"unexpected size of {}\");",
+                    name,
+                    layout.layout.size,
+                    self.export_name()
+                );
+                out.new_line();
+                write!(
+                    out,
+                    "static_assert(alignof({}) == {}, \"unexpected alignment of {}\");",
+                    name,
+                    layout.layout.align,
+                    self.export_name()
+                );
+                for (field, offset) in &layout.offsets {
+                    out.new_line();
+                    write!(
+                        out,
+                        "static_assert(offsetof({}, {}) == {}, \"unexpected offset of {}.{}\");",
+                        name,
+                        field,
+                        offset,
+                        self.export_name(),
+                        field
+                    );
+                }
+                if layout.layout.only_64_bit {
+                    out.new_line();
+                    out.write("#endif");
+                }
+            }
+        }
+
         condition.write_after(config, out);
     }
 }
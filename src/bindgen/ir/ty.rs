@@ -3,21 +3,25 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use std::borrow::Cow;
+use std::fmt;
 use std::io::Write;
 
+use log::Level;
 use syn::ext::IdentExt;
 
 use crate::bindgen::cdecl;
-use crate::bindgen::config::{Config, Language};
+use crate::bindgen::config::{CCharStyle, CimportStyle, Config, Int128Style, Language};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
+use crate::bindgen::diagnostic::{self, DiagnosticKind};
+use crate::bindgen::includes;
 use crate::bindgen::ir::{GenericArgument, GenericParams, GenericPath, Path};
 use crate::bindgen::library::Library;
 use crate::bindgen::monomorph::Monomorphs;
 use crate::bindgen::utilities::IterHelpers;
 use crate::bindgen::writer::{Source, SourceWriter};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum PrimitiveType {
     Void,
     Bool,
@@ -25,6 +29,8 @@ pub enum PrimitiveType {
     SChar,
     UChar,
     Char32,
+    /// A UTF-8 code unit, `char8_t` in C++.
+    Char8,
     Float,
     Double,
     VaList,
@@ -36,7 +42,7 @@ pub enum PrimitiveType {
     },
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum IntKind {
     Short,
     Int,
@@ -48,6 +54,7 @@ pub enum IntKind {
     B16,
     B32,
     B64,
+    B128,
 }
 
 impl PrimitiveType {
@@ -88,10 +95,12 @@ impl PrimitiveType {
                     "u16" | "uint16_t" => (IntKind::B16, false),
                     "u32" | "uint32_t" => (IntKind::B32, false),
                     "u64" | "uint64_t" => (IntKind::B64, false),
+                    "u128" => (IntKind::B128, false),
                     "i8" | "int8_t" => (IntKind::B8, true),
                     "i16" | "int16_t" => (IntKind::B16, true),
                     "i32" | "int32_t" => (IntKind::B32, true),
                     "i64" | "int64_t" => (IntKind::B64, true),
+                    "i128" => (IntKind::B128, true),
                     _ => return None,
                 };
                 PrimitiveType::Integer {
@@ -111,6 +120,7 @@ impl PrimitiveType {
             PrimitiveType::SChar => "c_schar",
             PrimitiveType::UChar => "c_uchar",
             PrimitiveType::Char32 => "char",
+            PrimitiveType::Char8 => "u8",
             PrimitiveType::Integer {
                 kind,
                 signed,
@@ -186,6 +196,13 @@ impl PrimitiveType {
                         "u64"
                     }
                 }
+                IntKind::B128 => {
+                    if signed {
+                        "i128"
+                    } else {
+                        "u128"
+                    }
+                }
             },
             PrimitiveType::Float => "f32",
             PrimitiveType::Double => "f64",
@@ -198,7 +215,11 @@ impl PrimitiveType {
         match *self {
             PrimitiveType::Void => "void",
             PrimitiveType::Bool => "bool",
-            PrimitiveType::Char => "char",
+            PrimitiveType::Char => match config.c_char {
+                CCharStyle::Char => "char",
+                CCharStyle::SignedChar => "signed char",
+                CCharStyle::Int8 => "int8_t",
+            },
             PrimitiveType::SChar => "signed char",
             PrimitiveType::UChar => "unsigned char",
             // NOTE: It'd be nice to use a char32_t, but:
@@ -210,6 +231,7 @@ impl PrimitiveType {
             //    uint_least32_t, which is _not_ guaranteed to be 4-bytes.
             //
             PrimitiveType::Char32 => "uint32_t",
+            PrimitiveType::Char8 => "char8_t",
             PrimitiveType::Integer {
                 kind,
                 signed,
@@ -291,6 +313,31 @@ impl PrimitiveType {
                         "uint64_t"
                     }
                 }
+                IntKind::B128 => match config.int128 {
+                    // Cython has no name for `__int128`, so it goes through a
+                    // typedef there.
+                    Int128Style::Builtin if config.language != Language::Cython => {
+                        if signed {
+                            "__int128"
+                        } else {
+                            "unsigned __int128"
+                        }
+                    }
+                    Int128Style::Builtin | Int128Style::Struct => {
+                        if signed {
+                            "cbindgen_int128_t"
+                        } else {
+                            "cbindgen_uint128_t"
+                        }
+                    }
+                    Int128Style::None => {
+                        if signed {
+                            "i128"
+                        } else {
+                            "u128"
+                        }
+                    }
+                },
             },
             PrimitiveType::Float => "float",
             PrimitiveType::Double => "double",
@@ -299,6 +346,20 @@ impl PrimitiveType {
         }
     }
 
+    /// The name of the type in declarations, which is qualified with the
+    /// module it's cimported from in Cython with `libc_cimports = "qualified"`.
+    pub fn to_repr_decl(&self, config: &Config) -> Cow<'static, str> {
+        let name = self.to_repr_c(config);
+        if config.language == Language::Cython
+            && config.cython.libc_cimports == CimportStyle::Qualified
+        {
+            if let Some(module) = includes::cython_cimport_module(name) {
+                return Cow::Owned(format!("{}.{}", module, name));
+            }
+        }
+        Cow::Borrowed(name)
+    }
+
     fn can_cmp_order(&self) -> bool {
         match *self {
             PrimitiveType::Bool => false,
@@ -315,7 +376,7 @@ impl PrimitiveType {
 ///
 /// Used for the `U` part of `[T; U]` and const generics. We support a very
 /// limited vocabulary here: only identifiers and literals.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum ConstExpr {
     Name(String),
     Value(String),
@@ -389,7 +450,7 @@ impl Source for ConstExpr {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Type {
     Ptr {
         ty: Box<Type>,
@@ -411,6 +472,44 @@ pub enum Type {
     },
 }
 
+/// Displays the type in Rust syntax, as it was instantiated.
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Type::Ptr {
+                ref ty,
+                is_const,
+                is_ref,
+                ..
+            } => match (is_ref, is_const) {
+                (true, true) => write!(f, "&{}", ty),
+                (true, false) => write!(f, "&mut {}", ty),
+                (false, true) => write!(f, "*const {}", ty),
+                (false, false) => write!(f, "*mut {}", ty),
+            },
+            Type::Path(ref generic) => generic.fmt(f),
+            Type::Primitive(ref primitive) => f.write_str(primitive.to_repr_rust()),
+            Type::Array(ref ty, ref len) => write!(f, "[{}; {}]", ty, len.as_str()),
+            Type::FuncPtr {
+                ref ret, ref args, ..
+            } => {
+                f.write_str("fn(")?;
+                for (i, (_, arg)) in args.iter().enumerate() {
+                    if i != 0 {
+                        f.write_str(", ")?;
+                    }
+                    arg.fmt(f)?;
+                }
+                f.write_str(")")?;
+                if **ret != Type::Primitive(PrimitiveType::Void) {
+                    write!(f, " -> {}", ret)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
 impl Type {
     pub fn const_ref_to(ty: &Self) -> Self {
         Type::Ptr {
@@ -532,6 +631,14 @@ impl Type {
                     never_return,
                 }
             }
+            syn::Type::TraitObject(ref trait_object) => {
+                // Trait objects are declared as opaque types named after
+                // their trait, see `Parse::add_trait_objects`.
+                match trait_object_name(trait_object) {
+                    Some(name) => Type::Path(GenericPath::new(Path::new(name), vec![])),
+                    None => return Err("Trait object has no trait bound.".to_owned()),
+                }
+            }
             syn::Type::Tuple(ref tuple) => {
                 if tuple.elems.is_empty() {
                     return Ok(None);
@@ -544,6 +651,32 @@ impl Type {
         Ok(Some(converted))
     }
 
+    /// Calls `visit` with this type and each type it's made of, along with
+    /// whether they're only used behind a pointer.
+    pub fn walk(&self, behind_pointer: bool, visit: &mut dyn FnMut(&Type, bool)) {
+        visit(self, behind_pointer);
+        match *self {
+            Type::Ptr { ref ty, .. } => ty.walk(true, visit),
+            Type::Path(ref generic) => {
+                for generic in generic.generics() {
+                    if let GenericArgument::Type(ref ty) = *generic {
+                        ty.walk(behind_pointer, visit);
+                    }
+                }
+            }
+            Type::Primitive(..) => {}
+            Type::Array(ref ty, _) => ty.walk(behind_pointer, visit),
+            Type::FuncPtr {
+                ref ret, ref args, ..
+            } => {
+                ret.walk(true, visit);
+                for (_, arg) in args {
+                    arg.walk(true, visit);
+                }
+            }
+        }
+    }
+
     pub fn is_ptr(&self) -> bool {
         matches!(*self, Type::Ptr { .. } | Type::FuncPtr { .. })
     }
@@ -624,11 +757,13 @@ impl Type {
             "NonZeroU16" => (IntKind::B16, false),
             "NonZeroU32" => (IntKind::B32, false),
             "NonZeroU64" => (IntKind::B64, false),
+            "NonZeroU128" => (IntKind::B128, false),
             "NonZeroUSize" => (IntKind::Size, false),
             "NonZeroI8" => (IntKind::B8, true),
             "NonZeroI16" => (IntKind::B16, true),
             "NonZeroI32" => (IntKind::B32, true),
             "NonZeroI64" => (IntKind::B64, true),
+            "NonZeroI128" => (IntKind::B128, true),
             "NonZeroISize" => (IntKind::Size, true),
             _ => return None,
         };
@@ -685,10 +820,8 @@ impl Type {
                 is_nullable: false,
                 is_ref: false,
             }),
-            "Cell" => Some(generic.into_owned()),
-            "ManuallyDrop" | "MaybeUninit" | "Pin" if config.language != Language::Cxx => {
-                Some(generic.into_owned())
-            }
+            "Cell" | "MaybeUninit" | "ManuallyDrop" => Some(generic.into_owned()),
+            "Pin" if config.language != Language::Cxx => Some(generic.into_owned()),
             _ => None,
         }
     }
@@ -698,6 +831,23 @@ impl Type {
         if let Some(ty) = self.simplified_type(config) {
             *self = ty;
         }
+        if let Type::Primitive(PrimitiveType::Integer {
+            kind: IntKind::B128,
+            ..
+        }) = *self
+        {
+            if config.int128 == Int128Style::None {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::UnsupportedType,
+                    None,
+                    format_args!(
+                        "{} has no portable C equivalent, set `int128` to choose how to emit it.",
+                        self
+                    ),
+                );
+            }
+        }
     }
 
     pub fn replace_self_with(&mut self, self_ty: &Path) {
@@ -707,6 +857,19 @@ impl Type {
         self.visit_types(|ty| ty.replace_self_with(self_ty))
     }
 
+    /// Replaces the function pointers within this type with what `name` gives
+    /// for them, starting from the innermost ones. The type itself is only
+    /// replaced when `outermost` is set.
+    pub fn name_fn_ptrs(&mut self, outermost: bool, name: &mut dyn FnMut(Type) -> Type) {
+        self.visit_types(|ty| ty.name_fn_ptrs(true, name));
+        if outermost {
+            if let Type::FuncPtr { .. } = *self {
+                let ty = std::mem::replace(self, Type::Primitive(PrimitiveType::Void));
+                *self = name(ty);
+            }
+        }
+    }
+
     fn visit_types(&mut self, mut visitor: impl FnMut(&mut Type)) {
         match *self {
             Type::Array(ref mut ty, ..) | Type::Ptr { ref mut ty, .. } => visitor(ty),
@@ -838,10 +1001,15 @@ impl Type {
                             }
                         }
                     } else {
-                        warn!(
+                        diagnostic::report(
+                            Level::Warn,
+                            DiagnosticKind::UnknownType,
+                            None,
+                            format_args!(
                             "Can't find {}. This usually means that this type was incompatible or \
                              not found.",
                             path
+                        ),
                         );
                     }
                 }
@@ -961,10 +1129,15 @@ impl Type {
                 if let Some(mangled_path) = monomorphs.mangle_path(generic_path) {
                     *generic_path = GenericPath::new(mangled_path.clone(), vec![]);
                 } else {
-                    warn!(
+                    diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::UnknownType,
+                        None,
+                        format_args!(
                         "Cannot find a mangling for generic path {:?}. This usually means that a \
                          type referenced by this generic was incompatible or not found.",
                         generic_path
+                    ),
                     );
                 }
             }
@@ -1013,6 +1186,19 @@ impl Source for String {
     }
 }
 
+/// Returns the name of the trait of a trait object, ignoring lifetimes and any
+/// additional auto traits.
+pub fn trait_object_name(trait_object: &syn::TypeTraitObject) -> Option<String> {
+    trait_object.bounds.iter().find_map(|bound| match *bound {
+        syn::TypeParamBound::Trait(ref bound) => bound
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.unraw().to_string()),
+        syn::TypeParamBound::Lifetime(_) => None,
+    })
+}
+
 impl Source for Type {
     fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
         cdecl::write_type(out, self, config);
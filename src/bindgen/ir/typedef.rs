@@ -5,11 +5,13 @@
 use std::collections::HashMap;
 use std::io::Write;
 
+use log::Level;
 use syn::ext::IdentExt;
 
 use crate::bindgen::config::{Config, Language};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
+use crate::bindgen::diagnostic::{self, DiagnosticKind};
 use crate::bindgen::ir::{
     AnnotationSet, Cfg, ConditionWrite, Documentation, Field, GenericArgument, GenericParams, Item,
     ItemContainer, Path, ToCondition, Type,
@@ -20,7 +22,7 @@ use crate::bindgen::monomorph::Monomorphs;
 use crate::bindgen::writer::{Source, SourceWriter};
 
 /// A type alias that is represented as a C typedef
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Typedef {
     pub path: Path,
     pub export_name: String,
@@ -72,6 +74,12 @@ impl Typedef {
         self.aliased.simplify_standard_types(config);
     }
 
+    /// Names the function pointers within the aliased type. The aliased type
+    /// itself is already named by this typedef.
+    pub fn name_fn_ptrs(&mut self, name: &mut dyn FnMut(Type) -> Type) {
+        self.aliased.name_fn_ptrs(false, name);
+    }
+
     pub fn transfer_annotations(&mut self, out: &mut HashMap<Path, AnnotationSet>) {
         if self.annotations.is_empty() {
             return;
@@ -79,9 +87,14 @@ impl Typedef {
 
         if let Some(alias_path) = self.aliased.get_root_path() {
             if out.contains_key(&alias_path) {
-                warn!(
-                    "Multiple typedef's with annotations for {}. Ignoring annotations from {}.",
-                    alias_path, self.path
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::IgnoredAnnotation,
+                    None,
+                    format_args!(
+                        "Multiple typedef's with annotations for {}. Ignoring annotations from {}.",
+                        alias_path, self.path
+                    ),
                 );
                 return;
             }
@@ -92,7 +105,7 @@ impl Typedef {
     }
 
     pub fn is_generic(&self) -> bool {
-        self.generic_params.len() > 0
+        !self.generic_params.is_empty()
     }
 
     pub fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
@@ -203,6 +216,18 @@ impl Source for Typedef {
 
         out.write(";");
 
+        if let Type::Array(_, ref len) = self.aliased {
+            if config.array_lengths() && self.generic_params.is_empty() {
+                out.new_line();
+                write!(
+                    out,
+                    "#define {}_LEN {}",
+                    self.export_name().to_uppercase(),
+                    len.as_str()
+                );
+            }
+        }
+
         condition.write_after(config, out);
     }
 }
@@ -6,12 +6,12 @@ use std::io::Write;
 
 use syn::ext::IdentExt;
 
-use crate::bindgen::config::{Config, Language, LayoutConfig};
+use crate::bindgen::config::{Config, Language};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Documentation, Field, GenericArgument, GenericParams, Item,
-    ItemContainer, Path, Repr, ReprAlign, ReprStyle, ToCondition,
+    AnnotationSet, Cfg, ConditionWrite, Documentation, Field, FieldDeclaration, GenericArgument,
+    GenericParams, Item, ItemContainer, Path, Repr, ReprAlign, ReprStyle, ToCondition, Type,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::mangle;
@@ -20,7 +20,7 @@ use crate::bindgen::rename::{IdentifierType, RenameRule};
 use crate::bindgen::utilities::IterHelpers;
 use crate::bindgen::writer::{ListType, Source, SourceWriter};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Union {
     pub path: Path,
     pub export_name: String,
@@ -35,7 +35,7 @@ pub struct Union {
 
 impl Union {
     pub fn load(
-        layout_config: &LayoutConfig,
+        config: &Config,
         item: &syn::ItemUnion,
         mod_cfg: Option<&Cfg>,
     ) -> Result<Union, String> {
@@ -45,8 +45,11 @@ impl Union {
         }
 
         // Ensure we can safely represent the union given the configuration.
+        // Cython extern declarations leave the layout to the C declaration.
         if let Some(align) = repr.align {
-            layout_config.ensure_safe_to_represent(&align)?;
+            if config.language != Language::Cython {
+                config.layout.ensure_safe_to_represent(&align)?;
+            }
         }
 
         let path = Path::new(item.ident.unraw().to_string());
@@ -103,8 +106,14 @@ impl Union {
         }
     }
 
+    pub fn name_fn_ptrs(&mut self, name: &mut dyn FnMut(Type) -> Type) {
+        for field in &mut self.fields {
+            field.ty.name_fn_ptrs(true, name);
+        }
+    }
+
     pub fn is_generic(&self) -> bool {
-        self.generic_params.len() > 0
+        !self.generic_params.is_empty()
     }
 
     pub fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
@@ -204,13 +213,17 @@ impl Item for Union {
                     documentation: field.documentation.clone(),
                 })
                 .collect();
-        } else if self.tuple_union {
-            // If we don't have any rules for a tuple union, prefix them with
-            // an underscore so it still compiles
+        }
+
+        if self.tuple_union {
             for field in &mut self.fields {
-                field.name.insert(0, '_');
+                config.structure.name_tuple_field(&mut field.name);
             }
         }
+
+        for field in &mut self.fields {
+            field.apply_rename_annotation();
+        }
     }
 
     fn add_dependencies(&self, library: &Library, out: &mut Dependencies) {
@@ -264,8 +277,30 @@ impl Source for Union {
         let condition = self.cfg.to_condition(config);
         condition.write_before(config, out);
 
+        let pragma_pack = config.pragma_pack(self.alignment);
+        if pragma_pack {
+            out.write("#pragma pack(push, 1)");
+            out.new_line();
+        }
+
         self.documentation.write(config, out);
 
+        // Cython has no syntax for the layout of unions, which is left to the
+        // C declaration anyway.
+        if config.language == Language::Cython {
+            match self.alignment {
+                Some(ReprAlign::Packed) => {
+                    out.write("# #[repr(packed)]");
+                    out.new_line();
+                }
+                Some(ReprAlign::Align(n)) => {
+                    write!(out, "# #[repr(align({}))]", n);
+                    out.new_line();
+                }
+                None => {}
+            }
+        }
+
         self.generic_params.write(config, out);
 
         // The following results in
@@ -295,12 +330,18 @@ impl Source for Union {
                     ReprAlign::Align(n) => {
                         if let Some(ref anno) = config.layout.aligned_n {
                             write!(out, " {}({})", anno, n);
+                        } else if config.language == Language::Cxx && config.layout.alignas {
+                            write!(out, " alignas({})", n);
                         }
                     }
                 }
             }
         }
 
+        if let Some(swift_name) = self.annotations.swift_name_attribute(config) {
+            write!(out, " {}", swift_name);
+        }
+
         if config.language != Language::C || config.style.generate_tag() {
             write!(out, " {}", self.export_name);
         }
@@ -313,11 +354,16 @@ impl Source for Union {
             out.new_line();
         }
 
-        out.write_vertical_source_list(&self.fields, ListType::Cap(";"));
+        let fields =
+            FieldDeclaration::list(config, &self.path, &self.fields, false, self.alignment);
+        out.write_vertical_source_list(&fields, ListType::Cap(";"));
         if config.language == Language::Cython && self.fields.is_empty() {
             out.write("pass");
         }
 
+        self.generic_params
+            .write_static_asserts(self.fields.iter().map(|f| &f.ty), config, out);
+
         // Emit the post_body section, if relevant
         if let Some(body) = config.export.post_body(&self.path) {
             out.new_line();
@@ -331,6 +377,11 @@ impl Source for Union {
             out.close_brace(true);
         }
 
+        if pragma_pack {
+            out.new_line();
+            out.write("#pragma pack(pop)");
+        }
+
         condition.write_after(config, out);
     }
 }
@@ -0,0 +1,244 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+
+use crate::bindgen::ir::{
+    ConstExpr, Enum, Field, GenericPath, IntKind, ItemMap, Path, PrimitiveType, ReprAlign,
+    ReprStyle, Struct, Type, Typedef, Union,
+};
+
+/// The size of pointers, `size_t` and `intptr_t` on the targets layouts are
+/// computed for.
+const POINTER_SIZE: usize = 8;
+
+/// The size and alignment of a type, in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeLayout {
+    pub size: usize,
+    pub align: usize,
+    /// Whether the layout only holds on 64-bit targets, as it depends on the
+    /// size of pointers, or on the alignment of 64-bit primitives, which is 4
+    /// on i386.
+    pub only_64_bit: bool,
+}
+
+/// The layout of a struct, along with the offset of each of its fields.
+#[derive(Debug, Clone)]
+pub struct StructLayout {
+    pub layout: TypeLayout,
+    pub offsets: Vec<(String, usize)>,
+}
+
+/// Computes the layout C compilers give to types on 64-bit targets where
+/// primitive types are aligned to their size, like x86_64 and aarch64.
+///
+/// Types whose layout differs across such targets or can't be known, like
+/// `long`, opaque items and enums with data, have no layout.
+pub struct LayoutComputer<'a> {
+    structs: &'a ItemMap<Struct>,
+    unions: &'a ItemMap<Union>,
+    enums: &'a ItemMap<Enum>,
+    typedefs: &'a ItemMap<Typedef>,
+    memo: HashMap<Path, Option<TypeLayout>>,
+}
+
+impl<'a> LayoutComputer<'a> {
+    pub fn new(
+        structs: &'a ItemMap<Struct>,
+        unions: &'a ItemMap<Union>,
+        enums: &'a ItemMap<Enum>,
+        typedefs: &'a ItemMap<Typedef>,
+    ) -> Self {
+        LayoutComputer {
+            structs,
+            unions,
+            enums,
+            typedefs,
+            memo: HashMap::new(),
+        }
+    }
+
+    /// Computes the layout of every struct which has one.
+    pub fn struct_layouts(&mut self) -> HashMap<Path, StructLayout> {
+        let mut layouts = HashMap::new();
+        let structs = self.structs;
+        structs.for_all_items(|s| {
+            if let Some(layout) = self.struct_layout(s) {
+                layouts.insert(s.path.clone(), layout);
+            }
+        });
+        layouts
+    }
+
+    fn struct_layout(&mut self, s: &Struct) -> Option<StructLayout> {
+        if s.is_generic() || s.fields.is_empty() {
+            return None;
+        }
+
+        let packed = s.alignment == Some(ReprAlign::Packed);
+        let mut offsets = Vec::with_capacity(s.fields.len());
+        let mut size = 0;
+        let mut align = 1;
+        let mut only_64_bit = false;
+        for field in &s.fields {
+            let layout = self.field_layout(field)?;
+            let field_align = if packed { 1 } else { layout.align };
+            size = round_up(size, field_align);
+            offsets.push((field.name.clone(), size));
+            size += layout.size;
+            align = align.max(field_align);
+            only_64_bit |= layout.only_64_bit;
+        }
+        if let Some(ReprAlign::Align(n)) = s.alignment {
+            align = align.max(n as usize);
+        }
+
+        Some(StructLayout {
+            layout: TypeLayout {
+                size: round_up(size, align),
+                align,
+                only_64_bit,
+            },
+            offsets,
+        })
+    }
+
+    fn union_layout(&mut self, u: &Union) -> Option<TypeLayout> {
+        if u.is_generic() || u.fields.is_empty() {
+            return None;
+        }
+
+        let packed = u.alignment == Some(ReprAlign::Packed);
+        let mut size = 0;
+        let mut align = 1;
+        let mut only_64_bit = false;
+        for field in &u.fields {
+            let layout = self.field_layout(field)?;
+            size = size.max(layout.size);
+            align = align.max(if packed { 1 } else { layout.align });
+            only_64_bit |= layout.only_64_bit;
+        }
+        if let Some(ReprAlign::Align(n)) = u.alignment {
+            align = align.max(n as usize);
+        }
+
+        Some(TypeLayout {
+            size: round_up(size, align),
+            align,
+            only_64_bit,
+        })
+    }
+
+    fn field_layout(&mut self, field: &Field) -> Option<TypeLayout> {
+        // Neither the layout of conditional fields nor the one of bitfields
+        // can be known up front.
+        if field.cfg.is_some() || field.annotations.atom("bitfield").is_some() {
+            return None;
+        }
+        self.type_layout(&field.ty)
+    }
+
+    fn type_layout(&mut self, ty: &Type) -> Option<TypeLayout> {
+        match *ty {
+            Type::Ptr { .. } | Type::FuncPtr { .. } => Some(TypeLayout {
+                size: POINTER_SIZE,
+                align: POINTER_SIZE,
+                only_64_bit: true,
+            }),
+            Type::Primitive(ref primitive) => primitive_layout(primitive),
+            Type::Array(ref ty, ConstExpr::Value(ref len)) => {
+                let len = len.parse::<usize>().ok()?;
+                let layout = self.type_layout(ty)?;
+                Some(TypeLayout {
+                    size: layout.size * len,
+                    ..layout
+                })
+            }
+            Type::Array(_, ConstExpr::Name(_)) => None,
+            Type::Path(ref generic) => self.path_layout(generic),
+        }
+    }
+
+    fn path_layout(&mut self, generic: &GenericPath) -> Option<TypeLayout> {
+        if !generic.generics().is_empty() {
+            return None;
+        }
+        let path = generic.path();
+        if let Some(layout) = self.memo.get(path) {
+            return *layout;
+        }
+
+        let layout = if let Some(s) = self.structs.get_single(path) {
+            if s.is_transparent {
+                s.fields.first().and_then(|f| self.field_layout(f))
+            } else {
+                self.struct_layout(s).map(|s| s.layout)
+            }
+        } else if let Some(u) = self.unions.get_single(path) {
+            self.union_layout(u)
+        } else if let Some(e) = self.enums.get_single(path) {
+            enum_layout(e)
+        } else if let Some(t) = self.typedefs.get_single(path) {
+            if t.is_generic() {
+                None
+            } else {
+                self.type_layout(&t.aliased)
+            }
+        } else {
+            None
+        };
+
+        self.memo.insert(path.clone(), layout);
+        layout
+    }
+}
+
+fn enum_layout(e: &Enum) -> Option<TypeLayout> {
+    if e.tag.is_some() {
+        return None;
+    }
+    match e.repr.ty {
+        Some(ty) => primitive_layout(&ty.to_primitive()),
+        None if e.repr.style == ReprStyle::C => primitive_layout(&PrimitiveType::Integer {
+            zeroable: true,
+            signed: true,
+            kind: IntKind::Int,
+        }),
+        None => None,
+    }
+}
+
+fn primitive_layout(primitive: &PrimitiveType) -> Option<TypeLayout> {
+    let size = match *primitive {
+        PrimitiveType::Bool
+        | PrimitiveType::Char
+        | PrimitiveType::SChar
+        | PrimitiveType::UChar
+        | PrimitiveType::Char8 => 1,
+        PrimitiveType::Char32 | PrimitiveType::Float => 4,
+        PrimitiveType::Double => 8,
+        PrimitiveType::PtrDiffT => POINTER_SIZE,
+        PrimitiveType::Integer { kind, .. } => match kind {
+            IntKind::B8 => 1,
+            IntKind::Short | IntKind::B16 => 2,
+            IntKind::Int | IntKind::B32 => 4,
+            IntKind::LongLong | IntKind::B64 => 8,
+            IntKind::SizeT | IntKind::Size => POINTER_SIZE,
+            // `long` is 32-bit on Windows, and the alignment of 128-bit
+            // integers varies across compilers.
+            IntKind::Long | IntKind::B128 => return None,
+        },
+        PrimitiveType::Void | PrimitiveType::VaList => return None,
+    };
+    Some(TypeLayout {
+        size,
+        align: size,
+        only_64_bit: size == 8,
+    })
+}
+
+fn round_up(offset: usize, align: usize) -> usize {
+    (offset + align - 1) / align * align
+}
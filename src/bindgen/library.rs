@@ -2,16 +2,26 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
 
-use crate::bindgen::bindings::Bindings;
-use crate::bindgen::config::{Config, Language, SortKey};
+use log::Level;
+
+use crate::bindgen::bindings::{
+    top_level_module, Bindings, ModuleDependencies, TemplateInstantiation,
+};
+use crate::bindgen::config::{Config, Int128Style, Language, SortKey};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
+use crate::bindgen::diagnostic::{self, DiagnosticKind, Span};
 use crate::bindgen::error::Error;
-use crate::bindgen::ir::{Constant, Enum, Function, Item, ItemContainer, ItemMap};
-use crate::bindgen::ir::{OpaqueItem, Path, Static, Struct, Typedef, Union};
+use crate::bindgen::ir::{AnnotationSet, Constant, Documentation, Enum, Function, GenericParams};
+use crate::bindgen::ir::{GenericPath, Item, ItemContainer, ItemMap, OpaqueItem, Path};
+use crate::bindgen::ir::{PrimitiveType, Static, Struct, Type, Typedef, Union};
+use crate::bindgen::layout::LayoutComputer;
+use crate::bindgen::mangle;
 use crate::bindgen::monomorph::Monomorphs;
+use crate::bindgen::parser::SourceLocation;
 use crate::bindgen::ItemType;
 
 #[derive(Debug, Clone)]
@@ -25,6 +35,20 @@ pub struct Library {
     opaque_items: ItemMap<OpaqueItem>,
     typedefs: ItemMap<Typedef>,
     functions: Vec<Function>,
+    instantiations: Vec<Type>,
+    /// Every instantiation of a generic item, and the path of its monomorph.
+    monomorphs: Vec<(String, Path)>,
+    /// The path of the module that each type was declared in.
+    module_paths: HashMap<String, Vec<String>>,
+    /// The crate that each type from another crate than the binding crate was
+    /// declared in.
+    crates: HashMap<String, String>,
+    /// The position in the source of each type.
+    source_order: HashMap<String, usize>,
+    /// Where each item was declared in the source.
+    source_locations: HashMap<String, SourceLocation>,
+    /// The files the bindings were generated from.
+    source_files: Vec<PathBuf>,
 }
 
 impl Library {
@@ -39,7 +63,17 @@ impl Library {
         opaque_items: ItemMap<OpaqueItem>,
         typedefs: ItemMap<Typedef>,
         functions: Vec<Function>,
+        module_paths: HashMap<String, Vec<String>>,
+        crates: HashMap<String, String>,
+        source_order: Vec<String>,
+        source_locations: HashMap<String, SourceLocation>,
+        source_files: Vec<PathBuf>,
     ) -> Library {
+        let mut source_positions = HashMap::new();
+        for (i, name) in source_order.into_iter().enumerate() {
+            source_positions.entry(name).or_insert(i);
+        }
+
         Library {
             config,
             constants,
@@ -50,6 +84,13 @@ impl Library {
             opaque_items,
             typedefs,
             functions,
+            instantiations: Vec::new(),
+            monomorphs: Vec::new(),
+            module_paths,
+            crates,
+            source_order: source_positions,
+            source_locations,
+            source_files,
         }
     }
 
@@ -68,27 +109,61 @@ impl Library {
                 Default::default(),
                 Default::default(),
                 Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
                 true,
             ));
         }
 
+        if self.config.int128 == Int128Style::Struct
+            && self.config.language != Language::Cython
+            && self.config.layout.aligned_n.is_none()
+            && !self.config.layout.alignas
+        {
+            diagnostic::report(
+                Level::Warn,
+                DiagnosticKind::InvalidConfig,
+                None,
+                format_args!(
+                    "The structs of the `struct` style of `int128` are less aligned than \
+                    128-bit integers, set `layout.aligned_n` or `layout.alignas` to align them."
+                ),
+            );
+        }
+
         self.transfer_annotations();
+        self.load_instantiations();
         self.simplify_standard_types();
 
-        match self.config.function.sort_by.unwrap_or(self.config.sort_by) {
-            SortKey::Name => self.functions.sort_by(|x, y| x.path.cmp(&y.path)),
-            SortKey::None => { /* keep input order */ }
-        }
+        // Functions don't depend on each other.
+        let key = self.sort_key(self.config.function.sort_by);
+        sort_values(&mut self.functions, key, |x| &x.path, |_, _| false);
 
         if self.config.language != Language::Cxx {
             self.instantiate_monomorphs();
         }
+        self.resolve_transparent_fields();
         self.remove_excluded();
+        if self.config.function.fn_ptr_typedefs {
+            self.name_fn_ptrs();
+        }
         if self.config.language == Language::C {
             self.resolve_declaration_types();
         }
 
+        if self.config.export.namespace_from_modules {
+            self.add_module_renames();
+        }
         self.rename_items();
+        self.check_rename_collisions();
+        if self.config.export.namespace_from_modules && self.config.language == Language::Cxx {
+            self.unqualify_declarations();
+        }
 
         let mut dependencies = Dependencies::new();
 
@@ -101,6 +176,9 @@ impl Library {
         self.constants.for_all_items(|constant| {
             constant.add_dependencies(&self, &mut dependencies);
         });
+        for ty in &self.instantiations {
+            ty.add_dependencies(&self, &mut dependencies);
+        }
         for name in &self.config.export.include {
             let path = Path::new(name.clone());
             if let Some(items) = self.get_items(&path) {
@@ -116,34 +194,76 @@ impl Library {
         }
 
         dependencies.sort();
+        match self.sort_key(self.config.export.sort_by) {
+            SortKey::Name => dependencies
+                .sort_topologically(&self, |x, y| x.deref().path().cmp(y.deref().path())),
+            SortKey::Source => dependencies.sort_topologically(&self, |x, y| {
+                self.source_position(x.deref().path())
+                    .cmp(&self.source_position(y.deref().path()))
+            }),
+            SortKey::None | SortKey::Dependency => { /* keep dependency order */ }
+        }
 
-        let items = dependencies.order;
+        let items = self.cimport_crate_items(dependencies.order);
         let constants = if self.config.export.should_generate(ItemType::Constants) {
             let mut constants = self.constants.to_vec();
-            match self.config.constant.sort_by.unwrap_or(self.config.sort_by) {
-                SortKey::Name => constants.sort_by(|x, y| x.path.cmp(&y.path)),
-                SortKey::None => { /* keep input order */ }
-            }
+            let key = self.sort_key(self.config.constant.sort_by);
+            sort_values(
+                &mut constants,
+                key,
+                |x| &x.path,
+                |x, y| x.path != y.path && x.value.refers_to(y),
+            );
             constants
         } else {
             vec![]
         };
 
         let globals = if self.config.export.should_generate(ItemType::Globals) {
+            // Globals don't depend on each other.
             let mut globals = self.globals.to_vec();
-            match self.config.constant.sort_by.unwrap_or(self.config.sort_by) {
-                SortKey::Name => globals.sort_by(|x, y| x.path.cmp(&y.path)),
-                SortKey::None => { /* keep input order */ }
-            }
+            let key = self.sort_key(self.config.constant.sort_by);
+            sort_values(&mut globals, key, |x| &x.path, |_, _| false);
             globals
         } else {
             vec![]
         };
+        let monomorphs = self
+            .monomorphs
+            .iter()
+            .map(|(instantiation, path)| {
+                let name = self
+                    .get_items(path)
+                    .and_then(|items| {
+                        items
+                            .first()
+                            .map(|item| item.deref().export_name().to_owned())
+                    })
+                    .unwrap_or_else(|| path.name().to_owned());
+                (instantiation.clone(), name)
+            })
+            .collect();
+        let template_instantiations = if self.config.cpp_instantiations_file().is_some() {
+            self.template_instantiations(&items)
+        } else {
+            vec![]
+        };
+        let module_dependencies = if self.config.split_by_module() {
+            self.module_dependencies(&items)
+        } else {
+            HashMap::new()
+        };
         let functions = if self.config.export.should_generate(ItemType::Functions) {
             self.functions
         } else {
             vec![]
         };
+        let struct_layouts = if self.config.layout.static_asserts {
+            LayoutComputer::new(&self.structs, &self.unions, &self.enums, &self.typedefs)
+                .struct_layouts()
+        } else {
+            HashMap::new()
+        };
 
         Ok(Bindings::new(
             self.config,
@@ -153,6 +273,13 @@ impl Library {
             globals,
             items,
             functions,
+            monomorphs,
+            template_instantiations,
+            self.module_paths,
+            module_dependencies,
+            struct_layouts,
+            self.source_locations,
+            self.source_files,
             false,
         ))
     }
@@ -177,6 +304,108 @@ impl Library {
         None
     }
 
+    /// How the items of each top-level module depend on the ones of the
+    /// others, for `split_by_module`.
+    fn module_dependencies(&self, items: &[ItemContainer]) -> HashMap<String, ModuleDependencies> {
+        // The items of other modules that each module uses, and whether it
+        // only uses them behind pointers.
+        let mut uses = HashMap::<String, BTreeMap<Path, bool>>::new();
+        let mut add = |path: &Path, types: Vec<&Type>| {
+            let module = top_level_module(&self.module_paths, path.name());
+            for ty in types {
+                ty.walk(false, &mut |ty, behind_pointer| {
+                    let used = match *ty {
+                        Type::Path(ref generic) => generic.path(),
+                        _ => return,
+                    };
+                    if self.get_items(used).is_none()
+                        || top_level_module(&self.module_paths, used.name()) == module
+                    {
+                        return;
+                    }
+                    *uses
+                        .entry(module.to_owned())
+                        .or_default()
+                        .entry(used.clone())
+                        .or_insert(true) &= behind_pointer;
+                });
+            }
+        };
+
+        for item in items {
+            add(item.deref().path(), item.types());
+        }
+        for function in &self.functions {
+            add(&function.path, function.types());
+        }
+        self.globals
+            .for_all_items(|global| add(&global.path, vec![&global.ty]));
+        self.constants
+            .for_all_items(|constant| add(&constant.path, vec![&constant.ty]));
+
+        uses.into_iter()
+            .map(|(module, used)| {
+                let mut dependencies = ModuleDependencies::default();
+                let mut declarations = Vec::new();
+                for (path, pointer_only) in used {
+                    match self.forward_declaration(&path) {
+                        Some(item) if pointer_only => declarations.push(item),
+                        _ => {
+                            let used_module = top_level_module(&self.module_paths, path.name());
+                            dependencies.includes.insert(used_module.to_owned());
+                        }
+                    }
+                }
+                // The items of included modules are declared already.
+                dependencies.forward_declarations = declarations
+                    .into_iter()
+                    .filter(|item| {
+                        let used_module =
+                            top_level_module(&self.module_paths, item.deref().path().name());
+                        !dependencies.includes.contains(used_module)
+                    })
+                    .collect();
+                (module, dependencies)
+            })
+            .collect()
+    }
+
+    /// Returns the item to declare instead of including the module of `path`
+    /// with `forward_declarations`, if it can be declared on its own.
+    fn forward_declaration(&self, path: &Path) -> Option<ItemContainer> {
+        if !self.config.forward_declarations
+            || (self.config.language == Language::C && !self.config.style.generate_tag())
+        {
+            return None;
+        }
+        let items = self.get_items(path)?;
+        let declarable = items.iter().all(|item| match *item {
+            ItemContainer::Struct(ref x) => !x.is_generic() && !x.is_transparent,
+            ItemContainer::Union(ref x) => !x.is_generic(),
+            ItemContainer::OpaqueItem(ref x) => x.generic_params.is_empty(),
+            _ => false,
+        });
+        if declarable {
+            items.into_iter().next()
+        } else {
+            None
+        }
+    }
+
+    /// The position in the source of a type, if it was declared there.
+    /// The order of the items of a kind, given by `key` or else by the
+    /// top-level `sort_by`.
+    fn sort_key(&self, key: Option<SortKey>) -> SortKey {
+        key.unwrap_or(self.config.sort_by)
+    }
+
+    fn source_position(&self, path: &Path) -> usize {
+        self.source_order
+            .get(path.name())
+            .copied()
+            .unwrap_or(std::usize::MAX)
+    }
+
     pub fn get_config(&self) -> &Config {
         &self.config
     }
@@ -202,6 +431,75 @@ impl Library {
             .filter(|x| config.export.exclude.iter().any(|y| y == x.path().name()));
     }
 
+    fn load_instantiations(&mut self) {
+        for instantiation in self.config.export.instantiations() {
+            let ty = syn::parse_str::<syn::Type>(&instantiation)
+                .map_err(|e| e.to_string())
+                .and_then(|ty| Type::load(&ty));
+            match ty {
+                Ok(Some(ty)) => self.instantiations.push(ty),
+                Ok(None) => {}
+                Err(msg) => diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::InvalidConfig,
+                    None,
+                    format_args!("Skipping instantiation {} - ({})", instantiation, msg),
+                ),
+            }
+        }
+    }
+
+    /// Renames the types declared in modules after them, as `module::Name` in
+    /// C++ and as `module_Name` otherwise, unless `[export.rename]` already
+    /// renames them.
+    fn add_module_renames(&mut self) {
+        let separator = if self.config.language == Language::Cxx {
+            "::"
+        } else {
+            "_"
+        };
+        let mut renames = Vec::new();
+        for (name, module_path) in &self.module_paths {
+            if self.get_items(&Path::new(name.clone())).is_none() {
+                continue;
+            }
+            let mut renamed = module_path.join(separator);
+            renamed.push_str(separator);
+            renamed.push_str(name);
+            renames.push((name.clone(), renamed));
+        }
+        for (name, renamed) in renames {
+            self.config.export.rename.entry(name).or_insert(renamed);
+        }
+    }
+
+    /// Declares the types that were renamed to `module::Name` as `Name`, since
+    /// they are written in the namespace of their module.
+    fn unqualify_declarations(&mut self) {
+        fn unqualify(export_name: &mut String) {
+            if let Some(i) = export_name.rfind("::") {
+                export_name.replace_range(..i + 2, "");
+            }
+        }
+        self.enums
+            .for_all_items_mut(|x| unqualify(&mut x.export_name));
+        self.structs
+            .for_all_items_mut(|x| unqualify(&mut x.export_name));
+        self.unions
+            .for_all_items_mut(|x| unqualify(&mut x.export_name));
+        self.opaque_items
+            .for_all_items_mut(|x| unqualify(&mut x.export_name));
+        self.typedefs
+            .for_all_items_mut(|x| unqualify(&mut x.export_name));
+    }
+
+    fn resolve_transparent_fields(&mut self) {
+        let structs = self.structs.clone();
+        let is_zero_sized = |ty: &Type| is_zero_sized(&structs, ty, 0);
+        self.structs
+            .for_all_items_mut(|x| x.resolve_transparent_field(&is_zero_sized));
+    }
+
     fn transfer_annotations(&mut self) {
         let mut annotations = HashMap::new();
 
@@ -212,16 +510,22 @@ impl Library {
         for (alias_path, annotations) in annotations {
             // TODO
             let mut transferred = false;
+            let span = self.span(&alias_path);
 
             self.enums.for_items_mut(&alias_path, |x| {
                 if x.annotations().is_empty() {
                     *x.annotations_mut() = annotations.clone();
                     transferred = true;
                 } else {
-                    warn!(
-                        "Can't transfer annotations from typedef to alias ({}) \
-                         that already has annotations.",
-                        alias_path
+                    diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::IgnoredAnnotation,
+                        span.clone(),
+                        format_args!(
+                            "Can't transfer annotations from typedef to alias ({}) \
+                             that already has annotations.",
+                            alias_path
+                        ),
                     );
                 }
             });
@@ -233,10 +537,15 @@ impl Library {
                     *x.annotations_mut() = annotations.clone();
                     transferred = true;
                 } else {
-                    warn!(
-                        "Can't transfer annotations from typedef to alias ({}) \
-                         that already has annotations.",
-                        alias_path
+                    diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::IgnoredAnnotation,
+                        span.clone(),
+                        format_args!(
+                            "Can't transfer annotations from typedef to alias ({}) \
+                             that already has annotations.",
+                            alias_path
+                        ),
                     );
                 }
             });
@@ -248,10 +557,15 @@ impl Library {
                     *x.annotations_mut() = annotations.clone();
                     transferred = true;
                 } else {
-                    warn!(
-                        "Can't transfer annotations from typedef to alias ({}) \
-                         that already has annotations.",
-                        alias_path
+                    diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::IgnoredAnnotation,
+                        span.clone(),
+                        format_args!(
+                            "Can't transfer annotations from typedef to alias ({}) \
+                             that already has annotations.",
+                            alias_path
+                        ),
                     );
                 }
             });
@@ -263,10 +577,15 @@ impl Library {
                     *x.annotations_mut() = annotations.clone();
                     transferred = true;
                 } else {
-                    warn!(
-                        "Can't transfer annotations from typedef to alias ({}) \
-                         that already has annotations.",
-                        alias_path
+                    diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::IgnoredAnnotation,
+                        span.clone(),
+                        format_args!(
+                            "Can't transfer annotations from typedef to alias ({}) \
+                             that already has annotations.",
+                            alias_path
+                        ),
                     );
                 }
             });
@@ -278,10 +597,15 @@ impl Library {
                     *x.annotations_mut() = annotations.clone();
                     transferred = true;
                 } else {
-                    warn!(
-                        "Can't transfer annotations from typedef to alias ({}) \
-                         that already has annotations.",
-                        alias_path
+                    diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::IgnoredAnnotation,
+                        span.clone(),
+                        format_args!(
+                            "Can't transfer annotations from typedef to alias ({}) \
+                             that already has annotations.",
+                            alias_path
+                        ),
                     );
                 }
             });
@@ -327,6 +651,46 @@ impl Library {
         }
     }
 
+    /// Warns about the types which are declared with the same name as another
+    /// one once renamed.
+    fn check_rename_collisions(&self) {
+        let mut types = Vec::new();
+        let mut add =
+            |item: &dyn Item| types.push((item.path().clone(), item.export_name().to_owned()));
+        self.structs.for_all_items(|x| add(x));
+        self.unions.for_all_items(|x| add(x));
+        self.enums.for_all_items(|x| add(x));
+        self.opaque_items.for_all_items(|x| add(x));
+        self.typedefs.for_all_items(|x| add(x));
+
+        let mut declared = HashMap::new();
+        for (path, export_name) in types {
+            let first = declared
+                .entry(export_name.clone())
+                .or_insert_with(|| path.clone());
+            if *first != path {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::RenameCollision,
+                    self.span(&path),
+                    format_args!(
+                        "{} and {} are both declared as {}.",
+                        first, path, export_name
+                    ),
+                );
+            }
+        }
+    }
+
+    /// Where the item at `path` was declared, for the diagnostics about it.
+    fn span(&self, path: &Path) -> Option<Span> {
+        self.source_locations.get(path.name()).map(|location| Span {
+            file: location.file.clone(),
+            line: location.line,
+            column: None,
+        })
+    }
+
     fn resolve_declaration_types(&mut self) {
         if !self.config.style.generate_tag() {
             return;
@@ -371,11 +735,79 @@ impl Library {
         self.globals
             .for_all_items_mut(|x| x.resolve_declaration_types(&resolver));
 
+        self.constants
+            .for_all_items_mut(|x| x.resolve_declaration_types(&resolver));
+
         for item in &mut self.functions {
             item.resolve_declaration_types(&resolver);
         }
     }
 
+    /// Replaces the function pointers which appear inline in declarations with
+    /// typedefs named after their signature, shared by all the occurrences of
+    /// a signature, which keep the argument names of the first one. Generic items are left alone, since their signatures may
+    /// depend on their generic parameters.
+    fn name_fn_ptrs(&mut self) {
+        let config = &self.config;
+        let mut typedefs: Vec<Typedef> = vec![];
+        let mut name = |ty: Type| -> Type {
+            let name = fn_ptr_typedef_name(&ty, config);
+            if !typedefs.iter().any(|typedef| typedef.path.name() == name) {
+                typedefs.push(Typedef::new(
+                    Path::new(name.clone()),
+                    GenericParams::default(),
+                    ty,
+                    None,
+                    AnnotationSet::new(),
+                    Documentation::none(),
+                ));
+            }
+            Type::Path(GenericPath::new(Path::new(name), vec![]))
+        };
+
+        for function in &mut self.functions {
+            function.name_fn_ptrs(&mut name);
+        }
+        self.structs.for_all_items_mut(|x| {
+            if !x.is_generic() {
+                x.name_fn_ptrs(&mut name);
+            }
+        });
+        self.unions.for_all_items_mut(|x| {
+            if !x.is_generic() {
+                x.name_fn_ptrs(&mut name);
+            }
+        });
+        self.enums.for_all_items_mut(|x| {
+            if x.generic_params.is_empty() {
+                x.name_fn_ptrs(&mut name);
+            }
+        });
+        self.typedefs.for_all_items_mut(|x| {
+            if x.generic_params.is_empty() {
+                x.name_fn_ptrs(&mut name);
+            }
+        });
+        self.globals.for_all_items_mut(|x| {
+            x.name_fn_ptrs(&mut name);
+        });
+
+        for typedef in typedefs {
+            let path = typedef.path.clone();
+            if !self.typedefs.try_insert(typedef) {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::RenameCollision,
+                    None,
+                    format_args!(
+                        "Can't declare a function pointer type as {}, which is already taken.",
+                        path
+                    ),
+                );
+            }
+        }
+    }
+
     fn simplify_standard_types(&mut self) {
         let config = &self.config;
 
@@ -397,6 +829,83 @@ impl Library {
         for x in &mut self.functions {
             x.simplify_standard_types(config);
         }
+        for x in &mut self.instantiations {
+            x.simplify_standard_types(config);
+        }
+    }
+
+    /// Removes from `items` the ones declared in the crates of
+    /// `cython.crate_modules`, which are cimported from the `.pxd` module of
+    /// their crate instead.
+    fn cimport_crate_items(&mut self, items: Vec<ItemContainer>) -> Vec<ItemContainer> {
+        if self.config.language != Language::Cython || self.config.cython.crate_modules.is_empty() {
+            return items;
+        }
+
+        let mut declared = Vec::with_capacity(items.len());
+        for item in items {
+            let module = self
+                .crates
+                .get(item.deref().path().name())
+                .and_then(|crate_name| self.config.cython.crate_modules.get(crate_name));
+            let module = match module {
+                Some(module) => module.clone(),
+                None => {
+                    declared.push(item);
+                    continue;
+                }
+            };
+            let names = self.config.cython.cimports.entry(module).or_default();
+            let name = item.deref().export_name().to_owned();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        declared
+    }
+
+    /// The instantiations of the generic structs, unions and enums that
+    /// `items`, the functions and the globals use, which are C++ templates.
+    fn template_instantiations(&self, items: &[ItemContainer]) -> Vec<TemplateInstantiation> {
+        let mut monomorphs = Monomorphs::default();
+        for item in items {
+            match *item {
+                ItemContainer::Struct(ref x) => x.add_monomorphs(self, &mut monomorphs),
+                ItemContainer::Union(ref x) => x.add_monomorphs(self, &mut monomorphs),
+                ItemContainer::Enum(ref x) => x.add_monomorphs(self, &mut monomorphs),
+                ItemContainer::Typedef(ref x) => x.add_monomorphs(self, &mut monomorphs),
+                ItemContainer::Constant(..)
+                | ItemContainer::Static(..)
+                | ItemContainer::OpaqueItem(..) => {}
+            }
+        }
+        self.globals.for_all_items(|x| {
+            x.add_monomorphs(self, &mut monomorphs);
+        });
+        for x in &self.functions {
+            x.add_monomorphs(self, &mut monomorphs);
+        }
+
+        let mut instantiations: Vec<_> = monomorphs
+            .replacements()
+            .filter_map(|(generic, _)| {
+                let items = self.get_items(generic.path())?;
+                let item = items.first()?;
+                let class_key = match *item {
+                    ItemContainer::Struct(ref x) if !x.is_transparent => "struct",
+                    ItemContainer::Union(_) => "union",
+                    ItemContainer::Enum(ref x) => x.class_key()?,
+                    _ => return None,
+                };
+                Some(TemplateInstantiation {
+                    class_key,
+                    ty: Type::Path(generic.clone()),
+                    cfg: item.deref().cfg().cloned(),
+                })
+            })
+            .collect();
+        instantiations.sort_by_key(|instantiation| instantiation.ty.to_string());
+        instantiations
     }
 
     fn instantiate_monomorphs(&mut self) {
@@ -415,9 +924,30 @@ impl Library {
         self.typedefs.for_all_items(|x| {
             x.add_monomorphs(self, &mut monomorphs);
         });
+        self.globals.for_all_items(|x| {
+            x.add_monomorphs(self, &mut monomorphs);
+        });
         for x in &self.functions {
             x.add_monomorphs(self, &mut monomorphs);
         }
+        for x in &self.instantiations {
+            x.add_monomorphs(self, &mut monomorphs);
+        }
+
+        self.monomorphs = monomorphs
+            .replacements()
+            .map(|(generic, path)| (generic.to_string(), path.clone()))
+            .collect();
+        self.monomorphs.sort();
+        for (generic, path) in monomorphs.replacements() {
+            if let Some(module_path) = self.module_paths.get(generic.name()).cloned() {
+                self.module_paths
+                    .insert(path.name().to_owned(), module_path);
+            }
+            if let Some(&position) = self.source_order.get(generic.name()) {
+                self.source_order.insert(path.name().to_owned(), position);
+            }
+        }
 
         // Insert the monomorphs into self
         for monomorph in monomorphs.drain_structs() {
@@ -437,11 +967,11 @@ impl Library {
         }
 
         // Remove structs and opaque items that are generic
-        self.opaque_items.filter(|x| x.generic_params.len() > 0);
-        self.structs.filter(|x| x.generic_params.len() > 0);
-        self.unions.filter(|x| x.generic_params.len() > 0);
-        self.enums.filter(|x| x.generic_params.len() > 0);
-        self.typedefs.filter(|x| x.generic_params.len() > 0);
+        self.opaque_items.filter(|x| !x.generic_params.is_empty());
+        self.structs.filter(|x| !x.generic_params.is_empty());
+        self.unions.filter(|x| !x.generic_params.is_empty());
+        self.enums.filter(|x| !x.generic_params.is_empty());
+        self.typedefs.filter(|x| !x.generic_params.is_empty());
 
         // Mangle the paths that remain
         self.unions
@@ -452,8 +982,117 @@ impl Library {
             .for_all_items_mut(|x| x.mangle_paths(&monomorphs));
         self.typedefs
             .for_all_items_mut(|x| x.mangle_paths(&monomorphs));
+        self.globals
+            .for_all_items_mut(|x| x.mangle_paths(&monomorphs));
         for x in &mut self.functions {
             x.mangle_paths(&monomorphs);
         }
+        for x in &mut self.instantiations {
+            x.mangle_paths(&monomorphs);
+        }
+    }
+}
+
+/// Whether `ty` is known to be zero-sized. Types that aren't structs, like
+/// opaque ones, are assumed not to be.
+fn is_zero_sized(structs: &ItemMap<Struct>, ty: &Type, depth: usize) -> bool {
+    // Guard against recursive types.
+    const MAX_DEPTH: usize = 16;
+    if depth > MAX_DEPTH {
+        return false;
+    }
+
+    match *ty {
+        Type::Array(ref ty, ref len) => {
+            len.as_str() == "0" || is_zero_sized(structs, ty, depth + 1)
+        }
+        Type::Path(ref generic) if generic.generics().is_empty() => {
+            let mut zero_sized = false;
+            structs.for_items(generic.path(), |x| {
+                zero_sized |= x.is_zero_sized(&|ty| is_zero_sized(structs, ty, depth + 1));
+            });
+            zero_sized
+        }
+        _ => false,
+    }
+}
+
+/// Sorts functions, constants or globals, which are in the order they were
+/// parsed in, i.e. the one of the sources, by `key`. With `Dependency`, items
+/// come after the other ones they refer to, as told by `refers_to`, keeping
+/// the order of the sources otherwise.
+fn sort_values<T>(
+    items: &mut Vec<T>,
+    key: SortKey,
+    path: impl Fn(&T) -> &Path,
+    refers_to: impl Fn(&T, &T) -> bool,
+) {
+    match key {
+        SortKey::Name => items.sort_by(|x, y| path(x).cmp(path(y))),
+        SortKey::Dependency => {
+            let mut remaining = std::mem::take(items);
+            while !remaining.is_empty() {
+                let next = remaining
+                    .iter()
+                    .position(|item| !remaining.iter().any(|other| refers_to(item, other)))
+                    .unwrap_or(0);
+                items.push(remaining.remove(next));
+            }
+        }
+        SortKey::None | SortKey::Source => { /* already in the order of the sources */ }
+    }
+}
+
+/// The name of the typedef declaring the function pointer type `ty`, made of
+/// its return and argument types.
+fn fn_ptr_typedef_name(ty: &Type, config: &Config) -> String {
+    let (ret, args, never_return) = match *ty {
+        Type::FuncPtr {
+            ref ret,
+            ref args,
+            never_return,
+            ..
+        } => (ret, args, never_return),
+        _ => unreachable!("Only function pointers are named, not {:?}", ty),
+    };
+    let ret = if never_return {
+        "never".to_owned()
+    } else {
+        type_name_part(ret, config)
+    };
+    let args = if args.is_empty() {
+        "void".to_owned()
+    } else {
+        args.iter()
+            .map(|(_, ty)| type_name_part(ty, config))
+            .collect::<Vec<_>>()
+            .join("_")
+    };
+    config
+        .function
+        .fn_ptr_typedef_name
+        .replace("{ret}", &ret)
+        .replace("{args}", &args)
+}
+
+fn type_name_part(ty: &Type, config: &Config) -> String {
+    match *ty {
+        Type::Primitive(PrimitiveType::Void) => "void".to_owned(),
+        Type::Primitive(ref primitive) => primitive.to_repr_rust().to_owned(),
+        Type::Path(ref generic) => mangle::mangle_name(
+            generic.export_name(),
+            generic.generics(),
+            &config.export.mangle,
+        ),
+        Type::Ptr {
+            ref ty, is_const, ..
+        } => format!(
+            "{}_{}",
+            type_name_part(ty, config),
+            if is_const { "const_ptr" } else { "ptr" }
+        ),
+        Type::Array(ref ty, ref len) => format!("{}_{}", type_name_part(ty, config), len.as_str()),
+        // Inner function pointers are named before the outer ones.
+        Type::FuncPtr { .. } => fn_ptr_typedef_name(ty, config),
     }
 }
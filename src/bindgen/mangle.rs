@@ -60,11 +60,7 @@ impl<'a> Mangler<'a> {
 
     fn push(&mut self, id: Separator) {
         let count = id as usize;
-        let separator = if self.config.remove_underscores {
-            ""
-        } else {
-            "_"
-        };
+        let separator = self.config.separator();
         self.output.extend(std::iter::repeat(separator).take(count));
     }
 
@@ -85,9 +81,8 @@ impl<'a> Mangler<'a> {
     fn append_mangled_type(&mut self, ty: &Type, last: bool) {
         match *ty {
             Type::Path(ref generic) => {
-                let sub_path =
-                    Mangler::new(generic.export_name(), generic.generics(), last, self.config)
-                        .mangle();
+                let name = self.config.abbreviate(generic.export_name());
+                let sub_path = Mangler::new(name, generic.generics(), last, self.config).mangle();
 
                 self.output.push_str(
                     &self
@@ -97,12 +92,10 @@ impl<'a> Mangler<'a> {
                 );
             }
             Type::Primitive(ref primitive) => {
-                self.output.push_str(
-                    &self
-                        .config
-                        .rename_types
-                        .apply(primitive.to_repr_rust(), IdentifierType::Type),
-                );
+                self.output.push_str(&self.config.rename_types.apply(
+                    self.config.abbreviate(primitive.to_repr_rust()),
+                    IdentifierType::Type,
+                ));
             }
             Type::Ptr {
                 ref ty, is_const, ..
@@ -112,13 +105,13 @@ impl<'a> Mangler<'a> {
                 } else {
                     Separator::BeginMutPtr
                 });
-                self.append_mangled_type(&**ty, last);
+                self.append_mangled_type(ty, last);
             }
             Type::FuncPtr {
                 ref ret, ref args, ..
             } => {
                 self.push(Separator::BeginFn);
-                self.append_mangled_type(&**ret, args.is_empty());
+                self.append_mangled_type(ret, args.is_empty());
                 for (i, arg) in args.iter().enumerate() {
                     self.push(Separator::BetweenFnArg);
                     let last = last && i == args.len() - 1;
@@ -214,6 +207,7 @@ fn generics() {
             &MangleConfig {
                 remove_underscores: true,
                 rename_types: RenameRule::None,
+                ..MangleConfig::default()
             }
         ),
         Path::new("FooBar")
@@ -227,6 +221,7 @@ fn generics() {
             &MangleConfig {
                 remove_underscores: true,
                 rename_types: PascalCase,
+                ..MangleConfig::default()
             },
         ),
         Path::new("FooBarF32")
@@ -240,6 +235,7 @@ fn generics() {
             &MangleConfig {
                 remove_underscores: true,
                 rename_types: PascalCase,
+                ..MangleConfig::default()
             },
         ),
         Path::new("FooBarCChar")
@@ -286,6 +282,7 @@ fn generics() {
             &MangleConfig {
                 remove_underscores: true,
                 rename_types: PascalCase,
+                ..MangleConfig::default()
             },
         ),
         Path::new("FooBarTE")
@@ -302,6 +299,7 @@ fn generics() {
             &MangleConfig {
                 remove_underscores: true,
                 rename_types: PascalCase,
+                ..MangleConfig::default()
             },
         ),
         Path::new("FooBarTBarE")
@@ -345,4 +343,34 @@ fn generics() {
         ),
         Path::new("Foo_f32__40")
     );
+
+    // Foo<Bar<T>, E> => Foo-Bar-T-----E
+    assert_eq!(
+        mangle_path(
+            &Path::new("Foo"),
+            &[generic_path("Bar", &[path("T")]), path("E")],
+            &MangleConfig {
+                separator: Some("-".to_owned()),
+                ..MangleConfig::default()
+            },
+        ),
+        Path::new("Foo-Bar-T-----E")
+    );
+
+    // Foo<Option<c_char>> => FooOptCChar
+    assert_eq!(
+        mangle_path(
+            &Path::new("Foo"),
+            &[generic_path("Option", &[c_char()])],
+            &MangleConfig {
+                remove_underscores: true,
+                rename_types: PascalCase,
+                abbreviations: vec![("Option".to_owned(), "Opt".to_owned())]
+                    .into_iter()
+                    .collect(),
+                ..MangleConfig::default()
+            },
+        ),
+        Path::new("FooOptCChar")
+    );
 }
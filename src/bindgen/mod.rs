@@ -2,11 +2,24 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-/// A helper macro for deriving deserialize for an enum to be used in toml-rs.
+/// A helper macro for deriving serialize and deserialize for an enum to be used
+/// in toml-rs.
 /// This macro works be relying on an existing FromStr implementation for the
-/// desired type.
+/// desired type. Each variant is serialized as the given string, which
+/// `from_str` must accept.
 macro_rules! deserialize_enum_str {
-    ($name:ident) => {
+    ($name:ident { $($variant:ident => $value:expr,)* }) => {
+        impl ::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                serializer.serialize_str(match *self {
+                    $($name::$variant => $value,)*
+                })
+            }
+        }
+
         impl<'de> ::serde::Deserialize<'de> for $name {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
@@ -17,7 +30,7 @@ macro_rules! deserialize_enum_str {
                     type Value = $name;
 
                     fn expecting(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                        f.write_str("$name")
+                        f.write_str(stringify!($name))
                     }
 
                     fn visit_str<E>(self, v: &str) -> Result<$name, E>
@@ -29,13 +42,25 @@ macro_rules! deserialize_enum_str {
                             Err(m) => Err(E::custom(m)),
                         }
                     }
+
+                    fn visit_enum<A>(self, data: A) -> Result<$name, A::Error>
+                    where
+                        A: ::serde::de::EnumAccess<'de>,
+                    {
+                        let (v, variant): (String, _) = data.variant()?;
+                        ::serde::de::VariantAccess::unit_variant(variant)?;
+                        self.visit_str(&v)
+                    }
                 }
-                deserializer.deserialize_str(Visitor)
+                // Deserialized as an enum of unit variants, which the formats
+                // read from strings, so that the schema knows the values.
+                deserializer.deserialize_enum(stringify!($name), &[$($value,)*], Visitor)
             }
         }
     };
 }
 
+pub(crate) mod backend;
 mod bindings;
 mod bitflags;
 mod builder;
@@ -44,22 +69,28 @@ mod cdecl;
 mod config;
 mod declarationtyperesolver;
 mod dependencies;
+pub mod diagnostic;
 mod error;
-mod ir;
+mod includes;
+pub(crate) mod ir;
+mod layout;
 mod library;
 mod mangle;
 mod monomorph;
-mod parser;
+pub(crate) mod parser;
 mod rename;
 mod reserved;
+mod schema;
+mod type_map;
 mod utilities;
-mod writer;
+pub(crate) mod writer;
 
 #[allow(unused)]
 pub(crate) use self::cargo::*;
 
-pub use self::bindings::Bindings;
+pub use self::bindings::{Bindings, FileChange};
 pub use self::builder::Builder;
 pub use self::config::Profile; // disambiguate with cargo::Profile
 pub use self::config::*;
 pub use self::error::Error;
+pub use self::parser::ParseCache;
@@ -34,7 +34,7 @@ impl Monomorphs {
     ) {
         let replacement_path = GenericPath::new(generic.path.clone(), arguments);
 
-        debug_assert!(generic.generic_params.len() > 0);
+        debug_assert!(!generic.generic_params.is_empty());
         debug_assert!(!self.contains(&replacement_path));
 
         self.replacements
@@ -54,7 +54,7 @@ impl Monomorphs {
     ) {
         let replacement_path = GenericPath::new(generic.path.clone(), arguments);
 
-        debug_assert!(generic.generic_params.len() > 0);
+        debug_assert!(!generic.generic_params.is_empty());
         debug_assert!(!self.contains(&replacement_path));
 
         self.replacements
@@ -74,7 +74,7 @@ impl Monomorphs {
     ) {
         let replacement_path = GenericPath::new(generic.path.clone(), arguments);
 
-        debug_assert!(generic.generic_params.len() > 0);
+        debug_assert!(!generic.generic_params.is_empty());
         debug_assert!(!self.contains(&replacement_path));
 
         self.replacements
@@ -93,7 +93,7 @@ impl Monomorphs {
     ) {
         let replacement_path = GenericPath::new(generic.path.clone(), arguments);
 
-        debug_assert!(generic.generic_params.len() > 0);
+        debug_assert!(!generic.generic_params.is_empty());
         debug_assert!(!self.contains(&replacement_path));
 
         self.replacements
@@ -110,7 +110,7 @@ impl Monomorphs {
     ) {
         let replacement_path = GenericPath::new(generic.path.clone(), arguments);
 
-        debug_assert!(generic.generic_params.len() > 0);
+        debug_assert!(!generic.generic_params.is_empty());
         debug_assert!(!self.contains(&replacement_path));
 
         self.replacements
@@ -125,6 +125,11 @@ impl Monomorphs {
         self.replacements.get(path)
     }
 
+    /// Returns every instantiation along with the path of its monomorph.
+    pub fn replacements(&self) -> impl Iterator<Item = (&GenericPath, &Path)> {
+        self.replacements.iter()
+    }
+
     pub fn drain_opaques(&mut self) -> Vec<OpaqueItem> {
         mem::take(&mut self.opaques)
     }
@@ -2,23 +2,35 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::collections::hash_map::Entry;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::Read;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::mem;
 use std::path::{Path as FilePath, PathBuf as FilePathBuf};
 
+use log::Level;
+use serde::{Deserialize, Serialize};
 use syn::ext::IdentExt;
+use syn::visit::{self, Visit};
 
 use crate::bindgen::bitflags;
 use crate::bindgen::cargo::{Cargo, PackageRef};
-use crate::bindgen::config::{Config, ParseConfig};
+use crate::bindgen::config::{Config, ParseConfig, Profile};
+use crate::bindgen::diagnostic::{self, DiagnosticKind, Span};
 use crate::bindgen::error::Error;
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, Constant, Documentation, Enum, Function, GenericParam, GenericParams,
-    ItemMap, OpaqueItem, Path, Static, Struct, Type, Typedef, Union,
+    trait_object_name, AnnotationSet, Cfg, Constant, Documentation, Enum, Field, Function,
+    GenericParam, GenericParams, GenericPath, IntKind, Item, ItemMap, OpaqueItem, Path,
+    PrimitiveType, Static, Struct, Type, Typedef, Union,
+};
+use crate::bindgen::type_map::TypeMap;
+use crate::bindgen::utilities::{
+    rustc_version, StableHasher, SynAbiHelpers, SynAttributeHelpers, SynForeignItemHelpers,
+    SynItemFnHelpers,
 };
-use crate::bindgen::utilities::{SynAbiHelpers, SynAttributeHelpers, SynItemFnHelpers};
 
 const STD_CRATES: &[&str] = &[
     "std",
@@ -31,12 +43,332 @@ const STD_CRATES: &[&str] = &[
 
 type ParseResult = Result<Parse, Error>;
 
+/// The syntax trees of the source files and expanded crates read while
+/// parsing, which can be shared between the parses of several outputs, and
+/// between generations with `Builder::with_parse_cache`, e.g. when watching
+/// the sources. Files are only parsed again when their contents change.
+#[derive(Debug, Clone, Default)]
+pub struct ParseCache {
+    /// The syntax tree of each source file, and the hash of the contents it
+    /// was parsed from.
+    src: HashMap<FilePathBuf, (u64, Vec<syn::Item>)>,
+    /// The syntax tree of each expanded crate, and the hash of the sources and
+    /// options it was expanded from.
+    expanded_crate: HashMap<String, (u64, Vec<syn::Item>)>,
+    /// The sources which were given as strings instead of read from files.
+    in_memory: HashSet<FilePathBuf>,
+}
+
+impl ParseCache {
+    /// Returns the syntax tree of the source file `src_file`, whose contents
+    /// are `contents`, unless they didn't change since they were last parsed.
+    fn parse_src(
+        &mut self,
+        crate_name: &str,
+        src_file: &FilePath,
+        contents: &str,
+    ) -> Result<Vec<syn::Item>, Error> {
+        let hash = content_hash(contents);
+        if let Some(&(cached_hash, ref items)) = self.src.get(src_file) {
+            if cached_hash == hash {
+                return Ok(items.clone());
+            }
+        }
+
+        let i = syn::parse_file(contents).map_err(|x| Error::ParseSyntaxError {
+            crate_name: crate_name.to_owned(),
+            src_path: src_file.to_string_lossy().into(),
+            error: x,
+        })?;
+        self.src
+            .insert(src_file.to_path_buf(), (hash, i.items.clone()));
+        Ok(i.items)
+    }
+
+    /// Records `file`, parsed elsewhere, as the syntax tree of the source file
+    /// `src_file`, which is then never read.
+    fn insert_parsed(&mut self, src_file: &FilePath, file: &syn::File) {
+        let hash = content_hash(file);
+        self.src
+            .insert(src_file.to_path_buf(), (hash, file.items.clone()));
+        self.in_memory.insert(src_file.to_path_buf());
+    }
+}
+
+/// The items parsed from a crate, cached on disk along with the hashes of the
+/// contents of the files they were parsed from.
+#[derive(Serialize, Deserialize)]
+struct CachedItems {
+    files: Vec<(FilePathBuf, Option<u64>)>,
+    items: Parse,
+}
+
+impl CachedItems {
+    /// Whether none of the files changed since the items were parsed.
+    fn is_fresh(&self) -> bool {
+        self.files
+            .iter()
+            .all(|&(ref file, hash)| fs::read(file).ok().map(|c| file_hash(&c)) == hash)
+    }
+}
+
+fn file_hash(contents: &[u8]) -> u64 {
+    let mut hasher = StableHasher::new();
+    hasher.write(contents);
+    hasher.finish()
+}
+
+fn content_hash<T: Hash + ?Sized>(contents: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    contents.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Collects the names of the traits used as trait objects in some syntax.
+#[derive(Default)]
+struct TraitObjects(Vec<String>);
+
+impl<'ast> Visit<'ast> for TraitObjects {
+    fn visit_type_trait_object(&mut self, trait_object: &'ast syn::TypeTraitObject) {
+        if let Some(name) = trait_object_name(trait_object) {
+            self.0.push(name);
+        }
+        visit::visit_type_trait_object(self, trait_object);
+    }
+
+    fn visit_block(&mut self, _: &'ast syn::Block) {}
+}
+
+/// Returns the names of the functions, constants and statics an item declares.
+fn value_names(item: &syn::Item) -> Vec<String> {
+    match *item {
+        syn::Item::Fn(ref item) => item.exported_name().into_iter().collect(),
+        syn::Item::Const(ref item) => vec![item.ident.unraw().to_string()],
+        syn::Item::Static(ref item) => vec![item.ident.unraw().to_string()],
+        syn::Item::Impl(ref item) => item
+            .items
+            .iter()
+            .filter_map(|item| match *item {
+                syn::ImplItem::Method(ref method) => method.exported_name(),
+                _ => None,
+            })
+            .collect(),
+        syn::Item::ForeignMod(ref item) => item
+            .items
+            .iter()
+            .filter_map(|item| match *item {
+                syn::ForeignItem::Fn(ref function) => Some(function.link_name()),
+                syn::ForeignItem::Static(ref global) => Some(global.link_name()),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// The names declared by an item, along with the line they are declared on.
+fn item_lines(item: &syn::Item) -> Vec<(String, usize)> {
+    let line = |ident: &syn::Ident| ident.span().start().line;
+    match *item {
+        syn::Item::Struct(ref item) => vec![(item.ident.unraw().to_string(), line(&item.ident))],
+        syn::Item::Union(ref item) => vec![(item.ident.unraw().to_string(), line(&item.ident))],
+        syn::Item::Enum(ref item) => vec![(item.ident.unraw().to_string(), line(&item.ident))],
+        syn::Item::Type(ref item) => vec![(item.ident.unraw().to_string(), line(&item.ident))],
+        syn::Item::Const(ref item) => vec![(item.ident.unraw().to_string(), line(&item.ident))],
+        syn::Item::Static(ref item) => vec![(item.ident.unraw().to_string(), line(&item.ident))],
+        syn::Item::Fn(ref item) => item
+            .exported_name()
+            .map(|name| (name, line(&item.sig.ident)))
+            .into_iter()
+            .collect(),
+        syn::Item::Impl(ref item) => item
+            .items
+            .iter()
+            .filter_map(|item| match *item {
+                syn::ImplItem::Method(ref method) => method
+                    .exported_name()
+                    .map(|name| (name, line(&method.sig.ident))),
+                _ => None,
+            })
+            .collect(),
+        syn::Item::ForeignMod(ref item) => item
+            .items
+            .iter()
+            .filter_map(|item| match *item {
+                syn::ForeignItem::Fn(ref function) => {
+                    Some((function.link_name(), line(&function.sig.ident)))
+                }
+                syn::ForeignItem::Static(ref global) => {
+                    Some((global.link_name(), line(&global.ident)))
+                }
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Keeps the elements of `items` for which `keep` returns true.
+fn retain<T>(items: Vec<T>, mut keep: impl FnMut(&mut T) -> bool) -> Vec<T> {
+    items
+        .into_iter()
+        .filter_map(|mut item| if keep(&mut item) { Some(item) } else { None })
+        .collect()
+}
+
+/// Resolves the `feature = "..."` parts of the `#[cfg]` attributes with the
+/// `enabled` features of a package, as `Cfg::resolve_features` does. Returns
+/// whether the item with the attributes exists.
+fn resolve_feature_attrs(attrs: &mut Vec<syn::Attribute>, enabled: Option<&[String]>) -> bool {
+    let mut exists = true;
+    *attrs = retain(mem::take(attrs), |attr| {
+        if !attr.path.is_ident("cfg") {
+            return true;
+        }
+        let cfg = match Cfg::load(std::slice::from_ref(attr)) {
+            Some(cfg) => cfg,
+            None => return true,
+        };
+        match cfg.resolve_features(enabled) {
+            Ok(cfg) => {
+                let text = format!("#[cfg({})]", cfg);
+                if let Ok(mut parsed) =
+                    syn::parse::Parser::parse_str(syn::Attribute::parse_outer, &text)
+                {
+                    *attr = parsed.remove(0);
+                }
+                true
+            }
+            Err(holds) => {
+                exists &= holds;
+                false
+            }
+        }
+    });
+    exists
+}
+
+fn resolve_feature_fields(fields: &mut syn::Fields, enabled: Option<&[String]>) {
+    let fields = match *fields {
+        syn::Fields::Named(ref mut fields) => &mut fields.named,
+        syn::Fields::Unnamed(ref mut fields) => &mut fields.unnamed,
+        syn::Fields::Unit => return,
+    };
+    *fields = retain(mem::take(fields).into_iter().collect(), |field| {
+        resolve_feature_attrs(&mut field.attrs, enabled)
+    })
+    .into_iter()
+    .collect();
+}
+
+/// Resolves the `feature` cfgs of `items` and of their fields, variants and
+/// associated items with the `enabled` features of their package, dropping
+/// those which don't exist.
+fn resolve_feature_cfgs(items: &mut Vec<syn::Item>, enabled: Option<&[String]>) {
+    *items = retain(mem::take(items), |item| {
+        match *item {
+            syn::Item::Struct(ref mut item) => resolve_feature_fields(&mut item.fields, enabled),
+            syn::Item::Union(ref mut item) => {
+                item.fields.named = retain(
+                    mem::take(&mut item.fields.named).into_iter().collect(),
+                    |field| resolve_feature_attrs(&mut field.attrs, enabled),
+                )
+                .into_iter()
+                .collect();
+            }
+            syn::Item::Enum(ref mut item) => {
+                item.variants = retain(
+                    mem::take(&mut item.variants).into_iter().collect(),
+                    |variant| {
+                        resolve_feature_fields(&mut variant.fields, enabled);
+                        resolve_feature_attrs(&mut variant.attrs, enabled)
+                    },
+                )
+                .into_iter()
+                .collect();
+            }
+            syn::Item::Impl(ref mut item) => {
+                item.items = retain(mem::take(&mut item.items), |item| match *item {
+                    syn::ImplItem::Const(ref mut item) => {
+                        resolve_feature_attrs(&mut item.attrs, enabled)
+                    }
+                    syn::ImplItem::Method(ref mut item) => {
+                        resolve_feature_attrs(&mut item.attrs, enabled)
+                    }
+                    _ => true,
+                });
+            }
+            syn::Item::ForeignMod(ref mut item) => {
+                item.items = retain(mem::take(&mut item.items), |item| match *item {
+                    syn::ForeignItem::Fn(ref mut item) => {
+                        resolve_feature_attrs(&mut item.attrs, enabled)
+                    }
+                    syn::ForeignItem::Static(ref mut item) => {
+                        resolve_feature_attrs(&mut item.attrs, enabled)
+                    }
+                    _ => true,
+                });
+            }
+            syn::Item::Mod(ref mut item) => {
+                if let Some((_, ref mut items)) = item.content {
+                    resolve_feature_cfgs(items, enabled);
+                }
+            }
+            _ => {}
+        }
+        match *item {
+            syn::Item::Const(ref mut item) => resolve_feature_attrs(&mut item.attrs, enabled),
+            syn::Item::Enum(ref mut item) => resolve_feature_attrs(&mut item.attrs, enabled),
+            syn::Item::ExternCrate(ref mut item) => resolve_feature_attrs(&mut item.attrs, enabled),
+            syn::Item::Fn(ref mut item) => resolve_feature_attrs(&mut item.attrs, enabled),
+            syn::Item::ForeignMod(ref mut item) => resolve_feature_attrs(&mut item.attrs, enabled),
+            syn::Item::Impl(ref mut item) => resolve_feature_attrs(&mut item.attrs, enabled),
+            syn::Item::Macro(ref mut item) => resolve_feature_attrs(&mut item.attrs, enabled),
+            syn::Item::Mod(ref mut item) => resolve_feature_attrs(&mut item.attrs, enabled),
+            syn::Item::Static(ref mut item) => resolve_feature_attrs(&mut item.attrs, enabled),
+            syn::Item::Struct(ref mut item) => resolve_feature_attrs(&mut item.attrs, enabled),
+            syn::Item::Type(ref mut item) => resolve_feature_attrs(&mut item.attrs, enabled),
+            syn::Item::Union(ref mut item) => resolve_feature_attrs(&mut item.attrs, enabled),
+            _ => true,
+        }
+    });
+}
+
+/// Adds the Rust sources in `dir` and its subdirectories to `files`.
+fn collect_rust_files(dir: &FilePath, files: &mut Vec<FilePathBuf>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    let mut paths: Vec<_> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .collect();
+    paths.sort();
+    for path in paths {
+        if path.is_dir() {
+            collect_rust_files(&path, files);
+        } else if path
+            .extension()
+            .map_or(false, |extension| extension == "rs")
+        {
+            files.push(path);
+        }
+    }
+}
+
 /// Parses a single rust source file, not following `mod` or `extern crate`.
-pub fn parse_src(src_file: &FilePath, config: &Config) -> ParseResult {
+pub(crate) fn parse_src(
+    src_file: &FilePath,
+    config: &Config,
+    cache: &mut ParseCache,
+) -> ParseResult {
     let mod_name = src_file.file_stem().unwrap().to_str().unwrap();
     let mut config = config.clone();
     config.parse = ParseConfig {
         parse_deps: true,
+        foreign_statics: config.parse.foreign_statics,
+        type_map: config.parse.type_map.clone(),
         ..ParseConfig::default()
     };
 
@@ -45,9 +377,12 @@ pub fn parse_src(src_file: &FilePath, config: &Config) -> ParseResult {
         config: &config,
         lib: None,
         parsed_crates: HashSet::new(),
-        cache_src: HashMap::new(),
-        cache_expanded_crate: HashMap::new(),
+        cache: mem::take(cache),
         cfg_stack: Vec::new(),
+        mod_stack: Vec::new(),
+        src_file: None,
+        rustc_version: None,
+        type_map: TypeMap::new(&config.parse.type_map),
         out: Parse::new(),
     };
 
@@ -56,8 +391,35 @@ pub fn parse_src(src_file: &FilePath, config: &Config) -> ParseResult {
         version: None,
     };
 
-    context.parse_mod(&pkg_ref, src_file, 0)?;
-    Ok(context.out)
+    let result = context.parse_mod(&pkg_ref, src_file, 0);
+    *cache = mem::take(&mut context.cache);
+    result.map(|()| context.out)
+}
+
+/// Parses `contents` as if it was the rust source file `src_file`, which needs
+/// not exist, like `parse_src`.
+pub(crate) fn parse_src_str(
+    src_file: &FilePath,
+    contents: &str,
+    config: &Config,
+    cache: &mut ParseCache,
+) -> ParseResult {
+    let crate_name = src_file.file_stem().unwrap().to_string_lossy();
+    cache.parse_src(&crate_name, src_file, contents)?;
+    cache.in_memory.insert(src_file.to_path_buf());
+    parse_src(src_file, config, cache)
+}
+
+/// Parses the syntax tree `file` as if it was the rust source file `src_file`,
+/// like `parse_src_str`.
+pub(crate) fn parse_file(
+    src_file: &FilePath,
+    file: &syn::File,
+    config: &Config,
+    cache: &mut ParseCache,
+) -> ParseResult {
+    cache.insert_parsed(src_file, file);
+    parse_src(src_file, config, cache)
 }
 
 /// Recursively parses a rust library starting at the root crate's directory.
@@ -65,21 +427,25 @@ pub fn parse_src(src_file: &FilePath, config: &Config) -> ParseResult {
 /// Inside a crate, `mod` and `extern crate` declarations are followed
 /// and parsed. To find an external crate, the parser uses the `cargo metadata`
 /// command to find the location of dependencies.
-pub(crate) fn parse_lib(lib: Cargo, config: &Config) -> ParseResult {
+pub(crate) fn parse_lib(lib: Cargo, config: &Config, cache: &mut ParseCache) -> ParseResult {
     let mut context = Parser {
         binding_crate_name: lib.binding_crate_name().to_owned(),
         config,
         lib: Some(lib),
         parsed_crates: HashSet::new(),
-        cache_src: HashMap::new(),
-        cache_expanded_crate: HashMap::new(),
+        cache: mem::take(cache),
         cfg_stack: Vec::new(),
+        mod_stack: Vec::new(),
+        src_file: None,
+        rustc_version: None,
+        type_map: TypeMap::new(&config.parse.type_map),
         out: Parse::new(),
     };
 
     let binding_crate = context.lib.as_ref().unwrap().binding_crate_ref();
-    context.parse_crate(&binding_crate)?;
-    Ok(context.out)
+    let result = context.parse_crate(&binding_crate);
+    *cache = mem::take(&mut context.cache);
+    result.map(|()| context.out)
 }
 
 #[derive(Debug, Clone)]
@@ -89,10 +455,16 @@ struct Parser<'a> {
     config: &'a Config,
 
     parsed_crates: HashSet<String>,
-    cache_src: HashMap<FilePathBuf, Vec<syn::Item>>,
-    cache_expanded_crate: HashMap<String, Vec<syn::Item>>,
+    cache: ParseCache,
 
     cfg_stack: Vec<Cfg>,
+    mod_stack: Vec<String>,
+    /// The source file being parsed, unless the crate was expanded.
+    src_file: Option<FilePathBuf>,
+    /// The version of rustc, once needed by a cache.
+    rustc_version: Option<String>,
+    /// The types to replace in the items, from `parse.type_map`.
+    type_map: TypeMap,
 
     out: Parse,
 }
@@ -120,21 +492,46 @@ impl<'a> Parser<'a> {
         }
 
         // If we have a whitelist, check it
-        if let Some(ref include) = self.config.parse.include {
-            if !include.iter().any(|name| name == pkg_name) {
-                debug!("Excluding crate {}", pkg_name);
-                return false;
-            }
+        if !self.config.parse.includes_crate(pkg_name) {
+            debug!("Excluding crate {}", pkg_name);
+            return false;
         }
 
         // Check the blacklist
-        !STD_CRATES.contains(&pkg_name)
-            && !self
-                .config
-                .parse
-                .exclude
-                .iter()
-                .any(|name| name == pkg_name)
+        !STD_CRATES.contains(&pkg_name) && !self.config.parse.excludes_crate(pkg_name)
+    }
+
+    /// The name of `src_file` in the bindings, relative to the current
+    /// directory if it's in it. With `deterministic`, it doesn't depend on
+    /// where the crates are on the machine: it's relative to the directory of
+    /// its crate, prefixed with the name and version of the crate unless it's
+    /// the binding crate, and uses `/` as separator.
+    fn source_file_name(&self, pkg: &PackageRef, src_file: &FilePath) -> String {
+        let current_dir = env::current_dir().ok();
+        if !self.config.deterministic {
+            let relative = current_dir.and_then(|dir| src_file.strip_prefix(dir).ok());
+            return relative.unwrap_or(src_file).display().to_string();
+        }
+
+        let crate_dir = self.lib.as_ref().and_then(|lib| lib.find_crate_dir(pkg));
+        let mut components = Vec::new();
+        if crate_dir.is_some() && pkg.name != self.binding_crate_name {
+            components.push(match pkg.version {
+                Some(ref version) => format!("{}-{}", pkg.name, version),
+                None => pkg.name.clone(),
+            });
+        }
+        let relative = crate_dir
+            .or(current_dir)
+            .and_then(|dir| src_file.strip_prefix(dir).ok())
+            .or_else(|| src_file.file_name().map(FilePath::new))
+            .unwrap_or(src_file);
+        components.extend(
+            relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy().into_owned()),
+        );
+        components.join("/")
     }
 
     fn parse_crate(&mut self, pkg: &PackageRef) -> Result<(), Error> {
@@ -152,14 +549,20 @@ impl<'a> Parser<'a> {
             let crate_src = self.lib.as_ref().unwrap().find_crate_src(pkg);
 
             match crate_src {
-                Some(crate_src) => self.parse_mod(pkg, crate_src.as_path(), 0)?,
+                Some(crate_src) => self.parse_crate_src(pkg, crate_src.as_path())?,
                 None => {
                     // This should be an error, but is common enough to just elicit a warning
-                    warn!(
-                        "Parsing crate `{}`: can't find lib.rs with `cargo metadata`. \
-                        The crate may be available only on a particular platform, \
-                        so consider setting `fetch_all_dependencies` in your cbindgen configuration.",
-                        pkg.name
+                    diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::MissingSource,
+                        None,
+                        format_args!(
+                            "Parsing crate `{}`: can't find lib.rs with `cargo metadata`. \
+                            The crate may be available only on a particular platform, \
+                            so consider setting `fetch_all_dependencies` in your cbindgen \
+                            configuration.",
+                            pkg.name
+                        ),
                     );
                 }
             }
@@ -184,33 +587,167 @@ impl<'a> Parser<'a> {
         Ok(())
     }
 
+    /// The version of rustc, which is only run once.
+    fn rustc_version(&mut self) -> &str {
+        self.rustc_version.get_or_insert_with(rustc_version)
+    }
+
+    /// Parses the crate `pkg` from its root module `crate_src`, or reuses the
+    /// items cached in the target directory with `parse.cache`.
+    fn parse_crate_src(&mut self, pkg: &PackageRef, crate_src: &FilePath) -> Result<(), Error> {
+        if !self.config.parse.cache {
+            return self.parse_mod(pkg, crate_src, 0);
+        }
+
+        let cache_file = self.items_cache_file(pkg);
+        let cached = fs::read_to_string(&cache_file)
+            .ok()
+            .and_then(|cached| serde_json::from_str::<CachedItems>(&cached).ok());
+        if let Some(cached) = cached.filter(CachedItems::is_fresh) {
+            debug!("Reusing the cached items of {}", pkg.name);
+            self.out.extend_with(&cached.items);
+            return Ok(());
+        }
+
+        // The items of the crate are parsed on their own to be cached, and
+        // then added like the items of the next crates would be.
+        let reported = diagnostic::reported();
+        let out = mem::replace(&mut self.out, Parse::new());
+        let result = self.parse_mod(pkg, crate_src, 0);
+        let items = mem::replace(&mut self.out, out);
+        result?;
+        self.out.extend_with(&items);
+
+        // A crate which reported diagnostics is parsed again next time, so that
+        // they are reported again.
+        if diagnostic::reported() != reported {
+            return Ok(());
+        }
+        let cached = CachedItems {
+            files: items
+                .source_files
+                .iter()
+                .map(|file| (file.clone(), fs::read(file).ok().map(|c| file_hash(&c))))
+                .collect(),
+            items,
+        };
+        // The cache is only an optimization, so failing to write it isn't an
+        // error.
+        let written = serde_json::to_string(&cached)
+            .map_err(|err| err.to_string())
+            .and_then(|cached| {
+                fs::create_dir_all(cache_file.parent().unwrap())
+                    .and_then(|()| fs::write(&cache_file, cached))
+                    .map_err(|err| err.to_string())
+            });
+        if let Err(err) = written {
+            diagnostic::report(
+                Level::Warn,
+                DiagnosticKind::Environment,
+                None,
+                format_args!(
+                    "Couldn't cache the items of {} in {:?}: {}",
+                    pkg.name, cache_file, err
+                ),
+            );
+        }
+        Ok(())
+    }
+
+    /// The file the items of `pkg` are cached in, named after a hash of what
+    /// they depend on besides the sources: the versions of cbindgen and rustc,
+    /// the config, where the crate is parsed from and under which cfgs.
+    fn items_cache_file(&mut self, pkg: &PackageRef) -> FilePathBuf {
+        let mut hasher = StableHasher::new();
+        hasher.write_str(env!("CARGO_PKG_VERSION"));
+        hasher.write_str(self.rustc_version());
+        // Converting the config to a value sorts the keys of its maps.
+        let config = serde_json::to_value(self.config).map(|config| config.to_string());
+        hasher.write_str(&config.unwrap_or_default());
+        hasher.write_str(&self.binding_crate_name);
+        hasher.write_str(&pkg.name);
+        hasher.write_str(pkg.version.as_deref().unwrap_or_default());
+        for cfg in &self.cfg_stack {
+            hasher.write_str(&cfg.to_string());
+        }
+        // The names of the source files can be relative to it.
+        if let Ok(dir) = env::current_dir() {
+            hasher.write_str(&dir.to_string_lossy());
+        }
+        self.lib
+            .as_ref()
+            .unwrap()
+            .target_dir()
+            .join("cbindgen")
+            .join(format!("items-{}-{:016x}.json", pkg.name, hasher.finish()))
+    }
+
     fn parse_expand_crate(&mut self, pkg: &PackageRef) -> Result<(), Error> {
         assert!(self.lib.is_some());
+        let rustc_version = self.rustc_version().to_owned();
+        let lib = self.lib.as_ref().unwrap();
+
+        // The expansion depends on every source of the crate, not only on the
+        // ones which are declared as modules.
+        let mut src_files = Vec::new();
+        if let Some(crate_dir) = lib.find_crate_dir(pkg) {
+            src_files.push(crate_dir.join("Cargo.toml"));
+        }
+        if let Some(src_dir) = lib
+            .find_crate_src(pkg)
+            .as_deref()
+            .and_then(FilePath::parent)
+        {
+            collect_rust_files(src_dir, &mut src_files);
+        }
+
+        let mut hasher = StableHasher::new();
+        hasher.write_str(env!("CARGO_PKG_VERSION"));
+        hasher.write_str(&rustc_version);
+        // The flags can enable cfgs, and so change the expansion.
+        for var in &["RUSTFLAGS", "CARGO_ENCODED_RUSTFLAGS"] {
+            hasher.write_str(&env::var(var).unwrap_or_default());
+        }
+        hasher.write_str(&pkg.name);
+        hasher.write_str(pkg.version.as_deref().unwrap_or_default());
+        let (all_features, default_features, features) =
+            self.config.parse.expand_options(&pkg.name);
+        hasher.write(&[
+            all_features as u8,
+            default_features as u8,
+            (self.config.parse.expand.profile == Profile::Release) as u8,
+        ]);
+        for feature in features.iter().flatten() {
+            hasher.write_str(feature);
+        }
+        for file in src_files
+            .iter()
+            .chain(Some(&lib.workspace_root().join("Cargo.lock")))
+        {
+            hasher.write_str(&file.to_string_lossy());
+            match fs::read(file) {
+                Ok(contents) => hasher.write(&contents),
+                Err(_) => hasher.write(&[]),
+            }
+        }
+        let hash = hasher.finish();
 
-        let mod_items = {
-            if !self.cache_expanded_crate.contains_key(&pkg.name) {
-                let s = self
-                    .lib
-                    .as_ref()
-                    .unwrap()
-                    .expand_crate(
-                        pkg,
-                        self.config.parse.expand.all_features,
-                        self.config.parse.expand.default_features,
-                        &self.config.parse.expand.features,
-                        self.config.parse.expand.profile,
-                    )
-                    .map_err(|x| Error::CargoExpand(pkg.name.clone(), x))?;
+        let mod_items = match self.cache.expanded_crate.get(&pkg.name) {
+            Some(&(cached_hash, ref items)) if cached_hash == hash => items.clone(),
+            _ => {
+                let s = self.expand_crate(pkg, hash)?;
                 let i = syn::parse_file(&s).map_err(|x| Error::ParseSyntaxError {
                     crate_name: pkg.name.clone(),
                     src_path: "".to_owned(),
                     error: x,
                 })?;
-                self.cache_expanded_crate.insert(pkg.name.clone(), i.items);
+                self.cache
+                    .expanded_crate
+                    .insert(pkg.name.clone(), (hash, i.items.clone()));
+                i.items
             }
-
-            self.cache_expanded_crate.get(&pkg.name).unwrap().clone()
         };
+        self.out.source_files.extend(src_files);
 
         self.process_mod(
             pkg, None, None, &mod_items, 0, /* is_mod_rs = */ true,
@@ -218,36 +755,80 @@ impl<'a> Parser<'a> {
         )
     }
 
+    /// Expands the crate `pkg`, or reads its expansion from the target
+    /// directory with `parse.expand.cache`, where it's named after `hash`.
+    fn expand_crate(&self, pkg: &PackageRef, hash: u64) -> Result<String, Error> {
+        let lib = self.lib.as_ref().unwrap();
+        let cache_file = if self.config.parse.expand.cache {
+            Some(
+                lib.target_dir()
+                    .join("cbindgen")
+                    .join(format!("expanded-{}-{:016x}.rs", pkg.name, hash)),
+            )
+        } else {
+            None
+        };
+        if let Some(cached) = cache_file
+            .as_ref()
+            .and_then(|file| fs::read_to_string(file).ok())
+        {
+            debug!("Reusing the cached expansion of {}", pkg.name);
+            return Ok(cached);
+        }
+
+        let (all_features, default_features, features) =
+            self.config.parse.expand_options(&pkg.name);
+        let expanded = lib
+            .expand_crate(
+                pkg,
+                all_features,
+                default_features,
+                &features,
+                self.config.parse.expand.profile,
+            )
+            .map_err(|x| Error::CargoExpand(pkg.name.clone(), x))?;
+
+        if let Some(file) = cache_file {
+            // The cache is only an optimization, so failing to write it isn't
+            // an error.
+            let written = fs::create_dir_all(file.parent().unwrap())
+                .and_then(|()| fs::write(&file, &expanded));
+            if let Err(err) = written {
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::Environment,
+                    None,
+                    format_args!(
+                        "Couldn't cache the expansion of {} in {:?}: {}",
+                        pkg.name, file, err
+                    ),
+                );
+            }
+        }
+        Ok(expanded)
+    }
+
     fn parse_mod(
         &mut self,
         pkg: &PackageRef,
         mod_path: &FilePath,
         depth: usize,
     ) -> Result<(), Error> {
-        let mod_items = match self.cache_src.entry(mod_path.to_path_buf()) {
-            Entry::Vacant(vacant_entry) => {
-                let mut s = String::new();
-                let mut f = File::open(mod_path).map_err(|_| Error::ParseCannotOpenFile {
-                    crate_name: pkg.name.clone(),
-                    src_path: mod_path.to_str().unwrap().to_owned(),
-                })?;
-
-                f.read_to_string(&mut s)
-                    .map_err(|_| Error::ParseCannotOpenFile {
-                        crate_name: pkg.name.clone(),
-                        src_path: mod_path.to_str().unwrap().to_owned(),
-                    })?;
-
-                let i = syn::parse_file(&s).map_err(|x| Error::ParseSyntaxError {
-                    crate_name: pkg.name.clone(),
-                    src_path: mod_path.to_string_lossy().into(),
-                    error: x,
-                })?;
-
-                vacant_entry.insert(i.items).clone()
-            }
-            Entry::Occupied(occupied_entry) => occupied_entry.get().clone(),
+        let mut mod_items = if self.cache.in_memory.contains(mod_path) {
+            self.cache.src[mod_path].1.clone()
+        } else {
+            let s = fs::read_to_string(mod_path).map_err(|_| Error::ParseCannotOpenFile {
+                crate_name: pkg.name.clone(),
+                src_path: mod_path.to_str().unwrap().to_owned(),
+            })?;
+            self.out.source_files.push(mod_path.to_path_buf());
+            self.cache.parse_src(&pkg.name, mod_path, &s)?
         };
+        if let Some(package) = self.config.parse.packages.get(&pkg.name) {
+            if let Some(enabled) = package.enabled_features() {
+                resolve_feature_cfgs(&mut mod_items, enabled);
+            }
+        }
 
         // Compute module directory according to Rust 2018 rules
         let submod_dir_2018;
@@ -265,7 +846,8 @@ impl<'a> Parser<'a> {
             &submod_dir_2018
         };
 
-        self.process_mod(
+        let parent_src_file = self.src_file.replace(mod_path.to_path_buf());
+        let result = self.process_mod(
             pkg,
             Some(mod_dir),
             Some(submod_dir),
@@ -273,7 +855,9 @@ impl<'a> Parser<'a> {
             depth,
             /* is_inline = */ false,
             is_mod_rs,
-        )
+        );
+        self.src_file = parent_src_file;
+        result
     }
 
     /// `mod_dir` is the path to the current directory of the module. It may be
@@ -293,12 +877,27 @@ impl<'a> Parser<'a> {
         is_in_mod_rs: bool,
     ) -> Result<(), Error> {
         debug_assert_eq!(mod_dir.is_some(), submod_dir.is_some());
+        self.out.src_file_name = self
+            .src_file
+            .as_ref()
+            .map(|src_file| self.source_file_name(pkg, src_file));
+        if let Some(file_name) = self.out.src_file_name.clone() {
+            self.out.record_source_locations(&file_name, items);
+        }
+        let mapped_items;
+        let items = if self.type_map.is_empty() {
+            items
+        } else {
+            mapped_items = self.type_map.apply(&pkg.name, &self.mod_stack, items);
+            &mapped_items[..]
+        };
         // We process the items first then the nested modules.
         let nested_modules = self.out.load_syn_crate_mod(
             self.config,
             &self.binding_crate_name,
             &pkg.name,
             Cfg::join(&self.cfg_stack).as_ref(),
+            &self.mod_stack,
             items,
         );
 
@@ -308,6 +907,7 @@ impl<'a> Parser<'a> {
             if let Some(ref cfg) = cfg {
                 self.cfg_stack.push(cfg.clone());
             }
+            self.mod_stack.push(next_mod_name.clone());
 
             if let Some((_, ref inline_items)) = item.content {
                 // TODO(emilio): This should use #[path] attribute if present,
@@ -374,19 +974,30 @@ impl<'a> Parser<'a> {
                     // This should be an error, but it's common enough to
                     // just elicit a warning
                     if !path_attr_found {
-                        warn!(
-                            "Parsing crate `{}`: can't find mod {}`.",
-                            pkg.name, next_mod_name
+                        diagnostic::report(
+                            Level::Warn,
+                            DiagnosticKind::MissingSource,
+                            None,
+                            format_args!(
+                                "Parsing crate `{}`: can't find mod {}`.",
+                                pkg.name, next_mod_name
+                            ),
                         );
                     }
                 }
             } else {
-                warn!(
-                    "Parsing expanded crate `{}`: can't find mod {}`.",
-                    pkg.name, next_mod_name
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::MissingSource,
+                    None,
+                    format_args!(
+                        "Parsing expanded crate `{}`: can't find mod {}`.",
+                        pkg.name, next_mod_name
+                    ),
                 );
             }
 
+            self.mod_stack.pop();
             if cfg.is_some() {
                 self.cfg_stack.pop();
             }
@@ -396,7 +1007,7 @@ impl<'a> Parser<'a> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Parse {
     pub constants: ItemMap<Constant>,
     pub globals: ItemMap<Static>,
@@ -406,9 +1017,72 @@ pub struct Parse {
     pub opaque_items: ItemMap<OpaqueItem>,
     pub typedefs: ItemMap<Typedef>,
     pub functions: Vec<Function>,
+    /// The path of the module, within its crate, that each type was declared
+    /// in, if it isn't the root module.
+    pub module_paths: HashMap<String, Vec<String>>,
+    /// The crate that each type was declared in, if it isn't the binding
+    /// crate.
+    pub crates: HashMap<String, String>,
+    /// The names of the types, in the order they were declared in.
+    pub source_order: Vec<String>,
+    /// Where each item was declared in the source, unless it comes from an
+    /// expanded crate.
+    pub source_locations: HashMap<String, SourceLocation>,
+    /// The files the items were parsed from, including the manifest and the
+    /// sources of the expanded crates.
+    pub source_files: Vec<FilePathBuf>,
+    /// The name of the source file the items being loaded come from, for the
+    /// spans of the diagnostics.
+    #[serde(skip)]
+    src_file_name: Option<String>,
+}
+
+/// An item or a function parsed from the sources, as given to the filters of
+/// `Builder::with_item_filter`.
+#[allow(unused)]
+#[derive(Clone, Copy)]
+pub enum ParsedItem<'a> {
+    Item(&'a dyn Item),
+    Function(&'a Function),
+}
+
+#[allow(unused)]
+impl<'a> ParsedItem<'a> {
+    /// The name of the item in Rust.
+    pub fn name(&self) -> &'a str {
+        match *self {
+            ParsedItem::Item(item) => item.name(),
+            ParsedItem::Function(function) => function.path.name(),
+        }
+    }
+
+    pub fn annotations(&self) -> &'a AnnotationSet {
+        match *self {
+            ParsedItem::Item(item) => item.annotations(),
+            ParsedItem::Function(function) => &function.annotations,
+        }
+    }
+
+    pub fn cfg(&self) -> Option<&'a Cfg> {
+        match *self {
+            ParsedItem::Item(item) => item.cfg(),
+            ParsedItem::Function(function) => function.cfg.as_ref(),
+        }
+    }
+}
+
+/// The place an item was declared at in the Rust sources.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceLocation {
+    /// The source file, relative to the current directory if it's in it, or
+    /// to its crate with `deterministic`.
+    pub file: String,
+    /// The line of the name of the item, starting from 1.
+    pub line: usize,
 }
 
 impl Parse {
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Parse {
         Parse {
             constants: ItemMap::default(),
@@ -419,6 +1093,12 @@ impl Parse {
             opaque_items: ItemMap::default(),
             typedefs: ItemMap::default(),
             functions: Vec::new(),
+            module_paths: HashMap::new(),
+            crates: HashMap::new(),
+            source_order: Vec::new(),
+            source_locations: HashMap::new(),
+            source_files: Vec::new(),
+            src_file_name: None,
         }
     }
 
@@ -457,6 +1137,89 @@ impl Parse {
         add_opaque("MaybeUninit", vec!["T"]);
     }
 
+    /// Adds the `SliceRef<T>`, `SliceMut<T>`, `StrRef` and `RawVec<T>` structs
+    /// that slices, strings and vectors are turned into in functions annotated
+    /// with `cbindgen:slices-as-structs`, `cbindgen:strings-as-structs` and
+    /// `cbindgen:vecs-as-structs`, unless the crate already defines them.
+    pub fn add_view_types(&mut self) {
+        let uses_annotation = |name: &str| {
+            self.functions
+                .iter()
+                .any(|f| f.annotations.bool(name).unwrap_or(false))
+        };
+        let uses_slices = uses_annotation("slices-as-structs");
+        let uses_strs = uses_annotation("strings-as-structs");
+        let uses_vecs = uses_annotation("vecs-as-structs");
+
+        let size = Type::Primitive(PrimitiveType::Integer {
+            zeroable: true,
+            signed: false,
+            kind: IntKind::Size,
+        });
+        let mut add_view = |name: &str, generic_params: Vec<&str>, ptr_ty: Type, is_const, cap| {
+            let mut fields = vec![
+                Field::from_name_and_type(
+                    "ptr".to_owned(),
+                    Type::Ptr {
+                        ty: Box::new(ptr_ty),
+                        is_const,
+                        is_nullable: false,
+                        is_ref: false,
+                    },
+                ),
+                Field::from_name_and_type("len".to_owned(), size.clone()),
+            ];
+            if cap {
+                fields.push(Field::from_name_and_type("cap".to_owned(), size.clone()));
+            }
+            let generic_params = generic_params
+                .into_iter()
+                .map(GenericParam::new_type_param)
+                .collect();
+            self.structs.try_insert(Struct::new(
+                Path::new(name),
+                GenericParams(generic_params),
+                fields,
+                false,
+                false,
+                None,
+                false,
+                None,
+                AnnotationSet::new(),
+                Documentation::none(),
+            ))
+        };
+
+        let elem = Type::Path(GenericPath::new(Path::new("T"), vec![]));
+        if uses_slices {
+            add_view("SliceRef", vec!["T"], elem.clone(), true, false);
+            add_view("SliceMut", vec!["T"], elem.clone(), false, false);
+        }
+        if uses_strs {
+            let char_ty = Type::Primitive(PrimitiveType::Char);
+            add_view("StrRef", vec![], char_ty, true, false);
+        }
+        if uses_vecs {
+            add_view("RawVec", vec!["T"], elem, false, true);
+        }
+    }
+
+    /// Declares opaque types for the trait objects (`dyn Trait`) found by
+    /// `visit`, named after their trait.
+    fn add_trait_objects(&mut self, visit: impl FnOnce(&mut TraitObjects)) {
+        let mut trait_objects = TraitObjects::default();
+        visit(&mut trait_objects);
+        for name in trait_objects.0 {
+            self.opaque_items.try_insert(OpaqueItem::new(
+                Path::new(name),
+                GenericParams::default(),
+                None,
+                AnnotationSet::new(),
+                Documentation::none(),
+            ));
+        }
+    }
+
     pub fn extend_with(&mut self, other: &Parse) {
         self.constants.extend_with(&other.constants);
         self.globals.extend_with(&other.globals);
@@ -466,6 +1229,83 @@ impl Parse {
         self.opaque_items.extend_with(&other.opaque_items);
         self.typedefs.extend_with(&other.typedefs);
         self.functions.extend_from_slice(&other.functions);
+        for (name, module_path) in &other.module_paths {
+            self.module_paths
+                .entry(name.clone())
+                .or_insert_with(|| module_path.clone());
+        }
+        for (name, crate_name) in &other.crates {
+            self.crates
+                .entry(name.clone())
+                .or_insert_with(|| crate_name.clone());
+        }
+        self.source_order.extend_from_slice(&other.source_order);
+        for (name, location) in &other.source_locations {
+            self.source_locations
+                .entry(name.clone())
+                .or_insert_with(|| location.clone());
+        }
+        for file in &other.source_files {
+            if !self.source_files.contains(file) {
+                self.source_files.push(file.clone());
+            }
+        }
+    }
+
+    /// Keeps the items and functions for which `keep` returns true.
+    pub fn retain(&mut self, keep: &dyn Fn(ParsedItem) -> bool) {
+        self.constants.filter(|x| !keep(ParsedItem::Item(x)));
+        self.globals.filter(|x| !keep(ParsedItem::Item(x)));
+        self.enums.filter(|x| !keep(ParsedItem::Item(x)));
+        self.structs.filter(|x| !keep(ParsedItem::Item(x)));
+        self.unions.filter(|x| !keep(ParsedItem::Item(x)));
+        self.opaque_items.filter(|x| !keep(ParsedItem::Item(x)));
+        self.typedefs.filter(|x| !keep(ParsedItem::Item(x)));
+        self.functions.retain(|x| keep(ParsedItem::Function(x)));
+    }
+
+    fn record_module_path(&mut self, mod_path: &[String], name: &str) {
+        if !mod_path.is_empty() {
+            self.module_paths
+                .entry(name.to_owned())
+                .or_insert_with(|| mod_path.to_vec());
+        }
+    }
+
+    /// Where `ident` is in the source file being loaded, unless it comes from
+    /// an expanded crate or has no location.
+    fn span(&self, ident: &syn::Ident) -> Option<Span> {
+        let file = self.src_file_name.clone()?;
+        let start = ident.span().start();
+        if start.line == 0 {
+            return None;
+        }
+        Some(Span {
+            file,
+            line: start.line,
+            column: Some(start.column + 1),
+        })
+    }
+
+    /// Logs a diagnostic of `kind` about the item named `ident`.
+    fn report(&self, level: Level, kind: DiagnosticKind, ident: &syn::Ident, args: fmt::Arguments) {
+        diagnostic::report(level, kind, self.span(ident), args);
+    }
+
+    fn record_source_locations(&mut self, file_name: &str, items: &[syn::Item]) {
+        for item in items {
+            if item.should_skip_parsing() {
+                continue;
+            }
+            for (name, line) in item_lines(item) {
+                self.source_locations
+                    .entry(name)
+                    .or_insert_with(|| SourceLocation {
+                        file: file_name.to_owned(),
+                        line,
+                    });
+            }
+        }
     }
 
     fn load_syn_crate_mod<'a>(
@@ -474,6 +1314,7 @@ impl Parse {
         binding_crate_name: &str,
         crate_name: &str,
         mod_cfg: Option<&Cfg>,
+        mod_path: &[String],
         items: &'a [syn::Item],
     ) -> Vec<&'a syn::ItemMod> {
         let mut impls_with_assoc_consts = Vec::new();
@@ -483,6 +1324,26 @@ impl Parse {
             if item.should_skip_parsing() {
                 continue;
             }
+            let ident = match item {
+                syn::Item::Struct(ref item) => Some(&item.ident),
+                syn::Item::Union(ref item) => Some(&item.ident),
+                syn::Item::Enum(ref item) => Some(&item.ident),
+                syn::Item::Type(ref item) => Some(&item.ident),
+                _ => None,
+            };
+            if let Some(ident) = ident {
+                let name = ident.unraw().to_string();
+                self.record_module_path(mod_path, &name);
+                if crate_name != binding_crate_name {
+                    self.crates
+                        .entry(name.clone())
+                        .or_insert_with(|| crate_name.to_owned());
+                }
+                self.source_order.push(name);
+            }
+            for name in value_names(item) {
+                self.record_module_path(mod_path, &name);
+            }
             match item {
                 syn::Item::ForeignMod(ref item) => {
                     self.load_syn_foreign_mod(
@@ -591,31 +1452,57 @@ impl Parse {
         }
 
         for foreign_item in &item.items {
-            if let syn::ForeignItem::Fn(ref function) = *foreign_item {
-                if !config
-                    .parse
-                    .should_generate_top_level_item(crate_name, binding_crate_name)
-                {
-                    info!(
-                        "Skip {}::{} - (fn's outside of the binding crate are not used).",
-                        crate_name, &function.sig.ident
-                    );
-                    return;
-                }
-                let path = Path::new(function.sig.ident.unraw().to_string());
-                match Function::load(path, None, &function.sig, true, &function.attrs, mod_cfg) {
-                    Ok(func) => {
-                        info!("Take {}::{}.", crate_name, &function.sig.ident);
-
-                        self.functions.push(func);
+            if !config
+                .parse
+                .should_generate_top_level_item(crate_name, binding_crate_name)
+            {
+                info!(
+                    "Skip {} - (foreign items outside of the binding crate are not used).",
+                    crate_name
+                );
+                return;
+            }
+            match *foreign_item {
+                syn::ForeignItem::Fn(ref function) => {
+                    let path = Path::new(function.link_name());
+                    match Function::load(path, None, &function.sig, true, &function.attrs, mod_cfg)
+                    {
+                        Ok(func) => {
+                            info!("Take {}::{}.", crate_name, &function.sig.ident);
+                            self.add_trait_objects(|v| v.visit_signature(&function.sig));
+                            self.functions.push(func);
+                        }
+                        Err(msg) => {
+                            self.report(
+                                Level::Error,
+                                DiagnosticKind::UnsupportedType,
+                                &function.sig.ident,
+                                format_args!(
+                                    "Cannot use fn {}::{} ({}).",
+                                    crate_name, &function.sig.ident, msg
+                                ),
+                            );
+                        }
                     }
-                    Err(msg) => {
-                        error!(
-                            "Cannot use fn {}::{} ({}).",
-                            crate_name, &function.sig.ident, msg
-                        );
+                }
+                syn::ForeignItem::Static(ref item) if config.parse.foreign_statics => {
+                    match Static::load_foreign(item, mod_cfg) {
+                        Ok(global) => {
+                            info!("Take {}::{}.", crate_name, &item.ident);
+                            self.add_trait_objects(|v| v.visit_type(&item.ty));
+                            self.globals.try_insert(global);
+                        }
+                        Err(msg) => {
+                            self.report(
+                                Level::Warn,
+                                DiagnosticKind::UnsupportedType,
+                                &item.ident,
+                                format_args!("Skip {}::{} - ({})", crate_name, &item.ident, msg),
+                            );
+                        }
                     }
                 }
+                _ => {}
             }
         }
     }
@@ -709,42 +1596,73 @@ impl Parse {
                     match Function::load(path, self_type, sig, false, attrs, mod_cfg) {
                         Ok(func) => {
                             info!("Take {}.", loggable_item_name());
+                            self.add_trait_objects(|v| v.visit_signature(sig));
                             self.functions.push(func);
                         }
                         Err(msg) => {
-                            error!("Cannot use fn {} ({}).", loggable_item_name(), msg);
+                            self.report(
+                                Level::Error,
+                                DiagnosticKind::UnsupportedType,
+                                &sig.ident,
+                                format_args!("Cannot use fn {} ({}).", loggable_item_name(), msg),
+                            );
                         }
                     }
                 }
                 (true, None) => {
-                    warn!(
-                        "Skipping {} - (not `no_mangle`, and has no `export_name` attribute)",
-                        loggable_item_name()
+                    self.report(
+                        Level::Warn,
+                        DiagnosticKind::SkippedItem,
+                        &sig.ident,
+                        format_args!(
+                            "Skipping {} - (not `no_mangle`, and has no `export_name` attribute)",
+                            loggable_item_name()
+                        ),
                     );
                 }
                 (false, Some(_exported_name)) => {
-                    warn!("Skipping {} - (not `extern \"C\"`", loggable_item_name());
+                    self.report(
+                        Level::Warn,
+                        DiagnosticKind::SkippedItem,
+                        &sig.ident,
+                        format_args!("Skipping {} - (not `extern \"C\"`", loggable_item_name()),
+                    );
                 }
                 (false, None) => {}
             }
         } else {
             match (is_extern_c, exported_name) {
                 (true, Some(..)) => {
-                    warn!(
-                        "Skipping {} - (not `pub` but is `extern \"C\"` and `no_mangle`)",
-                        loggable_item_name()
+                    self.report(
+                        Level::Warn,
+                        DiagnosticKind::SkippedItem,
+                        &sig.ident,
+                        format_args!(
+                            "Skipping {} - (not `pub` but is `extern \"C\"` and `no_mangle`)",
+                            loggable_item_name()
+                        ),
                     );
                 }
                 (true, None) => {
-                    warn!(
-                        "Skipping {} - (not `pub` but is `extern \"C\"`)",
-                        loggable_item_name()
+                    self.report(
+                        Level::Warn,
+                        DiagnosticKind::SkippedItem,
+                        &sig.ident,
+                        format_args!(
+                            "Skipping {} - (not `pub` but is `extern \"C\"`)",
+                            loggable_item_name()
+                        ),
                     );
                 }
                 (false, Some(..)) => {
-                    warn!(
-                        "Skipping {} - (not `pub` but is `no_mangle`)",
-                        loggable_item_name()
+                    self.report(
+                        Level::Warn,
+                        DiagnosticKind::SkippedItem,
+                        &sig.ident,
+                        format_args!(
+                            "Skipping {} - (not `pub` but is `no_mangle`)",
+                            loggable_item_name()
+                        ),
                     );
                 }
                 (false, None) => {}
@@ -765,7 +1683,12 @@ impl Parse {
         let ty = match Type::load(impl_ty) {
             Ok(ty) => ty,
             Err(e) => {
-                warn!("Skipping associated constants for {:?}: {:?}", impl_ty, e);
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::SkippedItem,
+                    None,
+                    format_args!("Skipping associated constants for {:?}: {:?}", impl_ty, e),
+                );
                 return;
             }
         };
@@ -778,9 +1701,14 @@ impl Parse {
         let impl_path = match ty.get_root_path() {
             Some(p) => p,
             None => {
-                warn!(
-                    "Couldn't find path for {:?}, skipping associated constants",
-                    ty
+                diagnostic::report(
+                    Level::Warn,
+                    DiagnosticKind::SkippedItem,
+                    None,
+                    format_args!(
+                        "Couldn't find path for {:?}, skipping associated constants",
+                        ty
+                    ),
                 );
                 return;
             }
@@ -789,7 +1717,12 @@ impl Parse {
         for item in items.into_iter() {
             if let syn::Visibility::Public(_) = item.vis {
             } else {
-                warn!("Skip {}::{} - (not `pub`).", crate_name, &item.ident);
+                self.report(
+                    Level::Warn,
+                    DiagnosticKind::SkippedItem,
+                    &item.ident,
+                    format_args!("Skip {}::{} - (not `pub`).", crate_name, &item.ident),
+                );
                 return;
             }
 
@@ -819,7 +1752,12 @@ impl Parse {
                     }
                 }
                 Err(msg) => {
-                    warn!("Skip {}::{} - ({})", crate_name, &item.ident, msg);
+                    self.report(
+                        Level::Warn,
+                        DiagnosticKind::UnsupportedLiteral,
+                        &item.ident,
+                        format_args!("Skip {}::{} - ({})", crate_name, &item.ident, msg),
+                    );
                 }
             }
         }
@@ -847,7 +1785,12 @@ impl Parse {
 
         if let syn::Visibility::Public(_) = item.vis {
         } else {
-            warn!("Skip {}::{} - (not `pub`).", crate_name, &item.ident);
+            self.report(
+                Level::Warn,
+                DiagnosticKind::SkippedItem,
+                &item.ident,
+                format_args!("Skip {}::{} - (not `pub`).", crate_name, &item.ident),
+            );
             return;
         }
 
@@ -862,7 +1805,12 @@ impl Parse {
                 }
             }
             Err(msg) => {
-                warn!("Skip {}::{} - ({})", crate_name, &item.ident, msg);
+                self.report(
+                    Level::Warn,
+                    DiagnosticKind::UnsupportedLiteral,
+                    &item.ident,
+                    format_args!("Skip {}::{} - ({})", crate_name, &item.ident, msg),
+                );
             }
         }
     }
@@ -892,11 +1840,16 @@ impl Parse {
                 match Static::load(item, mod_cfg) {
                     Ok(constant) => {
                         info!("Take {}::{}.", crate_name, &item.ident);
-
+                        self.add_trait_objects(|v| v.visit_type(&item.ty));
                         self.globals.try_insert(constant);
                     }
                     Err(msg) => {
-                        warn!("Skip {}::{} - ({})", crate_name, &item.ident, msg);
+                        self.report(
+                            Level::Warn,
+                            DiagnosticKind::UnsupportedType,
+                            &item.ident,
+                            format_args!("Skip {}::{} - ({})", crate_name, &item.ident, msg),
+                        );
                     }
                 }
             }
@@ -905,10 +1858,20 @@ impl Parse {
         // TODO
         if let syn::Visibility::Public(_) = item.vis {
         } else {
-            warn!("Skip {}::{} - (not `pub`).", crate_name, &item.ident);
+            self.report(
+                Level::Warn,
+                DiagnosticKind::SkippedItem,
+                &item.ident,
+                format_args!("Skip {}::{} - (not `pub`).", crate_name, &item.ident),
+            );
         }
         if !item.is_no_mangle() {
-            warn!("Skip {}::{} - (not `no_mangle`).", crate_name, &item.ident);
+            self.report(
+                Level::Warn,
+                DiagnosticKind::SkippedItem,
+                &item.ident,
+                format_args!("Skip {}::{} - (not `no_mangle`).", crate_name, &item.ident),
+            );
         }
     }
 
@@ -920,13 +1883,19 @@ impl Parse {
         mod_cfg: Option<&Cfg>,
         item: &syn::ItemStruct,
     ) {
-        match Struct::load(&config.layout, item, mod_cfg) {
+        match Struct::load(config, item, mod_cfg) {
             Ok(st) => {
                 info!("Take {}::{}.", crate_name, &item.ident);
+                self.add_trait_objects(|v| v.visit_fields(&item.fields));
                 self.structs.try_insert(st);
             }
             Err(msg) => {
-                info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
+                self.report(
+                    Level::Info,
+                    DiagnosticKind::OpaqueType,
+                    &item.ident,
+                    format_args!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg),
+                );
                 let path = Path::new(item.ident.unraw().to_string());
                 self.opaque_items.try_insert(
                     OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
@@ -943,14 +1912,19 @@ impl Parse {
         mod_cfg: Option<&Cfg>,
         item: &syn::ItemUnion,
     ) {
-        match Union::load(&config.layout, item, mod_cfg) {
+        match Union::load(config, item, mod_cfg) {
             Ok(st) => {
                 info!("Take {}::{}.", crate_name, &item.ident);
-
+                self.add_trait_objects(|v| v.visit_fields_named(&item.fields));
                 self.unions.try_insert(st);
             }
             Err(msg) => {
-                info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
+                self.report(
+                    Level::Info,
+                    DiagnosticKind::OpaqueType,
+                    &item.ident,
+                    format_args!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg),
+                );
                 let path = Path::new(item.ident.unraw().to_string());
                 self.opaque_items.try_insert(
                     OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
@@ -973,7 +1947,12 @@ impl Parse {
                 self.enums.try_insert(en);
             }
             Err(msg) => {
-                info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
+                self.report(
+                    Level::Info,
+                    DiagnosticKind::OpaqueType,
+                    &item.ident,
+                    format_args!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg),
+                );
                 let path = Path::new(item.ident.unraw().to_string());
                 self.opaque_items.try_insert(
                     OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
@@ -987,11 +1966,16 @@ impl Parse {
         match Typedef::load(item, mod_cfg) {
             Ok(st) => {
                 info!("Take {}::{}.", crate_name, &item.ident);
-
+                self.add_trait_objects(|v| v.visit_type(&item.ty));
                 self.typedefs.try_insert(st);
             }
             Err(msg) => {
-                info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
+                self.report(
+                    Level::Info,
+                    DiagnosticKind::OpaqueType,
+                    &item.ident,
+                    format_args!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg),
+                );
                 let path = Path::new(item.ident.unraw().to_string());
                 self.opaque_items.try_insert(
                     OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
@@ -1019,12 +2003,18 @@ impl Parse {
         let bitflags = match bitflags::parse(item.mac.tokens.clone()) {
             Ok(b) => b,
             Err(e) => {
-                warn!("Failed to parse bitflags invocation: {:?}", e);
+                self.report(
+                    Level::Warn,
+                    DiagnosticKind::SkippedItem,
+                    &item.mac.path.segments.last().unwrap().ident,
+                    format_args!("Failed to parse bitflags invocation: {:?}", e),
+                );
                 return;
             }
         };
 
         let (struct_, impl_) = bitflags.expand();
+        self.source_order.push(struct_.ident.unraw().to_string());
         self.load_syn_struct(config, crate_name, mod_cfg, &struct_);
         // We know that the expansion will only reference `struct_`, so it's
         // fine to just do it here instead of deferring it like we do with the
@@ -142,4 +142,14 @@ impl FromStr for RenameRule {
     }
 }
 
-deserialize_enum_str!(RenameRule);
+deserialize_enum_str!(RenameRule {
+    None => "None",
+    GeckoCase => "GeckoCase",
+    LowerCase => "LowerCase",
+    UpperCase => "UpperCase",
+    PascalCase => "PascalCase",
+    CamelCase => "CamelCase",
+    SnakeCase => "SnakeCase",
+    ScreamingSnakeCase => "ScreamingSnakeCase",
+    QualifiedScreamingSnakeCase => "QualifiedScreamingSnakeCase",
+});
@@ -89,3 +89,19 @@ pub fn escape(rust_identifier: &mut String) {
         rust_identifier.push('_');
     }
 }
+
+/// Taken from `keyword.kwlist` of Python 3, sorted.
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+/// Escapes a name of the Python code cbindgen writes along with Cython
+/// bindings.
+pub fn escape_python(identifier: &mut String) {
+    if PYTHON_KEYWORDS.binary_search(&identifier.as_ref()).is_ok() {
+        identifier.push('_');
+    }
+}
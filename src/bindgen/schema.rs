@@ -0,0 +1,593 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The schema of the config, and the checks of configs against it.
+//!
+//! The keys and their types are found by deserializing a `Config` from a
+//! deserializer which records what it's asked for, so that they can't get out
+//! of sync with the config. Each value it gives is the empty one of its type,
+//! or the first variant of enums. The keys whose values are rejected anyway
+//! are left out of the next attempts, until one succeeds.
+//!
+//! The values of the enums are checked by deserializing them the same way,
+//! from the string found in the config at their key, so that they're parsed
+//! like when loading the config.
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+
+use serde::de::value::{Error, StrDeserializer};
+use serde::de::{self, Deserialize, DeserializeSeed, Deserializer, IntoDeserializer, Visitor};
+use serde_json::{json, Map, Value};
+
+use crate::bindgen::config::Config;
+
+/// The type of a value of the config.
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Any,
+    Bool,
+    Integer,
+    Float,
+    String,
+    /// An array, whose elements are at the `[]` key below.
+    Array,
+    /// A table with any keys, whose values are at the `*` key below.
+    Map,
+    /// A table with these keys.
+    Struct(&'static [&'static str]),
+    /// A string naming one of these variants, which may also be accepted
+    /// under other spellings.
+    Enum(&'static [&'static str]),
+}
+
+impl Kind {
+    fn json_type(self) -> Option<&'static str> {
+        match self {
+            Kind::Any => None,
+            Kind::Bool => Some("boolean"),
+            Kind::Integer => Some("integer"),
+            Kind::Float => Some("number"),
+            Kind::String | Kind::Enum(_) => Some("string"),
+            Kind::Array => Some("array"),
+            Kind::Map | Kind::Struct(_) => Some("object"),
+        }
+    }
+
+    fn accepts(self, value: &Value) -> bool {
+        match self {
+            Kind::Any => true,
+            Kind::Bool => value.is_boolean(),
+            Kind::Integer => value.is_i64() || value.is_u64(),
+            Kind::Float => value.is_number(),
+            Kind::String | Kind::Enum(_) => value.is_string(),
+            Kind::Array => value.is_array(),
+            Kind::Map | Kind::Struct(_) => value.is_object(),
+        }
+    }
+}
+
+/// The types of the keys of the config, by their dotted path.
+#[derive(Debug, Default)]
+struct Trace {
+    kinds: HashMap<String, Kind>,
+    /// The keys which can be null.
+    nullable: HashSet<String>,
+    /// The keys left out of the next attempts.
+    omitted: HashSet<String>,
+    /// The key being deserialized.
+    current: String,
+    /// The key of an enum to deserialize from a string instead of its first
+    /// variant, and the error deserializing it, if any.
+    probe: Option<(String, String, Option<String>)>,
+}
+
+impl Trace {
+    fn of_config() -> Trace {
+        let trace = RefCell::new(Trace::default());
+        loop {
+            let tracer = Tracer {
+                trace: &trace,
+                path: String::new(),
+            };
+            if Config::deserialize(tracer).is_ok() {
+                break;
+            }
+            let mut trace = trace.borrow_mut();
+            let current = trace.current.clone();
+            if !trace.omitted.insert(current) {
+                break;
+            }
+        }
+        trace.into_inner()
+    }
+
+    /// Deserializes `value` as the enum at `path`, and returns the error it
+    /// gives, if any.
+    fn probe(&self, path: &str, value: &str) -> Option<String> {
+        let trace = RefCell::new(Trace {
+            omitted: self.omitted.clone(),
+            probe: Some((path.to_owned(), value.to_owned(), None)),
+            ..Trace::default()
+        });
+        let tracer = Tracer {
+            trace: &trace,
+            path: String::new(),
+        };
+        let _ = Config::deserialize(tracer);
+        let probe = trace.into_inner().probe;
+        probe.and_then(|(_, _, error)| error)
+    }
+
+    fn kind(&self, path: &str) -> Kind {
+        self.kinds.get(path).copied().unwrap_or(Kind::Any)
+    }
+
+    fn schema(&self, path: &str, default: Option<&Value>) -> Value {
+        let kind = self.kind(path);
+        let mut schema = Map::new();
+        if let Some(json_type) = kind.json_type() {
+            let json_type = if self.nullable.contains(path) {
+                json!([json_type, "null"])
+            } else {
+                json!(json_type)
+            };
+            schema.insert("type".to_owned(), json_type);
+        }
+        match kind {
+            Kind::Array => {
+                let items = self.schema(&child(path, "[]"), None);
+                schema.insert("items".to_owned(), items);
+            }
+            Kind::Map => {
+                let values = self.schema(&child(path, "*"), None);
+                schema.insert("additionalProperties".to_owned(), values);
+            }
+            Kind::Enum(variants) => {
+                // The spellings of the variants which are parsed.
+                let mut values: Vec<Value> = Vec::new();
+                for spelling in variants.iter().flat_map(|variant| spellings(variant)) {
+                    let value = json!(spelling);
+                    if !values.contains(&value) && self.probe(path, &spelling).is_none() {
+                        values.push(value);
+                    }
+                }
+                if self.nullable.contains(path) {
+                    values.push(Value::Null);
+                }
+                schema.insert("enum".to_owned(), Value::Array(values));
+            }
+            Kind::Struct(keys) => {
+                let properties = keys
+                    .iter()
+                    .map(|key| {
+                        let default = default.and_then(|default| default.get(key));
+                        (key.to_string(), self.schema(&child(path, key), default))
+                    })
+                    .collect();
+                schema.insert("properties".to_owned(), Value::Object(properties));
+                schema.insert("additionalProperties".to_owned(), Value::Bool(false));
+            }
+            _ => {}
+        }
+        if let (Some(default), false) = (default, matches!(kind, Kind::Struct(_))) {
+            schema.insert("default".to_owned(), default.clone());
+        }
+        Value::Object(schema)
+    }
+
+    /// Checks `value`, found at `key` in the config, against the type at
+    /// `path`.
+    fn check(&self, path: &str, key: &str, value: &Value, problems: &mut Vec<String>) {
+        let kind = self.kind(path);
+        if value.is_null() && self.nullable.contains(path) {
+            return;
+        }
+        if !kind.accepts(value) {
+            problems.push(format!(
+                "`{}` should be of type {}, not {}.",
+                key,
+                kind.json_type().unwrap_or("any"),
+                json_type_of(value)
+            ));
+            return;
+        }
+        match (kind, value) {
+            (Kind::Array, Value::Array(values)) => {
+                for (i, value) in values.iter().enumerate() {
+                    let key = format!("{}[{}]", key, i);
+                    self.check(&child(path, "[]"), &key, value, problems);
+                }
+            }
+            (Kind::Map, Value::Object(values)) => {
+                for (name, value) in values {
+                    self.check(&child(path, "*"), &child(key, name), value, problems);
+                }
+            }
+            (Kind::Enum(_), Value::String(value)) => {
+                if let Some(error) = self.probe(path, value) {
+                    problems.push(format!("`{}`: {}", key, error));
+                }
+            }
+            (Kind::Struct(keys), Value::Object(values)) => {
+                for (name, value) in values {
+                    if keys.contains(&&**name) {
+                        self.check(&child(path, name), &child(key, name), value, problems);
+                    } else {
+                        problems.push(unknown_key(&child(key, name), name, keys));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn child(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_owned()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// `variant`, and the usual spellings of its words, like `snake_case` and
+/// `SCREAMING_SNAKE_CASE` for `SnakeCase`.
+fn spellings(variant: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut previous_lowercase = false;
+    for c in variant.chars() {
+        if c == '_' || c == '-' || (c.is_uppercase() && previous_lowercase) {
+            if !word.is_empty() {
+                words.push(word.to_lowercase());
+            }
+            word.clear();
+        }
+        if c != '_' && c != '-' {
+            word.push(c);
+        }
+        previous_lowercase = c.is_lowercase();
+    }
+    if !word.is_empty() {
+        words.push(word.to_lowercase());
+    }
+
+    let capitalized: Vec<String> = words
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    let camel = words
+        .iter()
+        .take(1)
+        .chain(capitalized.iter().skip(1))
+        .map(String::as_str)
+        .collect::<String>();
+    vec![
+        variant.to_owned(),
+        variant.to_lowercase(),
+        variant.to_uppercase(),
+        capitalized.concat(),
+        camel,
+        words.join("_"),
+        words.join("_").to_uppercase(),
+    ]
+}
+
+fn json_type_of(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(number) if number.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn unknown_key(key: &str, name: &str, keys: &[&str]) -> String {
+    let closest = keys
+        .iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .min();
+    match closest {
+        Some((distance, candidate)) if distance <= 2 || distance * 3 <= name.len() => {
+            format!("Unknown key `{}`, did you mean `{}`?", key, candidate)
+        }
+        _ => format!("Unknown key `{}`.", key),
+    }
+}
+
+/// The number of characters to insert, delete, replace or swap to turn `a`
+/// into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[a.len()][b.len()]
+}
+
+/// The JSON schema of the config, with the type and the default value of
+/// every key.
+pub(crate) fn config_schema() -> Value {
+    let default = serde_json::to_value(Config::default()).unwrap();
+    let mut schema = Trace::of_config().schema("", Some(&default));
+    schema["$schema"] = json!("http://json-schema.org/draft-07/schema#");
+    schema["title"] = json!("cbindgen config");
+    schema
+}
+
+/// Checks `config`, parsed in any format, against the schema of the config,
+/// and returns all its unknown keys and values of the wrong type.
+pub(crate) fn check_config(config: &Value) -> Vec<String> {
+    let trace = Trace::of_config();
+    let mut problems = Vec::new();
+    trace.check("", "", config, &mut problems);
+    // The profiles override the other keys.
+    if let Some(Value::Object(profiles)) = config.get("profile") {
+        for (name, profile) in profiles {
+            let key = format!("profile.{}", name);
+            trace.check("", &key, profile, &mut problems);
+        }
+    }
+    problems
+}
+
+/// A deserializer recording the types it's asked for into a trace.
+struct Tracer<'a> {
+    trace: &'a RefCell<Trace>,
+    path: String,
+}
+
+impl<'a> Tracer<'a> {
+    fn record(&self, kind: Kind) {
+        let mut trace = self.trace.borrow_mut();
+        trace.kinds.insert(self.path.clone(), kind);
+        trace.current = self.path.clone();
+    }
+
+    fn child(&self, key: &str) -> Tracer<'a> {
+        Tracer {
+            trace: self.trace,
+            path: child(&self.path, key),
+        }
+    }
+
+    fn is_omitted(&self, key: &str) -> bool {
+        self.trace
+            .borrow()
+            .omitted
+            .contains(&child(&self.path, key))
+    }
+}
+
+impl<'de, 'a> Deserializer<'de> for Tracer<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.record(Kind::Any);
+        visitor.visit_unit()
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.record(Kind::Bool);
+        visitor.visit_bool(false)
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.record(Kind::Integer);
+        visitor.visit_i64(0)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.record(Kind::Integer);
+        visitor.visit_u64(0)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.record(Kind::Float);
+        visitor.visit_f64(0.0)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.record(Kind::String);
+        visitor.visit_str("")
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_u64(visitor)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_u64(visitor)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_u64(visitor)
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_f64(visitor)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.trace.borrow_mut().nullable.insert(self.path.clone());
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.record(Kind::Array);
+        let element = if self.is_omitted("[]") {
+            None
+        } else {
+            Some(self.child("[]"))
+        };
+        visitor.visit_seq(Elements(element))
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.record(Kind::Map);
+        let mut entries = Vec::new();
+        if !self.is_omitted("*") {
+            entries.push(("*", self.child("*")));
+        }
+        visitor.visit_map(Entries(entries.into_iter(), None))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.record(Kind::Struct(fields));
+        let entries: Vec<_> = fields
+            .iter()
+            .filter(|field| !self.is_omitted(field))
+            .map(|field| (*field, self.child(field)))
+            .collect();
+        visitor.visit_map(Entries(entries.into_iter(), None))
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.record(Kind::Enum(variants));
+        let probed = match self.trace.borrow().probe {
+            Some((ref path, ref value, _)) if *path == self.path => Some(value.clone()),
+            _ => None,
+        };
+        match probed {
+            Some(value) => {
+                let value: StrDeserializer<Error> = value.as_str().into_deserializer();
+                let result = visitor.visit_enum(value);
+                if let Err(ref error) = result {
+                    if let Some(ref mut probe) = self.trace.borrow_mut().probe {
+                        probe.2 = Some(error.to_string());
+                    }
+                }
+                result
+            }
+            None => match variants.first() {
+                Some(variant) => {
+                    let variant: StrDeserializer<Error> = variant.into_deserializer();
+                    visitor.visit_enum(variant)
+                }
+                None => Err(de::Error::custom("enums without variants can't be traced")),
+            },
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bytes byte_buf unit unit_struct tuple_struct identifier ignored_any
+    }
+}
+
+/// The element of an array being traced, if it isn't omitted.
+struct Elements<'a>(Option<Tracer<'a>>);
+
+impl<'de, 'a> de::SeqAccess<'de> for Elements<'a> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        match self.0.take() {
+            Some(element) => seed.deserialize(element).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// The keys of a table being traced, and the value of the current one.
+struct Entries<'a, I: Iterator<Item = (&'static str, Tracer<'a>)>>(I, Option<Tracer<'a>>);
+
+impl<'de, 'a, I> de::MapAccess<'de> for Entries<'a, I>
+where
+    I: Iterator<Item = (&'static str, Tracer<'a>)>,
+{
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.0.next() {
+            Some((key, value)) => {
+                self.1 = Some(value);
+                seed.deserialize(key.into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        seed.deserialize(self.1.take().unwrap())
+    }
+}
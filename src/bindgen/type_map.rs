@@ -0,0 +1,294 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Replaces the types listed in `[parse.type_map]` in the items of a module,
+//! before they are loaded.
+//!
+//! The paths of the types are resolved with the `use` declarations and the
+//! items of their module, so that keys like `a::Id` and `b::Id` match the `Id`
+//! each module refers to. Only the names which can't be resolved this way fall
+//! back to the last segment of the keys.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use log::Level;
+use syn::ext::IdentExt;
+use syn::visit_mut::{self, VisitMut};
+
+use crate::bindgen::diagnostic::{self, DiagnosticKind};
+use crate::bindgen::ir::PrimitiveType;
+
+#[derive(Debug, Clone, Default)]
+pub struct TypeMap {
+    /// The target of each key.
+    exact: HashMap<String, syn::Type>,
+    /// The keys ending with each name.
+    by_name: HashMap<String, Vec<String>>,
+    /// The ambiguous names already warned about.
+    warned: HashSet<String>,
+}
+
+impl TypeMap {
+    pub fn new(type_map: &BTreeMap<String, String>) -> Self {
+        let mut map = TypeMap::default();
+        for (key, target) in type_map {
+            let ty = match syn::parse_str::<syn::Type>(target) {
+                Ok(ty) => ty,
+                Err(err) => {
+                    diagnostic::report(
+                        Level::Warn,
+                        DiagnosticKind::InvalidConfig,
+                        None,
+                        format_args!(
+                            "Can't map type {} to {:?} from `parse.type_map`: {}.",
+                            key, target, err
+                        ),
+                    );
+                    continue;
+                }
+            };
+            let key = key.trim_start_matches("::").to_owned();
+            let name = key.rsplit("::").next().unwrap().to_owned();
+            map.by_name.entry(name).or_default().push(key.clone());
+            map.exact.insert(key, ty);
+        }
+        map
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.exact.is_empty()
+    }
+
+    /// Returns `items`, which are in the module `mod_path` of `crate_name`,
+    /// with the mapped types replaced. Nested modules are left untouched.
+    pub fn apply(
+        &mut self,
+        crate_name: &str,
+        mod_path: &[String],
+        items: &[syn::Item],
+    ) -> Vec<syn::Item> {
+        let mut module = Vec::with_capacity(mod_path.len() + 1);
+        module.push(crate_name.replace('-', "_"));
+        module.extend(mod_path.iter().cloned());
+
+        let mut mapper = Mapper {
+            map: self,
+            scope: HashMap::new(),
+            module,
+        };
+        for item in items {
+            mapper.declare(item);
+        }
+        let mut items = items.to_vec();
+        for item in &mut items {
+            mapper.visit_item_mut(item);
+        }
+        items
+    }
+
+    fn lookup(&mut self, written: &str, resolved: Option<&str>) -> Option<syn::Type> {
+        let exact = resolved
+            .and_then(|path| self.exact.get(path))
+            .or_else(|| self.exact.get(written));
+        if exact.is_some() {
+            return exact.cloned();
+        }
+        // Only the names which couldn't be resolved may refer to any key
+        // ending with them.
+        if resolved.is_some() || written.contains("::") {
+            return None;
+        }
+        let keys = self.by_name.get(written)?;
+        let ty = &self.exact[&keys[0]];
+        if keys.iter().all(|key| self.exact[key] == *ty) {
+            return Some(ty.clone());
+        }
+        if self.warned.insert(written.to_owned()) {
+            diagnostic::report(
+                Level::Warn,
+                DiagnosticKind::InvalidConfig,
+                None,
+                format_args!(
+                    "Not mapping type {}: `parse.type_map` keys `{}` are ambiguous.",
+                    written,
+                    keys.join("`, `")
+                ),
+            );
+        }
+        None
+    }
+}
+
+struct Mapper<'a> {
+    map: &'a mut TypeMap,
+    /// The full path of the names declared or imported in the module.
+    scope: HashMap<String, Vec<String>>,
+    /// The full path of the module.
+    module: Vec<String>,
+}
+
+impl<'a> Mapper<'a> {
+    fn declare(&mut self, item: &syn::Item) {
+        let ident = match *item {
+            syn::Item::Struct(ref item) => &item.ident,
+            syn::Item::Enum(ref item) => &item.ident,
+            syn::Item::Union(ref item) => &item.ident,
+            syn::Item::Type(ref item) => &item.ident,
+            syn::Item::Use(ref item) => {
+                let prefix = if item.leading_colon.is_some() {
+                    vec![String::new()]
+                } else {
+                    Vec::new()
+                };
+                self.import(prefix, &item.tree);
+                return;
+            }
+            _ => return,
+        };
+        let mut path = self.module.clone();
+        path.push(ident.unraw().to_string());
+        self.scope.insert(ident.unraw().to_string(), path);
+    }
+
+    fn import(&mut self, mut prefix: Vec<String>, tree: &syn::UseTree) {
+        let (name, ident) = match *tree {
+            syn::UseTree::Path(ref path) => {
+                prefix.push(path.ident.unraw().to_string());
+                return self.import(prefix, &path.tree);
+            }
+            syn::UseTree::Group(ref group) => {
+                for tree in &group.items {
+                    self.import(prefix.clone(), tree);
+                }
+                return;
+            }
+            syn::UseTree::Name(ref name) => (&name.ident, &name.ident),
+            syn::UseTree::Rename(ref rename) => (&rename.rename, &rename.ident),
+            syn::UseTree::Glob(_) => return,
+        };
+        let name = match name.unraw().to_string() {
+            ref name if name == "self" => match prefix.last() {
+                Some(last) => last.clone(),
+                None => return,
+            },
+            name => name,
+        };
+        if ident != "self" {
+            prefix.push(ident.unraw().to_string());
+        }
+        // Paths starting with a crate are already complete.
+        let path = self.resolve(&prefix).unwrap_or(prefix);
+        self.scope.insert(name, path);
+    }
+
+    /// Returns the full path of `segments`, if it's known.
+    fn resolve(&self, segments: &[String]) -> Option<Vec<String>> {
+        let (first, rest) = segments.split_first()?;
+        let mut path = match first.as_str() {
+            // A leading `::`.
+            "" => return Some(rest.to_vec()),
+            "crate" => vec![self.module[0].clone()],
+            "self" => self.module.clone(),
+            "super" => {
+                let supers = segments.iter().take_while(|s| *s == "super").count();
+                if supers >= self.module.len() {
+                    return None;
+                }
+                let mut path = self.module[..self.module.len() - supers].to_vec();
+                path.extend(segments[supers..].iter().cloned());
+                return Some(path);
+            }
+            name => self.scope.get(name)?.clone(),
+        };
+        path.extend(rest.iter().cloned());
+        Some(path)
+    }
+
+    fn mapped_type(&mut self, path: &syn::TypePath) -> Option<syn::Type> {
+        if path.qself.is_some()
+            || path
+                .path
+                .segments
+                .iter()
+                .any(|segment| !segment.arguments.is_empty())
+        {
+            return None;
+        }
+        let mut segments: Vec<_> = path
+            .path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.unraw().to_string())
+            .collect();
+        // Builtin primitive types can't be remapped.
+        if segments.len() == 1 && PrimitiveType::maybe(&segments[0]).is_some() {
+            return None;
+        }
+        let written = segments.join("::");
+        if path.path.leading_colon.is_some() {
+            segments.insert(0, String::new());
+        }
+        let resolved = self.resolve(&segments).map(|path| path.join("::"));
+        self.map.lookup(&written, resolved.as_deref())
+    }
+}
+
+impl<'a> VisitMut for Mapper<'a> {
+    fn visit_type_mut(&mut self, ty: &mut syn::Type) {
+        if let syn::Type::Path(ref path) = *ty {
+            // The replacements aren't looked up in the map again, so that
+            // entries referring to each other can't loop forever.
+            if let Some(mapped) = self.mapped_type(path) {
+                *ty = mapped;
+                return;
+            }
+        }
+        visit_mut::visit_type_mut(self, ty);
+    }
+
+    // Nested modules are mapped with their own scope once they're parsed.
+    fn visit_item_mod_mut(&mut self, _: &mut syn::ItemMod) {}
+
+    fn visit_block_mut(&mut self, _: &mut syn::Block) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(type_map: &[(&str, &str)], source: &str) -> String {
+        let type_map = type_map
+            .iter()
+            .map(|&(key, ty)| (key.to_owned(), ty.to_owned()))
+            .collect();
+        let file = syn::parse_file(source).unwrap();
+        let items = TypeMap::new(&type_map).apply("my-crate", &["m".to_owned()], &file.items);
+        let items = items.iter().map(|item| quote::quote!(#item).to_string());
+        items.collect::<Vec<_>>().join("\n")
+    }
+
+    #[test]
+    fn resolves_the_keys() {
+        let type_map = [
+            ("a::Id", "u32"),
+            ("b::Id", "u64"),
+            ("my_crate::m::Local", "u8"),
+            ("Handle", "* mut c_void"),
+            ("x::Key", "u8"),
+            ("y::Key", "i8"),
+            ("z::Other", "i16"),
+        ];
+        let source = "
+            use a::Id;
+            use b::Id as BId;
+            struct Local;
+            type T = (Id, BId, Local, crate::m::Local, Handle, Key, Other, x::Other, u8);
+        ";
+        assert_eq!(
+            map(&type_map, source),
+            "use a :: Id ;\nuse b :: Id as BId ;\nstruct Local ;\n\
+             type T = (u32 , u64 , u8 , u8 , * mut c_void , Key , i16 , x :: Other , u8) ;"
+        );
+    }
+}
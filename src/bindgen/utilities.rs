@@ -4,6 +4,9 @@
 
 #![allow(clippy::redundant_closure_call)]
 
+use std::env;
+use std::process::Command;
+
 use syn::ext::IdentExt;
 
 pub trait IterHelpers: Iterator {
@@ -62,6 +65,27 @@ impl SynItemFnHelpers for syn::ImplItemMethod {
     }
 }
 
+pub trait SynForeignItemHelpers: SynAttributeHelpers {
+    /// Returns the name of the symbol a foreign item links to.
+    fn link_name(&self) -> String;
+}
+
+impl SynForeignItemHelpers for syn::ForeignItemFn {
+    fn link_name(&self) -> String {
+        self.attrs
+            .attr_name_value_lookup("link_name")
+            .unwrap_or_else(|| self.sig.ident.unraw().to_string())
+    }
+}
+
+impl SynForeignItemHelpers for syn::ForeignItemStatic {
+    fn link_name(&self) -> String {
+        self.attrs
+            .attr_name_value_lookup("link_name")
+            .unwrap_or_else(|| self.ident.unraw().to_string())
+    }
+}
+
 /// Returns whether this attribute causes us to skip at item. This basically
 /// checks for `#[cfg(test)]`, `#[test]`, `/// cbindgen::ignore` and
 /// variations thereof.
@@ -155,6 +179,51 @@ pub trait SynAttributeHelpers {
             .next()
     }
 
+    /// Looks for `#[deprecated]`, returning its note, or an empty string if
+    /// it has none.
+    fn deprecated_note(&self) -> Option<String> {
+        self.attrs()
+            .iter()
+            .filter_map(|attr| match attr.parse_meta().ok()? {
+                syn::Meta::Path(ref path) if path.is_ident("deprecated") => Some(String::new()),
+                syn::Meta::NameValue(syn::MetaNameValue {
+                    ref path,
+                    lit: syn::Lit::Str(ref lit),
+                    ..
+                }) if path.is_ident("deprecated") => Some(lit.value()),
+                syn::Meta::List(ref list) if list.path.is_ident("deprecated") => {
+                    let note = list.nested.iter().find_map(|nested| match *nested {
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                            ref path,
+                            lit: syn::Lit::Str(ref lit),
+                            ..
+                        })) if path.is_ident("note") => Some(lit.value()),
+                        _ => None,
+                    });
+                    Some(note.unwrap_or_default())
+                }
+                _ => None,
+            })
+            .next()
+    }
+
+    /// Looks for `#[must_use]`, returning its reason, or an empty string if
+    /// it has none.
+    fn must_use_reason(&self) -> Option<String> {
+        self.attrs()
+            .iter()
+            .filter_map(|attr| match attr.parse_meta().ok()? {
+                syn::Meta::Path(ref path) if path.is_ident("must_use") => Some(String::new()),
+                syn::Meta::NameValue(syn::MetaNameValue {
+                    ref path,
+                    lit: syn::Lit::Str(ref lit),
+                    ..
+                }) if path.is_ident("must_use") => Some(lit.value()),
+                _ => None,
+            })
+            .next()
+    }
+
     fn get_comment_lines(&self) -> Vec<String> {
         let mut comment = Vec::new();
 
@@ -238,6 +307,8 @@ impl_syn_item_helper!(syn::ItemImpl);
 impl_syn_item_helper!(syn::ItemMacro);
 impl_syn_item_helper!(syn::ItemMacro2);
 impl_syn_item_helper!(syn::ItemTraitAlias);
+impl_syn_item_helper!(syn::ForeignItemFn);
+impl_syn_item_helper!(syn::ForeignItemStatic);
 
 /// Helper function for accessing Abi information
 pub trait SynAbiHelpers {
@@ -293,3 +364,47 @@ fn split_doc_attr(input: &str) -> Vec<String> {
         .map(|s| s.trim_end().to_string())
         .collect()
 }
+
+/// A 64-bit FNV-1a hasher, whose hashes don't change between runs, platforms
+/// or versions of Rust unlike the ones of `DefaultHasher`, so that they can
+/// name the files cached on disk. Every value is prefixed with its length, so
+/// that consecutive values can't be confused.
+pub struct StableHasher(u64);
+
+impl StableHasher {
+    pub fn new() -> StableHasher {
+        StableHasher(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write_raw(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) {
+        self.write_raw(&(bytes.len() as u64).to_le_bytes());
+        self.write_raw(bytes);
+    }
+
+    pub fn write_str(&mut self, s: &str) {
+        self.write(s.as_bytes());
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// The version of the Rust toolchain which would compile or expand the crates,
+/// as told by `$RUSTC -vV`, or an empty string if it can't be run.
+pub fn rustc_version() -> String {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+    match Command::new(rustc).arg("-vV").output() {
+        Ok(ref output) if output.status.success() => {
+            String::from_utf8_lossy(&output.stdout).into_owned()
+        }
+        _ => String::new(),
+    }
+}
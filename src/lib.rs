@@ -7,7 +7,9 @@ extern crate log;
 extern crate proc_macro2;
 #[macro_use]
 extern crate serde;
+extern crate glob;
 extern crate serde_json;
+extern crate serde_yaml;
 #[macro_use]
 extern crate quote;
 #[macro_use]
@@ -16,12 +18,34 @@ extern crate toml;
 
 mod bindgen;
 
+pub use crate::bindgen::parser::{Parse, ParsedItem};
 pub use crate::bindgen::*;
 
+/// Writing bindings in other languages than the ones cbindgen supports.
+///
+/// A `LanguageBackend` registered with `Builder::with_backend` is given the
+/// bindings once their items are loaded and processed, and writes them in
+/// place of cbindgen's own writer. The items are processed as for the
+/// language of the config, e.g. renamed with its rules and sorted, so a
+/// backend usually starts from the C config. The items implement `Source`,
+/// which writes them as cbindgen would for that language.
+///
+/// The items are also those given to the hooks of `Builder::with_item_filter`
+/// and `Builder::with_item_rewriter`, before they are processed.
+pub mod backend {
+    pub use crate::bindgen::backend::{LanguageBackend, SourceWriter};
+    pub use crate::bindgen::ir::{
+        ConstExpr, Constant, Enum, EnumVariant, Field, Function, FunctionArgument, GenericArgument,
+        GenericPath, Item, ItemContainer, Literal, OpaqueItem, Path, PrimitiveType, Static, Struct,
+        Type, Typedef, Union, VariantBody,
+    };
+    pub use crate::bindgen::writer::{ListType, Source};
+}
+
 use std::path::Path;
 
 /// A utility function for build scripts to generate bindings for a crate, using
-/// a `cbindgen.toml` if it exists.
+/// a `cbindgen.toml`, `cbindgen.json` or `cbindgen.yaml` if it exists.
 pub fn generate<P: AsRef<Path>>(crate_dir: P) -> Result<Bindings, Error> {
     let config = Config::from_root_or_default(crate_dir.as_ref());
 
@@ -7,10 +7,15 @@ use std::io::Write;
 
 use log::*;
 
+use crate::bindgen::diagnostic::{self, DiagnosticKind};
+
 pub struct TraceLogger;
 pub struct WarnLogger;
 pub struct InfoLogger;
 pub struct ErrorLogger;
+/// Logs the records as JSON objects, one per line, along with the kind and
+/// the span of the diagnostics.
+pub struct JsonLogger;
 
 impl TraceLogger {
     pub fn init() -> Result<(), SetLoggerError> {
@@ -103,3 +108,50 @@ impl log::Log for InfoLogger {
         io::stderr().flush().unwrap();
     }
 }
+
+impl JsonLogger {
+    pub fn init(level: LevelFilter) -> Result<(), SetLoggerError> {
+        log::set_logger(&JsonLogger)?;
+        log::set_max_level(level);
+        Ok(())
+    }
+}
+impl log::Log for JsonLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let level = match record.level() {
+            Level::Error => "error",
+            Level::Warn => "warning",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        let spans: Vec<_> = diagnostic::current_span()
+            .into_iter()
+            .map(|span| {
+                serde_json::json!({
+                    "file_name": span.file,
+                    "line": span.line,
+                    "column": span.column,
+                })
+            })
+            .collect();
+        let message = serde_json::json!({
+            "level": level,
+            "code": DiagnosticKind::from_target(record.target()).map(DiagnosticKind::code),
+            "message": record.args().to_string(),
+            "spans": spans,
+        });
+        eprintln!("{}", message);
+    }
+
+    fn flush(&self) {
+        io::stderr().flush().unwrap();
+    }
+}
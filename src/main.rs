@@ -2,9 +2,12 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::cell::RefCell;
 use std::env;
-use std::io;
+use std::fs::{self, File};
+use std::io::{self, Read};
 use std::path::{Path, PathBuf};
+use std::rc::Rc;
 use std::str::FromStr;
 
 extern crate clap;
@@ -13,7 +16,10 @@ extern crate log;
 extern crate proc_macro2;
 #[macro_use]
 extern crate serde;
+extern crate glob;
 extern crate serde_json;
+extern crate serde_yaml;
+extern crate similar;
 #[macro_use]
 extern crate quote;
 #[macro_use]
@@ -21,11 +27,22 @@ extern crate syn;
 extern crate toml;
 
 use clap::{Arg, ArgMatches, Command};
+use log::{Level, LevelFilter};
 
 mod bindgen;
 mod logging;
+mod watch;
 
-use crate::bindgen::{Bindings, Builder, Cargo, Config, Error, Profile, Style};
+use similar::TextDiff;
+
+use crate::bindgen::diagnostic::{self, DiagnosticKind};
+use crate::bindgen::{
+    Bindings, Builder, Cargo, Config, ConfigFormat, Error, FileChange, MetadataFlags, ParseCache,
+    Profile, Style,
+};
+
+/// The name of the source read from stdin, in errors and `#line` directives.
+const STDIN_SRC_NAME: &str = "<stdin>";
 
 fn apply_config_overrides(config: &mut Config, matches: &ArgMatches) {
     // We allow specifying a language to override the config default. This is
@@ -78,21 +95,90 @@ fn apply_config_overrides(config: &mut Config, matches: &ArgMatches) {
     }
 }
 
-fn load_bindings(input: &Path, matches: &ArgMatches) -> Result<Bindings, Error> {
+/// Generates the bindings of each `[[output]]` of the config, unless the
+/// language or the output file is given on the command line. Bindings without
+/// a file of their own are written to the output file.
+fn generate_bindings(
+    builder: Builder,
+    config: Config,
+    cache: &Rc<RefCell<ParseCache>>,
+    matches: &ArgMatches,
+) -> Result<Vec<(Option<PathBuf>, Bindings)>, Error> {
+    let builder = builder.with_parse_cache(cache.clone());
+    if config.outputs.is_empty() || matches.is_present("out") || matches.is_present("lang") {
+        let bindings = builder.with_config(config).generate()?;
+        return Ok(vec![(None, bindings)]);
+    }
+
+    Ok(builder
+        .with_config(config)
+        .generate_outputs()?
+        .into_iter()
+        .map(|(file, bindings)| (Some(file), bindings))
+        .collect())
+}
+
+/// Loads the config at `file`, in the format and with the profile given on
+/// the command line.
+fn load_config<P: AsRef<Path>>(file: P, matches: &ArgMatches) -> Config {
+    let format = match matches.value_of("config-format") {
+        Some(format) => format.parse().unwrap(),
+        None => ConfigFormat::from_path(file.as_ref()),
+    };
+    let config = match matches.value_of("config-profile") {
+        Some(profile) => Config::from_file_with_profile(file, format, profile),
+        None => Config::from_file_with_format(file, format),
+    };
+    match config {
+        Ok(config) => config,
+        Err(err) => {
+            error!("{}", err);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Loads the config given on the command line, or the one found in `root`.
+fn find_config(root: &Path, matches: &ArgMatches) -> Config {
+    let file = match matches.value_of("config") {
+        Some(c) => Some(PathBuf::from(c)),
+        None => Config::find_in_root(root),
+    };
+    match file {
+        Some(file) => load_config(file, matches),
+        None if matches.is_present("config-profile") => {
+            error!("A config profile was selected, but there's no config.");
+            std::process::exit(1);
+        }
+        None => Config::default(),
+    }
+}
+
+fn load_bindings(
+    input: &Path,
+    stdin: Option<&str>,
+    cache: &Rc<RefCell<ParseCache>>,
+    matches: &ArgMatches,
+) -> Result<Vec<(Option<PathBuf>, Bindings)>, Error> {
+    // A source read from stdin is loaded like a single source, with the config
+    // of the current directory by default
+    if let Some(src) = stdin {
+        let mut config = find_config(Path::new("."), matches);
+
+        apply_config_overrides(&mut config, matches);
+
+        let builder = Builder::new().with_src_str(STDIN_SRC_NAME, src);
+        return generate_bindings(builder, config, cache, matches);
+    }
+
     // If a file is specified then we load it as a single source
     if !input.is_dir() {
         // Load any config specified or search in the input directory
-        let mut config = match matches.value_of("config") {
-            Some(c) => Config::from_file(c).unwrap(),
-            None => Config::from_root_or_default(input),
-        };
+        let mut config = find_config(input, matches);
 
         apply_config_overrides(&mut config, matches);
 
-        return Builder::new()
-            .with_config(config)
-            .with_src(input)
-            .generate();
+        return generate_bindings(Builder::new().with_src(input), config, cache, matches);
     }
 
     // We have to load a whole crate, so we use cargo to gather metadata
@@ -104,29 +190,23 @@ fn load_bindings(input: &Path, matches: &ArgMatches) -> Result<Bindings, Error>
         matches.is_present("clean"),
         matches.is_present("only-target-dependencies"),
         matches.value_of("metadata").map(Path::new),
+        MetadataFlags {
+            offline: matches.is_present("offline"),
+            frozen: matches.is_present("frozen"),
+            cache: matches.is_present("cache-metadata"),
+        },
     )?;
 
     // Load any config specified or search in the binding crate directory
-    let mut config = match matches.value_of("config") {
-        Some(c) => Config::from_file(c).unwrap(),
-        None => {
-            let binding_crate_dir = lib.find_crate_dir(&lib.binding_crate_ref());
-
-            if let Some(binding_crate_dir) = binding_crate_dir {
-                Config::from_root_or_default(&binding_crate_dir)
-            } else {
-                // This shouldn't happen
-                Config::from_root_or_default(input)
-            }
-        }
+    let mut config = match lib.find_crate_dir(&lib.binding_crate_ref()) {
+        Some(binding_crate_dir) => find_config(&binding_crate_dir, matches),
+        // This shouldn't happen
+        None => find_config(input, matches),
     };
 
     apply_config_overrides(&mut config, matches);
 
-    Builder::new()
-        .with_config(config)
-        .with_cargo(lib)
-        .generate()
+    generate_bindings(Builder::new().with_cargo(lib), config, cache, matches)
 }
 
 fn main() {
@@ -142,7 +222,23 @@ fn main() {
         .arg(
             Arg::new("verify")
                 .long("verify")
-                .help("Generate bindings and compare it to the existing bindings file and error if they are different"),
+                .help("Generate bindings and compare it to the existing bindings file and error if they are different, printing how they differ"),
+        )
+        .arg(
+            Arg::new("verify-diff")
+                .long("verify-diff")
+                .value_name("PATH")
+                .requires("verify")
+                .help("With `--verify`, also write the unified diff of the bindings which changed to this file"),
+        )
+        .arg(
+            Arg::new("watch")
+                .long("watch")
+                .conflicts_with("verify")
+                .help(
+                    "Keep running after generating the bindings, and regenerate them \
+                    whenever the Rust sources or the config change. Files are only \
+                    rewritten when their content differs.")
         )
         .arg(
             Arg::new("config")
@@ -151,6 +247,27 @@ fn main() {
                 .value_name("PATH")
                 .help("Specify path to a `cbindgen.toml` config to use"),
         )
+        .arg(
+            Arg::new("config-format")
+                .long("config-format")
+                .value_name("FORMAT")
+                .requires("config")
+                .help(
+                    "Specify the format of the config, instead of guessing it \
+                     from the extension of the file (TOML unless it's `.json`, \
+                     `.yaml` or `.yml`)",
+                )
+                .possible_values(&["toml", "json", "yaml"]),
+        )
+        .arg(
+            Arg::new("config-profile")
+                .long("config-profile")
+                .value_name("NAME")
+                .help(
+                    "Select the `[profile.<name>]` table of the config, whose \
+                     options override the others",
+                ),
+        )
         .arg(
             Arg::new("lang")
                 .short('l')
@@ -197,7 +314,8 @@ fn main() {
                 .help(
                     "A crate directory or source file to generate bindings for. \
                     In general this is the folder where the Cargo.toml file of \
-                    source Rust library resides.")
+                    source Rust library resides. `-` reads a single source file \
+                    from stdin.")
                 .required(false)
                 .index(1),
         )
@@ -219,6 +337,27 @@ fn main() {
                 .help("The file to output the bindings to")
                 .required(false),
         )
+        .arg(
+            Arg::new("monomorph-report")
+                .long("monomorph-report")
+                .value_name("PATH")
+                .help(
+                    "Write the list of generic instantiations, and the names \
+                    they were given in the bindings, to a file",
+                )
+                .required(false),
+        )
+        .arg(
+            Arg::new("depfile")
+                .long("depfile")
+                .value_name("PATH")
+                .help(
+                    "Write a depfile, in the Makefile format, listing the Rust \
+                    sources and the config the bindings were generated from, so \
+                    that build systems only regenerate them when those change",
+                )
+                .required(false),
+        )
         .arg(
             Arg::new("lockfile")
                 .long("lockfile")
@@ -233,6 +372,7 @@ fn main() {
         .arg(
             Arg::new("metadata")
                 .long("metadata")
+                .alias("metadata-path")
                 .value_name("PATH")
                 .help(
                     "Specify the path to the output of a `cargo metadata` \
@@ -245,6 +385,32 @@ fn main() {
                 )
                 .required(false),
         )
+        .arg(
+            Arg::new("cache-metadata")
+                .long("cache-metadata")
+                .conflicts_with("metadata")
+                .help(
+                    "Save the output of `cargo metadata` in the target directory, \
+                     and reuse it until `Cargo.lock` or the manifest of the crate \
+                     changes."
+                )
+                .required(false),
+        )
+        .arg(
+            Arg::new("offline")
+                .long("offline")
+                .help("Pass `--offline` to `cargo metadata`, so that it doesn't access the network.")
+                .required(false),
+        )
+        .arg(
+            Arg::new("frozen")
+                .long("frozen")
+                .help(
+                    "Pass `--frozen` to `cargo metadata`, so that it neither accesses \
+                     the network nor updates `Cargo.lock`."
+                )
+                .required(false),
+        )
         .arg(
             Arg::new("profile")
                 .long("profile")
@@ -262,17 +428,60 @@ fn main() {
                 .help("Report errors only (overrides verbosity options).")
                 .required(false),
         )
+        .arg(
+            Arg::new("message-format")
+                .long("message-format")
+                .value_name("FORMAT")
+                .help(
+                    "Report the warnings and errors as text, or as JSON objects, \
+                     one per line, along with where they are in the Rust sources.",
+                )
+                .possible_values(&["human", "json"])
+                .default_value("human")
+                .required(false),
+        )
+        .subcommand(
+            Command::new("config-schema")
+                .about("Print the JSON schema of the config, with the type and default of every key"),
+        )
+        .subcommand(
+            Command::new("check-config")
+                .about("Report all the unknown keys and invalid values of a config")
+                .arg(
+                    Arg::new("CONFIG")
+                        .help(
+                            "The config to check, by default the `cbindgen.toml`, \
+                             `cbindgen.json` or `cbindgen.yaml` of the current directory",
+                        )
+                        .required(false)
+                        .index(1),
+                )
+                .arg(
+                    Arg::new("config-format")
+                        .long("config-format")
+                        .value_name("FORMAT")
+                        .help(
+                            "Specify the format of the config, instead of guessing it \
+                             from the extension of the file",
+                        )
+                        .possible_values(&["toml", "json", "yaml"]),
+                ),
+        )
         .get_matches();
 
-    if !matches.is_present("out") && matches.is_present("verify") {
-        error!(
-            "Cannot verify bindings against `stdout`, please specify a file to compare against."
-        );
-        std::process::exit(2);
-    }
-
     // Initialize logging
-    if matches.is_present("quiet") {
+    if matches.value_of("message-format") == Some("json") {
+        let level = if matches.is_present("quiet") {
+            LevelFilter::Error
+        } else {
+            match matches.occurrences_of("v") {
+                0 => LevelFilter::Warn,
+                1 => LevelFilter::Info,
+                _ => LevelFilter::Trace,
+            }
+        };
+        logging::JsonLogger::init(level).unwrap();
+    } else if matches.is_present("quiet") {
         logging::ErrorLogger::init().unwrap();
     } else {
         match matches.occurrences_of("v") {
@@ -282,33 +491,253 @@ fn main() {
         }
     }
 
+    match matches.subcommand() {
+        Some(("config-schema", _)) => {
+            println!("{:#}", Config::schema());
+            return;
+        }
+        Some(("check-config", matches)) => std::process::exit(check_config(matches)),
+        _ => {}
+    }
+
     // Find the input directory
     let input = match matches.value_of("INPUT") {
         Some(input) => PathBuf::from(input),
         None => env::current_dir().unwrap(),
     };
 
-    let bindings = match load_bindings(&input, &matches) {
-        Ok(bindings) => bindings,
-        Err(msg) => {
-            error!("{}", msg);
-            error!("Couldn't generate bindings for {}.", input.display());
+    // `-` reads a single source from stdin, which can only be done once.
+    let stdin = if input == Path::new("-") {
+        if matches.is_present("watch") {
+            error!("Cannot watch a source read from `stdin`.");
+            std::process::exit(2);
+        }
+        let mut src = String::new();
+        if let Err(err) = io::stdin().read_to_string(&mut src) {
+            error!("Couldn't read the source from stdin: {}", err);
             std::process::exit(1);
         }
+        Some(src)
+    } else {
+        None
     };
 
-    // Write the bindings file
-    match matches.value_of("out") {
-        Some(file) => {
-            let changed = bindings.write_to_file(file);
+    // Watching reuses the syntax trees of the sources which didn't change.
+    let cache = Rc::default();
+    let generated = match generate(&input, stdin.as_deref(), &cache, &matches) {
+        Ok(generated) => generated,
+        // A failed generation is logged, and fixed by the next change, unless
+        // the command line itself is wrong.
+        Err(1) if matches.is_present("watch") => Generated {
+            changed: Vec::new(),
+            sources: Vec::new(),
+        },
+        Err(code) => std::process::exit(code),
+    };
 
-            if matches.is_present("verify") && changed {
-                error!("Bindings changed: {}", file);
-                std::process::exit(2);
+    if matches.is_present("verify") && !generated.changed.is_empty() {
+        for file in generated.changed {
+            error!("Bindings changed: {}", file.display());
+        }
+        std::process::exit(2);
+    }
+
+    if matches.is_present("watch") {
+        let config = matches.value_of("config").map(Path::new);
+        watch::watch(&input, config, generated.sources, || {
+            // Errors are logged, and fixed by the next change.
+            let generated = generate(&input, None, &cache, &matches).ok()?;
+            for file in generated.changed {
+                info!("Wrote {}.", file.display());
             }
+            Some(generated.sources)
+        });
+    }
+}
+
+/// Checks the config given to `check-config`, and returns the exit code after
+/// logging its problems.
+fn check_config(matches: &ArgMatches) -> i32 {
+    let file = match matches.value_of("CONFIG") {
+        Some(file) => PathBuf::from(file),
+        None => match Config::find_in_root(env::current_dir().unwrap()) {
+            Some(file) => file,
+            None => {
+                error!("Couldn't find a config in the current directory.");
+                return 2;
+            }
+        },
+    };
+    let text = match fs::read_to_string(&file) {
+        Ok(text) => text,
+        Err(err) => {
+            error!("Couldn't read {}: {}", file.display(), err);
+            return 2;
         }
-        _ => {
-            bindings.write(io::stdout());
+    };
+    let format = match matches.value_of("config-format") {
+        Some(format) => format.parse().unwrap(),
+        None => ConfigFormat::from_path(&file),
+    };
+    let problems = Config::check_str(&text, format);
+    for problem in &problems {
+        error!("{}: {}", file.display(), problem);
+    }
+    if problems.is_empty() {
+        0
+    } else {
+        1
+    }
+}
+
+/// The result of writing the bindings.
+struct Generated {
+    /// The files which changed.
+    changed: Vec<PathBuf>,
+    /// The sources and the config the bindings were generated from.
+    sources: Vec<PathBuf>,
+}
+
+/// Generates the bindings of `input`, or of `stdin` if it was read from it,
+/// and writes them along with the monomorph report. Returns the files which
+/// changed, or the exit code after logging the errors if it failed.
+fn generate(
+    input: &Path,
+    stdin: Option<&str>,
+    cache: &Rc<RefCell<ParseCache>>,
+    matches: &ArgMatches,
+) -> Result<Generated, i32> {
+    let outputs = match load_bindings(input, stdin, cache, matches) {
+        Ok(outputs) => outputs,
+        Err(err) => {
+            match err.span() {
+                Some(span) => diagnostic::report(
+                    Level::Error,
+                    DiagnosticKind::SyntaxError,
+                    Some(span),
+                    format_args!("{}", err),
+                ),
+                None => error!("{}", err),
+            }
+            error!("Couldn't generate bindings for {}.", input.display());
+            return Err(1);
+        }
+    };
+
+    // The monomorphs don't depend on the language, so the report is written
+    // once for all the outputs.
+    if let Some(file) = matches.value_of("monomorph-report") {
+        match File::create(file) {
+            Ok(file) => outputs[0].1.write_monomorph_report(file),
+            Err(err) => {
+                error!("Couldn't write the monomorph report to {}: {}", file, err);
+                return Err(1);
+            }
+        }
+    }
+
+    let mut written = Vec::new();
+    let mut changed = Vec::new();
+    let mut diffs = String::new();
+    for (file, bindings) in &outputs {
+        let file = file
+            .clone()
+            .or_else(|| matches.value_of("out").map(PathBuf::from));
+        let file = match file {
+            Some(file) => file,
+            None if matches.is_present("verify") => {
+                error!(
+                    "Cannot verify bindings against `stdout`, please specify a file to compare against."
+                );
+                return Err(2);
+            }
+            None if matches.is_present("watch") => {
+                error!(
+                    "Cannot watch bindings written to `stdout`, please specify a file to write to."
+                );
+                return Err(2);
+            }
+            None if !bindings.config.targets.is_empty() => {
+                error!(
+                    "Cannot write the headers of `[targets]` to `stdout`, please specify a file to write to."
+                );
+                return Err(2);
+            }
+            None => {
+                bindings.write(io::stdout());
+                continue;
+            }
+        };
+
+        if matches.is_present("verify") {
+            bindings.record_changes();
+        }
+
+        // Write the bindings file
+        let files = if !bindings.config.targets.is_empty() {
+            bindings.write_targets_to_files(&file)
+        } else if bindings.config.split_by_module() {
+            bindings.write_modules_to_files(&file)
+        } else {
+            vec![(file.clone(), bindings.write_to_file(&file))]
+        };
+        for (file, file_changed) in files {
+            if file_changed {
+                changed.push(file.clone());
+            }
+            written.push(file);
+        }
+        for change in bindings.take_changes() {
+            diffs.push_str(&unified_diff(&change));
+        }
+    }
+
+    // Every output is generated from the same sources.
+    if let Some(depfile) = matches.value_of("depfile") {
+        if written.is_empty() {
+            error!("Cannot write a depfile for bindings written to `stdout`, please specify a file to write to.");
+            return Err(2);
+        }
+        match File::create(depfile) {
+            Ok(depfile) => outputs[0].1.write_depfile(&written, depfile),
+            Err(err) => {
+                error!("Couldn't write the depfile to {}: {}", depfile, err);
+                return Err(1);
+            }
         }
     }
+
+    if !diffs.is_empty() {
+        eprint!("{}", diffs);
+        if let Some(file) = matches.value_of("verify-diff") {
+            if let Err(err) = fs::write(file, &diffs) {
+                error!("Couldn't write the diff to {}: {}", file, err);
+            }
+        }
+    }
+    Ok(Generated {
+        changed,
+        sources: outputs[0].1.source_files().to_vec(),
+    })
+}
+
+/// Returns the unified diff between the previous and the new contents of a
+/// file, as `diff -u` prints it.
+fn unified_diff(change: &FileChange) -> String {
+    let path = change.path.display().to_string();
+    let old = change
+        .old_contents
+        .as_ref()
+        .map(|contents| String::from_utf8_lossy(contents))
+        .unwrap_or_default();
+    let new = String::from_utf8_lossy(&change.new_contents);
+    let old_header = match change.old_contents {
+        Some(_) => format!("a/{}", path),
+        None => "/dev/null".to_owned(),
+    };
+
+    TextDiff::from_lines(&old, &new)
+        .unified_diff()
+        .header(&old_header, &format!("b/{}", path))
+        .to_string()
 }
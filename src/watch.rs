@@ -0,0 +1,133 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::bindgen::CONFIG_FILE_NAMES;
+
+/// How often the sources are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// How long the sources must stay unchanged before the bindings are
+/// regenerated, so that an editor or `git checkout` writing several files
+/// only triggers one regeneration.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// The modification time of each watched file.
+type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// Watches the Rust sources, the TOML files and the cbindgen configs of
+/// `input`, a crate directory or a source file, along with `config`, and calls
+/// `regenerate` whenever they change. Never returns.
+///
+/// The `sources` the bindings were generated from are watched too, along with
+/// the `Cargo.toml` of their crates, since path dependencies and workspace
+/// members can be anywhere. `regenerate` returns the sources of the new
+/// bindings, or `None` if it failed, in which case the previous ones are kept
+/// watched until a change fixes it.
+pub fn watch<F>(
+    input: &Path,
+    config: Option<&Path>,
+    mut sources: Vec<PathBuf>,
+    mut regenerate: F,
+) -> !
+where
+    F: FnMut() -> Option<Vec<PathBuf>>,
+{
+    // A single source file can declare modules in the files around it.
+    let root = if input.is_dir() {
+        input
+    } else {
+        match input.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        }
+    };
+    info!("Watching {} for changes.", root.display());
+
+    let mut last = snapshot(root, config, &sources);
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let mut current = snapshot(root, config, &sources);
+        if current == last {
+            continue;
+        }
+        loop {
+            thread::sleep(DEBOUNCE);
+            let settled = snapshot(root, config, &sources);
+            if settled == current {
+                break;
+            }
+            current = settled;
+        }
+        info!("Sources changed, regenerating the bindings.");
+        if let Some(new_sources) = regenerate() {
+            if new_sources != sources {
+                sources = new_sources;
+                current = snapshot(root, config, &sources);
+            }
+        }
+        last = current;
+    }
+}
+
+fn snapshot(root: &Path, config: Option<&Path>, sources: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    collect(root, &mut snapshot);
+    if let Some(config) = config {
+        snapshot.insert(config.to_owned(), modified(config));
+    }
+    for source in sources {
+        snapshot.insert(source.clone(), modified(source));
+        if let Some(manifest) = manifest_of(source) {
+            let time = modified(&manifest);
+            snapshot.insert(manifest, time);
+        }
+    }
+    snapshot
+}
+
+/// The `Cargo.toml` of the crate `source` belongs to, if any.
+fn manifest_of(source: &Path) -> Option<PathBuf> {
+    source
+        .ancestors()
+        .skip(1)
+        .map(|dir| dir.join("Cargo.toml"))
+        .find(|manifest| manifest.is_file())
+}
+
+fn collect(dir: &Path, snapshot: &mut Snapshot) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if path.is_dir() {
+            // Build artifacts and hidden directories, like `.git`, only change
+            // because of cbindgen or other tools.
+            if name != "target" && !name.starts_with('.') {
+                collect(&path, snapshot);
+            }
+        } else if name.ends_with(".rs")
+            || name.ends_with(".toml")
+            || CONFIG_FILE_NAMES.contains(&&*name)
+        {
+            let time = modified(&path);
+            snapshot.insert(path, time);
+        }
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
@@ -0,0 +1,147 @@
+use std::fs;
+use std::io::Write;
+
+use cbindgen::backend::{Item, ItemContainer, LanguageBackend, ListType, Source, SourceWriter};
+use cbindgen::{Bindings, Builder, Config, Language};
+
+/// Lists the types and the functions with their parameters.
+struct Listing;
+
+impl LanguageBackend for Listing {
+    fn write_bindings(&self, bindings: &Bindings, out: &mut SourceWriter<&mut dyn Write>) {
+        for item in bindings.items() {
+            write!(out, "type {}", item.deref().export_name());
+            out.new_line();
+        }
+        for function in bindings.functions() {
+            write!(out, "fn {}", function.path.name());
+            out.open_brace();
+            for (i, arg) in function.args.iter().enumerate() {
+                if i != 0 {
+                    out.new_line();
+                }
+                write!(out, "{}", arg.name.as_deref().unwrap_or("_"));
+            }
+            out.close_brace(false);
+            out.new_line();
+        }
+    }
+}
+
+/// Writes the fields of the structs and the C declarations of the functions,
+/// with cbindgen's own writers.
+struct Declarations;
+
+impl LanguageBackend for Declarations {
+    fn write_bindings(&self, bindings: &Bindings, out: &mut SourceWriter<&mut dyn Write>) {
+        for item in bindings.items() {
+            if let ItemContainer::Struct(ref item) = *item {
+                write!(out, "{}(", item.export_name());
+                out.write_horizontal_source_list(&item.fields, ListType::Join(", "));
+                write!(out, ")");
+                out.new_line();
+            }
+        }
+        for function in bindings.functions() {
+            function.write(&bindings.config, out);
+            out.new_line();
+        }
+    }
+}
+
+#[test]
+fn custom_backend() {
+    let src = r#"
+        #[repr(C)]
+        pub struct Point {
+            x: f32,
+            y: f32,
+        }
+
+        #[no_mangle]
+        pub extern "C" fn distance(a: Point, b: Point) -> f32 {
+            0.0
+        }
+    "#;
+    let bindings = Builder::new()
+        .with_language(Language::C)
+        .with_src_str("listing.rs", src)
+        .with_backend(Listing)
+        .generate()
+        .unwrap();
+
+    let mut out = Vec::new();
+    bindings.write(&mut out);
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "type Point\nfn distance {\n  a\n  b\n}\n"
+    );
+}
+
+#[test]
+fn backend_skips_the_c_companion_files() {
+    let src = r#"
+        #[no_mangle]
+        pub extern "C" fn answer(question: i32) -> i32 {
+            42
+        }
+    "#;
+    let mut config = Config {
+        language: Language::C,
+        ..Default::default()
+    };
+    config.export.umbrella.header = Some("umbrella.h".to_owned());
+    config.export.symbols.version_script = Some("listing.map".to_owned());
+    let bindings = Builder::new()
+        .with_config(config)
+        .with_src_str("listing.rs", src)
+        .with_backend(Listing)
+        .generate()
+        .unwrap();
+
+    let tmp_dir = tempfile::Builder::new()
+        .prefix("cbindgen-test-backend-")
+        .tempdir()
+        .expect("Creating tmp dir failed");
+    assert!(bindings.write_to_file(tmp_dir.path().join("listing.txt")));
+
+    let mut files: Vec<_> = fs::read_dir(tmp_dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    files.sort();
+    assert_eq!(files, ["listing.map", "listing.txt"]);
+    assert_eq!(
+        fs::read_to_string(tmp_dir.path().join("listing.txt")).unwrap(),
+        "fn answer {\n  question\n}\n"
+    );
+}
+
+#[test]
+fn backend_reuses_the_item_writers() {
+    let src = r#"
+        #[repr(C)]
+        pub struct Point {
+            x: f32,
+            y: f32,
+        }
+
+        #[no_mangle]
+        pub extern "C" fn distance(a: Point, b: Point) -> f32 {
+            0.0
+        }
+    "#;
+    let bindings = Builder::new()
+        .with_language(Language::C)
+        .with_src_str("declarations.rs", src)
+        .with_backend(Declarations)
+        .generate()
+        .unwrap();
+
+    let mut out = Vec::new();
+    bindings.write(&mut out);
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "Point(float x, float y)\nfloat distance(struct Point a, struct Point b);\n"
+    );
+}
@@ -0,0 +1,44 @@
+use std::fs;
+use std::process::{Command, Output};
+
+fn check_config(name: &str, config: &str, args: &[&str]) -> Output {
+    let dir = format!("{}/check config {}", env!("CARGO_TARGET_TMPDIR"), name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(format!("{}/bindings.conf", dir), config).unwrap();
+    Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+        .current_dir(&dir)
+        .arg("check-config")
+        .args(args)
+        .arg("bindings.conf")
+        .output()
+        .expect("failed to execute cbindgen")
+}
+
+#[test]
+fn reports_every_invalid_enum_value() {
+    let config = r#"{"language": "Klingon", "fn": {"rename_args": "Shouty"}}"#;
+    let output = check_config("json", config, &["--config-format", "json"]);
+    assert_eq!(output.status.code(), Some(1), "{:?}", output);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("`fn.rename_args`: Unrecognized RenameRule: 'Shouty'."),
+        "{}",
+        stderr
+    );
+    assert!(
+        stderr.contains("`language`: Unrecognized Language: 'Klingon'."),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn guesses_the_format_without_config_format() {
+    let config = r#"{"language": "C"}"#;
+    let output = check_config("toml", config, &[]);
+    assert_eq!(output.status.code(), Some(1), "{:?}", output);
+
+    let output = check_config("json ok", config, &["--config-format", "json"]);
+    assert!(output.status.success(), "{:?}", output);
+}
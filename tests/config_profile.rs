@@ -0,0 +1,56 @@
+use std::fs;
+use std::process::{Command, Output};
+
+fn run_in(name: &str, config: Option<&str>, profile: &str) -> Output {
+    let dir = format!("{}/config profile {}", env!("CARGO_TARGET_TMPDIR"), name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        format!("{}/lib.rs", dir),
+        "#[no_mangle]\npub extern \"C\" fn root() {}\n",
+    )
+    .unwrap();
+    let mut command = Command::new(env!("CARGO_BIN_EXE_cbindgen"));
+    if let Some(config) = config {
+        fs::write(format!("{}/cbindgen.toml", dir), config).unwrap();
+        command.args(&["--config", "cbindgen.toml"]);
+    }
+
+    command
+        .current_dir(&dir)
+        .args(&["--config-profile", profile, "lib.rs"])
+        .output()
+        .expect("failed to execute cbindgen")
+}
+
+fn assert_error(output: &Output, message: &str) {
+    assert_eq!(output.status.code(), Some(1), "{:?}", output);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains(message), "{}", stderr);
+    assert!(!stderr.contains("panicked"), "{}", stderr);
+}
+
+#[test]
+fn profile_without_config() {
+    let output = run_in("missing", None, "release");
+    assert_error(
+        &output,
+        "A config profile was selected, but there's no config.",
+    );
+}
+
+#[test]
+fn unknown_profile() {
+    let config = "language = \"C\"\n\n[profile.release]\nlanguage = \"C++\"\n";
+    let output = run_in("unknown", Some(config), "nope");
+    assert_error(&output, "Unknown config profile: 'nope'.");
+}
+
+#[test]
+fn selected_profile() {
+    let config = "language = \"C\"\n\n[profile.release]\nlanguage = \"C++\"\n";
+    let output = run_in("selected", Some(config), "release");
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("extern \"C\""), "{}", stdout);
+}
@@ -0,0 +1,37 @@
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn depfile_lists_sources_and_config() {
+    let dir = format!("{}/depfile crate", env!("CARGO_TARGET_TMPDIR"));
+    fs::create_dir_all(&dir).unwrap();
+    let src = format!("{}/lib.rs", dir);
+    let module = format!("{}/ffi.rs", dir);
+    let config = format!("{}/cbindgen.toml", dir);
+    let header = format!("{}/bindings.h", dir);
+    let depfile = format!("{}/bindings.d", dir);
+    fs::write(&src, "mod ffi;\n").unwrap();
+    fs::write(
+        &module,
+        "#[no_mangle]\npub extern \"C\" fn from_module(x: u8) -> bool { x != 0 }\n",
+    )
+    .unwrap();
+    fs::write(&config, "language = \"C\"\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+        .args(&["--config", &config, "--output", &header])
+        .args(&["--depfile", &depfile, &src])
+        .output()
+        .expect("failed to execute cbindgen");
+    assert!(output.status.success());
+
+    let escape = |path: &str| path.replace(' ', "\\ ");
+    let expected = format!(
+        "{}: \\\n  {} \\\n  {} \\\n  {}\n",
+        escape(&header),
+        escape(&src),
+        escape(&module),
+        escape(&config),
+    );
+    assert_eq!(fs::read_to_string(&depfile).unwrap(), expected);
+}
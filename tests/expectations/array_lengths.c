@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define NAME_SIZE 16
+
+typedef struct {
+  uint32_t id;
+  uint8_t name[NAME_SIZE];
+  float samples[4][3];
+#if defined(WITH_CHECKSUM)
+  uint8_t checksum[8]
+#endif
+  ;
+} Record;
+#define RECORD_NAME_LEN NAME_SIZE
+#define RECORD_SAMPLES_LEN 4
+#if defined(WITH_CHECKSUM)
+#define RECORD_CHECKSUM_LEN 8
+#endif
+
+typedef uint8_t Digest[32];
+#define DIGEST_LEN 32
+
+void root(const Record *record, const Digest *digest);
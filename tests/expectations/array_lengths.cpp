@@ -0,0 +1,31 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const uintptr_t NAME_SIZE = 16;
+
+struct Record {
+  uint32_t id;
+  uint8_t name[NAME_SIZE];
+  float samples[4][3];
+#if defined(WITH_CHECKSUM)
+  uint8_t checksum[8]
+#endif
+  ;
+};
+#define RECORD_NAME_LEN NAME_SIZE
+#define RECORD_SAMPLES_LEN 4
+#if defined(WITH_CHECKSUM)
+#define RECORD_CHECKSUM_LEN 8
+#endif
+
+using Digest = uint8_t[32];
+#define DIGEST_LEN 32
+
+extern "C" {
+
+void root(const Record *record, const Digest *digest);
+
+} // extern "C"
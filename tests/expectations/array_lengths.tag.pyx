@@ -0,0 +1,19 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uintptr_t NAME_SIZE # = 16
+
+  cdef struct Record:
+    uint32_t id;
+    uint8_t name[NAME_SIZE];
+    float samples[4][3];
+    uint8_t checksum[8];
+
+  ctypedef uint8_t Digest[32];
+
+  void root(const Record *record, const Digest *digest);
@@ -14,26 +14,26 @@ struct StyleAlignFlags {
 /**
  * 'auto'
  */
-#define StyleAlignFlags_AUTO (StyleAlignFlags){ .bits = (uint8_t)0 }
+#define StyleAlignFlags_AUTO (struct StyleAlignFlags){ .bits = (uint8_t)0 }
 /**
  * 'normal'
  */
-#define StyleAlignFlags_NORMAL (StyleAlignFlags){ .bits = (uint8_t)1 }
+#define StyleAlignFlags_NORMAL (struct StyleAlignFlags){ .bits = (uint8_t)1 }
 /**
  * 'start'
  */
-#define StyleAlignFlags_START (StyleAlignFlags){ .bits = (uint8_t)(1 << 1) }
+#define StyleAlignFlags_START (struct StyleAlignFlags){ .bits = (uint8_t)(1 << 1) }
 /**
  * 'end'
  */
-#define StyleAlignFlags_END (StyleAlignFlags){ .bits = (uint8_t)(1 << 2) }
-#define StyleAlignFlags_ALIAS (StyleAlignFlags){ .bits = (uint8_t)(StyleAlignFlags_END).bits }
+#define StyleAlignFlags_END (struct StyleAlignFlags){ .bits = (uint8_t)(1 << 2) }
+#define StyleAlignFlags_ALIAS (struct StyleAlignFlags){ .bits = (uint8_t)(StyleAlignFlags_END).bits }
 /**
  * 'flex-start'
  */
-#define StyleAlignFlags_FLEX_START (StyleAlignFlags){ .bits = (uint8_t)(1 << 3) }
-#define StyleAlignFlags_MIXED (StyleAlignFlags){ .bits = (uint8_t)(((1 << 4) | (StyleAlignFlags_FLEX_START).bits) | (StyleAlignFlags_END).bits) }
-#define StyleAlignFlags_MIXED_SELF (StyleAlignFlags){ .bits = (uint8_t)(((1 << 5) | (StyleAlignFlags_FLEX_START).bits) | (StyleAlignFlags_END).bits) }
+#define StyleAlignFlags_FLEX_START (struct StyleAlignFlags){ .bits = (uint8_t)(1 << 3) }
+#define StyleAlignFlags_MIXED (struct StyleAlignFlags){ .bits = (uint8_t)(((1 << 4) | (StyleAlignFlags_FLEX_START).bits) | (StyleAlignFlags_END).bits) }
+#define StyleAlignFlags_MIXED_SELF (struct StyleAlignFlags){ .bits = (uint8_t)(((1 << 5) | (StyleAlignFlags_FLEX_START).bits) | (StyleAlignFlags_END).bits) }
 
 /**
  * An arbitrary identifier for a native (OS compositor) surface
@@ -44,7 +44,7 @@ struct StyleNativeSurfaceId {
 /**
  * A special id for the native surface that is used for debug / profiler overlays.
  */
-#define StyleNativeSurfaceId_DEBUG_OVERLAY (StyleNativeSurfaceId){ ._0 = UINT64_MAX }
+#define StyleNativeSurfaceId_DEBUG_OVERLAY (struct StyleNativeSurfaceId){ ._0 = UINT64_MAX }
 
 struct StyleNativeTileId {
   struct StyleNativeSurfaceId surface_id;
@@ -54,6 +54,6 @@ struct StyleNativeTileId {
 /**
  * A special id for the native surface that is used for debug / profiler overlays.
  */
-#define StyleNativeTileId_DEBUG_OVERLAY (StyleNativeTileId){ .surface_id = StyleNativeSurfaceId_DEBUG_OVERLAY, .x = 0, .y = 0 }
+#define StyleNativeTileId_DEBUG_OVERLAY (struct StyleNativeTileId){ .surface_id = StyleNativeSurfaceId_DEBUG_OVERLAY, .x = 0, .y = 0 }
 
 void root(struct StyleAlignFlags flags, struct StyleNativeTileId tile);
@@ -14,26 +14,26 @@ struct AlignFlags {
 /**
  * 'auto'
  */
-#define AlignFlags_AUTO (AlignFlags){ .bits = (uint8_t)0 }
+#define AlignFlags_AUTO (struct AlignFlags){ .bits = (uint8_t)0 }
 /**
  * 'normal'
  */
-#define AlignFlags_NORMAL (AlignFlags){ .bits = (uint8_t)1 }
+#define AlignFlags_NORMAL (struct AlignFlags){ .bits = (uint8_t)1 }
 /**
  * 'start'
  */
-#define AlignFlags_START (AlignFlags){ .bits = (uint8_t)(1 << 1) }
+#define AlignFlags_START (struct AlignFlags){ .bits = (uint8_t)(1 << 1) }
 /**
  * 'end'
  */
-#define AlignFlags_END (AlignFlags){ .bits = (uint8_t)(1 << 2) }
-#define AlignFlags_ALIAS (AlignFlags){ .bits = (uint8_t)(AlignFlags_END).bits }
+#define AlignFlags_END (struct AlignFlags){ .bits = (uint8_t)(1 << 2) }
+#define AlignFlags_ALIAS (struct AlignFlags){ .bits = (uint8_t)(AlignFlags_END).bits }
 /**
  * 'flex-start'
  */
-#define AlignFlags_FLEX_START (AlignFlags){ .bits = (uint8_t)(1 << 3) }
-#define AlignFlags_MIXED (AlignFlags){ .bits = (uint8_t)(((1 << 4) | (AlignFlags_FLEX_START).bits) | (AlignFlags_END).bits) }
-#define AlignFlags_MIXED_SELF (AlignFlags){ .bits = (uint8_t)(((1 << 5) | (AlignFlags_FLEX_START).bits) | (AlignFlags_END).bits) }
+#define AlignFlags_FLEX_START (struct AlignFlags){ .bits = (uint8_t)(1 << 3) }
+#define AlignFlags_MIXED (struct AlignFlags){ .bits = (uint8_t)(((1 << 4) | (AlignFlags_FLEX_START).bits) | (AlignFlags_END).bits) }
+#define AlignFlags_MIXED_SELF (struct AlignFlags){ .bits = (uint8_t)(((1 << 5) | (AlignFlags_FLEX_START).bits) | (AlignFlags_END).bits) }
 
 struct DebugFlags {
   uint32_t bits;
@@ -41,7 +41,7 @@ struct DebugFlags {
 /**
  * Flag with the topmost bit set of the u32
  */
-#define DebugFlags_BIGGEST_ALLOWED (DebugFlags){ .bits = (uint32_t)(1 << 31) }
+#define DebugFlags_BIGGEST_ALLOWED (struct DebugFlags){ .bits = (uint32_t)(1 << 31) }
 
 struct LargeFlags {
   uint64_t bits;
@@ -49,8 +49,8 @@ struct LargeFlags {
 /**
  * Flag with a very large shift that usually would be narrowed.
  */
-#define LargeFlags_LARGE_SHIFT (LargeFlags){ .bits = (uint64_t)(1ull << 44) }
-#define LargeFlags_INVERTED (LargeFlags){ .bits = (uint64_t)~(LargeFlags_LARGE_SHIFT).bits }
+#define LargeFlags_LARGE_SHIFT (struct LargeFlags){ .bits = (uint64_t)(1ull << 44) }
+#define LargeFlags_INVERTED (struct LargeFlags){ .bits = (uint64_t)~(LargeFlags_LARGE_SHIFT).bits }
 
 #ifdef __cplusplus
 extern "C" {
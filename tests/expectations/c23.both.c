@@ -0,0 +1,25 @@
+#include <stdarg.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+static constexpr uint32_t MAX_LEN = 64;
+
+#define DEFAULT_NAME nullptr
+
+typedef enum Mode : uint8_t {
+  Read,
+  Write,
+} Mode;
+
+typedef struct Options {
+  Mode mode;
+  bool verbose;
+} Options;
+
+[[nodiscard]] struct Options open_options(Mode mode);
+
+[[deprecated]] void legacy_open(void);
+
+[[deprecated("use \"open_options\" instead")]] void legacy_open_with(const struct Options *options);
+
+[[nodiscard]] struct Options default_options(void);
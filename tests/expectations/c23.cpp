@@ -0,0 +1,31 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const uint32_t MAX_LEN = 64;
+
+constexpr static const uint8_t* DEFAULT_NAME = nullptr;
+
+enum class Mode : uint8_t {
+  Read,
+  Write,
+};
+
+struct Options {
+  Mode mode;
+  bool verbose;
+};
+
+extern "C" {
+
+Options open_options(Mode mode);
+
+void legacy_open();
+
+void legacy_open_with(const Options *options);
+
+[[nodiscard("the options should be used to open something")]] Options default_options();
+
+} // extern "C"
@@ -0,0 +1,28 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uint32_t MAX_LEN # = 64
+
+  const uint8_t *const DEFAULT_NAME # = NULL
+
+  cdef enum:
+    Read,
+    Write,
+  ctypedef uint8_t Mode;
+
+  ctypedef struct Options:
+    Mode mode;
+    bool verbose;
+
+  Options open_options(Mode mode);
+
+  void legacy_open();
+
+  void legacy_open_with(const Options *options);
+
+  Options default_options();
@@ -0,0 +1,109 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+enum Shape_Tag {
+  Empty,
+  Circle,
+  Polygon,
+  Label,
+};
+typedef uint8_t Shape_Tag;
+
+typedef struct Circle_Body {
+  struct Point center;
+  float radius;
+} Circle_Body;
+
+typedef struct Shape {
+  Shape_Tag tag;
+  union {
+    Circle_Body circle;
+    struct {
+      struct Point polygon[3];
+    };
+    struct {
+      const uint8_t *label;
+    };
+  };
+} Shape;
+
+Shape Shape_Empty(void);
+
+bool Shape_is_empty(const Shape *self);
+
+Shape Shape_Circle(struct Point center,
+                   float radius);
+
+bool Shape_is_circle(const Shape *self);
+
+const Circle_Body *Shape_as_circle(const Shape *self);
+
+Circle_Body *Shape_as_circle_mut(Shape *self);
+
+Shape Shape_Polygon(struct Point polygon[3]);
+
+bool Shape_is_polygon(const Shape *self);
+
+const struct Point (*Shape_as_polygon(const Shape *self))[3];
+
+struct Point (*Shape_as_polygon_mut(Shape *self))[3];
+
+Shape Shape_Label(const uint8_t *label);
+
+bool Shape_is_label(const Shape *self);
+
+const uint8_t *const *Shape_as_label(const Shape *self);
+
+const uint8_t **Shape_as_label_mut(Shape *self);
+
+enum Event_Tag {
+  Quit,
+  KeyPress,
+  Move,
+};
+typedef uint8_t Event_Tag;
+
+typedef struct Move_Body {
+  Event_Tag tag;
+  int32_t dx;
+  int32_t dy;
+} Move_Body;
+
+typedef union Event {
+  Event_Tag tag;
+  struct {
+    Event_Tag key_press_tag;
+    uint32_t key_press;
+  };
+  Move_Body move;
+} Event;
+
+Event Event_Quit(void);
+
+bool Event_is_quit(const Event *self);
+
+Event Event_KeyPress(uint32_t key_press);
+
+bool Event_is_key_press(const Event *self);
+
+const uint32_t *Event_as_key_press(const Event *self);
+
+uint32_t *Event_as_key_press_mut(Event *self);
+
+Event Event_Move(int32_t dx,
+                 int32_t dy);
+
+bool Event_is_move(const Event *self);
+
+const Move_Body *Event_as_move(const Event *self);
+
+Move_Body *Event_as_move_mut(Event *self);
+
+void root(struct Shape shape, union Event event);
@@ -0,0 +1,145 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+enum Shape_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Empty,
+  Circle,
+  Polygon,
+  Label,
+};
+#ifndef __cplusplus
+typedef uint8_t Shape_Tag;
+#endif // __cplusplus
+
+typedef struct {
+  Point center;
+  float radius;
+} Circle_Body;
+
+typedef struct {
+  Shape_Tag tag;
+  union {
+    Circle_Body circle;
+    struct {
+      Point polygon[3];
+    };
+    struct {
+      const uint8_t *label;
+    };
+  };
+} Shape;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Shape Shape_Empty(void);
+
+bool Shape_is_empty(const Shape *self);
+
+Shape Shape_Circle(Point center,
+                   float radius);
+
+bool Shape_is_circle(const Shape *self);
+
+const Circle_Body *Shape_as_circle(const Shape *self);
+
+Circle_Body *Shape_as_circle_mut(Shape *self);
+
+Shape Shape_Polygon(Point polygon[3]);
+
+bool Shape_is_polygon(const Shape *self);
+
+const Point (*Shape_as_polygon(const Shape *self))[3];
+
+Point (*Shape_as_polygon_mut(Shape *self))[3];
+
+Shape Shape_Label(const uint8_t *label);
+
+bool Shape_is_label(const Shape *self);
+
+const uint8_t *const *Shape_as_label(const Shape *self);
+
+const uint8_t **Shape_as_label_mut(Shape *self);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+enum Event_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Quit,
+  KeyPress,
+  Move,
+};
+#ifndef __cplusplus
+typedef uint8_t Event_Tag;
+#endif // __cplusplus
+
+typedef struct {
+  Event_Tag tag;
+  int32_t dx;
+  int32_t dy;
+} Move_Body;
+
+typedef union {
+  Event_Tag tag;
+  struct {
+    Event_Tag key_press_tag;
+    uint32_t key_press;
+  };
+  Move_Body move;
+} Event;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Event Event_Quit(void);
+
+bool Event_is_quit(const Event *self);
+
+Event Event_KeyPress(uint32_t key_press);
+
+bool Event_is_key_press(const Event *self);
+
+const uint32_t *Event_as_key_press(const Event *self);
+
+uint32_t *Event_as_key_press_mut(Event *self);
+
+Event Event_Move(int32_t dx,
+                 int32_t dy);
+
+bool Event_is_move(const Event *self);
+
+const Move_Body *Event_as_move(const Event *self);
+
+Move_Body *Event_as_move_mut(Event *self);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Shape shape, Event event);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,109 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Point {
+  float x;
+  float y;
+};
+
+enum Shape_Tag {
+  Empty,
+  Circle,
+  Polygon,
+  Label,
+};
+typedef uint8_t Shape_Tag;
+
+struct Circle_Body {
+  struct Point center;
+  float radius;
+};
+
+struct Shape {
+  Shape_Tag tag;
+  union {
+    struct Circle_Body circle;
+    struct {
+      struct Point polygon[3];
+    };
+    struct {
+      const uint8_t *label;
+    };
+  };
+};
+
+struct Shape Shape_Empty(void);
+
+bool Shape_is_empty(const struct Shape *self);
+
+struct Shape Shape_Circle(struct Point center,
+                          float radius);
+
+bool Shape_is_circle(const struct Shape *self);
+
+const struct Circle_Body *Shape_as_circle(const struct Shape *self);
+
+struct Circle_Body *Shape_as_circle_mut(struct Shape *self);
+
+struct Shape Shape_Polygon(struct Point polygon[3]);
+
+bool Shape_is_polygon(const struct Shape *self);
+
+const struct Point (*Shape_as_polygon(const struct Shape *self))[3];
+
+struct Point (*Shape_as_polygon_mut(struct Shape *self))[3];
+
+struct Shape Shape_Label(const uint8_t *label);
+
+bool Shape_is_label(const struct Shape *self);
+
+const uint8_t *const *Shape_as_label(const struct Shape *self);
+
+const uint8_t **Shape_as_label_mut(struct Shape *self);
+
+enum Event_Tag {
+  Quit,
+  KeyPress,
+  Move,
+};
+typedef uint8_t Event_Tag;
+
+struct Move_Body {
+  Event_Tag tag;
+  int32_t dx;
+  int32_t dy;
+};
+
+union Event {
+  Event_Tag tag;
+  struct {
+    Event_Tag key_press_tag;
+    uint32_t key_press;
+  };
+  struct Move_Body move;
+};
+
+union Event Event_Quit(void);
+
+bool Event_is_quit(const union Event *self);
+
+union Event Event_KeyPress(uint32_t key_press);
+
+bool Event_is_key_press(const union Event *self);
+
+const uint32_t *Event_as_key_press(const union Event *self);
+
+uint32_t *Event_as_key_press_mut(union Event *self);
+
+union Event Event_Move(int32_t dx,
+                       int32_t dy);
+
+bool Event_is_move(const union Event *self);
+
+const struct Move_Body *Event_as_move(const union Event *self);
+
+struct Move_Body *Event_as_move_mut(union Event *self);
+
+void root(struct Shape shape, union Event event);
@@ -0,0 +1,178 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+enum Shape_Tag {
+  Empty,
+  Circle,
+  Polygon,
+  Label,
+};
+typedef uint8_t Shape_Tag;
+
+typedef struct Circle_Body {
+  struct Point center;
+  float radius;
+} Circle_Body;
+
+typedef struct Shape {
+  Shape_Tag tag;
+  union {
+    Circle_Body circle;
+    struct {
+      struct Point polygon[3];
+    };
+    struct {
+      const uint8_t *label;
+    };
+  };
+} Shape;
+
+static inline Shape Shape_Empty(void) {
+  Shape result;
+  result.tag = Empty;
+  return result;
+}
+
+static inline bool Shape_is_empty(const Shape *self) {
+  return self->tag == Empty;
+}
+
+static inline Shape Shape_Circle(struct Point center,
+                                 float radius) {
+  Shape result;
+  result.circle.center = center;
+  result.circle.radius = radius;
+  result.tag = Circle;
+  return result;
+}
+
+static inline bool Shape_is_circle(const Shape *self) {
+  return self->tag == Circle;
+}
+
+static inline const Circle_Body *Shape_as_circle(const Shape *self) {
+  return self->tag == Circle ? &self->circle : NULL;
+}
+
+static inline Circle_Body *Shape_as_circle_mut(Shape *self) {
+  return self->tag == Circle ? &self->circle : NULL;
+}
+
+static inline Shape Shape_Polygon(struct Point polygon[3]) {
+  Shape result;
+  for (int i = 0; i < 3; i++) result.polygon[i] = polygon[i];
+  result.tag = Polygon;
+  return result;
+}
+
+static inline bool Shape_is_polygon(const Shape *self) {
+  return self->tag == Polygon;
+}
+
+static inline const struct Point (*Shape_as_polygon(const Shape *self))[3] {
+  return self->tag == Polygon ? &self->polygon : NULL;
+}
+
+static inline struct Point (*Shape_as_polygon_mut(Shape *self))[3] {
+  return self->tag == Polygon ? &self->polygon : NULL;
+}
+
+static inline Shape Shape_Label(const uint8_t *label) {
+  Shape result;
+  result.label = label;
+  result.tag = Label;
+  return result;
+}
+
+static inline bool Shape_is_label(const Shape *self) {
+  return self->tag == Label;
+}
+
+static inline const uint8_t *const *Shape_as_label(const Shape *self) {
+  return self->tag == Label ? &self->label : NULL;
+}
+
+static inline const uint8_t **Shape_as_label_mut(Shape *self) {
+  return self->tag == Label ? &self->label : NULL;
+}
+
+enum Event_Tag {
+  Quit,
+  KeyPress,
+  Move,
+};
+typedef uint8_t Event_Tag;
+
+typedef struct Move_Body {
+  Event_Tag tag;
+  int32_t dx;
+  int32_t dy;
+} Move_Body;
+
+typedef union Event {
+  Event_Tag tag;
+  struct {
+    Event_Tag key_press_tag;
+    uint32_t key_press;
+  };
+  Move_Body move;
+} Event;
+
+static inline Event Event_Quit(void) {
+  Event result;
+  result.tag = Quit;
+  return result;
+}
+
+static inline bool Event_is_quit(const Event *self) {
+  return self->tag == Quit;
+}
+
+static inline Event Event_KeyPress(uint32_t key_press) {
+  Event result;
+  result.key_press = key_press;
+  result.tag = KeyPress;
+  return result;
+}
+
+static inline bool Event_is_key_press(const Event *self) {
+  return self->tag == KeyPress;
+}
+
+static inline const uint32_t *Event_as_key_press(const Event *self) {
+  return self->tag == KeyPress ? &self->key_press : NULL;
+}
+
+static inline uint32_t *Event_as_key_press_mut(Event *self) {
+  return self->tag == KeyPress ? &self->key_press : NULL;
+}
+
+static inline Event Event_Move(int32_t dx,
+                               int32_t dy) {
+  Event result;
+  result.move.dx = dx;
+  result.move.dy = dy;
+  result.tag = Move;
+  return result;
+}
+
+static inline bool Event_is_move(const Event *self) {
+  return self->tag == Move;
+}
+
+static inline const Move_Body *Event_as_move(const Event *self) {
+  return self->tag == Move ? &self->move : NULL;
+}
+
+static inline Move_Body *Event_as_move_mut(Event *self) {
+  return self->tag == Move ? &self->move : NULL;
+}
+
+void root(struct Shape shape, union Event event);
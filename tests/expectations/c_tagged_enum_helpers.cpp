@@ -0,0 +1,70 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Shape {
+  enum class Tag : uint8_t {
+    Empty,
+    Circle,
+    Polygon,
+    Label,
+  };
+
+  struct Circle_Body {
+    Point center;
+    float radius;
+  };
+
+  struct Polygon_Body {
+    Point _0[3];
+  };
+
+  struct Label_Body {
+    const uint8_t *_0;
+  };
+
+  Tag tag;
+  union {
+    Circle_Body circle;
+    Polygon_Body polygon;
+    Label_Body label;
+  };
+};
+
+union Event {
+  enum class Tag : uint8_t {
+    Quit,
+    KeyPress,
+    Move,
+  };
+
+  struct KeyPress_Body {
+    Tag tag;
+    uint32_t _0;
+  };
+
+  struct Move_Body {
+    Tag tag;
+    int32_t dx;
+    int32_t dy;
+  };
+
+  struct {
+    Tag tag;
+  };
+  KeyPress_Body key_press;
+  Move_Body move;
+};
+
+extern "C" {
+
+void root(Shape shape, Event event);
+
+} // extern "C"
@@ -0,0 +1,198 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Point {
+  float x;
+  float y;
+};
+
+enum Shape_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Empty,
+  Circle,
+  Polygon,
+  Label,
+};
+#ifndef __cplusplus
+typedef uint8_t Shape_Tag;
+#endif // __cplusplus
+
+struct Circle_Body {
+  struct Point center;
+  float radius;
+};
+
+struct Shape {
+  Shape_Tag tag;
+  union {
+    struct Circle_Body circle;
+    struct {
+      struct Point polygon[3];
+    };
+    struct {
+      const uint8_t *label;
+    };
+  };
+};
+
+static inline struct Shape Shape_Empty(void) {
+  struct Shape result;
+  result.tag = Empty;
+  return result;
+}
+
+static inline bool Shape_is_empty(const struct Shape *self) {
+  return self->tag == Empty;
+}
+
+static inline struct Shape Shape_Circle(struct Point center,
+                                        float radius) {
+  struct Shape result;
+  result.circle.center = center;
+  result.circle.radius = radius;
+  result.tag = Circle;
+  return result;
+}
+
+static inline bool Shape_is_circle(const struct Shape *self) {
+  return self->tag == Circle;
+}
+
+static inline const struct Circle_Body *Shape_as_circle(const struct Shape *self) {
+  return self->tag == Circle ? &self->circle : NULL;
+}
+
+static inline struct Circle_Body *Shape_as_circle_mut(struct Shape *self) {
+  return self->tag == Circle ? &self->circle : NULL;
+}
+
+static inline struct Shape Shape_Polygon(struct Point polygon[3]) {
+  struct Shape result;
+  for (int i = 0; i < 3; i++) result.polygon[i] = polygon[i];
+  result.tag = Polygon;
+  return result;
+}
+
+static inline bool Shape_is_polygon(const struct Shape *self) {
+  return self->tag == Polygon;
+}
+
+static inline const struct Point (*Shape_as_polygon(const struct Shape *self))[3] {
+  return self->tag == Polygon ? &self->polygon : NULL;
+}
+
+static inline struct Point (*Shape_as_polygon_mut(struct Shape *self))[3] {
+  return self->tag == Polygon ? &self->polygon : NULL;
+}
+
+static inline struct Shape Shape_Label(const uint8_t *label) {
+  struct Shape result;
+  result.label = label;
+  result.tag = Label;
+  return result;
+}
+
+static inline bool Shape_is_label(const struct Shape *self) {
+  return self->tag == Label;
+}
+
+static inline const uint8_t *const *Shape_as_label(const struct Shape *self) {
+  return self->tag == Label ? &self->label : NULL;
+}
+
+static inline const uint8_t **Shape_as_label_mut(struct Shape *self) {
+  return self->tag == Label ? &self->label : NULL;
+}
+
+enum Event_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Quit,
+  KeyPress,
+  Move,
+};
+#ifndef __cplusplus
+typedef uint8_t Event_Tag;
+#endif // __cplusplus
+
+struct Move_Body {
+  Event_Tag tag;
+  int32_t dx;
+  int32_t dy;
+};
+
+union Event {
+  Event_Tag tag;
+  struct {
+    Event_Tag key_press_tag;
+    uint32_t key_press;
+  };
+  struct Move_Body move;
+};
+
+static inline union Event Event_Quit(void) {
+  union Event result;
+  result.tag = Quit;
+  return result;
+}
+
+static inline bool Event_is_quit(const union Event *self) {
+  return self->tag == Quit;
+}
+
+static inline union Event Event_KeyPress(uint32_t key_press) {
+  union Event result;
+  result.key_press = key_press;
+  result.tag = KeyPress;
+  return result;
+}
+
+static inline bool Event_is_key_press(const union Event *self) {
+  return self->tag == KeyPress;
+}
+
+static inline const uint32_t *Event_as_key_press(const union Event *self) {
+  return self->tag == KeyPress ? &self->key_press : NULL;
+}
+
+static inline uint32_t *Event_as_key_press_mut(union Event *self) {
+  return self->tag == KeyPress ? &self->key_press : NULL;
+}
+
+static inline union Event Event_Move(int32_t dx,
+                                     int32_t dy) {
+  union Event result;
+  result.move.dx = dx;
+  result.move.dy = dy;
+  result.tag = Move;
+  return result;
+}
+
+static inline bool Event_is_move(const union Event *self) {
+  return self->tag == Move;
+}
+
+static inline const struct Move_Body *Event_as_move(const union Event *self) {
+  return self->tag == Move ? &self->move : NULL;
+}
+
+static inline struct Move_Body *Event_as_move_mut(union Event *self) {
+  return self->tag == Move ? &self->move : NULL;
+}
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Shape shape, union Event event);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
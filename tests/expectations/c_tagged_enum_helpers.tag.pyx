@@ -0,0 +1,46 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Point:
+    float x;
+    float y;
+
+  cdef enum:
+    Empty,
+    Circle,
+    Polygon,
+    Label,
+  ctypedef uint8_t Shape_Tag;
+
+  cdef struct Circle_Body:
+    Point center;
+    float radius;
+
+  cdef struct Shape:
+    Shape_Tag tag;
+    Circle_Body circle;
+    Point polygon[3];
+    const uint8_t *label;
+
+  cdef enum:
+    Quit,
+    KeyPress,
+    Move,
+  ctypedef uint8_t Event_Tag;
+
+  cdef struct Move_Body:
+    Event_Tag tag;
+    int32_t dx;
+    int32_t dy;
+
+  cdef union Event:
+    Event_Tag tag;
+    uint32_t key_press;
+    Move_Body move;
+
+  void root(Shape shape, Event event);
@@ -0,0 +1,15 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Buffer {
+  uint8_t *data;
+  signed char prefix;
+} Buffer;
+
+const uint8_t *name(uint8_t *buf, uintptr_t len);
+
+void print(const signed char *s, const uint8_t *const *bytes);
+
+void fill(struct Buffer *b);
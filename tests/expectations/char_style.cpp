@@ -0,0 +1,20 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Buffer {
+  uint8_t *data;
+  signed char prefix;
+};
+
+extern "C" {
+
+const uint8_t *name(uint8_t *buf, uintptr_t len);
+
+void print(const signed char *s, const uint8_t *const *bytes);
+
+void fill(Buffer *b);
+
+} // extern "C"
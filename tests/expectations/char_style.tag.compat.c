@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Buffer {
+  uint8_t *data;
+  signed char prefix;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+const uint8_t *name(uint8_t *buf, uintptr_t len);
+
+void print(const signed char *s, const uint8_t *const *bytes);
+
+void fill(struct Buffer *b);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,121 @@
+#include "c_helpers_file.both.compat.c"
+
+Shape Shape_Empty(void) {
+  Shape result;
+  result.tag = Empty;
+  return result;
+}
+
+bool Shape_is_empty(const Shape *self) {
+  return self->tag == Empty;
+}
+
+Shape Shape_Circle(struct Point center,
+                   float radius) {
+  Shape result;
+  result.circle.center = center;
+  result.circle.radius = radius;
+  result.tag = Circle;
+  return result;
+}
+
+bool Shape_is_circle(const Shape *self) {
+  return self->tag == Circle;
+}
+
+const Circle_Body *Shape_as_circle(const Shape *self) {
+  return self->tag == Circle ? &self->circle : NULL;
+}
+
+Circle_Body *Shape_as_circle_mut(Shape *self) {
+  return self->tag == Circle ? &self->circle : NULL;
+}
+
+Shape Shape_Polygon(struct Point polygon[3]) {
+  Shape result;
+  for (int i = 0; i < 3; i++) result.polygon[i] = polygon[i];
+  result.tag = Polygon;
+  return result;
+}
+
+bool Shape_is_polygon(const Shape *self) {
+  return self->tag == Polygon;
+}
+
+const struct Point (*Shape_as_polygon(const Shape *self))[3] {
+  return self->tag == Polygon ? &self->polygon : NULL;
+}
+
+struct Point (*Shape_as_polygon_mut(Shape *self))[3] {
+  return self->tag == Polygon ? &self->polygon : NULL;
+}
+
+Shape Shape_Label(const uint8_t *label) {
+  Shape result;
+  result.label = label;
+  result.tag = Label;
+  return result;
+}
+
+bool Shape_is_label(const Shape *self) {
+  return self->tag == Label;
+}
+
+const uint8_t *const *Shape_as_label(const Shape *self) {
+  return self->tag == Label ? &self->label : NULL;
+}
+
+const uint8_t **Shape_as_label_mut(Shape *self) {
+  return self->tag == Label ? &self->label : NULL;
+}
+
+
+Event Event_Quit(void) {
+  Event result;
+  result.tag = Quit;
+  return result;
+}
+
+bool Event_is_quit(const Event *self) {
+  return self->tag == Quit;
+}
+
+Event Event_KeyPress(uint32_t key_press) {
+  Event result;
+  result.key_press = key_press;
+  result.tag = KeyPress;
+  return result;
+}
+
+bool Event_is_key_press(const Event *self) {
+  return self->tag == KeyPress;
+}
+
+const uint32_t *Event_as_key_press(const Event *self) {
+  return self->tag == KeyPress ? &self->key_press : NULL;
+}
+
+uint32_t *Event_as_key_press_mut(Event *self) {
+  return self->tag == KeyPress ? &self->key_press : NULL;
+}
+
+Event Event_Move(int32_t dx,
+                 int32_t dy) {
+  Event result;
+  result.move.dx = dx;
+  result.move.dy = dy;
+  result.tag = Move;
+  return result;
+}
+
+bool Event_is_move(const Event *self) {
+  return self->tag == Move;
+}
+
+const Move_Body *Event_as_move(const Event *self) {
+  return self->tag == Move ? &self->move : NULL;
+}
+
+Move_Body *Event_as_move_mut(Event *self) {
+  return self->tag == Move ? &self->move : NULL;
+}
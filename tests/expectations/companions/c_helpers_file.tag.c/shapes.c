@@ -0,0 +1,121 @@
+#include "c_helpers_file.tag.c"
+
+struct Shape Shape_Empty(void) {
+  struct Shape result;
+  result.tag = Empty;
+  return result;
+}
+
+bool Shape_is_empty(const struct Shape *self) {
+  return self->tag == Empty;
+}
+
+struct Shape Shape_Circle(struct Point center,
+                          float radius) {
+  struct Shape result;
+  result.circle.center = center;
+  result.circle.radius = radius;
+  result.tag = Circle;
+  return result;
+}
+
+bool Shape_is_circle(const struct Shape *self) {
+  return self->tag == Circle;
+}
+
+const struct Circle_Body *Shape_as_circle(const struct Shape *self) {
+  return self->tag == Circle ? &self->circle : NULL;
+}
+
+struct Circle_Body *Shape_as_circle_mut(struct Shape *self) {
+  return self->tag == Circle ? &self->circle : NULL;
+}
+
+struct Shape Shape_Polygon(struct Point polygon[3]) {
+  struct Shape result;
+  for (int i = 0; i < 3; i++) result.polygon[i] = polygon[i];
+  result.tag = Polygon;
+  return result;
+}
+
+bool Shape_is_polygon(const struct Shape *self) {
+  return self->tag == Polygon;
+}
+
+const struct Point (*Shape_as_polygon(const struct Shape *self))[3] {
+  return self->tag == Polygon ? &self->polygon : NULL;
+}
+
+struct Point (*Shape_as_polygon_mut(struct Shape *self))[3] {
+  return self->tag == Polygon ? &self->polygon : NULL;
+}
+
+struct Shape Shape_Label(const uint8_t *label) {
+  struct Shape result;
+  result.label = label;
+  result.tag = Label;
+  return result;
+}
+
+bool Shape_is_label(const struct Shape *self) {
+  return self->tag == Label;
+}
+
+const uint8_t *const *Shape_as_label(const struct Shape *self) {
+  return self->tag == Label ? &self->label : NULL;
+}
+
+const uint8_t **Shape_as_label_mut(struct Shape *self) {
+  return self->tag == Label ? &self->label : NULL;
+}
+
+
+union Event Event_Quit(void) {
+  union Event result;
+  result.tag = Quit;
+  return result;
+}
+
+bool Event_is_quit(const union Event *self) {
+  return self->tag == Quit;
+}
+
+union Event Event_KeyPress(uint32_t key_press) {
+  union Event result;
+  result.key_press = key_press;
+  result.tag = KeyPress;
+  return result;
+}
+
+bool Event_is_key_press(const union Event *self) {
+  return self->tag == KeyPress;
+}
+
+const uint32_t *Event_as_key_press(const union Event *self) {
+  return self->tag == KeyPress ? &self->key_press : NULL;
+}
+
+uint32_t *Event_as_key_press_mut(union Event *self) {
+  return self->tag == KeyPress ? &self->key_press : NULL;
+}
+
+union Event Event_Move(int32_t dx,
+                       int32_t dy) {
+  union Event result;
+  result.move.dx = dx;
+  result.move.dy = dy;
+  result.tag = Move;
+  return result;
+}
+
+bool Event_is_move(const union Event *self) {
+  return self->tag == Move;
+}
+
+const struct Move_Body *Event_as_move(const union Event *self) {
+  return self->tag == Move ? &self->move : NULL;
+}
+
+struct Move_Body *Event_as_move_mut(union Event *self) {
+  return self->tag == Move ? &self->move : NULL;
+}
@@ -0,0 +1,29 @@
+#pragma once
+
+#include "cpp_exceptions_header.cpp"
+#include <cstdint>
+#include <stdexcept>
+#include <string>
+
+struct FileError : std::runtime_error {
+  int64_t code;
+
+  FileError(const char *function, int64_t code)
+    : std::runtime_error(std::string(function) + " failed with error code " +
+                         std::to_string(code)),
+      code(code) {}
+};
+
+inline void open_file_or_throw(const uint8_t *path) {
+  auto code = open_file(path);
+  if (code != 0) {
+    throw FileError("open_file", static_cast<int64_t>(code));
+  }
+}
+
+inline void check_access_or_throw(uint32_t user) {
+  auto code = check_access(user);
+  if (code != Status::Ok) {
+    throw FileError("check_access", static_cast<int64_t>(code));
+  }
+}
@@ -0,0 +1,60 @@
+import typing
+
+
+class Shape:
+  """A shape."""
+
+  @property
+  def tag(self) -> int:
+    ...
+
+  def is_circle(self) -> bool:
+    ...
+
+  def as_circle(self) -> float:
+    ...
+
+  def is_rect(self) -> bool:
+    ...
+
+  def as_rect(self) -> dict:
+    ...
+
+  def is_empty(self) -> bool:
+    ...
+
+  def is_custom(self) -> bool:
+    ...
+
+
+class Reading:
+  @property
+  def tag(self) -> int:
+    ...
+
+  def is_level(self) -> bool:
+    ...
+
+  def as_level(self) -> int:
+    ...
+
+  def is_pair(self) -> bool:
+    ...
+
+  def as_pair(self) -> dict:
+    ...
+
+  def is_off(self) -> bool:
+    ...
+
+
+def shape_area(shape: Shape) -> float:
+  ...
+
+
+def unit_circle() -> Shape:
+  ...
+
+
+def read(sensor: int) -> Reading:
+  ...
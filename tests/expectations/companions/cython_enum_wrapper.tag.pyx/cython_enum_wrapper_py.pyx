@@ -0,0 +1,91 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cimport cython_enum_wrapper.tag
+
+
+cdef class Shape:
+  """A shape."""
+
+  cdef cython_enum_wrapper.tag.Shape value
+
+  @staticmethod
+  cdef Shape from_value(cython_enum_wrapper.tag.Shape value):
+    cdef Shape wrapper
+    wrapper = Shape.__new__(Shape)
+    wrapper.value = value
+    return wrapper
+
+  @property
+  def tag(self):
+    return self.value.tag
+
+  def is_circle(self):
+    return self.value.tag == cython_enum_wrapper.tag.Circle
+
+  def as_circle(self):
+    if self.value.tag != cython_enum_wrapper.tag.Circle:
+      raise ValueError("Shape is not Circle")
+    return self.value.circle
+
+  def is_rect(self):
+    return self.value.tag == cython_enum_wrapper.tag.Rect
+
+  def as_rect(self):
+    if self.value.tag != cython_enum_wrapper.tag.Rect:
+      raise ValueError("Shape is not Rect")
+    return self.value.rect
+
+  def is_empty(self):
+    return self.value.tag == cython_enum_wrapper.tag.Empty
+
+  def is_custom(self):
+    return self.value.tag == cython_enum_wrapper.tag.Custom
+
+
+cdef class Reading:
+  cdef cython_enum_wrapper.tag.Reading value
+
+  @staticmethod
+  cdef Reading from_value(cython_enum_wrapper.tag.Reading value):
+    cdef Reading wrapper
+    wrapper = Reading.__new__(Reading)
+    wrapper.value = value
+    return wrapper
+
+  @property
+  def tag(self):
+    return self.value.tag
+
+  def is_level(self):
+    return self.value.tag == cython_enum_wrapper.tag.Level
+
+  def as_level(self):
+    if self.value.tag != cython_enum_wrapper.tag.Level:
+      raise ValueError("Reading is not Level")
+    return self.value.level
+
+  def is_pair(self):
+    return self.value.tag == cython_enum_wrapper.tag.Pair
+
+  def as_pair(self):
+    if self.value.tag != cython_enum_wrapper.tag.Pair:
+      raise ValueError("Reading is not Pair")
+    return self.value.pair
+
+  def is_off(self):
+    return self.value.tag == cython_enum_wrapper.tag.Off
+
+
+def shape_area(Shape shape not None):
+  return cython_enum_wrapper.tag.shape_area(shape.value)
+
+
+def unit_circle():
+  cdef cython_enum_wrapper.tag.Shape result
+  with nogil:
+    result = cython_enum_wrapper.tag.unit_circle()
+  return Shape.from_value(result)
+
+
+def read(uint32_t sensor):
+  return Reading.from_value(cython_enum_wrapper.tag.read(sensor))
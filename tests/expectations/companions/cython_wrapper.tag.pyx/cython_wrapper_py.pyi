@@ -0,0 +1,41 @@
+import typing
+
+
+class Lexer:
+  """The destructor of `Parser` can't free a `Lexer`."""
+
+
+class Parser:
+  ...
+
+
+class Token:
+  ...
+
+
+def parser_new(strict: bool) -> typing.Optional[Parser]:
+  ...
+
+
+def parser_next(parser: Parser, limit: int) -> typing.Optional[Token]:
+  """Parses the next token."""
+
+
+def parser_reset(parser: Parser) -> None:
+  ...
+
+
+def token_len(token: Token) -> int:
+  ...
+
+
+def parser_current(parser: Parser) -> typing.Optional[Token]:
+  """The token is borrowed from the parser, which must outlive it."""
+
+
+def parser_into_lexer(parser: Parser) -> typing.Optional[Lexer]:
+  ...
+
+
+def lexer_run(lexer: Lexer, steps: int) -> int:
+  ...
@@ -0,0 +1,105 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cimport cython_wrapper.tag
+
+
+cdef class Lexer:
+  cdef cython_wrapper.tag.Lexer *ptr
+  cdef bint owned
+  cdef object _owner
+
+  @staticmethod
+  cdef Lexer from_ptr(cython_wrapper.tag.Lexer *ptr, bint owned, object owner=None):
+    cdef Lexer wrapper
+    if ptr == NULL:
+      return None
+    wrapper = Lexer.__new__(Lexer)
+    wrapper.ptr = ptr
+    wrapper.owned = owned
+    wrapper._owner = owner
+    return wrapper
+
+
+cdef class Parser:
+  cdef cython_wrapper.tag.Parser *ptr
+  cdef bint owned
+  cdef object _owner
+
+  def __dealloc__(self):
+    if self.owned and self.ptr != NULL:
+      cython_wrapper.tag.parser_free(self.ptr)
+
+  @staticmethod
+  cdef Parser from_ptr(cython_wrapper.tag.Parser *ptr, bint owned, object owner=None):
+    cdef Parser wrapper
+    if ptr == NULL:
+      return None
+    wrapper = Parser.__new__(Parser)
+    wrapper.ptr = ptr
+    wrapper.owned = owned
+    wrapper._owner = owner
+    return wrapper
+
+
+cdef class Token:
+  cdef cython_wrapper.tag.Token *ptr
+  cdef bint owned
+  cdef object _owner
+
+  @staticmethod
+  cdef Token from_ptr(cython_wrapper.tag.Token *ptr, bint owned, object owner=None):
+    cdef Token wrapper
+    if ptr == NULL:
+      return None
+    wrapper = Token.__new__(Token)
+    wrapper.ptr = ptr
+    wrapper.owned = owned
+    wrapper._owner = owner
+    return wrapper
+
+
+def parser_new(bint strict):
+  return Parser.from_ptr(cython_wrapper.tag.parser_new(strict), True)
+
+
+def parser_next(Parser parser not None, uint32_t limit):
+  """Parses the next token."""
+  cdef cython_wrapper.tag.Token *result
+  with nogil:
+    result = <cython_wrapper.tag.Token *>cython_wrapper.tag.parser_next(parser.ptr, limit)
+  return Token.from_ptr(result, False)
+
+
+def parser_reset(Parser parser not None):
+  with nogil:
+    cython_wrapper.tag.parser_reset(parser.ptr)
+
+
+def token_len(Token token not None):
+  return cython_wrapper.tag.token_len(token.ptr)
+
+
+def parser_current(Parser parser not None):
+  """The token is borrowed from the parser, which must outlive it."""
+  return Token.from_ptr(cython_wrapper.tag.parser_current(parser.ptr), False, parser)
+
+
+def parser_into_lexer(Parser parser not None):
+  cdef cython_wrapper.tag.Lexer *result
+  if not parser.owned:
+    raise ValueError("parser is borrowed, so it can't be given away")
+  result = cython_wrapper.tag.parser_into_lexer(parser.ptr)
+  parser.owned = False
+  parser.ptr = NULL
+  return Lexer.from_ptr(result, True)
+
+
+def lexer_run(Lexer lexer not None, uint32_t steps):
+  cdef uint32_t result
+  if not lexer.owned:
+    raise ValueError("lexer is borrowed, so it can't be given away")
+  with nogil:
+    result = cython_wrapper.tag.lexer_run(lexer.ptr, steps)
+  lexer.owned = False
+  lexer.ptr = NULL
+  return result
@@ -0,0 +1,15 @@
+#ifndef MYLIB_API
+#if defined(MYLIB_STATIC)
+#define MYLIB_API
+#elif defined(_WIN32) || defined(__CYGWIN__)
+#if defined(MYLIB_API_BUILD)
+#define MYLIB_API __declspec(dllexport)
+#else
+#define MYLIB_API __declspec(dllimport)
+#endif
+#elif defined(__GNUC__) && __GNUC__ >= 4
+#define MYLIB_API __attribute__((visibility("default")))
+#else
+#define MYLIB_API
+#endif
+#endif // MYLIB_API
@@ -0,0 +1,40 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cimport ownership
+
+
+cdef class Buffer:
+  cdef ownership.Buffer *ptr
+  cdef bint owned
+  cdef object _owner
+
+  def __dealloc__(self):
+    if self.owned and self.ptr != NULL:
+      ownership.buffer_free(self.ptr)
+
+  @staticmethod
+  cdef Buffer from_ptr(ownership.Buffer *ptr, bint owned, object owner=None):
+    cdef Buffer wrapper
+    if ptr == NULL:
+      return None
+    wrapper = Buffer.__new__(Buffer)
+    wrapper.ptr = ptr
+    wrapper.owned = owned
+    wrapper._owner = owner
+    return wrapper
+
+
+def buffer_new():
+  """Creates a new buffer."""
+  return Buffer.from_ptr(ownership.buffer_new(), True)
+
+
+def buffer_append(Buffer buffer not None, Buffer other not None):
+  """Appends `other` to `buffer`, returning the resulting buffer."""
+  cdef ownership.Buffer *result
+  if not buffer.owned:
+    raise ValueError("buffer is borrowed, so it can't be given away")
+  result = ownership.buffer_append(buffer.ptr, other.ptr)
+  buffer.owned = False
+  buffer.ptr = NULL
+  return Buffer.from_ptr(result, True)
@@ -0,0 +1,28 @@
+import enum
+
+
+class FillRule(enum.IntEnum):
+  """How shapes are filled."""
+
+  # The non-zero winding rule.
+  NonZero = 0
+  EvenOdd = 4
+  None_ = 5
+
+
+class Level(enum.IntEnum):
+  Low = -1
+  High = (1 << 4)
+  Higher = High + 1
+
+
+class Permissions(enum.IntFlag):
+  Read = 1
+  Write = 2
+  Execute = 4
+
+
+class Shape_Tag(enum.IntEnum):
+  Circle = 0
+  Square = 1
+  Nothing = 2
@@ -0,0 +1,42 @@
+#pragma once
+
+#include "result_out_param.cpp"
+#include <cstdint>
+#include <stdexcept>
+#include <string>
+
+struct Failure : std::runtime_error {
+  int64_t code;
+
+  Failure(const char *function, int64_t code)
+    : std::runtime_error(std::string(function) + " failed with error code " +
+                         std::to_string(code)),
+      code(code) {}
+};
+
+inline Point parse_point_or_throw(const uint8_t *input, uintptr_t len) {
+  Point out;
+  auto code = parse_point(input, len, &out);
+  if (code != Error::Ok) {
+    throw Failure("parse_point", static_cast<int64_t>(code));
+  }
+  return out;
+}
+
+inline Handle *open_or_throw(uint32_t id) {
+  Handle *handle;
+  auto code = open(id, &handle);
+  if (code != 0) {
+    throw Failure("open", static_cast<int64_t>(code));
+  }
+  return handle;
+}
+
+inline uint32_t handle_len_or_throw(const Handle *handle) {
+  uint32_t len;
+  auto code = handle_len(handle, &len);
+  if (code != Error::Ok) {
+    throw Failure("handle_len", static_cast<int64_t>(code));
+  }
+  return len;
+}
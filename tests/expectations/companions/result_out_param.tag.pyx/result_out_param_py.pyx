@@ -0,0 +1,38 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cimport result_out_param.tag
+
+
+cdef class Handle:
+  cdef result_out_param.tag.Handle *ptr
+  cdef bint owned
+  cdef object _owner
+
+  @staticmethod
+  cdef Handle from_ptr(result_out_param.tag.Handle *ptr, bint owned, object owner=None):
+    cdef Handle wrapper
+    if ptr == NULL:
+      return None
+    wrapper = Handle.__new__(Handle)
+    wrapper.ptr = ptr
+    wrapper.owned = owned
+    wrapper._owner = owner
+    return wrapper
+
+
+def open(uint32_t id):
+  cdef int32_t code
+  cdef result_out_param.tag.Handle *handle
+  code = result_out_param.tag.open(id, &handle)
+  if code != 0:
+    raise RuntimeError("open failed with error code %d" % code)
+  return Handle.from_ptr(handle, False)
+
+
+def handle_len(Handle handle not None):
+  cdef result_out_param.tag.Error code
+  cdef uint32_t len
+  code = result_out_param.tag.handle_len(handle.ptr, &len)
+  if code != result_out_param.tag.Ok:
+    raise RuntimeError("handle_len failed with error code %d" % code)
+  return len
@@ -0,0 +1,22 @@
+#ifndef SPLIT_BY_MODULE_BOTH_COLORS_C
+#define SPLIT_BY_MODULE_BOTH_COLORS_C
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "split_by_module.both_shapes.c"
+
+enum Color {
+  Red,
+  Green,
+  Blue,
+};
+typedef uint8_t Color;
+
+typedef struct Fill {
+  Color color;
+  const struct Circle *shape;
+} Fill;
+
+#endif /* SPLIT_BY_MODULE_BOTH_COLORS_C */
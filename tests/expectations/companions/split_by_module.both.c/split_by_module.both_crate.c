@@ -0,0 +1,13 @@
+#ifndef SPLIT_BY_MODULE_BOTH_CRATE_C
+#define SPLIT_BY_MODULE_BOTH_CRATE_C
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "split_by_module.both_colors.c"
+#include "split_by_module.both_shapes.c"
+
+void draw(struct Circle circle, struct Fill fill);
+
+#endif /* SPLIT_BY_MODULE_BOTH_CRATE_C */
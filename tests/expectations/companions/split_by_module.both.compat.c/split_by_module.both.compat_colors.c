@@ -0,0 +1,28 @@
+#ifndef SPLIT_BY_MODULE_BOTH_COMPAT_COLORS_C
+#define SPLIT_BY_MODULE_BOTH_COMPAT_COLORS_C
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "split_by_module.both.compat_shapes.c"
+
+enum Color
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Red,
+  Green,
+  Blue,
+};
+#ifndef __cplusplus
+typedef uint8_t Color;
+#endif // __cplusplus
+
+typedef struct Fill {
+  Color color;
+  const struct Circle *shape;
+} Fill;
+
+#endif /* SPLIT_BY_MODULE_BOTH_COMPAT_COLORS_C */
@@ -0,0 +1,21 @@
+#ifndef SPLIT_BY_MODULE_BOTH_COMPAT_CRATE_C
+#define SPLIT_BY_MODULE_BOTH_COMPAT_CRATE_C
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "split_by_module.both.compat_colors.c"
+#include "split_by_module.both.compat_shapes.c"
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void draw(struct Circle circle, struct Fill fill);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* SPLIT_BY_MODULE_BOTH_COMPAT_CRATE_C */
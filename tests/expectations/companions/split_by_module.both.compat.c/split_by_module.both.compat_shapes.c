@@ -0,0 +1,19 @@
+#ifndef SPLIT_BY_MODULE_BOTH_COMPAT_SHAPES_C
+#define SPLIT_BY_MODULE_BOTH_COMPAT_SHAPES_C
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+typedef struct Circle {
+  struct Point center;
+  float radius;
+} Circle;
+
+#endif /* SPLIT_BY_MODULE_BOTH_COMPAT_SHAPES_C */
@@ -0,0 +1,13 @@
+#ifndef SPLIT_BY_MODULE_CRATE_C
+#define SPLIT_BY_MODULE_CRATE_C
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "split_by_module_colors.c"
+#include "split_by_module_shapes.c"
+
+void draw(Circle circle, Fill fill);
+
+#endif /* SPLIT_BY_MODULE_CRATE_C */
@@ -0,0 +1,19 @@
+#ifndef SPLIT_BY_MODULE_SHAPES_C
+#define SPLIT_BY_MODULE_SHAPES_C
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+typedef struct {
+  Point center;
+  float radius;
+} Circle;
+
+#endif /* SPLIT_BY_MODULE_SHAPES_C */
@@ -0,0 +1,21 @@
+#ifndef SPLIT_BY_MODULE_COMPAT_CRATE_C
+#define SPLIT_BY_MODULE_COMPAT_CRATE_C
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "split_by_module.compat_colors.c"
+#include "split_by_module.compat_shapes.c"
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void draw(Circle circle, Fill fill);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* SPLIT_BY_MODULE_COMPAT_CRATE_C */
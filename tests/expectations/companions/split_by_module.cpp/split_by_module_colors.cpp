@@ -0,0 +1,22 @@
+#ifndef SPLIT_BY_MODULE_COLORS_CPP
+#define SPLIT_BY_MODULE_COLORS_CPP
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include "split_by_module_shapes.cpp"
+
+enum class Color : uint8_t {
+  Red,
+  Green,
+  Blue,
+};
+
+struct Fill {
+  Color color;
+  const Circle *shape;
+};
+
+#endif // SPLIT_BY_MODULE_COLORS_CPP
@@ -0,0 +1,18 @@
+#ifndef SPLIT_BY_MODULE_CRATE_CPP
+#define SPLIT_BY_MODULE_CRATE_CPP
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include "split_by_module_colors.cpp"
+#include "split_by_module_shapes.cpp"
+
+extern "C" {
+
+void draw(Circle circle, Fill fill);
+
+} // extern "C"
+
+#endif // SPLIT_BY_MODULE_CRATE_CPP
@@ -0,0 +1,20 @@
+#ifndef SPLIT_BY_MODULE_SHAPES_CPP
+#define SPLIT_BY_MODULE_SHAPES_CPP
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Circle {
+  Point center;
+  float radius;
+};
+
+#endif // SPLIT_BY_MODULE_SHAPES_CPP
@@ -0,0 +1,22 @@
+#ifndef SPLIT_BY_MODULE_TAG_COLORS_C
+#define SPLIT_BY_MODULE_TAG_COLORS_C
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "split_by_module.tag_shapes.c"
+
+enum Color {
+  Red,
+  Green,
+  Blue,
+};
+typedef uint8_t Color;
+
+struct Fill {
+  Color color;
+  const struct Circle *shape;
+};
+
+#endif /* SPLIT_BY_MODULE_TAG_COLORS_C */
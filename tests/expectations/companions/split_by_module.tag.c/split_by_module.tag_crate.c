@@ -0,0 +1,13 @@
+#ifndef SPLIT_BY_MODULE_TAG_CRATE_C
+#define SPLIT_BY_MODULE_TAG_CRATE_C
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "split_by_module.tag_colors.c"
+#include "split_by_module.tag_shapes.c"
+
+void draw(struct Circle circle, struct Fill fill);
+
+#endif /* SPLIT_BY_MODULE_TAG_CRATE_C */
@@ -0,0 +1,19 @@
+#ifndef SPLIT_BY_MODULE_TAG_SHAPES_C
+#define SPLIT_BY_MODULE_TAG_SHAPES_C
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Circle {
+  struct Point center;
+  float radius;
+};
+
+#endif /* SPLIT_BY_MODULE_TAG_SHAPES_C */
@@ -0,0 +1,28 @@
+#ifndef SPLIT_BY_MODULE_TAG_COMPAT_COLORS_C
+#define SPLIT_BY_MODULE_TAG_COMPAT_COLORS_C
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "split_by_module.tag.compat_shapes.c"
+
+enum Color
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Red,
+  Green,
+  Blue,
+};
+#ifndef __cplusplus
+typedef uint8_t Color;
+#endif // __cplusplus
+
+struct Fill {
+  Color color;
+  const struct Circle *shape;
+};
+
+#endif /* SPLIT_BY_MODULE_TAG_COMPAT_COLORS_C */
@@ -0,0 +1,18 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+from split_by_module.tag_shapes cimport *
+
+cdef extern from *:
+
+  cdef enum:
+    Red,
+    Green,
+    Blue,
+  ctypedef uint8_t Color;
+
+  cdef struct Fill:
+    Color color;
+    const Circle *shape;
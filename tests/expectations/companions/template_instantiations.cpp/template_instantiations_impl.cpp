@@ -0,0 +1,11 @@
+#include "template_instantiations.cpp"
+
+template union Either<float>;
+#if defined(DEFINE_EXTRA)
+template struct Extra<int32_t>;
+#endif
+template struct Maybe<uint8_t>;
+template struct Pair<double, double>;
+template struct Pair<int32_t, int32_t>;
+template struct Pair<uint8_t, float>;
+template struct Wrapper<double>;
@@ -0,0 +1,7 @@
+#ifndef UMBRELLA_HEADER_ALL_H
+#define UMBRELLA_HEADER_ALL_H
+
+#include "stdint.h"
+#include "umbrella_header.both.c"
+
+#endif /* UMBRELLA_HEADER_ALL_H */
@@ -0,0 +1,19 @@
+/* Generated from a JSON config. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum JsonShape {
+  JSON_SHAPE_CIRCLE,
+  JSON_SHAPE_SQUARE,
+};
+typedef uint8_t JsonShape;
+
+typedef struct JsonPoint {
+  int32_t x;
+  int32_t y;
+} JsonPoint;
+
+void draw(struct JsonPoint point, JsonShape shape);
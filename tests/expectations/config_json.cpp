@@ -0,0 +1,23 @@
+/* Generated from a JSON config. */
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class JsonShape : uint8_t {
+  JSON_SHAPE_CIRCLE,
+  JSON_SHAPE_SQUARE,
+};
+
+struct JsonPoint {
+  int32_t x;
+  int32_t y;
+};
+
+extern "C" {
+
+void draw(JsonPoint point, JsonShape shape);
+
+} // extern "C"
@@ -0,0 +1,33 @@
+/* Generated from a JSON config. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum JsonShape
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  JSON_SHAPE_CIRCLE,
+  JSON_SHAPE_SQUARE,
+};
+#ifndef __cplusplus
+typedef uint8_t JsonShape;
+#endif // __cplusplus
+
+struct JsonPoint {
+  int32_t x;
+  int32_t y;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void draw(struct JsonPoint point, JsonShape shape);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
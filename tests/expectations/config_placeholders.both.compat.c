@@ -0,0 +1,26 @@
+/* Bindings of config_placeholders 1.2.3 */
+
+#ifndef config_placeholders_H
+#define config_placeholders_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Version {
+  uint32_t major;
+  uint32_t minor;
+} Version;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Version version(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* config_placeholders_H */
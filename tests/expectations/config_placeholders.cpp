@@ -0,0 +1,23 @@
+/* Bindings of config_placeholders 1.2.3 */
+
+#ifndef config_placeholders_H
+#define config_placeholders_H
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Version {
+  uint32_t major;
+  uint32_t minor;
+};
+
+extern "C" {
+
+Version version();
+
+} // extern "C"
+
+#endif // config_placeholders_H
@@ -0,0 +1,15 @@
+/* Bindings of config_placeholders 1.2.3 */
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Version:
+    uint32_t major;
+    uint32_t minor;
+
+  Version version();
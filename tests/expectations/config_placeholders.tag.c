@@ -0,0 +1,18 @@
+/* Bindings of config_placeholders 1.2.3 */
+
+#ifndef config_placeholders_H
+#define config_placeholders_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Version {
+  uint32_t major;
+  uint32_t minor;
+};
+
+struct Version version(void);
+
+#endif /* config_placeholders_H */
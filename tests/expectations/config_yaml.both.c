@@ -0,0 +1,19 @@
+/* Generated from a YAML config. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum YamlShape {
+  YAML_SHAPE_CIRCLE,
+  YAML_SHAPE_SQUARE,
+};
+typedef uint8_t YamlShape;
+
+typedef struct YamlPoint {
+  int32_t x;
+  int32_t y;
+} YamlPoint;
+
+void draw(struct YamlPoint point, YamlShape shape);
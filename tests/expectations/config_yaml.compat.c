@@ -0,0 +1,33 @@
+/* Generated from a YAML config. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum YamlShape
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  YAML_SHAPE_CIRCLE,
+  YAML_SHAPE_SQUARE,
+};
+#ifndef __cplusplus
+typedef uint8_t YamlShape;
+#endif // __cplusplus
+
+typedef struct {
+  int32_t x;
+  int32_t y;
+} YamlPoint;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void draw(YamlPoint point, YamlShape shape);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,23 @@
+/* Generated from a YAML config. */
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class YamlShape : uint8_t {
+  YAML_SHAPE_CIRCLE,
+  YAML_SHAPE_SQUARE,
+};
+
+struct YamlPoint {
+  int32_t x;
+  int32_t y;
+};
+
+extern "C" {
+
+void draw(YamlPoint point, YamlShape shape);
+
+} // extern "C"
@@ -0,0 +1,20 @@
+/* Generated from a YAML config. */
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum:
+    YAML_SHAPE_CIRCLE,
+    YAML_SHAPE_SQUARE,
+  ctypedef uint8_t YamlShape;
+
+  cdef struct YamlPoint:
+    int32_t x;
+    int32_t y;
+
+  void draw(YamlPoint point, YamlShape shape);
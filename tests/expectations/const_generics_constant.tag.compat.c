@@ -14,7 +14,7 @@ typedef struct FixedPoint_FONT_WEIGHT_FRACTION_BITS FontWeightFixedPoint;
 struct FontWeight {
   FontWeightFixedPoint _0;
 };
-#define FontWeight_NORMAL (FontWeight){ ._0 = (FontWeightFixedPoint){ .value = (400 << FONT_WEIGHT_FRACTION_BITS) } }
+#define FontWeight_NORMAL (struct FontWeight){ ._0 = (struct FontWeightFixedPoint){ .value = (400 << FONT_WEIGHT_FRACTION_BITS) } }
 
 #ifdef __cplusplus
 extern "C" {
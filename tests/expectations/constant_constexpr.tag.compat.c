@@ -16,4 +16,4 @@ struct Foo {
 };
 #define Foo_CONSTANT_I64_BODY 216
 
-#define SomeFoo (Foo){ .x = 99 }
+#define SomeFoo (struct Foo){ .x = 99 }
@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+static const int64_t CONSTANT_I64 = 216;
+
+static const float CONSTANT_FLOAT32 = 312.292;
+
+static const uint32_t DELIMITER = ':';
+
+typedef struct {
+  int32_t x;
+} Foo;
+
+static const Foo SOME_FOO = (Foo){ .x = 99 };
+
+void root(Foo foo);
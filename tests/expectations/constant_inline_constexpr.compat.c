@@ -0,0 +1,34 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+namespace constants {
+#endif // __cplusplus
+
+static const int64_t CONSTANT_I64 = 216;
+
+static const float CONSTANT_FLOAT32 = 312.292;
+
+static const uint32_t DELIMITER = ':';
+
+typedef struct {
+  int32_t x;
+} Foo;
+
+static const Foo SOME_FOO = (Foo){ .x = 99 };
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Foo foo);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#ifdef __cplusplus
+} // namespace constants
+#endif // __cplusplus
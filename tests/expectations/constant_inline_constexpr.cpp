@@ -0,0 +1,27 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+namespace constants {
+
+inline constexpr int64_t CONSTANT_I64 = 216;
+
+inline constexpr float CONSTANT_FLOAT32 = 312.292;
+
+inline constexpr uint32_t DELIMITER = ':';
+
+struct Foo {
+  int32_t x;
+};
+
+inline constexpr Foo SOME_FOO = Foo{ /* .x = */ 99 };
+
+extern "C" {
+
+void root(Foo foo);
+
+} // extern "C"
+
+} // namespace constants
@@ -0,0 +1,19 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define NO_DATA (uint8_t*)0
+
+typedef struct Node {
+  int32_t value;
+  uint8_t *data;
+} Node;
+
+#define NO_NODE (const Node*)0
+
+extern const struct Node *ROOT;
+
+const struct Node *node_first(const struct Node *const *nodes);
+
+uint8_t *const *node_data(const struct Node *node);
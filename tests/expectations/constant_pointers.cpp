@@ -0,0 +1,24 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+#define NO_DATA (uint8_t*)0
+
+struct Node {
+  int32_t value;
+  uint8_t *data;
+};
+
+#define NO_NODE (const Node*)0
+
+extern "C" {
+
+extern const Node *ROOT;
+
+const Node *node_first(const Node *const *nodes);
+
+uint8_t *const *node_data(const Node *node);
+
+} // extern "C"
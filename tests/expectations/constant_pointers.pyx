@@ -0,0 +1,21 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  uint8_t *const NO_DATA # = <uint8_t*>0
+
+  ctypedef struct Node:
+    int32_t value;
+    uint8_t *data;
+
+  const Node *const NO_NODE # = <const Node*>0
+
+  extern const Node *ROOT;
+
+  const Node *node_first(const Node *const *nodes);
+
+  uint8_t *const *node_data(const Node *node);
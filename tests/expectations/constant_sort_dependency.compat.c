@@ -0,0 +1,12 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define A 0
+
+#define B (A + 1)
+
+#define C (B + 1)
+
+#define D 4
@@ -0,0 +1,13 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const uint8_t A = 0;
+
+constexpr static const uint8_t B = (A + 1);
+
+constexpr static const uint8_t C = (B + 1);
+
+constexpr static const uint8_t D = 4;
@@ -0,0 +1,15 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uint8_t A # = 0
+
+  const uint8_t B # = (A + 1)
+
+  const uint8_t C # = (B + 1)
+
+  const uint8_t D # = 4
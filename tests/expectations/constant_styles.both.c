@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Uses the style from the config.
+ */
+enum { MAX_ITEMS = 64 };
+
+enum { BUFFER_SIZE = 4096 };
+
+/**
+ * Not an integer, so it falls back to the default style.
+ */
+#define RATIO 1.5
+
+static const double SCALE = 2.0;
+
+static const uint8_t FLAGS = 15;
+
+typedef struct Point {
+  int32_t x;
+  int32_t y;
+} Point;
+
+static const struct Point ORIGIN = (Point){ .x = 0, .y = 0 };
+
+void root(struct Point point);
@@ -0,0 +1,30 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/// Uses the style from the config.
+enum { MAX_ITEMS = 64 };
+
+enum { BUFFER_SIZE = 4096 };
+
+/// Not an integer, so it falls back to the default style.
+constexpr static const float RATIO = 1.5;
+
+static const double SCALE = 2.0;
+
+constexpr static const uint8_t FLAGS = 15;
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+static const Point ORIGIN = Point{ /* .x = */ 0, /* .y = */ 0 };
+
+extern "C" {
+
+void root(Point point);
+
+} // extern "C"
@@ -0,0 +1,27 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  # Uses the style from the config.
+  const uint32_t MAX_ITEMS # = 64
+
+  const int32_t BUFFER_SIZE # = 4096
+
+  # Not an integer, so it falls back to the default style.
+  const float RATIO # = 1.5
+
+  const double SCALE # = 2.0
+
+  const uint8_t FLAGS # = 15
+
+  cdef struct Point:
+    int32_t x;
+    int32_t y;
+
+  const Point ORIGIN # = <Point>{ 0, 0 }
+
+  void root(Point point);
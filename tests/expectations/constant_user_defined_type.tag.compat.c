@@ -13,7 +13,7 @@ struct S {
 
 typedef uint8_t A;
 
-#define C1 (S){ .field = 0 }
+#define C1 (struct S){ .field = 0 }
 
 #define C2 V
 
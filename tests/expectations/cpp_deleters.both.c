@@ -0,0 +1,22 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if defined(WITH_DOCUMENTS)
+typedef struct Document Document;
+#endif
+
+typedef struct Parser Parser;
+
+struct Parser *parser_new(void);
+
+void parser_free(struct Parser *parser);
+
+#if defined(WITH_DOCUMENTS)
+struct Document *parser_parse(struct Parser *parser);
+#endif
+
+#if defined(WITH_DOCUMENTS)
+void document_free(struct Document *document);
+#endif
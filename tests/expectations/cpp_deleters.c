@@ -0,0 +1,22 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if defined(WITH_DOCUMENTS)
+typedef struct Document Document;
+#endif
+
+typedef struct Parser Parser;
+
+Parser *parser_new(void);
+
+void parser_free(Parser *parser);
+
+#if defined(WITH_DOCUMENTS)
+Document *parser_parse(Parser *parser);
+#endif
+
+#if defined(WITH_DOCUMENTS)
+void document_free(Document *document);
+#endif
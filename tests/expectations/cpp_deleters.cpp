@@ -0,0 +1,46 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include <memory>
+
+#if defined(WITH_DOCUMENTS)
+struct Document;
+#endif
+
+struct Parser;
+
+extern "C" {
+
+Parser *parser_new();
+
+void parser_free(Parser *parser);
+
+#if defined(WITH_DOCUMENTS)
+Document *parser_parse(Parser *parser);
+#endif
+
+#if defined(WITH_DOCUMENTS)
+void document_free(Document *document);
+#endif
+
+} // extern "C"
+
+#if defined(WITH_DOCUMENTS)
+struct DocumentDeleter {
+  void operator()(Document *ptr) const {
+    document_free(ptr);
+  }
+};
+
+using DocumentPtr = std::unique_ptr<Document, DocumentDeleter>;
+#endif
+
+struct ParserDeleter {
+  void operator()(Parser *ptr) const {
+    parser_free(ptr);
+  }
+};
+
+using ParserPtr = std::unique_ptr<Parser, ParserDeleter>;
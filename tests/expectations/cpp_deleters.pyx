@@ -0,0 +1,24 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  IF WITH_DOCUMENTS:
+    ctypedef struct Document:
+      pass
+
+  ctypedef struct Parser:
+    pass
+
+  Parser *parser_new();
+
+  void parser_free(Parser *parser);
+
+  IF WITH_DOCUMENTS:
+    Document *parser_parse(Parser *parser);
+
+  IF WITH_DOCUMENTS:
+    void document_free(Document *document);
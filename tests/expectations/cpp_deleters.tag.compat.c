@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if defined(WITH_DOCUMENTS)
+struct Document;
+#endif
+
+struct Parser;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Parser *parser_new(void);
+
+void parser_free(struct Parser *parser);
+
+#if defined(WITH_DOCUMENTS)
+struct Document *parser_parse(struct Parser *parser);
+#endif
+
+#if defined(WITH_DOCUMENTS)
+void document_free(struct Document *document);
+#endif
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
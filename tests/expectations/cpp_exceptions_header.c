@@ -0,0 +1,17 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Status {
+  Ok,
+  NotFound,
+  Denied,
+};
+typedef int32_t Status;
+
+int32_t open_file(const uint8_t *path);
+
+Status check_access(uint32_t user);
+
+int32_t not_an_error_code(void);
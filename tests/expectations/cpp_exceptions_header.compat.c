@@ -0,0 +1,31 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Status
+#ifdef __cplusplus
+  : int32_t
+#endif // __cplusplus
+ {
+  Ok,
+  NotFound,
+  Denied,
+};
+#ifndef __cplusplus
+typedef int32_t Status;
+#endif // __cplusplus
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+int32_t open_file(const uint8_t *path);
+
+Status check_access(uint32_t user);
+
+int32_t not_an_error_code(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
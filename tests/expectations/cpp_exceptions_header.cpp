@@ -0,0 +1,21 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Status : int32_t {
+  Ok,
+  NotFound,
+  Denied,
+};
+
+extern "C" {
+
+int32_t open_file(const uint8_t *path);
+
+Status check_access(uint32_t user);
+
+int32_t not_an_error_code();
+
+} // extern "C"
@@ -0,0 +1,19 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum:
+    Ok,
+    NotFound,
+    Denied,
+  ctypedef int32_t Status;
+
+  int32_t open_file(const uint8_t *path);
+
+  Status check_access(uint32_t user);
+
+  int32_t not_an_error_code();
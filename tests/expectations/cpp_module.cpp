@@ -0,0 +1,35 @@
+module;
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+export module geometry;
+
+export {
+
+namespace geometry {
+
+constexpr inline const uint32_t LIMIT = 10;
+
+enum class Direction : uint8_t {
+  Up,
+  Down,
+};
+
+struct Point {
+  float x;
+  float y;
+};
+
+extern "C" {
+
+Point step(Point point, Direction direction);
+
+} // extern "C"
+
+} // namespace geometry
+
+} // export
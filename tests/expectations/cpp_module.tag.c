@@ -0,0 +1,19 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define LIMIT 10
+
+enum Direction {
+  Up,
+  Down,
+};
+typedef uint8_t Direction;
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Point step(struct Point point, Direction direction);
@@ -0,0 +1,41 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+namespace geometry {
+#endif // __cplusplus
+
+#define LIMIT 10
+
+enum Direction
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Up,
+  Down,
+};
+#ifndef __cplusplus
+typedef uint8_t Direction;
+#endif // __cplusplus
+
+struct Point {
+  float x;
+  float y;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Point step(struct Point point, Direction direction);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#ifdef __cplusplus
+} // namespace geometry
+#endif // __cplusplus
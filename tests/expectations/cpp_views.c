@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+Point centroid(const Point *points, uintptr_t count);
+
+void fill(int32_t *values, uint32_t len, int32_t value);
+
+bool set_name(uint64_t id,
+              const char *name,
+              uintptr_t name_len,
+              const uint8_t *label,
+              uintptr_t label_len);
+
+void print_all(const char *const *names, uintptr_t count);
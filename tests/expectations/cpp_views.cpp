@@ -0,0 +1,52 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#if __cplusplus >= 202002L
+#include <span>
+#endif
+#include <string_view>
+
+struct Point {
+  float x;
+  float y;
+};
+
+extern "C" {
+
+Point centroid(const Point *points, uintptr_t count);
+
+void fill(int32_t *values, uint32_t len, int32_t value);
+
+bool set_name(uint64_t id,
+              const char *name,
+              uintptr_t name_len,
+              const uint8_t *label,
+              uintptr_t label_len);
+
+void print_all(const char *const *names, uintptr_t count);
+
+} // extern "C"
+
+#if __cplusplus >= 202002L
+inline Point centroid(std::span<const Point> points) {
+  return centroid(points.data(), points.size());
+}
+#endif
+
+#if __cplusplus >= 202002L
+inline void fill(std::span<int32_t> values, int32_t value) {
+  return fill(values.data(), values.size(), value);
+}
+#endif
+
+inline bool set_name(uint64_t id, std::string_view name, std::string_view label) {
+  return set_name(id, name.data(), name.size(), reinterpret_cast<const uint8_t *>(label.data()), label.size());
+}
+
+#if __cplusplus >= 202002L
+inline void print_all(std::span<const char* const> names) {
+  return print_all(names.data(), names.size());
+}
+#endif
@@ -0,0 +1,23 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Point:
+    float x;
+    float y;
+
+  Point centroid(const Point *points, uintptr_t count);
+
+  void fill(int32_t *values, uint32_t len, int32_t value);
+
+  bool set_name(uint64_t id,
+                const char *name,
+                uintptr_t name_len,
+                const uint8_t *label,
+                uintptr_t label_len);
+
+  void print_all(const char *const *names, uintptr_t count);
@@ -0,0 +1,22 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Unit {
+  Pixels,
+  Points,
+};
+typedef uint8_t Unit;
+
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+typedef struct Line {
+  struct Point a;
+  struct Point b;
+} Line;
+
+float line_length(const struct Line *line, Unit unit);
@@ -0,0 +1,22 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Unit {
+  Pixels,
+  Points,
+};
+typedef uint8_t Unit;
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+typedef struct {
+  Point a;
+  Point b;
+} Line;
+
+float line_length(const Line *line, Unit unit);
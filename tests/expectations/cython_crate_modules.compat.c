@@ -0,0 +1,36 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Unit
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Pixels,
+  Points,
+};
+#ifndef __cplusplus
+typedef uint8_t Unit;
+#endif // __cplusplus
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+typedef struct {
+  Point a;
+  Point b;
+} Line;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+float line_length(const Line *line, Unit unit);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
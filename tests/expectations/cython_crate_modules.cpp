@@ -0,0 +1,26 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Unit : uint8_t {
+  Pixels,
+  Points,
+};
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Line {
+  Point a;
+  Point b;
+};
+
+extern "C" {
+
+float line_length(const Line *line, Unit unit);
+
+} // extern "C"
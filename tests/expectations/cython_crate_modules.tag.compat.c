@@ -0,0 +1,36 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Unit
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Pixels,
+  Points,
+};
+#ifndef __cplusplus
+typedef uint8_t Unit;
+#endif // __cplusplus
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Line {
+  struct Point a;
+  struct Point b;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+float line_length(const struct Line *line, Unit unit);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
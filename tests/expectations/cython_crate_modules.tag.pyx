@@ -0,0 +1,14 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+from dep.bindings cimport Unit, Point
+
+cdef extern from *:
+
+  cdef struct Line:
+    Point a;
+    Point b;
+
+  float line_length(const Line *line, Unit unit);
@@ -0,0 +1,74 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A shape.
+ */
+typedef enum Shape_Tag {
+  Circle,
+  Rect,
+  Empty,
+  Custom,
+} Shape_Tag;
+
+typedef struct Rect_Body {
+  float w;
+  float h;
+} Rect_Body;
+
+typedef struct Shape {
+  Shape_Tag tag;
+  union {
+    struct {
+      float circle;
+    };
+    Rect_Body rect;
+    struct {
+      const uint8_t *custom;
+    };
+  };
+} Shape;
+
+enum Reading_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Level,
+  Pair,
+  Off,
+};
+#ifndef __cplusplus
+typedef uint8_t Reading_Tag;
+#endif // __cplusplus
+
+typedef struct Pair_Body {
+  Reading_Tag tag;
+  int16_t _0;
+  bool _1;
+} Pair_Body;
+
+typedef union Reading {
+  Reading_Tag tag;
+  struct {
+    Reading_Tag level_tag;
+    uint32_t level;
+  };
+  Pair_Body pair;
+} Reading;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+float shape_area(struct Shape shape);
+
+struct Shape unit_circle(void);
+
+union Reading read(uint32_t sensor);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,60 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A shape.
+ */
+typedef enum {
+  Circle,
+  Rect,
+  Empty,
+  Custom,
+} Shape_Tag;
+
+typedef struct {
+  float w;
+  float h;
+} Rect_Body;
+
+typedef struct {
+  Shape_Tag tag;
+  union {
+    struct {
+      float circle;
+    };
+    Rect_Body rect;
+    struct {
+      const uint8_t *custom;
+    };
+  };
+} Shape;
+
+enum Reading_Tag {
+  Level,
+  Pair,
+  Off,
+};
+typedef uint8_t Reading_Tag;
+
+typedef struct {
+  Reading_Tag tag;
+  int16_t _0;
+  bool _1;
+} Pair_Body;
+
+typedef union {
+  Reading_Tag tag;
+  struct {
+    Reading_Tag level_tag;
+    uint32_t level;
+  };
+  Pair_Body pair;
+} Reading;
+
+float shape_area(Shape shape);
+
+Shape unit_circle(void);
+
+Reading read(uint32_t sensor);
@@ -0,0 +1,70 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/// A shape.
+struct Shape {
+  enum class Tag {
+    Circle,
+    Rect,
+    Empty,
+    Custom,
+  };
+
+  struct Circle_Body {
+    float _0;
+  };
+
+  struct Rect_Body {
+    float w;
+    float h;
+  };
+
+  struct Custom_Body {
+    const uint8_t *_0;
+  };
+
+  Tag tag;
+  union {
+    Circle_Body circle;
+    Rect_Body rect;
+    Custom_Body custom;
+  };
+};
+
+union Reading {
+  enum class Tag : uint8_t {
+    Level,
+    Pair,
+    Off,
+  };
+
+  struct Level_Body {
+    Tag tag;
+    uint32_t _0;
+  };
+
+  struct Pair_Body {
+    Tag tag;
+    int16_t _0;
+    bool _1;
+  };
+
+  struct {
+    Tag tag;
+  };
+  Level_Body level;
+  Pair_Body pair;
+};
+
+extern "C" {
+
+float shape_area(Shape shape);
+
+Shape unit_circle();
+
+Reading read(uint32_t sensor);
+
+} // extern "C"
@@ -0,0 +1,46 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  # A shape.
+  cdef enum Shape_Tag:
+    Circle,
+    Rect,
+    Empty,
+    Custom,
+
+  cdef struct Rect_Body:
+    float w;
+    float h;
+
+  cdef struct Shape:
+    Shape_Tag tag;
+    float circle;
+    Rect_Body rect;
+    const uint8_t *custom;
+
+  cdef enum:
+    Level,
+    Pair,
+    Off,
+  ctypedef uint8_t Reading_Tag;
+
+  cdef struct Pair_Body:
+    Reading_Tag tag;
+    int16_t _0;
+    bool _1;
+
+  cdef union Reading:
+    Reading_Tag tag;
+    uint32_t level;
+    Pair_Body pair;
+
+  float shape_area(Shape shape);
+
+  Shape unit_circle() nogil;
+
+  Reading read(uint32_t sensor);
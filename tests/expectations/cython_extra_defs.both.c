@@ -0,0 +1,14 @@
+/* Text for C and C++ only. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Buffer {
+  uintptr_t len;
+} Buffer;
+
+void buffer_clear(struct Buffer *buffer);
+
+/* Text for C and C++ only. */
@@ -0,0 +1,22 @@
+/* Text for C and C++ only. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Buffer {
+  uintptr_t len;
+} Buffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void buffer_clear(struct Buffer *buffer);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+/* Text for C and C++ only. */
@@ -0,0 +1,19 @@
+/* Text for C and C++ only. */
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Buffer {
+  uintptr_t len;
+};
+
+extern "C" {
+
+void buffer_clear(Buffer *buffer);
+
+} // extern "C"
+
+/* Text for C and C++ only. */
@@ -0,0 +1,18 @@
+# cython: language_level=3
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Buffer:
+    uintptr_t len;
+
+  void buffer_clear(Buffer *buffer);
+
+  ctypedef struct FILE
+  void buffer_dump(const Buffer *buffer, FILE *stream)
+# End of the bindings.
@@ -0,0 +1,14 @@
+/* Text for C and C++ only. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Buffer {
+  uintptr_t len;
+};
+
+void buffer_clear(struct Buffer *buffer);
+
+/* Text for C and C++ only. */
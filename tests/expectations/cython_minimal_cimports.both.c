@@ -0,0 +1,16 @@
+#include <stddef.h>
+#include <stdint.h>
+
+enum Kind {
+  Start,
+  End,
+};
+typedef uint8_t Kind;
+
+typedef struct Point {
+  int32_t x;
+  ptrdiff_t offset;
+  Kind kind;
+} Point;
+
+float measure(struct Point p, uint64_t n);
@@ -0,0 +1,30 @@
+#include <stddef.h>
+#include <stdint.h>
+
+enum Kind
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Start,
+  End,
+};
+#ifndef __cplusplus
+typedef uint8_t Kind;
+#endif // __cplusplus
+
+struct Point {
+  int32_t x;
+  ptrdiff_t offset;
+  Kind kind;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+float measure(struct Point p, uint64_t n);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,19 @@
+from libc.stddef cimport ptrdiff_t
+from libc.stdint cimport int32_t, uint64_t, uint8_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum:
+    Start,
+    End,
+  ctypedef uint8_t Kind;
+
+  cdef struct Point:
+    int32_t x;
+    ptrdiff_t offset;
+    Kind kind;
+
+  float measure(Point p, uint64_t n);
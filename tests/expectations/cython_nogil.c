@@ -0,0 +1,14 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+int32_t compute(int32_t x);
+
+/**
+ * Calls back into Python code, so it needs the GIL.
+ *
+ */
+void run_callback(void (*callback)(int32_t));
+
+void reset(void);
@@ -0,0 +1,17 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+extern "C" {
+
+int32_t compute(int32_t x);
+
+/// Calls back into Python code, so it needs the GIL.
+///
+void run_callback(void (*callback)(int32_t));
+
+void reset();
+
+} // extern "C"
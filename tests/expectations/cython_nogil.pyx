@@ -0,0 +1,15 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  int32_t compute(int32_t x) nogil;
+
+  # Calls back into Python code, so it needs the GIL.
+  #
+  void run_callback(void (*callback)(int32_t));
+
+  void reset() nogil;
@@ -0,0 +1,21 @@
+#include <cstddef>
+#include <cstdint>
+#include <ostream>
+#include <new>
+
+enum class Kind : uint8_t {
+  Start,
+  End,
+};
+
+struct Point {
+  int32_t x;
+  ptrdiff_t offset;
+  Kind kind;
+};
+
+extern "C" {
+
+float measure(Point p, uint64_t n);
+
+} // extern "C"
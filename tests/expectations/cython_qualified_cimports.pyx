@@ -0,0 +1,19 @@
+cimport libc.stddef
+cimport libc.stdint
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum:
+    Start,
+    End,
+  ctypedef libc.stdint.uint8_t Kind;
+
+  ctypedef struct Point:
+    libc.stdint.int32_t x;
+    libc.stddef.ptrdiff_t offset;
+    Kind kind;
+
+  float measure(Point p, libc.stdint.uint64_t n);
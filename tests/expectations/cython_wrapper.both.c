@@ -0,0 +1,51 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The destructor of `Parser` can't free a `Lexer`.
+ *
+ */
+typedef struct Lexer Lexer;
+
+typedef struct Parser Parser;
+
+typedef struct Token Token;
+
+/**
+ * Ownership: the returned value is owned, the caller takes ownership of it.
+ */
+struct Parser *parser_new(bool strict);
+
+void parser_free(struct Parser *parser);
+
+/**
+ * Parses the next token.
+ *
+ */
+const struct Token *parser_next(struct Parser *parser, uint32_t limit);
+
+void parser_reset(struct Parser *parser);
+
+uintptr_t token_len(const struct Token *token);
+
+const char *token_text(const struct Token *token);
+
+/**
+ * The token is borrowed from the parser, which must outlive it.
+ *
+ * The returned value borrows from `parser`, which must outlive it.
+ */
+struct Token *parser_current(struct Parser *parser);
+
+/**
+ * Ownership: `parser` is owned, ownership is transferred to the callee.
+ * Ownership: the returned value is owned, the caller takes ownership of it.
+ */
+struct Lexer *parser_into_lexer(struct Parser *parser);
+
+/**
+ * Ownership: `lexer` is owned, ownership is transferred to the callee.
+ */
+uint32_t lexer_run(struct Lexer *lexer, uint32_t steps);
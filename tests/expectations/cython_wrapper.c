@@ -0,0 +1,51 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The destructor of `Parser` can't free a `Lexer`.
+ *
+ */
+typedef struct Lexer Lexer;
+
+typedef struct Parser Parser;
+
+typedef struct Token Token;
+
+/**
+ * Ownership: the returned value is owned, the caller takes ownership of it.
+ */
+Parser *parser_new(bool strict);
+
+void parser_free(Parser *parser);
+
+/**
+ * Parses the next token.
+ *
+ */
+const Token *parser_next(Parser *parser, uint32_t limit);
+
+void parser_reset(Parser *parser);
+
+uintptr_t token_len(const Token *token);
+
+const char *token_text(const Token *token);
+
+/**
+ * The token is borrowed from the parser, which must outlive it.
+ *
+ * The returned value borrows from `parser`, which must outlive it.
+ */
+Token *parser_current(Parser *parser);
+
+/**
+ * Ownership: `parser` is owned, ownership is transferred to the callee.
+ * Ownership: the returned value is owned, the caller takes ownership of it.
+ */
+Lexer *parser_into_lexer(Parser *parser);
+
+/**
+ * Ownership: `lexer` is owned, ownership is transferred to the callee.
+ */
+uint32_t lexer_run(Lexer *lexer, uint32_t steps);
@@ -0,0 +1,45 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  # The destructor of `Parser` can't free a `Lexer`.
+  #
+  ctypedef struct Lexer:
+    pass
+
+  ctypedef struct Parser:
+    pass
+
+  ctypedef struct Token:
+    pass
+
+  # Ownership: the returned value is owned, the caller takes ownership of it.
+  Parser *parser_new(bool strict);
+
+  void parser_free(Parser *parser);
+
+  # Parses the next token.
+  #
+  const Token *parser_next(Parser *parser, uint32_t limit) nogil;
+
+  void parser_reset(Parser *parser) nogil;
+
+  uintptr_t token_len(const Token *token);
+
+  const char *token_text(const Token *token);
+
+  # The token is borrowed from the parser, which must outlive it.
+  #
+  # The returned value borrows from `parser`, which must outlive it.
+  Token *parser_current(Parser *parser);
+
+  # Ownership: `parser` is owned, ownership is transferred to the callee.
+  # Ownership: the returned value is owned, the caller takes ownership of it.
+  Lexer *parser_into_lexer(Parser *parser);
+
+  # Ownership: `lexer` is owned, ownership is transferred to the callee.
+  uint32_t lexer_run(Lexer *lexer, uint32_t steps) nogil;
@@ -0,0 +1,19 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+__attribute__((used, section(".data.config"))) extern uint32_t CONFIG_VERSION;
+
+__attribute__((visibility("default"))) extern const int32_t EXPORTED_VALUE;
+
+__attribute__((weak)) int32_t plugin_hook(int32_t value);
+
+__attribute__((visibility("hidden"), section(".text.startup"))) void internal_helper(void);
+
+__attribute__((constructor)) void library_init(void);
+
+__attribute__((cold))
+void rarely_called(int32_t first_argument,
+                   int32_t second_argument,
+                   int32_t third_argument);
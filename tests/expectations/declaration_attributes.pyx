@@ -0,0 +1,19 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  extern uint32_t CONFIG_VERSION;
+
+  extern const int32_t EXPORTED_VALUE;
+
+  int32_t plugin_hook(int32_t value);
+
+  void internal_helper();
+
+  void library_init();
+
+  void rarely_called(int32_t first_argument, int32_t second_argument, int32_t third_argument);
@@ -0,0 +1,13 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t width;
+  uint32_t height;
+} Canvas;
+
+void draw(Canvas *canvas, int32_t x, int32_t y, uint32_t color, float scale);
+
+Canvas *canvas_new(uint32_t flags);
@@ -0,0 +1,33 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t width;
+  uint32_t height;
+} Canvas;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void draw(Canvas *canvas, int32_t x, int32_t y, uint32_t color, float scale);
+
+Canvas *canvas_new(uint32_t flags);
+
+#ifdef __cplusplus
+} // extern "C"
+
+inline void draw(Canvas *canvas, int32_t x, int32_t y, uint32_t color) {
+  return draw(canvas, x, y, color, 1.0f);
+}
+
+inline void draw(Canvas *canvas, int32_t x, int32_t y) {
+  return draw(canvas, x, y, 0xFFFFFFFF, 1.0f);
+}
+
+inline Canvas *canvas_new(void) {
+  return canvas_new(0);
+}
+#endif // __cplusplus
@@ -0,0 +1,18 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Canvas {
+  uint32_t width;
+  uint32_t height;
+};
+
+extern "C" {
+
+void draw(Canvas *canvas, int32_t x, int32_t y, uint32_t color = 0xFFFFFFFF, float scale = 1.0f);
+
+Canvas *canvas_new(uint32_t flags = 0);
+
+} // extern "C"
@@ -0,0 +1,15 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Canvas:
+    uint32_t width;
+    uint32_t height;
+
+  void draw(Canvas *canvas, int32_t x, int32_t y, uint32_t color, float scale);
+
+  Canvas *canvas_new(uint32_t flags);
@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Version {
+  uint32_t major;
+  uint32_t minor;
+  uint32_t patch;
+} Version;
+
+typedef struct Release {
+  struct Version version;
+  int64_t date;
+} Release;
+
+typedef struct Flags {
+  bool enabled;
+  uint8_t level;
+} Flags;
+
+typedef struct Opaque {
+  uint8_t *handle;
+} Opaque;
+
+typedef struct Id {
+  uint64_t _0;
+} Id;
+
+void root(struct Release release, struct Flags flags, struct Opaque opaque, struct Id id);
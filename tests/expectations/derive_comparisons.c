@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t major;
+  uint32_t minor;
+  uint32_t patch;
+} Version;
+
+typedef struct {
+  Version version;
+  int64_t date;
+} Release;
+
+typedef struct {
+  bool enabled;
+  uint8_t level;
+} Flags;
+
+typedef struct {
+  uint8_t *handle;
+} Opaque;
+
+typedef struct {
+  uint64_t _0;
+} Id;
+
+void root(Release release, Flags flags, Opaque opaque, Id id);
@@ -0,0 +1,138 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#if __cplusplus >= 202002L
+#include <compare>
+#endif
+
+struct Version {
+  uint32_t major;
+  uint32_t minor;
+  uint32_t patch;
+
+  #if __cplusplus >= 202002L
+  bool operator==(const Version&) const = default;
+  auto operator<=>(const Version&) const = default;
+  #else
+  bool operator==(const Version& other) const {
+    return major == other.major &&
+           minor == other.minor &&
+           patch == other.patch;
+  }
+  bool operator!=(const Version& other) const {
+    return !(*this == other);
+  }
+  bool operator<(const Version& other) const {
+    if (major != other.major) {
+      return major < other.major;
+    }
+    if (minor != other.minor) {
+      return minor < other.minor;
+    }
+    return patch < other.patch;
+  }
+  bool operator<=(const Version& other) const {
+    return !(other < *this);
+  }
+  bool operator>(const Version& other) const {
+    return other < *this;
+  }
+  bool operator>=(const Version& other) const {
+    return !(*this < other);
+  }
+  #endif
+};
+
+struct Release {
+  Version version;
+  int64_t date;
+
+  #if __cplusplus >= 202002L
+  bool operator==(const Release&) const = default;
+  auto operator<=>(const Release&) const = default;
+  #else
+  bool operator==(const Release& other) const {
+    return version == other.version &&
+           date == other.date;
+  }
+  bool operator!=(const Release& other) const {
+    return !(*this == other);
+  }
+  bool operator<(const Release& other) const {
+    if (version != other.version) {
+      return version < other.version;
+    }
+    return date < other.date;
+  }
+  bool operator<=(const Release& other) const {
+    return !(other < *this);
+  }
+  bool operator>(const Release& other) const {
+    return other < *this;
+  }
+  bool operator>=(const Release& other) const {
+    return !(*this < other);
+  }
+  #endif
+};
+
+struct Flags {
+  bool enabled;
+  uint8_t level;
+
+  #if __cplusplus >= 202002L
+  bool operator==(const Flags&) const = default;
+  #else
+  bool operator==(const Flags& other) const {
+    return enabled == other.enabled &&
+           level == other.level;
+  }
+  bool operator!=(const Flags& other) const {
+    return !(*this == other);
+  }
+  #endif
+};
+
+struct Opaque {
+  uint8_t *handle;
+
+  bool operator==(const Opaque& other) const {
+    return handle == other.handle;
+  }
+};
+
+struct Id {
+  uint64_t _0;
+
+  #if __cplusplus >= 202002L
+  bool operator==(const Id&) const = default;
+  auto operator<=>(const Id&) const = default;
+  #else
+  bool operator==(const Id& other) const {
+    return _0 == other._0;
+  }
+  bool operator!=(const Id& other) const {
+    return !(*this == other);
+  }
+  bool operator<(const Id& other) const {
+    return _0 < other._0;
+  }
+  bool operator<=(const Id& other) const {
+    return !(other < *this);
+  }
+  bool operator>(const Id& other) const {
+    return other < *this;
+  }
+  bool operator>=(const Id& other) const {
+    return !(*this < other);
+  }
+  #endif
+};
+
+extern "C" {
+
+void root(Release release, Flags flags, Opaque opaque, Id id);
+
+} // extern "C"
@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Version {
+  uint32_t major;
+  uint32_t minor;
+  uint32_t patch;
+};
+
+struct Release {
+  struct Version version;
+  int64_t date;
+};
+
+struct Flags {
+  bool enabled;
+  uint8_t level;
+};
+
+struct Opaque {
+  uint8_t *handle;
+};
+
+struct Id {
+  uint64_t _0;
+};
+
+void root(struct Release release, struct Flags flags, struct Opaque opaque, struct Id id);
@@ -0,0 +1,28 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Version:
+    uint32_t major;
+    uint32_t minor;
+    uint32_t patch;
+
+  cdef struct Release:
+    Version version;
+    int64_t date;
+
+  cdef struct Flags:
+    bool enabled;
+    uint8_t level;
+
+  cdef struct Opaque:
+    uint8_t *handle;
+
+  cdef struct Id:
+    uint64_t _0;
+
+  void root(Release release, Flags flags, Opaque opaque, Id id);
@@ -41,7 +41,7 @@ struct D {
   B Things;
 
   friend std::ostream& operator<<(std::ostream& stream, const D& instance) {
-    return stream << "{ " << "List=" << instance.List << ", "
+    return stream << "{ " << "List=" << +instance.List << ", "
                           << "Of=" << instance.Of << ", "
                           << "Things=" << instance.Things << " }";
   }
@@ -91,7 +91,7 @@ union F {
 
     friend std::ostream& operator<<(std::ostream& stream, const Bar_Body& instance) {
       return stream << "{ " << "tag=" << instance.tag << ", "
-                            << "x=" << instance.x << ", "
+                            << "x=" << +instance.x << ", "
                             << "y=" << instance.y << " }";
     }
   };
@@ -143,7 +143,7 @@ struct H {
     int16_t y;
 
     friend std::ostream& operator<<(std::ostream& stream, const There_Body& instance) {
-      return stream << "{ " << "x=" << instance.x << ", "
+      return stream << "{ " << "x=" << +instance.x << ", "
                             << "y=" << instance.y << " }";
     }
   };
@@ -166,7 +166,7 @@ struct I {
     int16_t y;
 
     friend std::ostream& operator<<(std::ostream& stream, const ThereAgain_Body& instance) {
-      return stream << "{ " << "x=" << instance.x << ", "
+      return stream << "{ " << "x=" << +instance.x << ", "
                             << "y=" << instance.y << " }";
     }
   };
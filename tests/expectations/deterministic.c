@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#line 2 "ffi_dep-0.2.0/src/lib.rs"
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Point;
+/* defined in ffi_dep-0.2.0/src/lib.rs:2 */
+
+#line 4 "src/shapes.rs"
+typedef struct {
+#if defined(DEFINE_FAST)
+  float length
+#endif
+  ;
+  Point start;
+  Point end;
+} Segment;
+/* defined in src/shapes.rs:4 */
+
+#line 4 "src/lib.rs"
+Point origin(void);
+/* defined in src/lib.rs:4 */
+
+#line 12 "src/shapes.rs"
+float length(Segment segment);
+/* defined in src/shapes.rs:12 */
@@ -0,0 +1,35 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+#line 2 "ffi_dep-0.2.0/src/lib.rs"
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+/* defined in ffi_dep-0.2.0/src/lib.rs:2 */
+
+#line 4 "src/shapes.rs"
+struct Segment {
+#if defined(DEFINE_FAST)
+  float length
+#endif
+  ;
+  Point start;
+  Point end;
+};
+/* defined in src/shapes.rs:4 */
+
+extern "C" {
+
+#line 4 "src/lib.rs"
+Point origin();
+/* defined in src/lib.rs:4 */
+
+#line 12 "src/shapes.rs"
+float length(Segment segment);
+/* defined in src/shapes.rs:12 */
+
+} // extern "C"
@@ -0,0 +1,38 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#line 2 "ffi_dep-0.2.0/src/lib.rs"
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+/* defined in ffi_dep-0.2.0/src/lib.rs:2 */
+
+#line 4 "src/shapes.rs"
+struct Segment {
+#if defined(DEFINE_FAST)
+  float length
+#endif
+  ;
+  struct Point start;
+  struct Point end;
+};
+/* defined in src/shapes.rs:4 */
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+#line 4 "src/lib.rs"
+struct Point origin(void);
+/* defined in src/lib.rs:4 */
+
+#line 12 "src/shapes.rs"
+float length(struct Segment segment);
+/* defined in src/shapes.rs:12 */
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
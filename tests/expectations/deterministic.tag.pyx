@@ -0,0 +1,24 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Point:
+    int32_t x;
+    int32_t y;
+  # defined in ffi_dep-0.2.0/src/lib.rs:2
+
+  cdef struct Segment:
+    float length;
+    Point start;
+    Point end;
+  # defined in src/shapes.rs:4
+
+  Point origin();
+  # defined in src/lib.rs:4
+
+  float length(Segment segment);
+  # defined in src/shapes.rs:12
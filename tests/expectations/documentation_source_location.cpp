@@ -0,0 +1,28 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const uint32_t LIMIT = 10;
+/* defined in tests/rust/documentation_source_location.rs:1 */
+
+/// A point on the plane.
+struct Point {
+  float x;
+  float y;
+};
+/* defined in tests/rust/documentation_source_location.rs:5 */
+
+using Callback = void(*)(Point);
+/* defined in tests/rust/documentation_source_location.rs:10 */
+
+extern "C" {
+
+extern Point ORIGIN;
+/* defined in tests/rust/documentation_source_location.rs:13 */
+
+void visit(Point point, Callback callback);
+/* defined in tests/rust/documentation_source_location.rs:16 */
+
+} // extern "C"
@@ -0,0 +1,33 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define LIMIT 10
+/* defined in tests/rust/documentation_source_location.rs:1 */
+
+/**
+ * A point on the plane.
+ */
+struct Point {
+  float x;
+  float y;
+};
+/* defined in tests/rust/documentation_source_location.rs:5 */
+
+typedef void (*Callback)(struct Point);
+/* defined in tests/rust/documentation_source_location.rs:10 */
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern struct Point ORIGIN;
+/* defined in tests/rust/documentation_source_location.rs:13 */
+
+void visit(struct Point point, Callback callback);
+/* defined in tests/rust/documentation_source_location.rs:16 */
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
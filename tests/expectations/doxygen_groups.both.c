@@ -0,0 +1,96 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * \defgroup mylib_colors The colors module
+ */
+
+/**
+ * \defgroup mylib_shapes The shapes module
+ */
+
+/**
+ * \defgroup mylib_shapes_polygons The polygons module
+ * \ingroup mylib_shapes
+ */
+
+/**
+ * The version of the bindings.
+ */
+#define VERSION 1
+
+/** \addtogroup mylib_shapes
+ * @{
+ */
+
+#define ORIGIN_X 0.0
+
+/** @} */
+
+/** \addtogroup mylib_colors
+ * @{
+ */
+
+enum Color {
+  Red,
+  Green,
+  Blue,
+};
+typedef uint8_t Color;
+
+/** @} */
+
+/** \addtogroup mylib_shapes
+ * @{
+ */
+
+/**
+ * A point in the plane.
+ */
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+/** @} */
+
+/** \addtogroup mylib_shapes_polygons
+ * @{
+ */
+
+/**
+ * A triangle made of three points.
+ */
+typedef struct Triangle {
+  struct Point points[3];
+} Triangle;
+
+/** @} */
+
+/** \addtogroup mylib_colors
+ * @{
+ */
+
+extern const Color DEFAULT_COLOR;
+
+/** @} */
+
+void root(Color color, struct Triangle triangle);
+
+/** \addtogroup mylib_shapes
+ * @{
+ */
+
+float point_length(struct Point point);
+
+/** @} */
+
+/** \addtogroup mylib_shapes_polygons
+ * @{
+ */
+
+float triangle_area(struct Triangle triangle);
+
+/** @} */
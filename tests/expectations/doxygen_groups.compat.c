@@ -0,0 +1,110 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * \defgroup mylib_colors The colors module
+ */
+
+/**
+ * \defgroup mylib_shapes The shapes module
+ */
+
+/**
+ * \defgroup mylib_shapes_polygons The polygons module
+ * \ingroup mylib_shapes
+ */
+
+/**
+ * The version of the bindings.
+ */
+#define VERSION 1
+
+/** \addtogroup mylib_shapes
+ * @{
+ */
+
+#define ORIGIN_X 0.0
+
+/** @} */
+
+/** \addtogroup mylib_colors
+ * @{
+ */
+
+enum Color
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Red,
+  Green,
+  Blue,
+};
+#ifndef __cplusplus
+typedef uint8_t Color;
+#endif // __cplusplus
+
+/** @} */
+
+/** \addtogroup mylib_shapes
+ * @{
+ */
+
+/**
+ * A point in the plane.
+ */
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+/** @} */
+
+/** \addtogroup mylib_shapes_polygons
+ * @{
+ */
+
+/**
+ * A triangle made of three points.
+ */
+typedef struct {
+  Point points[3];
+} Triangle;
+
+/** @} */
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/** \addtogroup mylib_colors
+ * @{
+ */
+
+extern const Color DEFAULT_COLOR;
+
+/** @} */
+
+void root(Color color, Triangle triangle);
+
+/** \addtogroup mylib_shapes
+ * @{
+ */
+
+float point_length(Point point);
+
+/** @} */
+
+/** \addtogroup mylib_shapes_polygons
+ * @{
+ */
+
+float triangle_area(Triangle triangle);
+
+/** @} */
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
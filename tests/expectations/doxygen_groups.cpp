@@ -0,0 +1,94 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/**
+ * \defgroup mylib_colors The colors module
+ */
+
+/**
+ * \defgroup mylib_shapes The shapes module
+ */
+
+/**
+ * \defgroup mylib_shapes_polygons The polygons module
+ * \ingroup mylib_shapes
+ */
+
+/// The version of the bindings.
+constexpr static const uint32_t VERSION = 1;
+
+/** \addtogroup mylib_shapes
+ * @{
+ */
+
+constexpr static const float ORIGIN_X = 0.0;
+
+/** @} */
+
+/** \addtogroup mylib_colors
+ * @{
+ */
+
+enum class Color : uint8_t {
+  Red,
+  Green,
+  Blue,
+};
+
+/** @} */
+
+/** \addtogroup mylib_shapes
+ * @{
+ */
+
+/// A point in the plane.
+struct Point {
+  float x;
+  float y;
+};
+
+/** @} */
+
+/** \addtogroup mylib_shapes_polygons
+ * @{
+ */
+
+/// A triangle made of three points.
+struct Triangle {
+  Point points[3];
+};
+
+/** @} */
+
+extern "C" {
+
+/** \addtogroup mylib_colors
+ * @{
+ */
+
+extern const Color DEFAULT_COLOR;
+
+/** @} */
+
+void root(Color color, Triangle triangle);
+
+/** \addtogroup mylib_shapes
+ * @{
+ */
+
+float point_length(Point point);
+
+/** @} */
+
+/** \addtogroup mylib_shapes_polygons
+ * @{
+ */
+
+float triangle_area(Triangle triangle);
+
+/** @} */
+
+} // extern "C"
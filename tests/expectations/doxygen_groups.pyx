@@ -0,0 +1,35 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  # The version of the bindings.
+  const uint32_t VERSION # = 1
+
+  const float ORIGIN_X # = 0.0
+
+  cdef enum:
+    Red,
+    Green,
+    Blue,
+  ctypedef uint8_t Color;
+
+  # A point in the plane.
+  ctypedef struct Point:
+    float x;
+    float y;
+
+  # A triangle made of three points.
+  ctypedef struct Triangle:
+    Point points[3];
+
+  extern const Color DEFAULT_COLOR;
+
+  void root(Color color, Triangle triangle);
+
+  float point_length(Point point);
+
+  float triangle_area(Triangle triangle);
@@ -0,0 +1,63 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+enum Shape_Tag {
+  Circle,
+  Rect,
+  Empty,
+};
+typedef uint8_t Shape_Tag;
+
+typedef struct {
+  Shape_Tag tag;
+  Point center;
+  float radius;
+} Circle_Body;
+
+typedef struct {
+  Shape_Tag tag;
+  Point _0;
+  Point _1;
+} Rect_Body;
+
+typedef union {
+  Shape_Tag tag;
+  Circle_Body circle;
+  Rect_Body rect;
+} Shape;
+
+enum Value_Tag {
+  Int,
+  Float,
+  Pair,
+  Null,
+  Undefined,
+};
+typedef uint8_t Value_Tag;
+
+typedef struct {
+  int32_t _0;
+  int32_t _1;
+} Pair_Body;
+
+typedef struct {
+  Value_Tag tag;
+  union {
+    struct {
+      int64_t int_;
+    };
+    struct {
+      double float_;
+    };
+    Pair_Body pair;
+  };
+} Value;
+
+void root(Shape shape, Value value);
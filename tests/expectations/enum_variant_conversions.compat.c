@@ -0,0 +1,83 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+enum Shape_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Circle,
+  Rect,
+  Empty,
+};
+#ifndef __cplusplus
+typedef uint8_t Shape_Tag;
+#endif // __cplusplus
+
+typedef struct {
+  Shape_Tag tag;
+  Point center;
+  float radius;
+} Circle_Body;
+
+typedef struct {
+  Shape_Tag tag;
+  Point _0;
+  Point _1;
+} Rect_Body;
+
+typedef union {
+  Shape_Tag tag;
+  Circle_Body circle;
+  Rect_Body rect;
+} Shape;
+
+enum Value_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Int,
+  Float,
+  Pair,
+  Null,
+  Undefined,
+};
+#ifndef __cplusplus
+typedef uint8_t Value_Tag;
+#endif // __cplusplus
+
+typedef struct {
+  int32_t _0;
+  int32_t _1;
+} Pair_Body;
+
+typedef struct {
+  Value_Tag tag;
+  union {
+    struct {
+      int64_t int_;
+    };
+    struct {
+      double float_;
+    };
+    Pair_Body pair;
+  };
+} Value;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Shape shape, Value value);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
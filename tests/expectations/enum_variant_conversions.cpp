@@ -0,0 +1,146 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#if __cplusplus >= 201703L
+#include <variant>
+#endif
+
+struct Point {
+  float x;
+  float y;
+};
+
+union Shape {
+  enum class Tag : uint8_t {
+    Circle,
+    Rect,
+    Empty,
+  };
+
+  struct Circle_Body {
+    Tag tag;
+    Point center;
+    float radius;
+  };
+
+  struct Rect_Body {
+    Tag tag;
+    Point _0;
+    Point _1;
+  };
+
+  struct {
+    Tag tag;
+  };
+  Circle_Body circle;
+  Rect_Body rect;
+
+  #if __cplusplus >= 201703L
+  using Variant = std::variant<Circle_Body, Rect_Body, std::monostate>;
+
+  Variant to_variant() const {
+    switch (tag) {
+      case Tag::Circle: return Variant(std::in_place_index<0>, circle);
+      case Tag::Rect: return Variant(std::in_place_index<1>, rect);
+      case Tag::Empty: break;
+    }
+    return Variant(std::in_place_index<2>);
+  }
+
+  static Shape from_variant(const Variant& variant) {
+    Shape result;
+    switch (variant.index()) {
+      case 0:
+        result.circle = std::get<0>(variant);
+        result.tag = Tag::Circle;
+        break;
+      case 1:
+        result.rect = std::get<1>(variant);
+        result.tag = Tag::Rect;
+        break;
+      case 2:
+        result.tag = Tag::Empty;
+        break;
+    }
+    return result;
+  }
+  #endif
+};
+
+struct Value {
+  enum class Tag : uint8_t {
+    Int,
+    Float,
+    Pair,
+    Null,
+    Undefined,
+  };
+
+  struct Int_Body {
+    int64_t _0;
+  };
+
+  struct Float_Body {
+    double _0;
+  };
+
+  struct Pair_Body {
+    int32_t _0;
+    int32_t _1;
+  };
+
+  Tag tag;
+  union {
+    Int_Body int_;
+    Float_Body float_;
+    Pair_Body pair;
+  };
+
+  #if __cplusplus >= 201703L
+  using Variant = std::variant<Int_Body, Float_Body, Pair_Body, std::monostate, std::monostate>;
+
+  Variant to_variant() const {
+    switch (tag) {
+      case Tag::Int: return Variant(std::in_place_index<0>, int_);
+      case Tag::Float: return Variant(std::in_place_index<1>, float_);
+      case Tag::Pair: return Variant(std::in_place_index<2>, pair);
+      case Tag::Null: return Variant(std::in_place_index<3>);
+      case Tag::Undefined: break;
+    }
+    return Variant(std::in_place_index<4>);
+  }
+
+  static Value from_variant(const Variant& variant) {
+    Value result;
+    switch (variant.index()) {
+      case 0:
+        result.int_ = std::get<0>(variant);
+        result.tag = Tag::Int;
+        break;
+      case 1:
+        result.float_ = std::get<1>(variant);
+        result.tag = Tag::Float;
+        break;
+      case 2:
+        result.pair = std::get<2>(variant);
+        result.tag = Tag::Pair;
+        break;
+      case 3:
+        result.tag = Tag::Null;
+        break;
+      case 4:
+        result.tag = Tag::Undefined;
+        break;
+    }
+    return result;
+  }
+  #endif
+};
+
+extern "C" {
+
+void root(Shape shape, Value value);
+
+} // extern "C"
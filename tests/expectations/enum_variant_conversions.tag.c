@@ -0,0 +1,63 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Point {
+  float x;
+  float y;
+};
+
+enum Shape_Tag {
+  Circle,
+  Rect,
+  Empty,
+};
+typedef uint8_t Shape_Tag;
+
+struct Circle_Body {
+  Shape_Tag tag;
+  struct Point center;
+  float radius;
+};
+
+struct Rect_Body {
+  Shape_Tag tag;
+  struct Point _0;
+  struct Point _1;
+};
+
+union Shape {
+  Shape_Tag tag;
+  struct Circle_Body circle;
+  struct Rect_Body rect;
+};
+
+enum Value_Tag {
+  Int,
+  Float,
+  Pair,
+  Null,
+  Undefined,
+};
+typedef uint8_t Value_Tag;
+
+struct Pair_Body {
+  int32_t _0;
+  int32_t _1;
+};
+
+struct Value {
+  Value_Tag tag;
+  union {
+    struct {
+      int64_t int_;
+    };
+    struct {
+      double float_;
+    };
+    struct Pair_Body pair;
+  };
+};
+
+void root(union Shape shape, struct Value value);
@@ -0,0 +1,83 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Point {
+  float x;
+  float y;
+};
+
+enum Shape_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Circle,
+  Rect,
+  Empty,
+};
+#ifndef __cplusplus
+typedef uint8_t Shape_Tag;
+#endif // __cplusplus
+
+struct Circle_Body {
+  Shape_Tag tag;
+  struct Point center;
+  float radius;
+};
+
+struct Rect_Body {
+  Shape_Tag tag;
+  struct Point _0;
+  struct Point _1;
+};
+
+union Shape {
+  Shape_Tag tag;
+  struct Circle_Body circle;
+  struct Rect_Body rect;
+};
+
+enum Value_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Int,
+  Float,
+  Pair,
+  Null,
+  Undefined,
+};
+#ifndef __cplusplus
+typedef uint8_t Value_Tag;
+#endif // __cplusplus
+
+struct Pair_Body {
+  int32_t _0;
+  int32_t _1;
+};
+
+struct Value {
+  Value_Tag tag;
+  union {
+    struct {
+      int64_t int_;
+    };
+    struct {
+      double float_;
+    };
+    struct Pair_Body pair;
+  };
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(union Shape shape, struct Value value);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
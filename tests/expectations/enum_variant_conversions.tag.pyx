@@ -0,0 +1,52 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Point:
+    float x;
+    float y;
+
+  cdef enum:
+    Circle,
+    Rect,
+    Empty,
+  ctypedef uint8_t Shape_Tag;
+
+  cdef struct Circle_Body:
+    Shape_Tag tag;
+    Point center;
+    float radius;
+
+  cdef struct Rect_Body:
+    Shape_Tag tag;
+    Point _0;
+    Point _1;
+
+  cdef union Shape:
+    Shape_Tag tag;
+    Circle_Body circle;
+    Rect_Body rect;
+
+  cdef enum:
+    Int,
+    Float,
+    Pair,
+    Null,
+    Undefined,
+  ctypedef uint8_t Value_Tag;
+
+  cdef struct Pair_Body:
+    int32_t _0;
+    int32_t _1;
+
+  cdef struct Value:
+    Value_Tag tag;
+    int64_t int_;
+    double float_;
+    Pair_Body pair;
+
+  void root(Shape shape, Value value);
@@ -0,0 +1,19 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Point:
+    int32_t x;
+    int32_t y;
+
+  extern uint32_t POINT_COUNT;
+
+  extern const Point ORIGIN;
+
+  Point point_new(int32_t x, int32_t y);
+
+  int32_t point_distance_squared(Point a, Point b);
@@ -0,0 +1,33 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifndef MYLIB_API
+#if defined(MYLIB_STATIC)
+#define MYLIB_API
+#elif defined(_WIN32) || defined(__CYGWIN__)
+#if defined(MYLIB_API_BUILD)
+#define MYLIB_API __declspec(dllexport)
+#else
+#define MYLIB_API __declspec(dllimport)
+#endif
+#elif defined(__GNUC__) && __GNUC__ >= 4
+#define MYLIB_API __attribute__((visibility("default")))
+#else
+#define MYLIB_API
+#endif
+#endif // MYLIB_API
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+extern MYLIB_API uint32_t POINT_COUNT;
+
+extern MYLIB_API const struct Point ORIGIN;
+
+MYLIB_API struct Point point_new(int32_t x, int32_t y);
+
+MYLIB_API int32_t point_distance_squared(struct Point a, struct Point b);
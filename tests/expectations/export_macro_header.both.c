@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "mylib_export.h"
+
+typedef struct Point {
+  int32_t x;
+  int32_t y;
+} Point;
+
+extern MYLIB_API uint32_t POINT_COUNT;
+
+extern MYLIB_API const struct Point ORIGIN;
+
+MYLIB_API struct Point point_new(int32_t x, int32_t y);
+
+MYLIB_API int32_t point_distance_squared(struct Point a, struct Point b);
@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "mylib_export.h"
+
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Point;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern MYLIB_API uint32_t POINT_COUNT;
+
+extern MYLIB_API const Point ORIGIN;
+
+MYLIB_API Point point_new(int32_t x, int32_t y);
+
+MYLIB_API int32_t point_distance_squared(Point a, Point b);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,12 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+extern uint32_t COUNTER;
+
+uint32_t counter_increment(void);
+
+void counter_reset(void);
+
+extern void defined_elsewhere(void);
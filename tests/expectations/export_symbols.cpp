@@ -0,0 +1,17 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+extern "C" {
+
+extern uint32_t COUNTER;
+
+uint32_t counter_increment();
+
+void counter_reset();
+
+extern void defined_elsewhere();
+
+} // extern "C"
@@ -0,0 +1,33 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum NotFlags {
+  A,
+  B,
+};
+typedef uint8_t NotFlags;
+
+enum Permissions {
+  Read = 1,
+  Write = 2,
+  Execute = 4,
+};
+typedef uint8_t Permissions;
+#define Permissions_OR(a, b) ((Permissions)((a) | (b)))
+#define Permissions_AND(a, b) ((Permissions)((a) & (b)))
+#define Permissions_XOR(a, b) ((Permissions)((a) ^ (b)))
+#define Permissions_NOT(a) ((Permissions)~(a))
+
+typedef enum {
+  Bold = 1,
+  Italic = 2,
+  Underline = 4,
+} Style;
+#define Style_OR(a, b) ((Style)((a) | (b)))
+#define Style_AND(a, b) ((Style)((a) & (b)))
+#define Style_XOR(a, b) ((Style)((a) ^ (b)))
+#define Style_NOT(a) ((Style)~(a))
+
+void root(Permissions permissions, Style style, NotFlags not_flags);
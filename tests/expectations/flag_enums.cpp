@@ -0,0 +1,92 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class NotFlags : uint8_t {
+  A,
+  B,
+};
+
+enum class Permissions : uint8_t {
+  Read = 1,
+  Write = 2,
+  Execute = 4,
+};
+
+constexpr Permissions operator|(Permissions lhs, Permissions rhs) {
+  return static_cast<Permissions>(static_cast<uint8_t>(lhs) | static_cast<uint8_t>(rhs));
+}
+
+constexpr Permissions operator&(Permissions lhs, Permissions rhs) {
+  return static_cast<Permissions>(static_cast<uint8_t>(lhs) & static_cast<uint8_t>(rhs));
+}
+
+constexpr Permissions operator^(Permissions lhs, Permissions rhs) {
+  return static_cast<Permissions>(static_cast<uint8_t>(lhs) ^ static_cast<uint8_t>(rhs));
+}
+
+constexpr Permissions operator~(Permissions lhs) {
+  return static_cast<Permissions>(~static_cast<uint8_t>(lhs));
+}
+
+constexpr bool has_flag(Permissions value, Permissions flag) {
+  return (value & flag) == flag;
+}
+
+inline Permissions& operator|=(Permissions& lhs, Permissions rhs) {
+  return lhs = lhs | rhs;
+}
+
+inline Permissions& operator&=(Permissions& lhs, Permissions rhs) {
+  return lhs = lhs & rhs;
+}
+
+inline Permissions& operator^=(Permissions& lhs, Permissions rhs) {
+  return lhs = lhs ^ rhs;
+}
+
+enum class Style : int {
+  Bold = 1,
+  Italic = 2,
+  Underline = 4,
+};
+
+constexpr Style operator|(Style lhs, Style rhs) {
+  return static_cast<Style>(static_cast<int>(lhs) | static_cast<int>(rhs));
+}
+
+constexpr Style operator&(Style lhs, Style rhs) {
+  return static_cast<Style>(static_cast<int>(lhs) & static_cast<int>(rhs));
+}
+
+constexpr Style operator^(Style lhs, Style rhs) {
+  return static_cast<Style>(static_cast<int>(lhs) ^ static_cast<int>(rhs));
+}
+
+constexpr Style operator~(Style lhs) {
+  return static_cast<Style>(~static_cast<int>(lhs));
+}
+
+constexpr bool has_flag(Style value, Style flag) {
+  return (value & flag) == flag;
+}
+
+inline Style& operator|=(Style& lhs, Style rhs) {
+  return lhs = lhs | rhs;
+}
+
+inline Style& operator&=(Style& lhs, Style rhs) {
+  return lhs = lhs & rhs;
+}
+
+inline Style& operator^=(Style& lhs, Style rhs) {
+  return lhs = lhs ^ rhs;
+}
+
+extern "C" {
+
+void root(Permissions permissions, Style style, NotFlags not_flags);
+
+} // extern "C"
@@ -0,0 +1,25 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum:
+    A,
+    B,
+  ctypedef uint8_t NotFlags;
+
+  cdef enum:
+    Read # = 1,
+    Write # = 2,
+    Execute # = 4,
+  ctypedef uint8_t Permissions;
+
+  ctypedef enum Style:
+    Bold # = 1,
+    Italic # = 2,
+    Underline # = 4,
+
+  void root(Permissions permissions, Style style, NotFlags not_flags);
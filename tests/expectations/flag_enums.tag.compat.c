@@ -0,0 +1,53 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum NotFlags
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  A,
+  B,
+};
+#ifndef __cplusplus
+typedef uint8_t NotFlags;
+#endif // __cplusplus
+
+enum Permissions
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Read = 1,
+  Write = 2,
+  Execute = 4,
+};
+#ifndef __cplusplus
+typedef uint8_t Permissions;
+#endif // __cplusplus
+#define Permissions_OR(a, b) ((Permissions)((a) | (b)))
+#define Permissions_AND(a, b) ((Permissions)((a) & (b)))
+#define Permissions_XOR(a, b) ((Permissions)((a) ^ (b)))
+#define Permissions_NOT(a) ((Permissions)~(a))
+
+enum Style {
+  Bold = 1,
+  Italic = 2,
+  Underline = 4,
+};
+#define Style_OR(a, b) ((enum Style)((a) | (b)))
+#define Style_AND(a, b) ((enum Style)((a) & (b)))
+#define Style_XOR(a, b) ((enum Style)((a) ^ (b)))
+#define Style_NOT(a) ((enum Style)~(a))
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Permissions permissions, enum Style style, NotFlags not_flags);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
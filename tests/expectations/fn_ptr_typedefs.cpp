@@ -0,0 +1,36 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Event {
+  uint32_t code;
+};
+
+using bool_fn_Event_const_ptr_u8_ptr = bool(*)(const Event*,
+                                               uint8_t*);
+
+using void_fn_void = void(*)();
+
+using Handler = bool(*)(const Event *event, uint8_t *user_data);
+
+struct Dispatcher {
+  bool_fn_Event_const_ptr_u8_ptr on_event;
+  void_fn_void on_close;
+  Handler fallback;
+};
+
+using bool_fn_u32 = bool(*)(uint32_t code);
+
+using void_fn_void_fn_bool_fn_u32 = void_fn_void(*)(bool_fn_u32);
+
+extern "C" {
+
+extern void_fn_void DEFAULT_CLOSE;
+
+void dispatcher_set_filter(Dispatcher *dispatcher, bool_fn_u32 filter);
+
+void dispatcher_map(Dispatcher *dispatcher, void_fn_void_fn_bool_fn_u32 wrap);
+
+} // extern "C"
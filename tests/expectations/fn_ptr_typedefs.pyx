@@ -0,0 +1,32 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Event:
+    uint32_t code;
+
+  ctypedef bool (*bool_fn_Event_const_ptr_u8_ptr)(const Event*,
+                                                  uint8_t*);
+
+  ctypedef void (*void_fn_void)();
+
+  ctypedef bool (*Handler)(const Event *event, uint8_t *user_data);
+
+  ctypedef struct Dispatcher:
+    bool_fn_Event_const_ptr_u8_ptr on_event;
+    void_fn_void on_close;
+    Handler fallback;
+
+  ctypedef bool (*bool_fn_u32)(uint32_t code);
+
+  ctypedef void_fn_void (*void_fn_void_fn_bool_fn_u32)(bool_fn_u32);
+
+  extern void_fn_void DEFAULT_CLOSE;
+
+  void dispatcher_set_filter(Dispatcher *dispatcher, bool_fn_u32 filter);
+
+  void dispatcher_map(Dispatcher *dispatcher, void_fn_void_fn_bool_fn_u32 wrap);
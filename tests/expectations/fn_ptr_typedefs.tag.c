@@ -0,0 +1,31 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Event {
+  uint32_t code;
+};
+
+typedef bool (*bool_fn_Event_const_ptr_u8_ptr)(const struct Event*,
+                                               uint8_t*);
+
+typedef void (*void_fn_void)(void);
+
+typedef bool (*Handler)(const struct Event *event, uint8_t *user_data);
+
+struct Dispatcher {
+  bool_fn_Event_const_ptr_u8_ptr on_event;
+  void_fn_void on_close;
+  Handler fallback;
+};
+
+typedef bool (*bool_fn_u32)(uint32_t code);
+
+typedef void_fn_void (*void_fn_void_fn_bool_fn_u32)(bool_fn_u32);
+
+extern void_fn_void DEFAULT_CLOSE;
+
+void dispatcher_set_filter(struct Dispatcher *dispatcher, bool_fn_u32 filter);
+
+void dispatcher_map(struct Dispatcher *dispatcher, void_fn_void_fn_bool_fn_u32 wrap);
@@ -0,0 +1,22 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Normal {
+  int32_t x;
+  float y;
+};
+
+extern "C" {
+
+extern const uint32_t COUNTER;
+
+extern Normal LAST;
+
+extern const uint8_t *foreign_name;
+
+extern int32_t real_foo();
+
+} // extern "C"
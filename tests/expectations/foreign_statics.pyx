@@ -0,0 +1,19 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Normal:
+    int32_t x;
+    float y;
+
+  extern const uint32_t COUNTER;
+
+  extern Normal LAST;
+
+  extern const uint8_t *foreign_name;
+
+  extern int32_t real_foo();
@@ -0,0 +1,25 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Normal {
+  int32_t x;
+  float y;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern const uint32_t COUNTER;
+
+extern struct Normal LAST;
+
+extern const uint8_t *foreign_name;
+
+extern int32_t real_foo(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+int32_t mylib_init_v2(uint32_t flags);
+#define init mylib_init_v2
+#define mylib_init mylib_init_v2
+
+void mylib_shutdown(void);
+#define mylib_fini mylib_shutdown
+
+#if defined(MYLIB_LEGACY)
+void mylib_reset_v2(void);
+#define reset mylib_reset_v2
+#endif
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
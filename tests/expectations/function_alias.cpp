@@ -0,0 +1,21 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+extern "C" {
+
+int32_t mylib_init_v2(uint32_t flags);
+#define init mylib_init_v2
+#define mylib_init mylib_init_v2
+
+void mylib_shutdown();
+#define mylib_fini mylib_shutdown
+
+#if defined(MYLIB_LEGACY)
+void mylib_reset_v2();
+#define reset mylib_reset_v2
+#endif
+
+} // extern "C"
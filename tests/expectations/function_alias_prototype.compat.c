@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+int32_t mylib_init_v2(uint32_t flags);
+int32_t init(uint32_t flags);
+
+void mylib_shutdown(void);
+void mylib_fini(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
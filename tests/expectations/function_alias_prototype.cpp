@@ -0,0 +1,15 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+extern "C" {
+
+int32_t mylib_init_v2(uint32_t flags);
+int32_t init(uint32_t flags);
+
+void mylib_shutdown();
+void mylib_fini();
+
+} // extern "C"
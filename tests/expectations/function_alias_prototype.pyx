@@ -0,0 +1,13 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  int32_t mylib_init_v2(uint32_t flags);
+  int32_t init "mylib_init_v2"(uint32_t flags);
+
+  void mylib_shutdown();
+  void mylib_fini "mylib_shutdown"();
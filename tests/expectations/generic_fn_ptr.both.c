@@ -0,0 +1,33 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef void (*Callback_u8)(uint8_t*);
+
+typedef void (*Callback_i16)(int16_t*);
+
+typedef bool (*Visitor_i16)(Callback_i16, int16_t);
+
+typedef void (*Callback_u32)(uint32_t*);
+
+typedef struct Holder_u32 {
+  Callback_u32 callback;
+  uint32_t value;
+} Holder_u32;
+
+typedef struct Foo {
+  int32_t a;
+} Foo;
+
+typedef void (*Callback_Foo)(struct Foo*);
+
+typedef void (*Callback_i64)(int64_t*);
+
+extern Callback_Foo FOO_CALLBACK;
+
+void root(Callback_u8 a,
+          Visitor_i16 b,
+          struct Holder_u32 c,
+          const Callback_Foo *d,
+          void (*e)(Callback_i64));
@@ -0,0 +1,33 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+template<typename T>
+using Callback = void(*)(T*);
+
+template<typename T>
+using Visitor = bool(*)(Callback<T>, T);
+
+template<typename T>
+struct Holder {
+  Callback<T> callback;
+  T value;
+};
+
+struct Foo {
+  int32_t a;
+};
+
+extern "C" {
+
+extern Callback<Foo> FOO_CALLBACK;
+
+void root(Callback<uint8_t> a,
+          Visitor<int16_t> b,
+          Holder<uint32_t> c,
+          const Callback<Foo> *d,
+          void (*e)(Callback<int64_t>));
+
+} // extern "C"
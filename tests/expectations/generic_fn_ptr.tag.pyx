@@ -0,0 +1,34 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef void (*Callback_u8)(uint8_t*);
+
+  ctypedef void (*Callback_i16)(int16_t*);
+
+  ctypedef bool (*Visitor_i16)(Callback_i16, int16_t);
+
+  ctypedef void (*Callback_u32)(uint32_t*);
+
+  cdef struct Holder_u32:
+    Callback_u32 callback;
+    uint32_t value;
+
+  cdef struct Foo:
+    int32_t a;
+
+  ctypedef void (*Callback_Foo)(Foo*);
+
+  ctypedef void (*Callback_i64)(int64_t*);
+
+  extern Callback_Foo FOO_CALLBACK;
+
+  void root(Callback_u8 a,
+            Visitor_i16 b,
+            Holder_u32 c,
+            const Callback_Foo *d,
+            void (*e)(Callback_i64));
@@ -0,0 +1,38 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float x;
+  float y;
+} Vec2;
+
+int32_t magnitude_i32(int32_t value);
+
+int64_t magnitude_i64(int64_t value);
+
+double magnitude_f64(double value);
+
+uint8_t clamp_u8(uint8_t value, uint8_t min, uint8_t max);
+
+float clamp_f32(float value, float min, float max);
+
+float length_vec2(const Vec2 *v);
+
+uintptr_t length_str(const uint8_t *s);
+
+uint16_t lonely_u16(uint16_t value);
+
+#define magnitude(value) _Generic((value), \
+  int32_t: magnitude_i32, \
+  int64_t: magnitude_i64, \
+  double: magnitude_f64)(value)
+
+#define clamp(value, min, max) _Generic((value), \
+  uint8_t: clamp_u8, \
+  float: clamp_f32)(value, min, max)
+
+#define length(v) _Generic((v), \
+  const Vec2*: length_vec2, \
+  const uint8_t*: length_str)(v)
@@ -0,0 +1,27 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Vec2:
+    float x;
+    float y;
+
+  int32_t magnitude_i32(int32_t value);
+
+  int64_t magnitude_i64(int64_t value);
+
+  double magnitude_f64(double value);
+
+  uint8_t clamp_u8(uint8_t value, uint8_t min, uint8_t max);
+
+  float clamp_f32(float value, float min, float max);
+
+  float length_vec2(const Vec2 *v);
+
+  uintptr_t length_str(const uint8_t *s);
+
+  uint16_t lonely_u16(uint16_t value);
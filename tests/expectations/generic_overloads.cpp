@@ -0,0 +1,70 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Vec2 {
+  float x;
+  float y;
+};
+
+extern "C" {
+
+int32_t magnitude_i32(int32_t value);
+
+int64_t magnitude_i64(int64_t value);
+
+double magnitude_f64(double value);
+
+uint8_t clamp_u8(uint8_t value, uint8_t min, uint8_t max);
+
+float clamp_f32(float value, float min, float max);
+
+float length_vec2(const Vec2 *v);
+
+uintptr_t length_str(const uint8_t *s);
+
+uint16_t lonely_u16(uint16_t value);
+
+Vec2 scale_uniform(Vec2 v, float factor);
+
+Vec2 scale_axes(Vec2 v, float x, float y);
+
+} // extern "C"
+
+inline int32_t magnitude(int32_t value) {
+  return magnitude_i32(value);
+}
+
+inline int64_t magnitude(int64_t value) {
+  return magnitude_i64(value);
+}
+
+inline double magnitude(double value) {
+  return magnitude_f64(value);
+}
+
+inline uint8_t clamp(uint8_t value, uint8_t min, uint8_t max) {
+  return clamp_u8(value, min, max);
+}
+
+inline float clamp(float value, float min, float max) {
+  return clamp_f32(value, min, max);
+}
+
+inline float length(const Vec2 *v) {
+  return length_vec2(v);
+}
+
+inline uintptr_t length(const uint8_t *s) {
+  return length_str(s);
+}
+
+inline Vec2 scale(Vec2 v, float factor) {
+  return scale_uniform(v, factor);
+}
+
+inline Vec2 scale(Vec2 v, float x, float y) {
+  return scale_axes(v, x, y);
+}
@@ -0,0 +1,90 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Vec2 {
+  float x;
+  float y;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+int32_t magnitude_i32(int32_t value);
+
+int64_t magnitude_i64(int64_t value);
+
+double magnitude_f64(double value);
+
+uint8_t clamp_u8(uint8_t value, uint8_t min, uint8_t max);
+
+float clamp_f32(float value, float min, float max);
+
+float length_vec2(const struct Vec2 *v);
+
+uintptr_t length_str(const uint8_t *s);
+
+uint16_t lonely_u16(uint16_t value);
+
+struct Vec2 scale_uniform(struct Vec2 v, float factor);
+
+struct Vec2 scale_axes(struct Vec2 v, float x, float y);
+
+#ifdef __cplusplus
+} // extern "C"
+
+inline int32_t magnitude(int32_t value) {
+  return magnitude_i32(value);
+}
+
+inline int64_t magnitude(int64_t value) {
+  return magnitude_i64(value);
+}
+
+inline double magnitude(double value) {
+  return magnitude_f64(value);
+}
+
+inline uint8_t clamp(uint8_t value, uint8_t min, uint8_t max) {
+  return clamp_u8(value, min, max);
+}
+
+inline float clamp(float value, float min, float max) {
+  return clamp_f32(value, min, max);
+}
+
+inline float length(const struct Vec2 *v) {
+  return length_vec2(v);
+}
+
+inline uintptr_t length(const uint8_t *s) {
+  return length_str(s);
+}
+
+inline struct Vec2 scale(struct Vec2 v, float factor) {
+  return scale_uniform(v, factor);
+}
+
+inline struct Vec2 scale(struct Vec2 v, float x, float y) {
+  return scale_axes(v, x, y);
+}
+#endif // __cplusplus
+
+#ifndef __cplusplus
+
+#define magnitude(value) _Generic((value), \
+  int32_t: magnitude_i32, \
+  int64_t: magnitude_i64, \
+  double: magnitude_f64)(value)
+
+#define clamp(value, min, max) _Generic((value), \
+  uint8_t: clamp_u8, \
+  float: clamp_f32)(value, min, max)
+
+#define length(v) _Generic((v), \
+  const struct Vec2*: length_vec2, \
+  const uint8_t*: length_str)(v)
+
+#endif // __cplusplus
@@ -0,0 +1,48 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The settings of a window.
+ *
+ */
+typedef struct {
+  /**
+   * The width of the window, in pixels.
+   *
+   * Defaults to `640`.
+   */
+  uint32_t width;
+  /**
+   * Defaults to `480`.
+   */
+  uint32_t height;
+  /**
+   * Defaults to `true`.
+   */
+  bool resizable;
+  const uint8_t *title;
+  /**
+   * Defaults to `1.0f`.
+   */
+  float scale;
+} WindowConfig;
+#define WindowConfig_INIT(...) ((WindowConfig){ \
+  .width = 640, \
+  .height = 480, \
+  .resizable = true, \
+  .scale = 1.0f, \
+  __VA_ARGS__ \
+})
+
+typedef struct {
+  int32_t value;
+} Counter;
+#define Counter_INIT(...) ((Counter){ __VA_ARGS__ })
+
+typedef struct {
+  int32_t value;
+} NoMacro;
+
+void create_window(WindowConfig config, Counter counter, NoMacro no_macro);
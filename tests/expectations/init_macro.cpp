@@ -0,0 +1,35 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/// The settings of a window.
+///
+struct WindowConfig {
+  /// The width of the window, in pixels.
+  ///
+  /// Defaults to `640`.
+  uint32_t width = 640;
+  /// Defaults to `480`.
+  uint32_t height = 480;
+  /// Defaults to `true`.
+  bool resizable = true;
+  const uint8_t *title;
+  /// Defaults to `1.0f`.
+  float scale = 1.0f;
+};
+
+struct Counter {
+  int32_t value;
+};
+
+struct NoMacro {
+  int32_t value;
+};
+
+extern "C" {
+
+void create_window(WindowConfig config, Counter counter, NoMacro no_macro);
+
+} // extern "C"
@@ -0,0 +1,48 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The settings of a window.
+ *
+ */
+struct WindowConfig {
+  /**
+   * The width of the window, in pixels.
+   *
+   * Defaults to `640`.
+   */
+  uint32_t width;
+  /**
+   * Defaults to `480`.
+   */
+  uint32_t height;
+  /**
+   * Defaults to `true`.
+   */
+  bool resizable;
+  const uint8_t *title;
+  /**
+   * Defaults to `1.0f`.
+   */
+  float scale;
+};
+#define WindowConfig_INIT(...) ((struct WindowConfig){ \
+  .width = 640, \
+  .height = 480, \
+  .resizable = true, \
+  .scale = 1.0f, \
+  __VA_ARGS__ \
+})
+
+struct Counter {
+  int32_t value;
+};
+#define Counter_INIT(...) ((struct Counter){ __VA_ARGS__ })
+
+struct NoMacro {
+  int32_t value;
+};
+
+void create_window(struct WindowConfig config, struct Counter counter, struct NoMacro no_macro);
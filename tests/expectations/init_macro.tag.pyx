@@ -0,0 +1,26 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  # The settings of a window.
+  #
+  cdef struct WindowConfig:
+    # The width of the window, in pixels.
+    #
+    uint32_t width;
+    uint32_t height;
+    bool resizable;
+    const uint8_t *title;
+    float scale;
+
+  cdef struct Counter:
+    int32_t value;
+
+  cdef struct NoMacro:
+    int32_t value;
+
+  void create_window(WindowConfig config, Counter counter, NoMacro no_macro);
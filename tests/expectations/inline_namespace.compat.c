@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+namespace ffi {
+inline namespace v1_2 {
+#endif // __cplusplus
+
+typedef struct {
+  uint32_t major;
+  uint32_t minor;
+} Version;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Version library_version(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#ifdef __cplusplus
+} // namespace v1_2
+} // namespace ffi
+#endif // __cplusplus
@@ -0,0 +1,22 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+namespace ffi {
+inline namespace v1_2 {
+
+struct Version {
+  uint32_t major;
+  uint32_t minor;
+};
+
+extern "C" {
+
+Version library_version();
+
+} // extern "C"
+
+} // namespace v1_2
+} // namespace ffi
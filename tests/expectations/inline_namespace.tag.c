@@ -0,0 +1,11 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Version {
+  uint32_t major;
+  uint32_t minor;
+};
+
+struct Version library_version(void);
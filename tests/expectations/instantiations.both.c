@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Wrapper_u8 {
+  uint8_t value;
+} Wrapper_u8;
+
+typedef bool (*Callback_u16)(uint16_t);
+
+typedef struct Pair_i8__Wrapper_u8 {
+  int8_t first;
+  struct Wrapper_u8 second;
+} Pair_i8__Wrapper_u8;
+
+typedef struct Pair_u32__f64 {
+  uint32_t first;
+  double second;
+} Pair_u32__f64;
+
+typedef struct Wrapper_f32 {
+  float value;
+} Wrapper_f32;
+
+void root(struct Wrapper_u8 wrapper);
@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint8_t value;
+} Wrapper_u8;
+
+typedef bool (*Callback_u16)(uint16_t);
+
+typedef struct {
+  int8_t first;
+  Wrapper_u8 second;
+} Pair_i8__Wrapper_u8;
+
+typedef struct {
+  uint32_t first;
+  double second;
+} Pair_u32__f64;
+
+typedef struct {
+  float value;
+} Wrapper_f32;
+
+void root(Wrapper_u8 wrapper);
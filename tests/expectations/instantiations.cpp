@@ -0,0 +1,25 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+template<typename T>
+struct Wrapper {
+  T value;
+};
+
+template<typename T>
+using Callback = bool(*)(T);
+
+template<typename T, typename U>
+struct Pair {
+  T first;
+  U second;
+};
+
+extern "C" {
+
+void root(Wrapper<uint8_t> wrapper);
+
+} // extern "C"
@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Wrapper_u8 {
+  uint8_t value;
+};
+
+typedef bool (*Callback_u16)(uint16_t);
+
+struct Pair_i8__Wrapper_u8 {
+  int8_t first;
+  struct Wrapper_u8 second;
+};
+
+struct Pair_u32__f64 {
+  uint32_t first;
+  double second;
+};
+
+struct Wrapper_f32 {
+  float value;
+};
+
+void root(struct Wrapper_u8 wrapper);
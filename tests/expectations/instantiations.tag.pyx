@@ -0,0 +1,25 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Wrapper_u8:
+    uint8_t value;
+
+  ctypedef bool (*Callback_u16)(uint16_t);
+
+  cdef struct Pair_i8__Wrapper_u8:
+    int8_t first;
+    Wrapper_u8 second;
+
+  cdef struct Pair_u32__f64:
+    uint32_t first;
+    double second;
+
+  cdef struct Wrapper_f32:
+    float value;
+
+  void root(Wrapper_u8 wrapper);
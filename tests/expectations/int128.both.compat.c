@@ -0,0 +1,19 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Wide {
+  unsigned __int128 unsigned_;
+  __int128 signed_;
+} Wide;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+__int128 root(struct Wide w, unsigned __int128 x);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
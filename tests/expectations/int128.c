@@ -0,0 +1,11 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  unsigned __int128 unsigned_;
+  __int128 signed_;
+} Wide;
+
+__int128 root(Wide w, unsigned __int128 x);
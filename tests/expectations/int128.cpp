@@ -0,0 +1,16 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Wide {
+  unsigned __int128 unsigned_;
+  __int128 signed_;
+};
+
+extern "C" {
+
+__int128 root(Wide w, unsigned __int128 x);
+
+} // extern "C"
@@ -0,0 +1,16 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef long long cbindgen_int128_t "__int128"
+  ctypedef unsigned long long cbindgen_uint128_t "unsigned __int128"
+
+  cdef struct Wide:
+    cbindgen_uint128_t unsigned_;
+    cbindgen_int128_t signed_;
+
+  cbindgen_int128_t root(Wide w, cbindgen_uint128_t x);
@@ -0,0 +1,26 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+typedef struct cbindgen_int128_t {
+  uint64_t lo;
+  int64_t hi;
+} cbindgen_int128_t;
+
+typedef struct cbindgen_uint128_t {
+  uint64_t lo;
+  uint64_t hi;
+} cbindgen_uint128_t;
+
+struct Wide {
+  cbindgen_uint128_t unsigned_;
+  cbindgen_int128_t signed_;
+};
+
+extern "C" {
+
+cbindgen_int128_t root(Wide w, cbindgen_uint128_t x);
+
+} // extern "C"
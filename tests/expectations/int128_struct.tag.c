@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct cbindgen_int128_t {
+  uint64_t lo;
+  int64_t hi;
+} cbindgen_int128_t;
+
+typedef struct cbindgen_uint128_t {
+  uint64_t lo;
+  uint64_t hi;
+} cbindgen_uint128_t;
+
+struct Wide {
+  cbindgen_uint128_t unsigned_;
+  cbindgen_int128_t signed_;
+};
+
+cbindgen_int128_t root(struct Wide w, cbindgen_uint128_t x);
@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifndef CBINDGEN_ALIGNAS
+#if defined(_MSC_VER)
+#define CBINDGEN_ALIGNAS(n) __declspec(align(n))
+#else
+#define CBINDGEN_ALIGNAS(n) _Alignas(n)
+#endif
+#endif // CBINDGEN_ALIGNAS
+
+typedef struct cbindgen_int128_t {
+  CBINDGEN_ALIGNAS(16) uint64_t lo;
+  int64_t hi;
+} cbindgen_int128_t;
+
+typedef struct cbindgen_uint128_t {
+  CBINDGEN_ALIGNAS(16) uint64_t lo;
+  uint64_t hi;
+} cbindgen_uint128_t;
+
+struct Wide {
+  cbindgen_uint128_t unsigned_;
+  cbindgen_int128_t signed_;
+};
+
+cbindgen_int128_t root(struct Wide w, cbindgen_uint128_t x);
@@ -31,22 +31,27 @@ cdef extern from *:
   ctypedef struct UnsupportedPacked4Union:
     pass
 
+  # #[repr(align(1))]
   ctypedef struct Align1Struct:
     uintptr_t arg1;
     uint8_t *arg2;
 
+  # #[repr(align(2))]
   ctypedef struct Align2Struct:
     uintptr_t arg1;
     uint8_t *arg2;
 
+  # #[repr(align(4))]
   ctypedef struct Align4Struct:
     uintptr_t arg1;
     uint8_t *arg2;
 
+  # #[repr(align(8))]
   ctypedef struct Align8Struct:
     uintptr_t arg1;
     uint8_t *arg2;
 
+  # #[repr(align(32))]
   ctypedef struct Align32Struct:
     uintptr_t arg1;
     uint8_t *arg2;
@@ -55,18 +60,22 @@ cdef extern from *:
     uintptr_t arg1;
     uint8_t *arg2;
 
+  # #[repr(align(1))]
   ctypedef union Align1Union:
     uintptr_t variant1;
     uint8_t *variant2;
 
+  # #[repr(align(4))]
   ctypedef union Align4Union:
     uintptr_t variant1;
     uint8_t *variant2;
 
+  # #[repr(align(16))]
   ctypedef union Align16Union:
     uintptr_t variant1;
     uint8_t *variant2;
 
+  # #[repr(packed)]
   ctypedef union PackedUnion:
     uintptr_t variant1;
     uint8_t *variant2;
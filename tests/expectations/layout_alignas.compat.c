@@ -0,0 +1,41 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifndef CBINDGEN_ALIGNAS
+#if defined(__cplusplus)
+#define CBINDGEN_ALIGNAS(n) alignas(n)
+#elif defined(_MSC_VER)
+#define CBINDGEN_ALIGNAS(n) __declspec(align(n))
+#else
+#define CBINDGEN_ALIGNAS(n) _Alignas(n)
+#endif
+#endif // CBINDGEN_ALIGNAS
+
+typedef struct {
+  CBINDGEN_ALIGNAS(16) float x;
+  float y;
+  float z;
+} Vector;
+
+typedef union {
+  CBINDGEN_ALIGNAS(8) uint32_t value;
+  uint8_t bytes[4];
+} Bits;
+
+typedef struct {
+  Vector position;
+  Vector velocity;
+  Bits bits;
+} Particle;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Particle *particle);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,28 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct alignas(16) Vector {
+  float x;
+  float y;
+  float z;
+};
+
+union alignas(8) Bits {
+  uint32_t value;
+  uint8_t bytes[4];
+};
+
+struct Particle {
+  Vector position;
+  Vector velocity;
+  Bits bits;
+};
+
+extern "C" {
+
+void root(Particle *particle);
+
+} // extern "C"
@@ -0,0 +1,25 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  # #[repr(align(16))]
+  ctypedef struct Vector:
+    float x;
+    float y;
+    float z;
+
+  # #[repr(align(8))]
+  ctypedef union Bits:
+    uint32_t value;
+    uint8_t bytes[4];
+
+  ctypedef struct Particle:
+    Vector position;
+    Vector velocity;
+    Bits bits;
+
+  void root(Particle *particle);
@@ -10,34 +10,51 @@ cdef extern from *:
 
 cdef extern from *:
 
-  ctypedef struct OpaqueAlign16Union:
-    pass
+  ctypedef packed struct PackedStruct:
+    uintptr_t arg1;
+    uint8_t *arg2;
 
-  ctypedef struct OpaqueAlign1Struct:
-    pass
+  # #[repr(packed)]
+  ctypedef union PackedUnion:
+    uintptr_t variant1;
+    uint8_t *variant2;
 
-  ctypedef struct OpaqueAlign1Union:
-    pass
+  # #[repr(align(1))]
+  ctypedef union OpaqueAlign1Union:
+    uintptr_t variant1;
+    uint8_t *variant2;
 
-  ctypedef struct OpaqueAlign2Struct:
-    pass
+  # #[repr(align(4))]
+  ctypedef union OpaqueAlign4Union:
+    uintptr_t variant1;
+    uint8_t *variant2;
 
-  ctypedef struct OpaqueAlign32Struct:
-    pass
+  # #[repr(align(16))]
+  ctypedef union OpaqueAlign16Union:
+    uintptr_t variant1;
+    uint8_t *variant2;
 
-  ctypedef struct OpaqueAlign4Struct:
-    pass
+  # #[repr(align(1))]
+  ctypedef struct OpaqueAlign1Struct:
+    uintptr_t arg1;
+    uint8_t *arg2;
 
-  ctypedef struct OpaqueAlign4Union:
-    pass
+  # #[repr(align(2))]
+  ctypedef struct OpaqueAlign2Struct:
+    uintptr_t arg1;
+    uint8_t *arg2;
 
-  ctypedef struct OpaqueAlign8Struct:
-    pass
+  # #[repr(align(4))]
+  ctypedef struct OpaqueAlign4Struct:
+    uintptr_t arg1;
+    uint8_t *arg2;
 
-  ctypedef packed struct PackedStruct:
+  # #[repr(align(8))]
+  ctypedef struct OpaqueAlign8Struct:
     uintptr_t arg1;
     uint8_t *arg2;
 
-  ctypedef union PackedUnion:
-    uintptr_t variant1;
-    uint8_t *variant2;
+  # #[repr(align(32))]
+  ctypedef struct OpaqueAlign32Struct:
+    uintptr_t arg1;
+    uint8_t *arg2;
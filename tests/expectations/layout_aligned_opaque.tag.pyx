@@ -10,34 +10,51 @@ cdef extern from *:
 
 cdef extern from *:
 
-  cdef struct OpaqueAlign16Union:
-    pass
+  cdef packed struct PackedStruct:
+    uintptr_t arg1;
+    uint8_t *arg2;
 
-  cdef struct OpaqueAlign1Struct:
-    pass
+  # #[repr(packed)]
+  cdef union PackedUnion:
+    uintptr_t variant1;
+    uint8_t *variant2;
 
-  cdef struct OpaqueAlign1Union:
-    pass
+  # #[repr(align(1))]
+  cdef union OpaqueAlign1Union:
+    uintptr_t variant1;
+    uint8_t *variant2;
 
-  cdef struct OpaqueAlign2Struct:
-    pass
+  # #[repr(align(4))]
+  cdef union OpaqueAlign4Union:
+    uintptr_t variant1;
+    uint8_t *variant2;
 
-  cdef struct OpaqueAlign32Struct:
-    pass
+  # #[repr(align(16))]
+  cdef union OpaqueAlign16Union:
+    uintptr_t variant1;
+    uint8_t *variant2;
 
-  cdef struct OpaqueAlign4Struct:
-    pass
+  # #[repr(align(1))]
+  cdef struct OpaqueAlign1Struct:
+    uintptr_t arg1;
+    uint8_t *arg2;
 
-  cdef struct OpaqueAlign4Union:
-    pass
+  # #[repr(align(2))]
+  cdef struct OpaqueAlign2Struct:
+    uintptr_t arg1;
+    uint8_t *arg2;
 
-  cdef struct OpaqueAlign8Struct:
-    pass
+  # #[repr(align(4))]
+  cdef struct OpaqueAlign4Struct:
+    uintptr_t arg1;
+    uint8_t *arg2;
 
-  cdef packed struct PackedStruct:
+  # #[repr(align(8))]
+  cdef struct OpaqueAlign8Struct:
     uintptr_t arg1;
     uint8_t *arg2;
 
-  cdef union PackedUnion:
-    uintptr_t variant1;
-    uint8_t *variant2;
+  # #[repr(align(32))]
+  cdef struct OpaqueAlign32Struct:
+    uintptr_t arg1;
+    uint8_t *arg2;
@@ -10,40 +10,51 @@ cdef extern from *:
 
 cdef extern from *:
 
-  cdef struct OpaquePackedStruct:
-    pass
-
-  cdef struct OpaquePackedUnion:
-    pass
-
+  # #[repr(align(1))]
   cdef union Align1Union:
     uintptr_t variant1;
     uint8_t *variant2;
 
+  # #[repr(align(4))]
   cdef union Align4Union:
     uintptr_t variant1;
     uint8_t *variant2;
 
+  # #[repr(align(16))]
   cdef union Align16Union:
     uintptr_t variant1;
     uint8_t *variant2;
 
+  # #[repr(align(1))]
   cdef struct Align1Struct:
     uintptr_t arg1;
     uint8_t *arg2;
 
+  # #[repr(align(2))]
   cdef struct Align2Struct:
     uintptr_t arg1;
     uint8_t *arg2;
 
+  # #[repr(align(4))]
   cdef struct Align4Struct:
     uintptr_t arg1;
     uint8_t *arg2;
 
+  # #[repr(align(8))]
   cdef struct Align8Struct:
     uintptr_t arg1;
     uint8_t *arg2;
 
+  # #[repr(align(32))]
   cdef struct Align32Struct:
     uintptr_t arg1;
     uint8_t *arg2;
+
+  cdef packed struct OpaquePackedStruct:
+    uintptr_t arg1;
+    uint8_t *arg2;
+
+  # #[repr(packed)]
+  cdef union OpaquePackedUnion:
+    uintptr_t variant1;
+    uint8_t *variant2;
@@ -0,0 +1,31 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+#pragma pack(push, 1)
+struct Header {
+  uint8_t kind;
+  uint32_t length;
+  uint16_t checksum;
+};
+#pragma pack(pop)
+
+#pragma pack(push, 1)
+union Payload {
+  uint32_t word;
+  uint8_t bytes[3];
+};
+#pragma pack(pop)
+
+struct Message {
+  Header header;
+  Payload payload;
+};
+
+extern "C" {
+
+void root(const Message *message);
+
+} // extern "C"
@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#pragma pack(push, 1)
+struct Header {
+  uint8_t kind;
+  uint32_t length;
+  uint16_t checksum;
+};
+#pragma pack(pop)
+
+#pragma pack(push, 1)
+union Payload {
+  uint32_t word;
+  uint8_t bytes[3];
+};
+#pragma pack(pop)
+
+struct Message {
+  struct Header header;
+  union Payload payload;
+};
+
+void root(const struct Message *message);
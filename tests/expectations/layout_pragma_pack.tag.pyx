@@ -0,0 +1,23 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef packed struct Header:
+    uint8_t kind;
+    uint32_t length;
+    uint16_t checksum;
+
+  # #[repr(packed)]
+  cdef union Payload:
+    uint32_t word;
+    uint8_t bytes[3];
+
+  cdef struct Message:
+    Header header;
+    Payload payload;
+
+  void root(const Message *message);
@@ -0,0 +1,36 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  const uint8_t *name;
+} Entry;
+
+typedef struct {
+  uintptr_t len;
+} Map;
+
+/**
+ * The returned value borrows from `self`, which must outlive it.
+ */
+const Entry *map_first(const Map *self);
+
+/**
+ * Looks up a key.
+ *
+ * The returned value borrows from `map`, which must outlive it.
+ */
+const Entry *map_get(const Map *map, const uint8_t *key);
+
+/**
+ * The returned value borrows from `first` and `second`, which must outlive it.
+ */
+const Map *pick(const Map *first, const Map *second, bool flag);
+
+/**
+ * The returned value borrows from `value`, which must outlive it.
+ */
+const uint32_t *identity(const uint32_t *value);
+
+const Map *global_map(const Map *map);
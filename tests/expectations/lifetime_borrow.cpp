@@ -0,0 +1,33 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Entry {
+  const uint8_t *name;
+};
+
+struct Map {
+  uintptr_t len;
+};
+
+extern "C" {
+
+/// The returned value borrows from `self`, which must outlive it.
+const Entry *map_first(const Map *self);
+
+/// Looks up a key.
+///
+/// The returned value borrows from `map`, which must outlive it.
+const Entry *map_get(const Map *map, const uint8_t *key);
+
+/// The returned value borrows from `first` and `second`, which must outlive it.
+const Map *pick(const Map *first, const Map *second, bool flag);
+
+/// The returned value borrows from `value`, which must outlive it.
+const uint32_t *identity(const uint32_t *value);
+
+const Map *global_map(const Map *map);
+
+} // extern "C"
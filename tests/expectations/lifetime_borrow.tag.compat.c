@@ -0,0 +1,44 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Entry {
+  const uint8_t *name;
+};
+
+struct Map {
+  uintptr_t len;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * The returned value borrows from `self`, which must outlive it.
+ */
+const struct Entry *map_first(const struct Map *self);
+
+/**
+ * Looks up a key.
+ *
+ * The returned value borrows from `map`, which must outlive it.
+ */
+const struct Entry *map_get(const struct Map *map, const uint8_t *key);
+
+/**
+ * The returned value borrows from `first` and `second`, which must outlive it.
+ */
+const struct Map *pick(const struct Map *first, const struct Map *second, bool flag);
+
+/**
+ * The returned value borrows from `value`, which must outlive it.
+ */
+const uint32_t *identity(const uint32_t *value);
+
+const struct Map *global_map(const struct Map *map);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
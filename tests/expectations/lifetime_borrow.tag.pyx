@@ -0,0 +1,29 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Entry:
+    const uint8_t *name;
+
+  cdef struct Map:
+    uintptr_t len;
+
+  # The returned value borrows from `self`, which must outlive it.
+  const Entry *map_first(const Map *self);
+
+  # Looks up a key.
+  #
+  # The returned value borrows from `map`, which must outlive it.
+  const Entry *map_get(const Map *map, const uint8_t *key);
+
+  # The returned value borrows from `first` and `second`, which must outlive it.
+  const Map *pick(const Map *first, const Map *second, bool flag);
+
+  # The returned value borrows from `value`, which must outlive it.
+  const uint32_t *identity(const uint32_t *value);
+
+  const Map *global_map(const Map *map);
@@ -0,0 +1,25 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#line 1 "tests/rust/line_directives.rs"
+#define LIMIT 10
+
+#line 5 "tests/rust/line_directives.rs"
+/**
+ * A point on the plane.
+ */
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+#line 10 "tests/rust/line_directives.rs"
+typedef void (*Callback)(Point);
+
+#line 13 "tests/rust/line_directives.rs"
+extern Point ORIGIN;
+
+#line 16 "tests/rust/line_directives.rs"
+void visit(Point point, Callback callback);
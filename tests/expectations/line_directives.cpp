@@ -0,0 +1,28 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+#line 1 "tests/rust/line_directives.rs"
+constexpr static const uint32_t LIMIT = 10;
+
+#line 5 "tests/rust/line_directives.rs"
+/// A point on the plane.
+struct Point {
+  float x;
+  float y;
+};
+
+#line 10 "tests/rust/line_directives.rs"
+using Callback = void(*)(Point);
+
+extern "C" {
+
+#line 13 "tests/rust/line_directives.rs"
+extern Point ORIGIN;
+
+#line 16 "tests/rust/line_directives.rs"
+void visit(Point point, Callback callback);
+
+} // extern "C"
@@ -0,0 +1,20 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uint32_t LIMIT # = 10
+
+  # A point on the plane.
+  cdef struct Point:
+    float x;
+    float y;
+
+  ctypedef void (*Callback)(Point);
+
+  extern Point ORIGIN;
+
+  void visit(Point point, Callback callback);
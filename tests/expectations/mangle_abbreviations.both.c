@@ -0,0 +1,19 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Foo_U64 {
+  uint64_t a;
+} Foo_U64;
+
+typedef struct Foo_i32 {
+  int32_t a;
+} Foo_i32;
+
+typedef struct Bar_F_i32_____U64 {
+  struct Foo_i32 a;
+  uint64_t b;
+} Bar_F_i32_____U64;
+
+void root(struct Foo_U64 x, struct Bar_F_i32_____U64 y);
@@ -0,0 +1,19 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint64_t a;
+} Foo_U64;
+
+typedef struct {
+  int32_t a;
+} Foo_i32;
+
+typedef struct {
+  Foo_i32 a;
+  uint64_t b;
+} Bar_F_i32_____U64;
+
+void root(Foo_U64 x, Bar_F_i32_____U64 y);
@@ -0,0 +1,22 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+template<typename T>
+struct Foo {
+  T a;
+};
+
+template<typename T, typename U>
+struct Bar {
+  T a;
+  U b;
+};
+
+extern "C" {
+
+void root(Foo<uint64_t> x, Bar<Foo<int32_t>, uint64_t> y);
+
+} // extern "C"
@@ -0,0 +1,19 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Foo_U64:
+    uint64_t a;
+
+  ctypedef struct Foo_i32:
+    int32_t a;
+
+  ctypedef struct Bar_F_i32_____U64:
+    Foo_i32 a;
+    uint64_t b;
+
+  void root(Foo_U64 x, Bar_F_i32_____U64 y);
@@ -0,0 +1,27 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Foo_U64 {
+  uint64_t a;
+};
+
+struct Foo_i32 {
+  int32_t a;
+};
+
+struct Bar_F_i32_____U64 {
+  struct Foo_i32 a;
+  uint64_t b;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Foo_U64 x, struct Bar_F_i32_____U64 y);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -1,17 +1,3 @@
-#if 0
-''' '
-#endif
-
-#ifdef __cplusplus
-template <typename T>
-using ManuallyDrop = T;
-#endif
-
-#if 0
-' '''
-#endif
-
-
 #include <stdarg.h>
 #include <stdbool.h>
 #include <stdint.h>
@@ -30,6 +16,13 @@ typedef struct {
   Point point;
 } MyStruct;
 
+typedef union {
+  Point point;
+  uint64_t bits;
+} MyUnion;
+
 void root(const Foo *a, const MyStruct *with_manual_drop);
 
 void take(Point with_manual_drop);
+
+void take_union(MyUnion with_manual_drop);
@@ -1,17 +1,3 @@
-#if 0
-''' '
-#endif
-
-#ifdef __cplusplus
-template <typename T>
-using ManuallyDrop = T;
-#endif
-
-#if 0
-' '''
-#endif
-
-
 #include <cstdarg>
 #include <cstdint>
 #include <cstdlib>
@@ -26,16 +12,23 @@ struct Point {
   int32_t y;
 };
 
-using Foo = NotReprC<ManuallyDrop<Point>>;
+using Foo = NotReprC<Point>;
 
 struct MyStruct {
-  ManuallyDrop<Point> point;
+  Point point;
+};
+
+union MyUnion {
+  Point point;
+  uint64_t bits;
 };
 
 extern "C" {
 
 void root(const Foo *a, const MyStruct *with_manual_drop);
 
-void take(ManuallyDrop<Point> with_manual_drop);
+void take(Point with_manual_drop);
+
+void take_union(MyUnion with_manual_drop);
 
 } // extern "C"
@@ -1,17 +1,3 @@
-#if 0
-''' '
-#endif
-
-#ifdef __cplusplus
-template <typename T>
-using ManuallyDrop = T;
-#endif
-
-#if 0
-' '''
-#endif
-
-
 from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
 from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
 cdef extern from *:
@@ -32,6 +18,12 @@ cdef extern from *:
   ctypedef struct MyStruct:
     Point point;
 
+  ctypedef union MyUnion:
+    Point point;
+    uint64_t bits;
+
   void root(const Foo *a, const MyStruct *with_manual_drop);
 
   void take(Point with_manual_drop);
+
+  void take_union(MyUnion with_manual_drop);
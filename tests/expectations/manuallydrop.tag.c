@@ -1,17 +1,3 @@
-#if 0
-''' '
-#endif
-
-#ifdef __cplusplus
-template <typename T>
-using ManuallyDrop = T;
-#endif
-
-#if 0
-' '''
-#endif
-
-
 #include <stdarg.h>
 #include <stdbool.h>
 #include <stdint.h>
@@ -30,6 +16,13 @@ struct MyStruct {
   struct Point point;
 };
 
+union MyUnion {
+  struct Point point;
+  uint64_t bits;
+};
+
 void root(const Foo *a, const struct MyStruct *with_manual_drop);
 
 void take(struct Point with_manual_drop);
+
+void take_union(union MyUnion with_manual_drop);
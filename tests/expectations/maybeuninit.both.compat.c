@@ -1,17 +1,3 @@
-#if 0
-''' '
-#endif
-
-#ifdef __cplusplus
-template <typename T>
-using MaybeUninit = T;
-#endif
-
-#if 0
-' '''
-#endif
-
-
 #include <stdarg.h>
 #include <stdbool.h>
 #include <stdint.h>
@@ -31,6 +17,8 @@ extern "C" {
 
 void root(const Foo *a, const struct MyStruct *with_maybe_uninit);
 
+void init(struct MyStruct *out, uintptr_t *len);
+
 #ifdef __cplusplus
 } // extern "C"
 #endif // __cplusplus
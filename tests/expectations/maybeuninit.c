@@ -1,17 +1,3 @@
-#if 0
-''' '
-#endif
-
-#ifdef __cplusplus
-template <typename T>
-using MaybeUninit = T;
-#endif
-
-#if 0
-' '''
-#endif
-
-
 #include <stdarg.h>
 #include <stdbool.h>
 #include <stdint.h>
@@ -26,3 +12,5 @@ typedef struct {
 } MyStruct;
 
 void root(const Foo *a, const MyStruct *with_maybe_uninit);
+
+void init(MyStruct *out, uintptr_t *len);
@@ -1,17 +1,3 @@
-#if 0
-''' '
-#endif
-
-#ifdef __cplusplus
-template <typename T>
-using MaybeUninit = T;
-#endif
-
-#if 0
-' '''
-#endif
-
-
 #include <cstdarg>
 #include <cstdint>
 #include <cstdlib>
@@ -21,14 +7,16 @@ using MaybeUninit = T;
 template<typename T = void>
 struct NotReprC;
 
-using Foo = NotReprC<MaybeUninit<const int32_t*>>;
+using Foo = NotReprC<const int32_t*>;
 
 struct MyStruct {
-  MaybeUninit<const int32_t*> number;
+  const int32_t *number;
 };
 
 extern "C" {
 
 void root(const Foo *a, const MyStruct *with_maybe_uninit);
 
+void init(MyStruct *out, uintptr_t *len);
+
 } // extern "C"
@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Counter {
+  int32_t value;
+} Counter;
+
+void counter_increment(struct Counter *counter, int32_t by);
+
+int32_t counter_get(const struct Counter *counter);
+
+bool counter_is_zero(const struct Counter *counter);
+
+void reset_counter(struct Counter *counter);
+
+struct Counter counter_new(int32_t value);
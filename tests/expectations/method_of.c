@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t value;
+} Counter;
+
+void counter_increment(Counter *counter, int32_t by);
+
+int32_t counter_get(const Counter *counter);
+
+bool counter_is_zero(const Counter *counter);
+
+void reset_counter(Counter *counter);
+
+Counter counter_new(int32_t value);
@@ -0,0 +1,34 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+namespace counters {
+#endif // __cplusplus
+
+typedef struct {
+  int32_t value;
+} Counter;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void counter_increment(Counter *counter, int32_t by);
+
+int32_t counter_get(const Counter *counter);
+
+bool counter_is_zero(const Counter *counter);
+
+void reset_counter(Counter *counter);
+
+Counter counter_new(int32_t value);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#ifdef __cplusplus
+} // namespace counters
+#endif // __cplusplus
@@ -0,0 +1,48 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+namespace counters {
+
+struct Counter {
+  int32_t value;
+
+  void increment(int32_t by);
+  int32_t get() const;
+  bool is_zero() const;
+  void reset_counter();
+};
+
+extern "C" {
+
+void counter_increment(Counter *counter, int32_t by);
+
+int32_t counter_get(const Counter *counter);
+
+bool counter_is_zero(const Counter *counter);
+
+void reset_counter(Counter *counter);
+
+Counter counter_new(int32_t value);
+
+} // extern "C"
+
+inline void Counter::increment(int32_t by) {
+  return ::counters::counter_increment(this, by);
+}
+
+inline int32_t Counter::get() const {
+  return ::counters::counter_get(this);
+}
+
+inline bool Counter::is_zero() const {
+  return ::counters::counter_is_zero(this);
+}
+
+inline void Counter::reset_counter() {
+  return ::counters::reset_counter(this);
+}
+
+} // namespace counters
@@ -0,0 +1,57 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+namespace counters {
+#endif // __cplusplus
+
+struct Counter {
+  int32_t value;
+
+#ifdef __cplusplus
+  void increment(int32_t by);
+  int32_t get(void) const;
+  bool is_zero(void) const;
+  void reset_counter(void);
+#endif // __cplusplus
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void counter_increment(struct Counter *counter, int32_t by);
+
+int32_t counter_get(const struct Counter *counter);
+
+bool counter_is_zero(const struct Counter *counter);
+
+void reset_counter(struct Counter *counter);
+
+struct Counter counter_new(int32_t value);
+
+#ifdef __cplusplus
+} // extern "C"
+
+inline void Counter::increment(int32_t by) {
+  return ::counters::counter_increment(this, by);
+}
+
+inline int32_t Counter::get(void) const {
+  return ::counters::counter_get(this);
+}
+
+inline bool Counter::is_zero(void) const {
+  return ::counters::counter_is_zero(this);
+}
+
+inline void Counter::reset_counter(void) {
+  return ::counters::reset_counter(this);
+}
+#endif // __cplusplus
+
+#ifdef __cplusplus
+} // namespace counters
+#endif // __cplusplus
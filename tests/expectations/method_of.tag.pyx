@@ -0,0 +1,20 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Counter:
+    int32_t value;
+
+  void counter_increment(Counter *counter, int32_t by);
+
+  int32_t counter_get(const Counter *counter);
+
+  bool counter_is_zero(const Counter *counter);
+
+  void reset_counter(Counter *counter);
+
+  Counter counter_new(int32_t value);
@@ -0,0 +1,10 @@
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+typedef struct {
+  bool verbose;
+  uint32_t level;
+} Options;
+
+void configure(const Options *options, size_t len);
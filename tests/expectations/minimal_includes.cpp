@@ -0,0 +1,15 @@
+#include <cstddef>
+#include <cstdint>
+#include <ostream>
+#include <new>
+
+struct Options {
+  bool verbose;
+  uint32_t level;
+};
+
+extern "C" {
+
+void configure(const Options *options, size_t len);
+
+} // extern "C"
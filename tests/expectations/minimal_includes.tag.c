@@ -0,0 +1,10 @@
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+struct Options {
+  bool verbose;
+  uint32_t level;
+};
+
+void configure(const struct Options *options, size_t len);
@@ -0,0 +1,18 @@
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+struct Options {
+  bool verbose;
+  uint32_t level;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void configure(const struct Options *options, size_t len);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,12 @@
+from libc.stdint cimport uint32_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Options:
+    bool verbose;
+    uint32_t level;
+
+  void configure(const Options *options, size_t len);
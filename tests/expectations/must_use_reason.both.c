@@ -0,0 +1,35 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum Status {
+  Ok,
+  Failed,
+} Status;
+
+typedef struct Handle {
+  int32_t fd;
+} Handle;
+
+enum Outcome_Tag {
+  Done,
+  Error,
+};
+typedef uint8_t Outcome_Tag;
+
+typedef struct Outcome {
+  Outcome_Tag tag;
+  union {
+    struct {
+      int32_t done;
+    };
+    struct {
+      enum Status error;
+    };
+  };
+} Outcome;
+
+struct Handle handle_open(const uint8_t *path);
+
+struct Outcome handle_read(struct Handle handle);
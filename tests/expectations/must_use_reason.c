@@ -0,0 +1,35 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum {
+  Ok,
+  Failed,
+} Status;
+
+typedef struct {
+  int32_t fd;
+} Handle;
+
+enum Outcome_Tag {
+  Done,
+  Error,
+};
+typedef uint8_t Outcome_Tag;
+
+typedef struct {
+  Outcome_Tag tag;
+  union {
+    struct {
+      int32_t done;
+    };
+    struct {
+      Status error;
+    };
+  };
+} Outcome;
+
+Handle handle_open(const uint8_t *path);
+
+Outcome handle_read(Handle handle);
@@ -0,0 +1,49 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum {
+  Ok,
+  Failed,
+} Status;
+
+typedef struct {
+  int32_t fd;
+} Handle;
+
+enum Outcome_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Done,
+  Error,
+};
+#ifndef __cplusplus
+typedef uint8_t Outcome_Tag;
+#endif // __cplusplus
+
+typedef struct {
+  Outcome_Tag tag;
+  union {
+    struct {
+      int32_t done;
+    };
+    struct {
+      Status error;
+    };
+  };
+} Outcome;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Handle handle_open(const uint8_t *path);
+
+Outcome handle_read(Handle handle);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
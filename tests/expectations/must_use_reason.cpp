@@ -0,0 +1,43 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class [[nodiscard("errors must be \"handled\"")]] Status {
+  Ok,
+  Failed,
+};
+
+struct [[nodiscard("leaks the handle unless it is closed")]] Handle {
+  int32_t fd;
+};
+
+struct [[nodiscard("might be an error")]] Outcome {
+  enum class [[nodiscard("might be an error")]] Tag : uint8_t {
+    Done,
+    Error,
+  };
+
+  struct Done_Body {
+    int32_t _0;
+  };
+
+  struct Error_Body {
+    Status _0;
+  };
+
+  Tag tag;
+  union {
+    Done_Body done;
+    Error_Body error;
+  };
+};
+
+extern "C" {
+
+[[nodiscard("the handle must be closed")]] Handle handle_open(const uint8_t *path);
+
+Outcome handle_read(Handle handle);
+
+} // extern "C"
@@ -0,0 +1,35 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Status {
+  Ok,
+  Failed,
+};
+
+struct Handle {
+  int32_t fd;
+};
+
+enum Outcome_Tag {
+  Done,
+  Error,
+};
+typedef uint8_t Outcome_Tag;
+
+struct Outcome {
+  Outcome_Tag tag;
+  union {
+    struct {
+      int32_t done;
+    };
+    struct {
+      enum Status error;
+    };
+  };
+};
+
+struct Handle handle_open(const uint8_t *path);
+
+struct Outcome handle_read(struct Handle handle);
@@ -0,0 +1,49 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Status {
+  Ok,
+  Failed,
+};
+
+struct Handle {
+  int32_t fd;
+};
+
+enum Outcome_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Done,
+  Error,
+};
+#ifndef __cplusplus
+typedef uint8_t Outcome_Tag;
+#endif // __cplusplus
+
+struct Outcome {
+  Outcome_Tag tag;
+  union {
+    struct {
+      int32_t done;
+    };
+    struct {
+      enum Status error;
+    };
+  };
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Handle handle_open(const uint8_t *path);
+
+struct Outcome handle_read(struct Handle handle);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
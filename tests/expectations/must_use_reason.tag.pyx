@@ -0,0 +1,28 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum Status:
+    Ok,
+    Failed,
+
+  cdef struct Handle:
+    int32_t fd;
+
+  cdef enum:
+    Done,
+    Error,
+  ctypedef uint8_t Outcome_Tag;
+
+  cdef struct Outcome:
+    Outcome_Tag tag;
+    int32_t done;
+    Status error;
+
+  Handle handle_open(const uint8_t *path);
+
+  Outcome handle_read(Handle handle);
@@ -0,0 +1,59 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MAX_SCENES 16
+
+enum geometry_shapes_Kind
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Circle,
+  Square,
+};
+#ifndef __cplusplus
+typedef uint8_t geometry_shapes_Kind;
+#endif // __cplusplus
+
+typedef struct io_Buffer io_Buffer;
+
+typedef struct geometry_Point {
+  float x;
+  float y;
+} geometry_Point;
+
+typedef struct geometry_shapes_Circle {
+  struct geometry_Point center;
+  float radius;
+} geometry_shapes_Circle;
+
+typedef struct wrappers_Wrapper_f32 {
+  float value;
+} wrappers_Wrapper_f32;
+
+typedef struct Scene {
+  struct geometry_Point origin;
+  struct geometry_shapes_Circle circle;
+  geometry_shapes_Kind kind;
+  struct wrappers_Wrapper_f32 scale;
+} Scene;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern uint32_t SCENE_COUNT;
+
+void draw(const struct Scene *scene, struct geometry_Point center);
+
+const struct Scene *find_scene(const char *name);
+
+struct io_Buffer *new_buffer(void);
+
+void free_buffer(struct io_Buffer *buffer);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,45 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MAX_SCENES 16
+
+enum geometry_shapes_Kind {
+  Circle,
+  Square,
+};
+typedef uint8_t geometry_shapes_Kind;
+
+typedef struct io_Buffer io_Buffer;
+
+typedef struct {
+  float x;
+  float y;
+} geometry_Point;
+
+typedef struct {
+  geometry_Point center;
+  float radius;
+} geometry_shapes_Circle;
+
+typedef struct {
+  float value;
+} wrappers_Wrapper_f32;
+
+typedef struct {
+  geometry_Point origin;
+  geometry_shapes_Circle circle;
+  geometry_shapes_Kind kind;
+  wrappers_Wrapper_f32 scale;
+} Scene;
+
+extern uint32_t SCENE_COUNT;
+
+void draw(const Scene *scene, geometry_Point center);
+
+const Scene *find_scene(const char *name);
+
+io_Buffer *new_buffer(void);
+
+void free_buffer(io_Buffer *buffer);
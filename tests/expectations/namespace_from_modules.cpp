@@ -0,0 +1,115 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include <memory>
+
+namespace io {
+
+constexpr static const uint32_t MAX_SCENES = 16;
+
+} // namespace io
+
+namespace geometry {
+namespace shapes {
+
+enum class Kind : uint8_t {
+  Circle,
+  Square,
+};
+
+} // namespace shapes
+} // namespace geometry
+
+namespace io {
+
+struct Buffer;
+
+} // namespace io
+
+namespace geometry {
+
+struct Point {
+  float x;
+  float y;
+};
+
+} // namespace geometry
+
+namespace geometry {
+namespace shapes {
+
+struct Circle {
+  geometry::Point center;
+  float radius;
+};
+
+} // namespace shapes
+} // namespace geometry
+
+namespace wrappers {
+
+template<typename T>
+struct Wrapper {
+  T value;
+};
+
+} // namespace wrappers
+
+struct Scene {
+  geometry::Point origin;
+  geometry::shapes::Circle circle;
+  geometry::shapes::Kind kind;
+  wrappers::Wrapper<float> scale;
+};
+
+extern "C" {
+
+namespace io {
+
+extern uint32_t SCENE_COUNT;
+
+} // namespace io
+
+void draw(const Scene *scene, geometry::Point center);
+
+namespace io {
+
+const Scene *find_scene(const char *name);
+
+io::Buffer *new_buffer();
+
+void free_buffer(io::Buffer *buffer);
+
+} // namespace io
+
+} // extern "C"
+
+namespace io {
+
+#if __cplusplus >= 201703L
+[[nodiscard]] inline const Scene *try_find_scene(const char *name) {
+  return find_scene(name);
+}
+#endif
+
+#if __cplusplus >= 201703L
+[[nodiscard]] inline io::Buffer *try_new_buffer() {
+  return new_buffer();
+}
+#endif
+
+} // namespace io
+
+namespace io {
+
+struct BufferDeleter {
+  void operator()(Buffer *ptr) const {
+    io::free_buffer(ptr);
+  }
+};
+
+using BufferPtr = std::unique_ptr<Buffer, BufferDeleter>;
+
+} // namespace io
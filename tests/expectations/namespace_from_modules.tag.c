@@ -0,0 +1,45 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MAX_SCENES 16
+
+enum geometry_shapes_Kind {
+  Circle,
+  Square,
+};
+typedef uint8_t geometry_shapes_Kind;
+
+struct io_Buffer;
+
+struct geometry_Point {
+  float x;
+  float y;
+};
+
+struct geometry_shapes_Circle {
+  struct geometry_Point center;
+  float radius;
+};
+
+struct wrappers_Wrapper_f32 {
+  float value;
+};
+
+struct Scene {
+  struct geometry_Point origin;
+  struct geometry_shapes_Circle circle;
+  geometry_shapes_Kind kind;
+  struct wrappers_Wrapper_f32 scale;
+};
+
+extern uint32_t SCENE_COUNT;
+
+void draw(const struct Scene *scene, struct geometry_Point center);
+
+const struct Scene *find_scene(const char *name);
+
+struct io_Buffer *new_buffer(void);
+
+void free_buffer(struct io_Buffer *buffer);
@@ -0,0 +1,44 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const uint32_t MAX_SCENES # = 16
+
+  cdef enum:
+    Circle,
+    Square,
+  ctypedef uint8_t geometry_shapes_Kind;
+
+  cdef struct io_Buffer:
+    pass
+
+  cdef struct geometry_Point:
+    float x;
+    float y;
+
+  cdef struct geometry_shapes_Circle:
+    geometry_Point center;
+    float radius;
+
+  cdef struct wrappers_Wrapper_f32:
+    float value;
+
+  cdef struct Scene:
+    geometry_Point origin;
+    geometry_shapes_Circle circle;
+    geometry_shapes_Kind kind;
+    wrappers_Wrapper_f32 scale;
+
+  extern uint32_t SCENE_COUNT;
+
+  void draw(const Scene *scene, geometry_Point center);
+
+  const Scene *find_scene(const char *name);
+
+  io_Buffer *new_buffer();
+
+  void free_buffer(io_Buffer *buffer);
@@ -0,0 +1,35 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#else
+#define CBINDGEN_NONNULL
+#endif
+
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Buffer {
+  uint8_t *data;
+  uintptr_t len;
+};
+
+extern "C" {
+
+__attribute__((nonnull(1))) void buffer_fill(Buffer *CBINDGEN_NONNULL buffer, uint8_t value);
+
+__attribute__((nonnull(1, 3)))
+void buffer_copy(Buffer *CBINDGEN_NONNULL dst,
+                 const Buffer *src,
+                 uint8_t *CBINDGEN_NONNULL data);
+
+__attribute__((nonnull(2))) void buffer_visit(const Buffer *buffer, void (*visitor)(uint8_t));
+
+void buffer_clear(Buffer *CBINDGEN_NONNULL buffer);
+
+__attribute__((nonnull(1))) __attribute__((weak))
+uintptr_t buffer_len(const Buffer *CBINDGEN_NONNULL buffer);
+
+} // extern "C"
@@ -0,0 +1,40 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#else
+#define CBINDGEN_NONNULL
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Buffer {
+  uint8_t *data;
+  uintptr_t len;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+__attribute__((nonnull(1))) void buffer_fill(struct Buffer *CBINDGEN_NONNULL buffer, uint8_t value);
+
+__attribute__((nonnull(1, 3)))
+void buffer_copy(struct Buffer *CBINDGEN_NONNULL dst,
+                 const struct Buffer *src,
+                 uint8_t *CBINDGEN_NONNULL data);
+
+__attribute__((nonnull(2)))
+void buffer_visit(const struct Buffer *buffer,
+                  void (*visitor)(uint8_t));
+
+void buffer_clear(struct Buffer *CBINDGEN_NONNULL buffer);
+
+__attribute__((nonnull(1))) __attribute__((weak))
+uintptr_t buffer_len(const struct Buffer *CBINDGEN_NONNULL buffer);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,28 @@
+#ifdef __clang__
+#define CBINDGEN_NONNULL _Nonnull
+#else
+#define CBINDGEN_NONNULL
+#endif
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Buffer:
+    uint8_t *data;
+    uintptr_t len;
+
+  void buffer_fill(Buffer *buffer, uint8_t value);
+
+  void buffer_copy(Buffer *dst, const Buffer *src, uint8_t *data);
+
+  void buffer_visit(const Buffer *buffer, void (*visitor)(uint8_t));
+
+  void buffer_clear(Buffer *buffer);
+
+  uintptr_t buffer_len(const Buffer *buffer);
@@ -0,0 +1,19 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Callbacks {
+  void (*on_fatal_error)(int32_t);
+};
+
+extern "C" {
+
+[[noreturn]] void fatal_error(int32_t code);
+
+void maybe_exit(int32_t code);
+
+void set_callbacks(Callbacks callbacks);
+
+} // extern "C"
@@ -0,0 +1,14 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Callbacks {
+  void (*on_fatal_error)(int32_t);
+};
+
+_Noreturn void fatal_error(int32_t code);
+
+void maybe_exit(int32_t code);
+
+void set_callbacks(struct Callbacks callbacks);
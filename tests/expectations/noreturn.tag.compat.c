@@ -0,0 +1,27 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Callbacks {
+  void (*on_fatal_error)(int32_t);
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+#ifdef __cplusplus
+[[noreturn]]
+#else
+_Noreturn
+#endif // __cplusplus
+void fatal_error(int32_t code);
+
+void maybe_exit(int32_t code);
+
+void set_callbacks(struct Callbacks callbacks);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
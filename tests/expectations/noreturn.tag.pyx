@@ -0,0 +1,16 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Callbacks:
+    void (*on_fatal_error)(int32_t);
+
+  void fatal_error(int32_t code);
+
+  void maybe_exit(int32_t code);
+
+  void set_callbacks(Callbacks callbacks);
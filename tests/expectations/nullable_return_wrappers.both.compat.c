@@ -0,0 +1,31 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Entry {
+  uint32_t key;
+  uint32_t value;
+} Entry;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * The returned value borrows from `entries`, which must outlive it.
+ */
+const struct Entry *find(const struct Entry (*entries)[4], uint32_t key);
+
+struct Entry *first_mut(struct Entry *entries);
+
+const uint8_t *data(uint32_t);
+
+/**
+ * The returned value borrows from `entries`, which must outlive it.
+ */
+const struct Entry *get(const struct Entry (*entries)[4]);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
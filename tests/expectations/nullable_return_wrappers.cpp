@@ -0,0 +1,57 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#if __cplusplus >= 201703L
+#include <optional>
+#endif
+
+struct Entry {
+  uint32_t key;
+  uint32_t value;
+};
+
+extern "C" {
+
+/// The returned value borrows from `entries`, which must outlive it.
+const Entry *find(const Entry (*entries)[4], uint32_t key);
+
+Entry *first_mut(Entry *entries);
+
+const uint8_t *data(uint32_t);
+
+/// The returned value borrows from `entries`, which must outlive it.
+const Entry *get(const Entry (*entries)[4]);
+
+} // extern "C"
+
+#if __cplusplus >= 201703L
+[[nodiscard]] inline std::optional<const Entry*> try_find(const Entry (*entries)[4], uint32_t key) {
+  auto result = find(entries, key);
+  if (result == nullptr) {
+    return std::nullopt;
+  }
+  return result;
+}
+#endif
+
+#if __cplusplus >= 201703L
+[[nodiscard]] inline std::optional<Entry*> try_first_mut(Entry *entries) {
+  auto result = first_mut(entries);
+  if (result == nullptr) {
+    return std::nullopt;
+  }
+  return result;
+}
+#endif
+
+#if __cplusplus >= 201703L
+[[nodiscard]] inline std::optional<const uint8_t*> try_data(uint32_t arg0) {
+  auto result = data(arg0);
+  if (result == nullptr) {
+    return std::nullopt;
+  }
+  return result;
+}
+#endif
@@ -0,0 +1,21 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Entry:
+    uint32_t key;
+    uint32_t value;
+
+  # The returned value borrows from `entries`, which must outlive it.
+  const Entry *find(const Entry (*entries)[4], uint32_t key);
+
+  Entry *first_mut(Entry *entries);
+
+  const uint8_t *data(uint32_t);
+
+  # The returned value borrows from `entries`, which must outlive it.
+  const Entry *get(const Entry (*entries)[4]);
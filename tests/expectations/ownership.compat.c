@@ -0,0 +1,43 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Buffer Buffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Creates a new buffer.
+ *
+ * Ownership: the returned value is owned, the caller takes ownership of it.
+ */
+Buffer *buffer_new(void);
+
+/**
+ * Ownership: `buffer` is owned, ownership is transferred to the callee.
+ */
+void buffer_free(Buffer *buffer);
+
+/**
+ * Returns the contents of the buffer.
+ *
+ * Ownership: `buffer` is borrowed, the caller keeps ownership of it.
+ * Ownership: the returned value is borrowed, the caller must not free it.
+ */
+const uint8_t *buffer_data(const Buffer *buffer, uintptr_t *len);
+
+/**
+ * Appends `other` to `buffer`, returning the resulting buffer.
+ *
+ * Ownership: `buffer` is owned, ownership is transferred to the callee.
+ * Ownership: `other` is borrowed, the caller keeps ownership of it.
+ * Ownership: the returned value is owned, the caller takes ownership of it.
+ */
+Buffer *buffer_append(Buffer *buffer, const Buffer *other);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,41 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include <memory>
+
+struct Buffer;
+
+extern "C" {
+
+/// Creates a new buffer.
+///
+/// Ownership: the returned value is owned, the caller takes ownership of it.
+Buffer *buffer_new();
+
+/// Ownership: `buffer` is owned, ownership is transferred to the callee.
+void buffer_free(Buffer *buffer);
+
+/// Returns the contents of the buffer.
+///
+/// Ownership: `buffer` is borrowed, the caller keeps ownership of it.
+/// Ownership: the returned value is borrowed, the caller must not free it.
+const uint8_t *buffer_data(const Buffer *buffer, uintptr_t *len);
+
+/// Appends `other` to `buffer`, returning the resulting buffer.
+///
+/// Ownership: `buffer` is owned, ownership is transferred to the callee.
+/// Ownership: `other` is borrowed, the caller keeps ownership of it.
+/// Ownership: the returned value is owned, the caller takes ownership of it.
+Buffer *buffer_append(Buffer *buffer, const Buffer *other);
+
+} // extern "C"
+
+struct BufferDeleter {
+  void operator()(Buffer *ptr) const {
+    buffer_free(ptr);
+  }
+};
+
+using BufferPtr = std::unique_ptr<Buffer, BufferDeleter>;
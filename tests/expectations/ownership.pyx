@@ -0,0 +1,31 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Buffer:
+    pass
+
+  # Creates a new buffer.
+  #
+  # Ownership: the returned value is owned, the caller takes ownership of it.
+  Buffer *buffer_new();
+
+  # Ownership: `buffer` is owned, ownership is transferred to the callee.
+  void buffer_free(Buffer *buffer);
+
+  # Returns the contents of the buffer.
+  #
+  # Ownership: `buffer` is borrowed, the caller keeps ownership of it.
+  # Ownership: the returned value is borrowed, the caller must not free it.
+  const uint8_t *buffer_data(const Buffer *buffer, uintptr_t *len);
+
+  # Appends `other` to `buffer`, returning the resulting buffer.
+  #
+  # Ownership: `buffer` is owned, ownership is transferred to the callee.
+  # Ownership: `other` is borrowed, the caller keeps ownership of it.
+  # Ownership: the returned value is owned, the caller takes ownership of it.
+  Buffer *buffer_append(Buffer *buffer, const Buffer *other);
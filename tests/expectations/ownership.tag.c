@@ -0,0 +1,35 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Buffer;
+
+/**
+ * Creates a new buffer.
+ *
+ * Ownership: the returned value is owned, the caller takes ownership of it.
+ */
+struct Buffer *buffer_new(void);
+
+/**
+ * Ownership: `buffer` is owned, ownership is transferred to the callee.
+ */
+void buffer_free(struct Buffer *buffer);
+
+/**
+ * Returns the contents of the buffer.
+ *
+ * Ownership: `buffer` is borrowed, the caller keeps ownership of it.
+ * Ownership: the returned value is borrowed, the caller must not free it.
+ */
+const uint8_t *buffer_data(const struct Buffer *buffer, uintptr_t *len);
+
+/**
+ * Appends `other` to `buffer`, returning the resulting buffer.
+ *
+ * Ownership: `buffer` is owned, ownership is transferred to the callee.
+ * Ownership: `other` is borrowed, the caller keeps ownership of it.
+ * Ownership: the returned value is owned, the caller takes ownership of it.
+ */
+struct Buffer *buffer_append(struct Buffer *buffer, const struct Buffer *other);
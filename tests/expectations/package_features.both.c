@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Options {
+  uint32_t flags;
+  uint64_t extra;
+} Options;
+
+void init(struct Options options);
+
+void log_options(struct Options options);
+
+void options_extended(struct Options options);
+
+#if defined(DEFINE_UNIX)
+void options_unix(struct Options options);
+#endif
+
+#if defined(DEFINE_WINDOWS)
+void options_windows(struct Options options);
+#endif
@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t flags;
+  uint64_t extra;
+} Options;
+
+void init(Options options);
+
+void log_options(Options options);
+
+void options_extended(Options options);
+
+#if defined(DEFINE_UNIX)
+void options_unix(Options options);
+#endif
+
+#if defined(DEFINE_WINDOWS)
+void options_windows(Options options);
+#endif
@@ -0,0 +1,31 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t flags;
+  uint64_t extra;
+} Options;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void init(Options options);
+
+void log_options(Options options);
+
+void options_extended(Options options);
+
+#if defined(DEFINE_UNIX)
+void options_unix(Options options);
+#endif
+
+#if defined(DEFINE_WINDOWS)
+void options_windows(Options options);
+#endif
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
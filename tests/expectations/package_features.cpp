@@ -0,0 +1,28 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Options {
+  uint32_t flags;
+  uint64_t extra;
+};
+
+extern "C" {
+
+void init(Options options);
+
+void log_options(Options options);
+
+void options_extended(Options options);
+
+#if defined(DEFINE_UNIX)
+void options_unix(Options options);
+#endif
+
+#if defined(DEFINE_WINDOWS)
+void options_windows(Options options);
+#endif
+
+} // extern "C"
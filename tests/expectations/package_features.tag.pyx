@@ -0,0 +1,23 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Options:
+    uint32_t flags;
+    uint64_t extra;
+
+  void init(Options options);
+
+  void log_options(Options options);
+
+  void options_extended(Options options);
+
+  IF DEFINE_UNIX:
+    void options_unix(Options options);
+
+  IF DEFINE_WINDOWS:
+    void options_windows(Options options);
@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Alpha {
+  uint32_t a;
+} Alpha;
+
+typedef struct Beta {
+  float b;
+} Beta;
+
+typedef struct Plugin {
+  struct Alpha alpha;
+  uint32_t version;
+} Plugin;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void combine(struct Alpha alpha, struct Beta beta);
+
+uint32_t plugin_version(const struct Plugin *plugin);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t a;
+} Alpha;
+
+typedef struct {
+  float b;
+} Beta;
+
+typedef struct {
+  Alpha alpha;
+  uint32_t version;
+} Plugin;
+
+void combine(Alpha alpha, Beta beta);
+
+uint32_t plugin_version(const Plugin *plugin);
@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t a;
+} Alpha;
+
+typedef struct {
+  float b;
+} Beta;
+
+typedef struct {
+  Alpha alpha;
+  uint32_t version;
+} Plugin;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void combine(Alpha alpha, Beta beta);
+
+uint32_t plugin_version(const Plugin *plugin);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,26 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Alpha {
+  uint32_t a;
+};
+
+struct Beta {
+  float b;
+};
+
+struct Plugin {
+  Alpha alpha;
+  uint32_t version;
+};
+
+extern "C" {
+
+void combine(Alpha alpha, Beta beta);
+
+uint32_t plugin_version(const Plugin *plugin);
+
+} // extern "C"
@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Alpha {
+  uint32_t a;
+};
+
+struct Beta {
+  float b;
+};
+
+struct Plugin {
+  struct Alpha alpha;
+  uint32_t version;
+};
+
+void combine(struct Alpha alpha, struct Beta beta);
+
+uint32_t plugin_version(const struct Plugin *plugin);
@@ -7,8 +7,8 @@ struct PREFIXFoo {
   int32_t a;
   uint32_t b;
 };
-#define PREFIXFoo_FOO (PREFIXFoo){ .a = 42, .b = 47 }
+#define PREFIXFoo_FOO (struct PREFIXFoo){ .a = 42, .b = 47 }
 
-#define PREFIXBAR (PREFIXFoo){ .a = 42, .b = 1337 }
+#define PREFIXBAR (struct PREFIXFoo){ .a = 42, .b = 1337 }
 
 void root(struct PREFIXFoo x);
@@ -13,6 +13,6 @@ struct PREFIXFoo {
   struct PREFIXBar bar;
 };
 
-#define PREFIXVAL (PREFIXFoo){ .a = 42, .b = 1337, .bar = (PREFIXBar){ .a = 323 } }
+#define PREFIXVAL (struct PREFIXFoo){ .a = 42, .b = 1337, .bar = (struct PREFIXBar){ .a = 323 } }
 
 void root(struct PREFIXFoo x);
@@ -13,7 +13,7 @@ struct PREFIXFoo {
   struct PREFIXBar bar;
 };
 
-#define PREFIXVAL (PREFIXFoo){ .a = 42, .b = 1337, .bar = (PREFIXBar){ .a = 323 } }
+#define PREFIXVAL (struct PREFIXFoo){ .a = 42, .b = 1337, .bar = (struct PREFIXBar){ .a = 323 } }
 
 #ifdef __cplusplus
 extern "C" {
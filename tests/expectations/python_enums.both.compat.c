@@ -0,0 +1,90 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * How shapes are filled.
+ */
+enum FillRule
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  /**
+   * The non-zero winding rule.
+   */
+  NonZero,
+  EvenOdd = 4,
+  None,
+};
+#ifndef __cplusplus
+typedef uint8_t FillRule;
+#endif // __cplusplus
+
+enum Level
+#ifdef __cplusplus
+  : int32_t
+#endif // __cplusplus
+ {
+  Low = -1,
+  High = (1 << 4),
+  Higher,
+};
+#ifndef __cplusplus
+typedef int32_t Level;
+#endif // __cplusplus
+
+enum Permissions
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Read = 1,
+  Write = 2,
+  Execute = 4,
+};
+#ifndef __cplusplus
+typedef uint8_t Permissions;
+#endif // __cplusplus
+#define Permissions_OR(a, b) ((Permissions)((a) | (b)))
+#define Permissions_AND(a, b) ((Permissions)((a) & (b)))
+#define Permissions_XOR(a, b) ((Permissions)((a) ^ (b)))
+#define Permissions_NOT(a) ((Permissions)~(a))
+
+enum Shape_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Circle,
+  Square,
+  Nothing,
+};
+#ifndef __cplusplus
+typedef uint8_t Shape_Tag;
+#endif // __cplusplus
+
+typedef struct Square_Body {
+  float side;
+} Square_Body;
+
+typedef struct Shape {
+  Shape_Tag tag;
+  union {
+    struct {
+      float circle;
+    };
+    Square_Body square;
+  };
+} Shape;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(FillRule a, Level b, struct Shape c, Permissions d);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
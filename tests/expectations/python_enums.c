@@ -0,0 +1,58 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * How shapes are filled.
+ */
+enum FillRule {
+  /**
+   * The non-zero winding rule.
+   */
+  NonZero,
+  EvenOdd = 4,
+  None,
+};
+typedef uint8_t FillRule;
+
+enum Level {
+  Low = -1,
+  High = (1 << 4),
+  Higher,
+};
+typedef int32_t Level;
+
+enum Permissions {
+  Read = 1,
+  Write = 2,
+  Execute = 4,
+};
+typedef uint8_t Permissions;
+#define Permissions_OR(a, b) ((Permissions)((a) | (b)))
+#define Permissions_AND(a, b) ((Permissions)((a) & (b)))
+#define Permissions_XOR(a, b) ((Permissions)((a) ^ (b)))
+#define Permissions_NOT(a) ((Permissions)~(a))
+
+enum Shape_Tag {
+  Circle,
+  Square,
+  Nothing,
+};
+typedef uint8_t Shape_Tag;
+
+typedef struct {
+  float side;
+} Square_Body;
+
+typedef struct {
+  Shape_Tag tag;
+  union {
+    struct {
+      float circle;
+    };
+    Square_Body square;
+  };
+} Shape;
+
+void root(FillRule a, Level b, Shape c, Permissions d);
@@ -0,0 +1,85 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/// How shapes are filled.
+enum class FillRule : uint8_t {
+  /// The non-zero winding rule.
+  NonZero,
+  EvenOdd = 4,
+  None,
+};
+
+enum class Level : int32_t {
+  Low = -1,
+  High = (1 << 4),
+  Higher,
+};
+
+enum class Permissions : uint8_t {
+  Read = 1,
+  Write = 2,
+  Execute = 4,
+};
+
+constexpr Permissions operator|(Permissions lhs, Permissions rhs) {
+  return static_cast<Permissions>(static_cast<uint8_t>(lhs) | static_cast<uint8_t>(rhs));
+}
+
+constexpr Permissions operator&(Permissions lhs, Permissions rhs) {
+  return static_cast<Permissions>(static_cast<uint8_t>(lhs) & static_cast<uint8_t>(rhs));
+}
+
+constexpr Permissions operator^(Permissions lhs, Permissions rhs) {
+  return static_cast<Permissions>(static_cast<uint8_t>(lhs) ^ static_cast<uint8_t>(rhs));
+}
+
+constexpr Permissions operator~(Permissions lhs) {
+  return static_cast<Permissions>(~static_cast<uint8_t>(lhs));
+}
+
+constexpr bool has_flag(Permissions value, Permissions flag) {
+  return (value & flag) == flag;
+}
+
+inline Permissions& operator|=(Permissions& lhs, Permissions rhs) {
+  return lhs = lhs | rhs;
+}
+
+inline Permissions& operator&=(Permissions& lhs, Permissions rhs) {
+  return lhs = lhs & rhs;
+}
+
+inline Permissions& operator^=(Permissions& lhs, Permissions rhs) {
+  return lhs = lhs ^ rhs;
+}
+
+struct Shape {
+  enum class Tag : uint8_t {
+    Circle,
+    Square,
+    Nothing,
+  };
+
+  struct Circle_Body {
+    float _0;
+  };
+
+  struct Square_Body {
+    float side;
+  };
+
+  Tag tag;
+  union {
+    Circle_Body circle;
+    Square_Body square;
+  };
+};
+
+extern "C" {
+
+void root(FillRule a, Level b, Shape c, Permissions d);
+
+} // extern "C"
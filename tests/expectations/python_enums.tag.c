@@ -0,0 +1,58 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * How shapes are filled.
+ */
+enum FillRule {
+  /**
+   * The non-zero winding rule.
+   */
+  NonZero,
+  EvenOdd = 4,
+  None,
+};
+typedef uint8_t FillRule;
+
+enum Level {
+  Low = -1,
+  High = (1 << 4),
+  Higher,
+};
+typedef int32_t Level;
+
+enum Permissions {
+  Read = 1,
+  Write = 2,
+  Execute = 4,
+};
+typedef uint8_t Permissions;
+#define Permissions_OR(a, b) ((Permissions)((a) | (b)))
+#define Permissions_AND(a, b) ((Permissions)((a) & (b)))
+#define Permissions_XOR(a, b) ((Permissions)((a) ^ (b)))
+#define Permissions_NOT(a) ((Permissions)~(a))
+
+enum Shape_Tag {
+  Circle,
+  Square,
+  Nothing,
+};
+typedef uint8_t Shape_Tag;
+
+struct Square_Body {
+  float side;
+};
+
+struct Shape {
+  Shape_Tag tag;
+  union {
+    struct {
+      float circle;
+    };
+    struct Square_Body square;
+  };
+};
+
+void root(FillRule a, Level b, struct Shape c, Permissions d);
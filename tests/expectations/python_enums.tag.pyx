@@ -0,0 +1,43 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  # How shapes are filled.
+  cdef enum:
+    # The non-zero winding rule.
+    NonZero,
+    EvenOdd # = 4,
+    None,
+  ctypedef uint8_t FillRule;
+
+  cdef enum:
+    Low # = -1,
+    High # = (1 << 4),
+    Higher,
+  ctypedef int32_t Level;
+
+  cdef enum:
+    Read # = 1,
+    Write # = 2,
+    Execute # = 4,
+  ctypedef uint8_t Permissions;
+
+  cdef enum:
+    Circle,
+    Square,
+    Nothing,
+  ctypedef uint8_t Shape_Tag;
+
+  cdef struct Square_Body:
+    float side;
+
+  cdef struct Shape:
+    Shape_Tag tag;
+    float circle;
+    Square_Body square;
+
+  void root(FillRule a, Level b, Shape c, Permissions d);
@@ -0,0 +1,41 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Instruction {
+  /**
+   * The kind of the instruction.
+   */
+  uint8_t kind;
+  uint32_t reg;
+  int64_t operand;
+} Instruction;
+
+typedef union Value {
+  int64_t as_int;
+  double real;
+} Value;
+
+typedef struct Pair {
+  int32_t first;
+  int32_t _1;
+} Pair;
+
+enum Operand_Tag {
+  Immediate,
+};
+typedef uint8_t Operand_Tag;
+
+typedef struct Immediate_Body {
+  int64_t value;
+} Immediate_Body;
+
+typedef struct Operand {
+  Operand_Tag tag;
+  union {
+    Immediate_Body immediate;
+  };
+} Operand;
+
+void root(struct Instruction i, union Value v, struct Pair p, struct Operand o);
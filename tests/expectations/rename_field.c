@@ -0,0 +1,41 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  /**
+   * The kind of the instruction.
+   */
+  uint8_t kind;
+  uint32_t reg;
+  int64_t operand;
+} Instruction;
+
+typedef union {
+  int64_t as_int;
+  double real;
+} Value;
+
+typedef struct {
+  int32_t first;
+  int32_t _1;
+} Pair;
+
+enum Operand_Tag {
+  Immediate,
+};
+typedef uint8_t Operand_Tag;
+
+typedef struct {
+  int64_t value;
+} Immediate_Body;
+
+typedef struct {
+  Operand_Tag tag;
+  union {
+    Immediate_Body immediate;
+  };
+} Operand;
+
+void root(Instruction i, Value v, Pair p, Operand o);
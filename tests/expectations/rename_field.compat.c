@@ -0,0 +1,55 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  /**
+   * The kind of the instruction.
+   */
+  uint8_t kind;
+  uint32_t reg;
+  int64_t operand;
+} Instruction;
+
+typedef union {
+  int64_t as_int;
+  double real;
+} Value;
+
+typedef struct {
+  int32_t first;
+  int32_t _1;
+} Pair;
+
+enum Operand_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Immediate,
+};
+#ifndef __cplusplus
+typedef uint8_t Operand_Tag;
+#endif // __cplusplus
+
+typedef struct {
+  int64_t value;
+} Immediate_Body;
+
+typedef struct {
+  Operand_Tag tag;
+  union {
+    Immediate_Body immediate;
+  };
+} Operand;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Instruction i, Value v, Pair p, Operand o);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,64 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Instruction {
+  /// The kind of the instruction.
+  uint8_t kind;
+  uint32_t reg;
+  int64_t operand;
+
+  Instruction(uint8_t const& kind,
+              uint32_t const& reg,
+              int64_t const& operand)
+    : kind(kind),
+      reg(reg),
+      operand(operand)
+  {}
+
+};
+
+union Value {
+  int64_t as_int;
+  double real;
+};
+
+struct Pair {
+  int32_t first;
+  int32_t _1;
+
+  Pair(int32_t const& first,
+       int32_t const& _1)
+    : first(first),
+      _1(_1)
+  {}
+
+};
+
+struct Operand {
+  enum class Tag : uint8_t {
+    Immediate,
+  };
+
+  struct Immediate_Body {
+    int64_t value;
+
+    Immediate_Body(int64_t const& value)
+      : value(value)
+    {}
+
+  };
+
+  Tag tag;
+  union {
+    Immediate_Body immediate;
+  };
+};
+
+extern "C" {
+
+void root(Instruction i, Value v, Pair p, Operand o);
+
+} // extern "C"
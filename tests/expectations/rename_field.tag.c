@@ -0,0 +1,41 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Instruction {
+  /**
+   * The kind of the instruction.
+   */
+  uint8_t kind;
+  uint32_t reg;
+  int64_t operand;
+};
+
+union Value {
+  int64_t as_int;
+  double real;
+};
+
+struct Pair {
+  int32_t first;
+  int32_t _1;
+};
+
+enum Operand_Tag {
+  Immediate,
+};
+typedef uint8_t Operand_Tag;
+
+struct Immediate_Body {
+  int64_t value;
+};
+
+struct Operand {
+  Operand_Tag tag;
+  union {
+    struct Immediate_Body immediate;
+  };
+};
+
+void root(struct Instruction i, union Value v, struct Pair p, struct Operand o);
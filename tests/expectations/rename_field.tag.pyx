@@ -0,0 +1,34 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Instruction:
+    # The kind of the instruction.
+    uint8_t kind;
+    uint32_t reg;
+    int64_t operand;
+
+  cdef union Value:
+    int64_t as_int;
+    double real;
+
+  cdef struct Pair:
+    int32_t first;
+    int32_t _1;
+
+  cdef enum:
+    Immediate,
+  ctypedef uint8_t Operand_Tag;
+
+  cdef struct Immediate_Body:
+    int64_t value;
+
+  cdef struct Operand:
+    Operand_Tag tag;
+    Immediate_Body immediate;
+
+  void root(Instruction i, Value v, Pair p, Operand o);
@@ -0,0 +1,33 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Mode : uint8_t {
+  MODE_READ,
+  MODE_WRITE_ALL,
+  Register,
+};
+
+struct Event {
+  enum class Tag : uint8_t {
+    EVENT_START,
+    Finish,
+  };
+
+  struct Event_Stop_Body {
+    int32_t code;
+  };
+
+  Tag tag;
+  union {
+    Event_Stop_Body STOP;
+  };
+};
+
+extern "C" {
+
+void root(Mode m, Event e);
+
+} // extern "C"
@@ -0,0 +1,27 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum:
+    MODE_READ,
+    MODE_WRITE_ALL,
+    Register,
+  ctypedef uint8_t Mode;
+
+  cdef enum:
+    EVENT_START,
+    Finish,
+  ctypedef uint8_t Event_Tag;
+
+  ctypedef struct Event_Stop_Body:
+    int32_t code;
+
+  ctypedef struct Event:
+    Event_Tag tag;
+    Event_Stop_Body STOP;
+
+  void root(Mode m, Event e);
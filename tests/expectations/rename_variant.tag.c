@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Mode {
+  MODE_READ,
+  MODE_WRITE_ALL,
+  Register,
+};
+typedef uint8_t Mode;
+
+enum Event_Tag {
+  EVENT_START,
+  Finish,
+};
+typedef uint8_t Event_Tag;
+
+struct Event_Stop_Body {
+  int32_t code;
+};
+
+struct Event {
+  Event_Tag tag;
+  union {
+    struct Event_Stop_Body STOP;
+  };
+};
+
+void root(Mode m, struct Event e);
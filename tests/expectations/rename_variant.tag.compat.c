@@ -0,0 +1,50 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Mode
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  MODE_READ,
+  MODE_WRITE_ALL,
+  Register,
+};
+#ifndef __cplusplus
+typedef uint8_t Mode;
+#endif // __cplusplus
+
+enum Event_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  EVENT_START,
+  Finish,
+};
+#ifndef __cplusplus
+typedef uint8_t Event_Tag;
+#endif // __cplusplus
+
+struct Event_Stop_Body {
+  int32_t code;
+};
+
+struct Event {
+  Event_Tag tag;
+  union {
+    struct Event_Stop_Body STOP;
+  };
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Mode m, struct Event e);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,13 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+void copy_floats(float *restrict dst, const float *restrict src, uintptr_t len);
+
+void add_floats(float *restrict out,
+                const float *restrict lhs,
+                const float *restrict rhs,
+                uintptr_t len);
+
+void scale_floats(float *values, float factor, uintptr_t len);
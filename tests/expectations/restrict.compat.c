@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void copy_floats(float *__restrict dst, const float *__restrict src, uintptr_t len);
+
+void add_floats(float *__restrict out,
+                const float *__restrict lhs,
+                const float *__restrict rhs,
+                uintptr_t len);
+
+void scale_floats(float *values, float factor, uintptr_t len);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
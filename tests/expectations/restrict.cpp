@@ -0,0 +1,18 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+extern "C" {
+
+void copy_floats(float *__restrict dst, const float *__restrict src, uintptr_t len);
+
+void add_floats(float *__restrict out,
+                const float *__restrict lhs,
+                const float *__restrict rhs,
+                uintptr_t len);
+
+void scale_floats(float *values, float factor, uintptr_t len);
+
+} // extern "C"
@@ -0,0 +1,13 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  void copy_floats(float *dst, const float *src, uintptr_t len);
+
+  void add_floats(float *out, const float *lhs, const float *rhs, uintptr_t len);
+
+  void scale_floats(float *values, float factor, uintptr_t len);
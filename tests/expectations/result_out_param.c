@@ -0,0 +1,28 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Error {
+  Ok = 0,
+  InvalidInput = 1,
+  OutOfMemory = 2,
+};
+typedef uint8_t Error;
+
+typedef struct Handle Handle;
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+/**
+ * Parses a point.
+ *
+ */
+Error parse_point(const uint8_t *input, uintptr_t len, Point *out);
+
+int32_t open(uint32_t id, Handle **handle);
+
+Error handle_len(const Handle *handle, uint32_t *len);
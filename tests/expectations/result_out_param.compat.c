@@ -0,0 +1,42 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Error
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Ok = 0,
+  InvalidInput = 1,
+  OutOfMemory = 2,
+};
+#ifndef __cplusplus
+typedef uint8_t Error;
+#endif // __cplusplus
+
+typedef struct Handle Handle;
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Parses a point.
+ *
+ */
+Error parse_point(const uint8_t *input, uintptr_t len, Point *out);
+
+int32_t open(uint32_t id, Handle **handle);
+
+Error handle_len(const Handle *handle, uint32_t *len);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,30 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Error : uint8_t {
+  Ok = 0,
+  InvalidInput = 1,
+  OutOfMemory = 2,
+};
+
+struct Handle;
+
+struct Point {
+  float x;
+  float y;
+};
+
+extern "C" {
+
+/// Parses a point.
+///
+Error parse_point(const uint8_t *input, uintptr_t len, Point *out);
+
+int32_t open(uint32_t id, Handle **handle);
+
+Error handle_len(const Handle *handle, uint32_t *len);
+
+} // extern "C"
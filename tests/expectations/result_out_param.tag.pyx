@@ -0,0 +1,28 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum:
+    Ok # = 0,
+    InvalidInput # = 1,
+    OutOfMemory # = 2,
+  ctypedef uint8_t Error;
+
+  cdef struct Handle:
+    pass
+
+  cdef struct Point:
+    float x;
+    float y;
+
+  # Parses a point.
+  #
+  Error parse_point(const uint8_t *input, uintptr_t len, Point *out);
+
+  int32_t open(uint32_t id, Handle **handle);
+
+  Error handle_len(const Handle *handle, uint32_t *len);
@@ -0,0 +1,28 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Table {
+  uint32_t *rows;
+  uintptr_t len;
+} Table;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+const uint32_t *table_rows(struct Table *table);
+
+/**
+ * The returned value borrows from `table`, which must outlive it.
+ */
+const struct Table *table_get(struct Table *table);
+
+const uint32_t *table_first(const struct Table *table);
+
+uint32_t *table_rows_mut(struct Table *table);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,20 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t *rows;
+  uintptr_t len;
+} Table;
+
+const uint32_t *table_rows(Table *table);
+
+/**
+ * The returned value borrows from `table`, which must outlive it.
+ */
+const Table *table_get(Table *table);
+
+const uint32_t *table_first(const Table *table);
+
+uint32_t *table_rows_mut(Table *table);
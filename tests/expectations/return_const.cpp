@@ -0,0 +1,23 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Table {
+  uint32_t *rows;
+  uintptr_t len;
+};
+
+extern "C" {
+
+const uint32_t *table_rows(Table *table);
+
+/// The returned value borrows from `table`, which must outlive it.
+const Table *table_get(Table *table);
+
+const uint32_t *table_first(const Table *table);
+
+uint32_t *table_rows_mut(Table *table);
+
+} // extern "C"
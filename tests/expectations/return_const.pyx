@@ -0,0 +1,20 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Table:
+    uint32_t *rows;
+    uintptr_t len;
+
+  const uint32_t *table_rows(Table *table);
+
+  # The returned value borrows from `table`, which must outlive it.
+  const Table *table_get(Table *table);
+
+  const uint32_t *table_first(const Table *table);
+
+  uint32_t *table_rows_mut(Table *table);
@@ -0,0 +1,20 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Table {
+  uint32_t *rows;
+  uintptr_t len;
+};
+
+const uint32_t *table_rows(struct Table *table);
+
+/**
+ * The returned value borrows from `table`, which must outlive it.
+ */
+const struct Table *table_get(struct Table *table);
+
+const uint32_t *table_first(const struct Table *table);
+
+uint32_t *table_rows_mut(struct Table *table);
@@ -0,0 +1,43 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  const uint32_t *ptr;
+  uintptr_t len;
+} SliceRef_u32;
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+typedef struct {
+  Point *ptr;
+  uintptr_t len;
+} SliceMut_Point;
+
+typedef struct {
+  const uint8_t *ptr;
+  uintptr_t len;
+} SliceRef_u8;
+
+typedef struct {
+  const Point *ptr;
+  uintptr_t len;
+} SliceRef_Point;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+uint64_t sum(SliceRef_u32 values);
+
+void fill(SliceMut_Point points, const SliceRef_u8 *bytes);
+
+SliceRef_Point origins(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
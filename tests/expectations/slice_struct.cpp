@@ -0,0 +1,32 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+template<typename T>
+struct SliceRef {
+  const T *ptr;
+  uintptr_t len;
+};
+
+struct Point {
+  float x;
+  float y;
+};
+
+template<typename T>
+struct SliceMut {
+  T *ptr;
+  uintptr_t len;
+};
+
+extern "C" {
+
+uint64_t sum(SliceRef<uint32_t> values);
+
+void fill(SliceMut<Point> points, const SliceRef<uint8_t> *bytes);
+
+SliceRef<Point> origins();
+
+} // extern "C"
@@ -0,0 +1,33 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct SliceRef_u32:
+    const uint32_t *ptr;
+    uintptr_t len;
+
+  ctypedef struct Point:
+    float x;
+    float y;
+
+  ctypedef struct SliceMut_Point:
+    Point *ptr;
+    uintptr_t len;
+
+  ctypedef struct SliceRef_u8:
+    const uint8_t *ptr;
+    uintptr_t len;
+
+  ctypedef struct SliceRef_Point:
+    const Point *ptr;
+    uintptr_t len;
+
+  uint64_t sum(SliceRef_u32 values);
+
+  void fill(SliceMut_Point points, const SliceRef_u8 *bytes);
+
+  SliceRef_Point origins();
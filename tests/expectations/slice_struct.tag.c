@@ -0,0 +1,35 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct SliceRef_u32 {
+  const uint32_t *ptr;
+  uintptr_t len;
+};
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct SliceMut_Point {
+  struct Point *ptr;
+  uintptr_t len;
+};
+
+struct SliceRef_u8 {
+  const uint8_t *ptr;
+  uintptr_t len;
+};
+
+struct SliceRef_Point {
+  const struct Point *ptr;
+  uintptr_t len;
+};
+
+uint64_t sum(struct SliceRef_u32 values);
+
+void fill(struct SliceMut_Point points, const struct SliceRef_u8 *bytes);
+
+struct SliceRef_Point origins(void);
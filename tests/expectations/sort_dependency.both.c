@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define FIRST 1
+
+#define LAST (FIRST + 1)
+
+typedef struct Zeta {
+  int32_t a;
+} Zeta;
+
+typedef struct Alpha {
+  struct Zeta a;
+} Alpha;
+
+extern int32_t ZULU;
+
+extern int32_t ALPHA;
+
+void zulu(struct Alpha a);
+
+void alpha(struct Zeta z);
@@ -0,0 +1,32 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define FIRST 1
+
+#define LAST (FIRST + 1)
+
+typedef struct Zeta {
+  int32_t a;
+} Zeta;
+
+typedef struct Alpha {
+  struct Zeta a;
+} Alpha;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern int32_t ZULU;
+
+extern int32_t ALPHA;
+
+void zulu(struct Alpha a);
+
+void alpha(struct Zeta z);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,29 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+constexpr static const int32_t FIRST = 1;
+
+constexpr static const int32_t LAST = (FIRST + 1);
+
+struct Zeta {
+  int32_t a;
+};
+
+struct Alpha {
+  Zeta a;
+};
+
+extern "C" {
+
+extern int32_t ZULU;
+
+extern int32_t ALPHA;
+
+void zulu(Alpha a);
+
+void alpha(Zeta z);
+
+} // extern "C"
@@ -0,0 +1,25 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  const int32_t FIRST # = 1
+
+  const int32_t LAST # = (FIRST + 1)
+
+  cdef struct Zeta:
+    int32_t a;
+
+  cdef struct Alpha:
+    Zeta a;
+
+  extern int32_t ZULU;
+
+  extern int32_t ALPHA;
+
+  void zulu(Alpha a);
+
+  void alpha(Zeta z);
@@ -0,0 +1,12 @@
+#ifndef SPLIT_BY_MODULE_H
+#define SPLIT_BY_MODULE_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "split_by_module.compat_shapes.c"
+#include "split_by_module.compat_colors.c"
+#include "split_by_module.compat_crate.c"
+
+#endif /* SPLIT_BY_MODULE_H */
@@ -0,0 +1,13 @@
+#ifndef SPLIT_BY_MODULE_H
+#define SPLIT_BY_MODULE_H
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include "split_by_module_shapes.cpp"
+#include "split_by_module_colors.cpp"
+#include "split_by_module_crate.cpp"
+
+#endif // SPLIT_BY_MODULE_H
@@ -0,0 +1,11 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+from split_by_module.tag_colors cimport *
+from split_by_module.tag_crate cimport *
+from split_by_module.tag_shapes cimport *
+
+cdef extern from *:
+  pass
\ No newline at end of file
@@ -0,0 +1,96 @@
+#define CBINDGEN_PACKED     __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n) __attribute__ ((aligned(n)))
+
+
+#include <assert.h>
+#include <stdalign.h>
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Kind
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  A,
+  B,
+};
+#ifndef __cplusplus
+typedef uint8_t Kind;
+#endif // __cplusplus
+
+typedef enum {
+  Ok,
+  Failed,
+} Status;
+
+typedef struct {
+  Kind kind;
+  uint32_t len;
+  Status status;
+} Header;
+static_assert(sizeof(Header) == 12, "unexpected size of Header");
+static_assert(alignof(Header) == 4, "unexpected alignment of Header");
+static_assert(offsetof(Header, kind) == 0, "unexpected offset of Header.kind");
+static_assert(offsetof(Header, len) == 4, "unexpected offset of Header.len");
+static_assert(offsetof(Header, status) == 8, "unexpected offset of Header.status");
+
+typedef union {
+  int32_t number;
+  uint8_t *ptr;
+} Value;
+
+typedef struct {
+  Header header;
+  uint8_t flags;
+  uint16_t payload[3];
+  Value value;
+  bool (*callback)(uint32_t);
+} Packet;
+#if UINTPTR_MAX == UINT64_MAX
+static_assert(sizeof(Packet) == 40, "unexpected size of Packet");
+static_assert(alignof(Packet) == 8, "unexpected alignment of Packet");
+static_assert(offsetof(Packet, header) == 0, "unexpected offset of Packet.header");
+static_assert(offsetof(Packet, flags) == 12, "unexpected offset of Packet.flags");
+static_assert(offsetof(Packet, payload) == 14, "unexpected offset of Packet.payload");
+static_assert(offsetof(Packet, value) == 24, "unexpected offset of Packet.value");
+static_assert(offsetof(Packet, callback) == 32, "unexpected offset of Packet.callback");
+#endif
+
+typedef struct CBINDGEN_PACKED {
+  uint8_t a;
+  uint64_t b;
+} Packed;
+#if UINTPTR_MAX == UINT64_MAX
+static_assert(sizeof(Packed) == 9, "unexpected size of Packed");
+static_assert(alignof(Packed) == 1, "unexpected alignment of Packed");
+static_assert(offsetof(Packed, a) == 0, "unexpected offset of Packed.a");
+static_assert(offsetof(Packed, b) == 1, "unexpected offset of Packed.b");
+#endif
+
+typedef struct CBINDGEN_ALIGNED(16) {
+  uint8_t a;
+} Aligned;
+static_assert(sizeof(Aligned) == 16, "unexpected size of Aligned");
+static_assert(alignof(Aligned) == 16, "unexpected alignment of Aligned");
+static_assert(offsetof(Aligned, a) == 0, "unexpected offset of Aligned.a");
+
+/**
+ * Not checked, as the size of `long` varies.
+ */
+typedef struct {
+  long a;
+} Long;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Packet p, Packed q, Aligned a, Long l);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
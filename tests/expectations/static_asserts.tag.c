@@ -0,0 +1,82 @@
+#define CBINDGEN_PACKED     __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n) __attribute__ ((aligned(n)))
+
+
+#include <assert.h>
+#include <stdalign.h>
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Kind {
+  A,
+  B,
+};
+typedef uint8_t Kind;
+
+enum Status {
+  Ok,
+  Failed,
+};
+
+struct Header {
+  Kind kind;
+  uint32_t len;
+  enum Status status;
+};
+static_assert(sizeof(struct Header) == 12, "unexpected size of Header");
+static_assert(alignof(struct Header) == 4, "unexpected alignment of Header");
+static_assert(offsetof(struct Header, kind) == 0, "unexpected offset of Header.kind");
+static_assert(offsetof(struct Header, len) == 4, "unexpected offset of Header.len");
+static_assert(offsetof(struct Header, status) == 8, "unexpected offset of Header.status");
+
+union Value {
+  int32_t number;
+  uint8_t *ptr;
+};
+
+struct Packet {
+  struct Header header;
+  uint8_t flags;
+  uint16_t payload[3];
+  union Value value;
+  bool (*callback)(uint32_t);
+};
+#if UINTPTR_MAX == UINT64_MAX
+static_assert(sizeof(struct Packet) == 40, "unexpected size of Packet");
+static_assert(alignof(struct Packet) == 8, "unexpected alignment of Packet");
+static_assert(offsetof(struct Packet, header) == 0, "unexpected offset of Packet.header");
+static_assert(offsetof(struct Packet, flags) == 12, "unexpected offset of Packet.flags");
+static_assert(offsetof(struct Packet, payload) == 14, "unexpected offset of Packet.payload");
+static_assert(offsetof(struct Packet, value) == 24, "unexpected offset of Packet.value");
+static_assert(offsetof(struct Packet, callback) == 32, "unexpected offset of Packet.callback");
+#endif
+
+struct CBINDGEN_PACKED Packed {
+  uint8_t a;
+  uint64_t b;
+};
+#if UINTPTR_MAX == UINT64_MAX
+static_assert(sizeof(struct Packed) == 9, "unexpected size of Packed");
+static_assert(alignof(struct Packed) == 1, "unexpected alignment of Packed");
+static_assert(offsetof(struct Packed, a) == 0, "unexpected offset of Packed.a");
+static_assert(offsetof(struct Packed, b) == 1, "unexpected offset of Packed.b");
+#endif
+
+struct CBINDGEN_ALIGNED(16) Aligned {
+  uint8_t a;
+};
+static_assert(sizeof(struct Aligned) == 16, "unexpected size of Aligned");
+static_assert(alignof(struct Aligned) == 16, "unexpected alignment of Aligned");
+static_assert(offsetof(struct Aligned, a) == 0, "unexpected offset of Aligned.a");
+
+/**
+ * Not checked, as the size of `long` varies.
+ */
+struct Long {
+  long a;
+};
+
+void root(struct Packet p, struct Packed q, struct Aligned a, struct Long l);
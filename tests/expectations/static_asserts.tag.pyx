@@ -0,0 +1,50 @@
+#define CBINDGEN_PACKED     __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n) __attribute__ ((aligned(n)))
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef enum:
+    A,
+    B,
+  ctypedef uint8_t Kind;
+
+  cdef enum Status:
+    Ok,
+    Failed,
+
+  cdef struct Header:
+    Kind kind;
+    uint32_t len;
+    Status status;
+
+  cdef union Value:
+    int32_t number;
+    uint8_t *ptr;
+
+  cdef struct Packet:
+    Header header;
+    uint8_t flags;
+    uint16_t payload[3];
+    Value value;
+    bool (*callback)(uint32_t);
+
+  cdef packed struct Packed:
+    uint8_t a;
+    uint64_t b;
+
+  # #[repr(align(16))]
+  cdef struct Aligned:
+    uint8_t a;
+
+  # Not checked, as the size of `long` varies.
+  cdef struct Long:
+    long a;
+
+  void root(Packet p, Packed q, Aligned a, Long l);
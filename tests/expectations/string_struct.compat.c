@@ -0,0 +1,28 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  const char *ptr;
+  uintptr_t len;
+} StrRef;
+
+typedef struct {
+  const StrRef *ptr;
+  uintptr_t len;
+} SliceRef_StrRef;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+StrRef greet(StrRef name);
+
+StrRef version(void);
+
+StrRef join(SliceRef_StrRef parts, const StrRef *separator);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
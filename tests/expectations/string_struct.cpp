@@ -0,0 +1,26 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct StrRef {
+  const char *ptr;
+  uintptr_t len;
+};
+
+template<typename T>
+struct SliceRef {
+  const T *ptr;
+  uintptr_t len;
+};
+
+extern "C" {
+
+StrRef greet(StrRef name);
+
+StrRef version();
+
+StrRef join(SliceRef<StrRef> parts, const StrRef *separator);
+
+} // extern "C"
@@ -0,0 +1,21 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct StrRef:
+    const char *ptr;
+    uintptr_t len;
+
+  ctypedef struct SliceRef_StrRef:
+    const StrRef *ptr;
+    uintptr_t len;
+
+  StrRef greet(StrRef name);
+
+  StrRef version();
+
+  StrRef join(SliceRef_StrRef parts, const StrRef *separator);
@@ -0,0 +1,28 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct StrRef {
+  const char *ptr;
+  uintptr_t len;
+};
+
+struct SliceRef_StrRef {
+  const struct StrRef *ptr;
+  uintptr_t len;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct StrRef greet(struct StrRef name);
+
+struct StrRef version(void);
+
+struct StrRef join(struct SliceRef_StrRef parts, const struct StrRef *separator);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
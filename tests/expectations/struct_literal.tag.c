@@ -9,12 +9,12 @@ struct Foo {
   int32_t a;
   uint32_t b;
 };
-#define Foo_FOO (Foo){ .a = 42, .b = 47 }
-#define Foo_FOO2 (Foo){ .a = 42, .b = 47 }
-#define Foo_FOO3 (Foo){ .a = 42, .b = 47 }
+#define Foo_FOO (struct Foo){ .a = 42, .b = 47 }
+#define Foo_FOO2 (struct Foo){ .a = 42, .b = 47 }
+#define Foo_FOO3 (struct Foo){ .a = 42, .b = 47 }
 
 
-#define BAR (Foo){ .a = 42, .b = 1337 }
+#define BAR (struct Foo){ .a = 42, .b = 1337 }
 
 
 
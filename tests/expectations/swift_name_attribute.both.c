@@ -0,0 +1,46 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum __attribute__((swift_name("Direction"))) RustDirection {
+  Up,
+  Down,
+} RustDirection;
+
+typedef struct __attribute__((swift_name("Point"))) RustPoint {
+  int32_t x;
+  int32_t y;
+} RustPoint;
+
+typedef union __attribute__((swift_name("Value"))) RustValue {
+  int32_t integer;
+  float real;
+} RustValue;
+
+enum RustShape_Tag {
+  Circle,
+  Square,
+};
+typedef uint8_t RustShape_Tag;
+
+typedef struct __attribute__((swift_name("Shape"))) RustShape {
+  RustShape_Tag tag;
+  union {
+    struct {
+      float circle;
+    };
+    struct {
+      float square;
+    };
+  };
+} RustShape;
+
+float RustPoint_length(const struct RustPoint *self) __attribute__((swift_name("RustPoint.length(self:)")));
+
+struct RustPoint rust_point_new(int32_t x,
+                                int32_t y) __attribute__((swift_name("Point.init(x:y:)")));
+
+void rust_describe(union RustValue value,
+                   enum RustDirection direction,
+                   struct RustShape shape) __attribute__((swift_name("rust_describe(value:direction:shape:)")));
@@ -0,0 +1,53 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class __attribute__((swift_name("Direction"))) RustDirection {
+  Up,
+  Down,
+};
+
+struct __attribute__((swift_name("Point"))) RustPoint {
+  int32_t x;
+  int32_t y;
+};
+
+union __attribute__((swift_name("Value"))) RustValue {
+  int32_t integer;
+  float real;
+};
+
+struct __attribute__((swift_name("Shape"))) RustShape {
+  enum class Tag : uint8_t {
+    Circle,
+    Square,
+  };
+
+  struct Circle_Body {
+    float _0;
+  };
+
+  struct Square_Body {
+    float _0;
+  };
+
+  Tag tag;
+  union {
+    Circle_Body circle;
+    Square_Body square;
+  };
+};
+
+extern "C" {
+
+float RustPoint_length(const RustPoint *self) __attribute__((swift_name("RustPoint.length(self:)")));
+
+RustPoint rust_point_new(int32_t x, int32_t y) __attribute__((swift_name("Point.init(x:y:)")));
+
+void rust_describe(RustValue value,
+                   RustDirection direction,
+                   RustShape shape) __attribute__((swift_name("rust_describe(value:direction:shape:)")));
+
+} // extern "C"
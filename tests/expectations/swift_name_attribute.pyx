@@ -0,0 +1,35 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef enum RustDirection:
+    Up,
+    Down,
+
+  ctypedef struct RustPoint:
+    int32_t x;
+    int32_t y;
+
+  ctypedef union RustValue:
+    int32_t integer;
+    float real;
+
+  cdef enum:
+    Circle,
+    Square,
+  ctypedef uint8_t RustShape_Tag;
+
+  ctypedef struct RustShape:
+    RustShape_Tag tag;
+    float circle;
+    float square;
+
+  float RustPoint_length(const RustPoint *self);
+
+  RustPoint rust_point_new(int32_t x, int32_t y);
+
+  void rust_describe(RustValue value, RustDirection direction, RustShape shape);
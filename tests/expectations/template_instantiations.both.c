@@ -0,0 +1,61 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Pair_i32__i32 {
+  int32_t first;
+  int32_t second;
+} Pair_i32__i32;
+
+typedef struct Pair_i32__i32 IntPair;
+
+typedef union Either_f32 {
+  float value;
+  uint64_t bits;
+} Either_f32;
+
+enum Maybe_u8_Tag {
+  Nothing_u8,
+  Just_u8,
+};
+typedef uint8_t Maybe_u8_Tag;
+
+typedef struct Maybe_u8 {
+  Maybe_u8_Tag tag;
+  union {
+    struct {
+      uint8_t just;
+    };
+  };
+} Maybe_u8;
+
+typedef struct Pair_f64__f64 {
+  double first;
+  double second;
+} Pair_f64__f64;
+
+typedef struct Wrapper_f64 {
+  struct Pair_f64__f64 pair;
+} Wrapper_f64;
+
+typedef struct Pair_u8__f32 {
+  uint8_t first;
+  float second;
+} Pair_u8__f32;
+
+#if defined(DEFINE_EXTRA)
+typedef struct Extra_i32 {
+  int32_t value;
+} Extra_i32;
+#endif
+
+void root(IntPair a,
+          union Either_f32 b,
+          struct Maybe_u8 c,
+          struct Wrapper_f64 d,
+          struct Pair_u8__f32 e);
+
+#if defined(DEFINE_EXTRA)
+void extra(struct Extra_i32 a);
+#endif
@@ -0,0 +1,68 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+template<typename T, typename U>
+struct Pair {
+  T first;
+  U second;
+};
+
+using IntPair = Pair<int32_t, int32_t>;
+
+template<typename T>
+union Either {
+  T value;
+  uint64_t bits;
+};
+
+template<typename T>
+struct Maybe {
+  enum class Tag : uint8_t {
+    Nothing,
+    Just,
+  };
+
+  struct Just_Body {
+    T _0;
+  };
+
+  Tag tag;
+  union {
+    Just_Body just;
+  };
+};
+
+template<typename T>
+struct Wrapper {
+  Pair<T, T> pair;
+};
+
+#if defined(DEFINE_EXTRA)
+template<typename T>
+struct Extra {
+  T value;
+};
+#endif
+
+extern template union Either<float>;
+#if defined(DEFINE_EXTRA)
+extern template struct Extra<int32_t>;
+#endif
+extern template struct Maybe<uint8_t>;
+extern template struct Pair<double, double>;
+extern template struct Pair<int32_t, int32_t>;
+extern template struct Pair<uint8_t, float>;
+extern template struct Wrapper<double>;
+
+extern "C" {
+
+void root(IntPair a, Either<float> b, Maybe<uint8_t> c, Wrapper<double> d, Pair<uint8_t, float> e);
+
+#if defined(DEFINE_EXTRA)
+void extra(Extra<int32_t> a);
+#endif
+
+} // extern "C"
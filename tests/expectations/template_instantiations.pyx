@@ -0,0 +1,46 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Pair_i32__i32:
+    int32_t first;
+    int32_t second;
+
+  ctypedef Pair_i32__i32 IntPair;
+
+  ctypedef union Either_f32:
+    float value;
+    uint64_t bits;
+
+  cdef enum:
+    Nothing_u8,
+    Just_u8,
+  ctypedef uint8_t Maybe_u8_Tag;
+
+  ctypedef struct Maybe_u8:
+    Maybe_u8_Tag tag;
+    uint8_t just;
+
+  ctypedef struct Pair_f64__f64:
+    double first;
+    double second;
+
+  ctypedef struct Wrapper_f64:
+    Pair_f64__f64 pair;
+
+  ctypedef struct Pair_u8__f32:
+    uint8_t first;
+    float second;
+
+  IF DEFINE_EXTRA:
+    ctypedef struct Extra_i32:
+      int32_t value;
+
+  void root(IntPair a, Either_f32 b, Maybe_u8 c, Wrapper_f64 d, Pair_u8__f32 e);
+
+  IF DEFINE_EXTRA:
+    void extra(Extra_i32 a);
@@ -0,0 +1,61 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Pair_i32__i32 {
+  int32_t first;
+  int32_t second;
+};
+
+typedef struct Pair_i32__i32 IntPair;
+
+union Either_f32 {
+  float value;
+  uint64_t bits;
+};
+
+enum Maybe_u8_Tag {
+  Nothing_u8,
+  Just_u8,
+};
+typedef uint8_t Maybe_u8_Tag;
+
+struct Maybe_u8 {
+  Maybe_u8_Tag tag;
+  union {
+    struct {
+      uint8_t just;
+    };
+  };
+};
+
+struct Pair_f64__f64 {
+  double first;
+  double second;
+};
+
+struct Wrapper_f64 {
+  struct Pair_f64__f64 pair;
+};
+
+struct Pair_u8__f32 {
+  uint8_t first;
+  float second;
+};
+
+#if defined(DEFINE_EXTRA)
+struct Extra_i32 {
+  int32_t value;
+};
+#endif
+
+void root(IntPair a,
+          union Either_f32 b,
+          struct Maybe_u8 c,
+          struct Wrapper_f64 d,
+          struct Pair_u8__f32 e);
+
+#if defined(DEFINE_EXTRA)
+void extra(struct Extra_i32 a);
+#endif
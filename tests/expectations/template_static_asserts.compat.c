@@ -0,0 +1,57 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t first;
+  const float *second;
+} Pair_i32__f32;
+
+typedef struct {
+  uint8_t data[4];
+  uintptr_t len;
+} Buffer_u8__4;
+
+typedef union {
+  uint32_t value;
+  uint64_t bits;
+} Either_u32;
+
+enum Slot_i64_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Empty_i64,
+  Full_i64,
+  Many_i64,
+};
+#ifndef __cplusplus
+typedef uint8_t Slot_i64_Tag;
+#endif // __cplusplus
+
+typedef struct {
+  int64_t *_0;
+  uintptr_t _1;
+} Many_Body_i64;
+
+typedef struct {
+  Slot_i64_Tag tag;
+  union {
+    struct {
+      int64_t full;
+    };
+    Many_Body_i64 many;
+  };
+} Slot_i64;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Pair_i32__f32 a, Buffer_u8__4 b, Either_u32 c, Slot_i64 d);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,64 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include <type_traits>
+
+template<typename T, typename U>
+struct Pair {
+  T first;
+  const U *second;
+
+  static_assert(std::is_trivially_copyable<T>::value, "T must be trivially copyable to cross the FFI boundary");
+};
+
+template<typename T, uintptr_t N>
+struct Buffer {
+  T data[N];
+  uintptr_t len;
+
+  static_assert(std::is_trivially_copyable<T>::value, "T must be trivially copyable to cross the FFI boundary");
+};
+
+template<typename T>
+union Either {
+  T value;
+  uint64_t bits;
+
+  static_assert(std::is_trivially_copyable<T>::value, "T must be trivially copyable to cross the FFI boundary");
+};
+
+template<typename T>
+struct Slot {
+  enum class Tag : uint8_t {
+    Empty,
+    Full,
+    Many,
+  };
+
+  struct Full_Body {
+    T _0;
+
+    static_assert(std::is_trivially_copyable<T>::value, "T must be trivially copyable to cross the FFI boundary");
+  };
+
+  struct Many_Body {
+    T *_0;
+    uintptr_t _1;
+  };
+
+  Tag tag;
+  union {
+    Full_Body full;
+    Many_Body many;
+  };
+
+  static_assert(std::is_trivially_copyable<T>::value, "T must be trivially copyable to cross the FFI boundary");
+};
+
+extern "C" {
+
+void root(Pair<int32_t, float> a, Buffer<uint8_t, 4> b, Either<uint32_t> c, Slot<int64_t> d);
+
+} // extern "C"
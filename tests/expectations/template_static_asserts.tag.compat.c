@@ -0,0 +1,57 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Pair_i32__f32 {
+  int32_t first;
+  const float *second;
+};
+
+struct Buffer_u8__4 {
+  uint8_t data[4];
+  uintptr_t len;
+};
+
+union Either_u32 {
+  uint32_t value;
+  uint64_t bits;
+};
+
+enum Slot_i64_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Empty_i64,
+  Full_i64,
+  Many_i64,
+};
+#ifndef __cplusplus
+typedef uint8_t Slot_i64_Tag;
+#endif // __cplusplus
+
+struct Many_Body_i64 {
+  int64_t *_0;
+  uintptr_t _1;
+};
+
+struct Slot_i64 {
+  Slot_i64_Tag tag;
+  union {
+    struct {
+      int64_t full;
+    };
+    struct Many_Body_i64 many;
+  };
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Pair_i32__f32 a, struct Buffer_u8__4 b, union Either_u32 c, struct Slot_i64 d);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,36 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Pair_i32__f32:
+    int32_t first;
+    const float *second;
+
+  cdef struct Buffer_u8__4:
+    uint8_t data[4];
+    uintptr_t len;
+
+  cdef union Either_u32:
+    uint32_t value;
+    uint64_t bits;
+
+  cdef enum:
+    Empty_i64,
+    Full_i64,
+    Many_i64,
+  ctypedef uint8_t Slot_i64_Tag;
+
+  cdef struct Many_Body_i64:
+    int64_t *_0;
+    uintptr_t _1;
+
+  cdef struct Slot_i64:
+    Slot_i64_Tag tag;
+    int64_t full;
+    Many_Body_i64 many;
+
+  void root(Pair_i32__f32 a, Buffer_u8__4 b, Either_u32 c, Slot_i64 d);
@@ -0,0 +1,33 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Logger Logger;
+
+typedef struct Plugin Plugin;
+
+typedef struct Host {
+  struct Logger *logger;
+  uint32_t version;
+} Host;
+
+struct Plugin *plugin_create(void);
+
+void plugin_destroy(struct Plugin *plugin);
+
+void plugin_attach(struct Plugin *plugin, const struct Host *host);
@@ -0,0 +1,41 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Logger Logger;
+
+typedef struct Plugin Plugin;
+
+typedef struct {
+  Logger *logger;
+  uint32_t version;
+} Host;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Plugin *plugin_create(void);
+
+void plugin_destroy(Plugin *plugin);
+
+void plugin_attach(Plugin *plugin, const Host *host);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,38 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Logger;
+
+struct Plugin;
+
+struct Host {
+  Box<Logger> logger;
+  uint32_t version;
+};
+
+extern "C" {
+
+Box<Plugin> plugin_create();
+
+void plugin_destroy(Box<Plugin> plugin);
+
+void plugin_attach(Plugin *plugin, const Host *host);
+
+} // extern "C"
@@ -0,0 +1,41 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Logger;
+
+struct Plugin;
+
+struct Host {
+  struct Logger *logger;
+  uint32_t version;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Plugin *plugin_create(void);
+
+void plugin_destroy(struct Plugin *plugin);
+
+void plugin_attach(struct Plugin *plugin, const struct Host *host);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,37 @@
+#if 0
+''' '
+#endif
+
+#ifdef __cplusplus
+template <typename T>
+using Box = T*;
+#endif
+
+#if 0
+' '''
+#endif
+
+
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Logger:
+    pass
+
+  cdef struct Plugin:
+    pass
+
+  cdef struct Host:
+    Logger *logger;
+    uint32_t version;
+
+  Plugin *plugin_create();
+
+  void plugin_destroy(Plugin *plugin);
+
+  void plugin_attach(Plugin *plugin, const Host *host);
@@ -0,0 +1,25 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+using Meters = double;
+constexpr static const Meters Meters_ZERO = 0.0;
+
+template<typename T>
+using Handle = uint32_t;
+
+using Tagged = const uint8_t*;
+
+struct OnlyMarkers {
+
+};
+
+constexpr static const Meters FAR = 1000.0;
+
+extern "C" {
+
+void root(Meters meters, Handle<uint8_t> handle, Tagged tagged, const OnlyMarkers *markers);
+
+} // extern "C"
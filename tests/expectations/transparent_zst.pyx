@@ -0,0 +1,21 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef double Meters;
+  const Meters Meters_ZERO # = 0.0
+
+  ctypedef uint32_t Handle_u8;
+
+  ctypedef const uint8_t *Tagged;
+
+  ctypedef struct OnlyMarkers:
+    pass
+
+  const Meters FAR # = 1000.0
+
+  void root(Meters meters, Handle_u8 handle, Tagged tagged, const OnlyMarkers *markers);
@@ -0,0 +1,19 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef double Meters;
+#define Meters_ZERO 0.0
+
+typedef uint32_t Handle_u8;
+
+typedef const uint8_t *Tagged;
+
+struct OnlyMarkers {
+
+};
+
+#define FAR 1000.0
+
+void root(Meters meters, Handle_u8 handle, Tagged tagged, const struct OnlyMarkers *markers);
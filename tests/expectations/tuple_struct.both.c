@@ -0,0 +1,39 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Point {
+  float f0;
+  float f1;
+} Point;
+#define Point_ORIGIN (Point){ .f0 = 0.0, .f1 = 0.0 }
+
+typedef struct Wrapper {
+  const struct Point *f0;
+  uint32_t f1;
+} Wrapper;
+
+enum Shape_Tag {
+  Dot,
+  Segment,
+  None,
+};
+typedef uint8_t Shape_Tag;
+
+typedef struct Segment_Body {
+  struct Point f0;
+  struct Point f1;
+} Segment_Body;
+
+typedef struct Shape {
+  Shape_Tag tag;
+  union {
+    struct {
+      struct Point dot;
+    };
+    Segment_Body segment;
+  };
+} Shape;
+
+void root(struct Point p, struct Wrapper w, struct Shape s);
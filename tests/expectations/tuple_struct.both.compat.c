@@ -0,0 +1,53 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Point {
+  float f0;
+  float f1;
+} Point;
+#define Point_ORIGIN (Point){ .f0 = 0.0, .f1 = 0.0 }
+
+typedef struct Wrapper {
+  const struct Point *f0;
+  uint32_t f1;
+} Wrapper;
+
+enum Shape_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Dot,
+  Segment,
+  None,
+};
+#ifndef __cplusplus
+typedef uint8_t Shape_Tag;
+#endif // __cplusplus
+
+typedef struct Segment_Body {
+  struct Point f0;
+  struct Point f1;
+} Segment_Body;
+
+typedef struct Shape {
+  Shape_Tag tag;
+  union {
+    struct {
+      struct Point dot;
+    };
+    Segment_Body segment;
+  };
+} Shape;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Point p, struct Wrapper w, struct Shape s);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,53 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float f0;
+  float f1;
+} Point;
+#define Point_ORIGIN (Point){ .f0 = 0.0, .f1 = 0.0 }
+
+typedef struct {
+  const Point *f0;
+  uint32_t f1;
+} Wrapper;
+
+enum Shape_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Dot,
+  Segment,
+  None,
+};
+#ifndef __cplusplus
+typedef uint8_t Shape_Tag;
+#endif // __cplusplus
+
+typedef struct {
+  Point f0;
+  Point f1;
+} Segment_Body;
+
+typedef struct {
+  Shape_Tag tag;
+  union {
+    struct {
+      Point dot;
+    };
+    Segment_Body segment;
+  };
+} Shape;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Point p, Wrapper w, Shape s);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,90 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include <cassert>
+
+struct Point {
+  float f0;
+  float f1;
+};
+constexpr static const Point Point_ORIGIN = Point{ /* .f0 = */ 0.0, /* .f1 = */ 0.0 };
+
+struct Wrapper {
+  const Point *f0;
+  uint32_t f1;
+};
+
+struct Shape {
+  enum class Tag : uint8_t {
+    Dot,
+    Segment,
+    None,
+  };
+
+  struct Dot_Body {
+    Point f0;
+  };
+
+  struct Segment_Body {
+    Point f0;
+    Point f1;
+  };
+
+  Tag tag;
+  union {
+    Dot_Body dot;
+    Segment_Body segment;
+  };
+
+  static Shape Dot(const Point &f0) {
+    Shape result;
+    ::new (&result.dot.f0) (Point)(f0);
+    result.tag = Tag::Dot;
+    return result;
+  }
+
+  bool IsDot() const {
+    return tag == Tag::Dot;
+  }
+
+  const Point& AsDot() const {
+    assert(IsDot());
+    return dot.f0;
+  }
+
+  static Shape Segment(const Point &f0,
+                       const Point &f1) {
+    Shape result;
+    ::new (&result.segment.f0) (Point)(f0);
+    ::new (&result.segment.f1) (Point)(f1);
+    result.tag = Tag::Segment;
+    return result;
+  }
+
+  bool IsSegment() const {
+    return tag == Tag::Segment;
+  }
+
+  const Segment_Body& AsSegment() const {
+    assert(IsSegment());
+    return segment;
+  }
+
+  static Shape None() {
+    Shape result;
+    result.tag = Tag::None;
+    return result;
+  }
+
+  bool IsNone() const {
+    return tag == Tag::None;
+  }
+};
+
+extern "C" {
+
+void root(Point p, Wrapper w, Shape s);
+
+} // extern "C"
@@ -0,0 +1,39 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Point {
+  float f0;
+  float f1;
+};
+#define Point_ORIGIN (struct Point){ .f0 = 0.0, .f1 = 0.0 }
+
+struct Wrapper {
+  const struct Point *f0;
+  uint32_t f1;
+};
+
+enum Shape_Tag {
+  Dot,
+  Segment,
+  None,
+};
+typedef uint8_t Shape_Tag;
+
+struct Segment_Body {
+  struct Point f0;
+  struct Point f1;
+};
+
+struct Shape {
+  Shape_Tag tag;
+  union {
+    struct {
+      struct Point dot;
+    };
+    struct Segment_Body segment;
+  };
+};
+
+void root(struct Point p, struct Wrapper w, struct Shape s);
@@ -0,0 +1,33 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Point:
+    float f0;
+    float f1;
+  const Point Point_ORIGIN # = <Point>{ 0.0, 0.0 }
+
+  cdef struct Wrapper:
+    const Point *f0;
+    uint32_t f1;
+
+  cdef enum:
+    Dot,
+    Segment,
+    None,
+  ctypedef uint8_t Shape_Tag;
+
+  cdef struct Segment_Body:
+    Point f0;
+    Point f1;
+
+  cdef struct Shape:
+    Shape_Tag tag;
+    Point dot;
+    Segment_Body segment;
+
+  void root(Point p, Wrapper w, Shape s);
@@ -0,0 +1,34 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Handle Handle;
+
+typedef struct Wrapper {
+  uint64_t id;
+  uint32_t value;
+} Wrapper;
+
+typedef struct Config {
+  const char *name;
+  uint32_t flags;
+  struct Wrapper wrapper;
+  struct Handle *handle;
+} Config;
+
+typedef struct Ids {
+  uint32_t a;
+  uint16_t b;
+  uint32_t qualified;
+} Ids;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+uint32_t root(const struct Config *config, uint64_t id, struct Ids ids);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
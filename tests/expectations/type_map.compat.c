@@ -0,0 +1,34 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Handle Handle;
+
+typedef struct {
+  uint64_t id;
+  uint32_t value;
+} Wrapper;
+
+typedef struct {
+  const char *name;
+  uint32_t flags;
+  Wrapper wrapper;
+  Handle *handle;
+} Config;
+
+typedef struct {
+  uint32_t a;
+  uint16_t b;
+  uint32_t qualified;
+} Ids;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+uint32_t root(const Config *config, uint64_t id, Ids ids);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
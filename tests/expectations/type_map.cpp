@@ -0,0 +1,31 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Handle;
+
+struct Wrapper {
+  uint64_t id;
+  uint32_t value;
+};
+
+struct Config {
+  const char *name;
+  uint32_t flags;
+  Wrapper wrapper;
+  Handle *handle;
+};
+
+struct Ids {
+  uint32_t a;
+  uint16_t b;
+  uint32_t qualified;
+};
+
+extern "C" {
+
+uint32_t root(const Config *config, uint64_t id, Ids ids);
+
+} // extern "C"
@@ -0,0 +1,27 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Handle:
+    pass
+
+  ctypedef struct Wrapper:
+    uint64_t id;
+    uint32_t value;
+
+  ctypedef struct Config:
+    const char *name;
+    uint32_t flags;
+    Wrapper wrapper;
+    Handle *handle;
+
+  ctypedef struct Ids:
+    uint32_t a;
+    uint16_t b;
+    uint32_t qualified;
+
+  uint32_t root(const Config *config, uint64_t id, Ids ids);
@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Handle;
+
+struct Wrapper {
+  uint64_t id;
+  uint32_t value;
+};
+
+struct Config {
+  const char *name;
+  uint32_t flags;
+  struct Wrapper wrapper;
+  struct Handle *handle;
+};
+
+struct Ids {
+  uint32_t a;
+  uint16_t b;
+  uint32_t qualified;
+};
+
+uint32_t root(const struct Config *config, uint64_t id, struct Ids ids);
@@ -0,0 +1,20 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Mu {
+  int32_t a;
+} Mu;
+
+typedef struct Zeta {
+  int32_t a;
+} Zeta;
+
+typedef struct Alpha {
+  struct Zeta a;
+} Alpha;
+
+void branch(struct Alpha a);
+
+void root(struct Mu m, struct Zeta z);
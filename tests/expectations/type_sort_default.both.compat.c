@@ -0,0 +1,28 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Mu {
+  int32_t a;
+} Mu;
+
+typedef struct Zeta {
+  int32_t a;
+} Zeta;
+
+typedef struct Alpha {
+  struct Zeta a;
+} Alpha;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void branch(struct Alpha a);
+
+void root(struct Mu m, struct Zeta z);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
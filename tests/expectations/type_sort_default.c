@@ -0,0 +1,20 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t a;
+} Mu;
+
+typedef struct {
+  int32_t a;
+} Zeta;
+
+typedef struct {
+  Zeta a;
+} Alpha;
+
+void branch(Alpha a);
+
+void root(Mu m, Zeta z);
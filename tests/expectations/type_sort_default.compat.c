@@ -0,0 +1,28 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t a;
+} Mu;
+
+typedef struct {
+  int32_t a;
+} Zeta;
+
+typedef struct {
+  Zeta a;
+} Alpha;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void branch(Alpha a);
+
+void root(Mu m, Zeta z);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
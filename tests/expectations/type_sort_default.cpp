@@ -0,0 +1,25 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Mu {
+  int32_t a;
+};
+
+struct Zeta {
+  int32_t a;
+};
+
+struct Alpha {
+  Zeta a;
+};
+
+extern "C" {
+
+void branch(Alpha a);
+
+void root(Mu m, Zeta z);
+
+} // extern "C"
@@ -0,0 +1,20 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Mu {
+  int32_t a;
+};
+
+struct Zeta {
+  int32_t a;
+};
+
+struct Alpha {
+  struct Zeta a;
+};
+
+void branch(struct Alpha a);
+
+void root(struct Mu m, struct Zeta z);
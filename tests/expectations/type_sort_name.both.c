@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Alpha {
+  A,
+  B,
+};
+typedef uint8_t Alpha;
+
+typedef struct Bravo {
+  int32_t a;
+} Bravo;
+
+typedef struct Charlie {
+  int32_t a;
+} Charlie;
+
+typedef struct Delta {
+  struct Bravo a;
+} Delta;
+
+void root(struct Charlie c, struct Delta d, Alpha a);
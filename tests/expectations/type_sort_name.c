@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Alpha {
+  A,
+  B,
+};
+typedef uint8_t Alpha;
+
+typedef struct {
+  int32_t a;
+} Bravo;
+
+typedef struct {
+  int32_t a;
+} Charlie;
+
+typedef struct {
+  Bravo a;
+} Delta;
+
+void root(Charlie c, Delta d, Alpha a);
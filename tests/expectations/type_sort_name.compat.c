@@ -0,0 +1,38 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Alpha
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  A,
+  B,
+};
+#ifndef __cplusplus
+typedef uint8_t Alpha;
+#endif // __cplusplus
+
+typedef struct {
+  int32_t a;
+} Bravo;
+
+typedef struct {
+  int32_t a;
+} Charlie;
+
+typedef struct {
+  Bravo a;
+} Delta;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Charlie c, Delta d, Alpha a);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
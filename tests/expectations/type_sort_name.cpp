@@ -0,0 +1,28 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Alpha : uint8_t {
+  A,
+  B,
+};
+
+struct Bravo {
+  int32_t a;
+};
+
+struct Charlie {
+  int32_t a;
+};
+
+struct Delta {
+  Bravo a;
+};
+
+extern "C" {
+
+void root(Charlie c, Delta d, Alpha a);
+
+} // extern "C"
@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Alpha {
+  A,
+  B,
+};
+typedef uint8_t Alpha;
+
+struct Bravo {
+  int32_t a;
+};
+
+struct Charlie {
+  int32_t a;
+};
+
+struct Delta {
+  struct Bravo a;
+};
+
+void root(struct Charlie c, struct Delta d, Alpha a);
@@ -0,0 +1,38 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Alpha
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  A,
+  B,
+};
+#ifndef __cplusplus
+typedef uint8_t Alpha;
+#endif // __cplusplus
+
+struct Bravo {
+  int32_t a;
+};
+
+struct Charlie {
+  int32_t a;
+};
+
+struct Delta {
+  struct Bravo a;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Charlie c, struct Delta d, Alpha a);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t a;
+} Charlie;
+
+typedef struct {
+  int32_t a;
+} Bravo;
+
+typedef struct {
+  Bravo a;
+} Delta;
+
+enum Alpha {
+  A,
+  B,
+};
+typedef uint8_t Alpha;
+
+void root(Charlie c, Delta d, Alpha a);
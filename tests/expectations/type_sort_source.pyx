@@ -0,0 +1,23 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Charlie:
+    int32_t a;
+
+  ctypedef struct Bravo:
+    int32_t a;
+
+  ctypedef struct Delta:
+    Bravo a;
+
+  cdef enum:
+    A,
+    B,
+  ctypedef uint8_t Alpha;
+
+  void root(Charlie c, Delta d, Alpha a);
@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint8_t *data;
+  char prefix;
+} Buffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+const char *name(char *buf, uintptr_t len);
+
+void print(const char *s, const uint8_t *const *bytes);
+
+void fill(Buffer *b);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
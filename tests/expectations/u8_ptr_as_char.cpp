@@ -0,0 +1,20 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Buffer {
+  uint8_t *data;
+  char prefix;
+};
+
+extern "C" {
+
+const char *name(char *buf, uintptr_t len);
+
+void print(const char *s, const uint8_t *const *bytes);
+
+void fill(Buffer *b);
+
+} // extern "C"
@@ -0,0 +1,17 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Buffer:
+    uint8_t *data;
+    char prefix;
+
+  const char *name(char *buf, uintptr_t len);
+
+  void print(const char *s, const uint8_t *const *bytes);
+
+  void fill(Buffer *b);
@@ -0,0 +1,11 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Point {
+  int32_t x;
+  int32_t y;
+} Point;
+
+struct Point point_origin(void);
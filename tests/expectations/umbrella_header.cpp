@@ -0,0 +1,16 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+extern "C" {
+
+Point point_origin();
+
+} // extern "C"
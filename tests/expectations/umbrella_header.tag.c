@@ -0,0 +1,11 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+struct Point point_origin(void);
@@ -0,0 +1,19 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Point point_origin(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
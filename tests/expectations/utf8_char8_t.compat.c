@@ -0,0 +1,16 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+const char *utf8_copy(const char *text, uintptr_t len, uint8_t *buffer);
+
+void utf8_greet(const char *name);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
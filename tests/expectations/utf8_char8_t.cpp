@@ -0,0 +1,17 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+#if !defined(__cpp_char8_t)
+typedef char char8_t;
+#endif
+
+extern "C" {
+
+const char8_t *utf8_copy(const char8_t *text, uintptr_t len, uint8_t *buffer);
+
+void utf8_greet(const char8_t *name);
+
+} // extern "C"
@@ -0,0 +1,39 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+typedef struct RawVec_Point {
+  struct Point *ptr;
+  uintptr_t len;
+  uintptr_t cap;
+} RawVec_Point;
+
+typedef struct RawVec_usize {
+  uintptr_t *ptr;
+  uintptr_t len;
+  uintptr_t cap;
+} RawVec_usize;
+
+typedef struct RawVec_u8 {
+  uint8_t *ptr;
+  uintptr_t len;
+  uintptr_t cap;
+} RawVec_u8;
+
+/**
+ * The returned vector is owned by the caller, and must be given back to Rust to be freed.
+ */
+struct RawVec_Point points(uintptr_t count);
+
+void free_points(struct RawVec_Point points);
+
+/**
+ * The returned vector is owned by the caller, and must be given back to Rust to be freed.
+ */
+struct RawVec_usize lengths(const struct RawVec_u8 *strings, uintptr_t count);
@@ -0,0 +1,47 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+typedef struct {
+  Point *ptr;
+  uintptr_t len;
+  uintptr_t cap;
+} RawVec_Point;
+
+typedef struct {
+  uintptr_t *ptr;
+  uintptr_t len;
+  uintptr_t cap;
+} RawVec_usize;
+
+typedef struct {
+  uint8_t *ptr;
+  uintptr_t len;
+  uintptr_t cap;
+} RawVec_u8;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * The returned vector is owned by the caller, and must be given back to Rust to be freed.
+ */
+RawVec_Point points(uintptr_t count);
+
+void free_points(RawVec_Point points);
+
+/**
+ * The returned vector is owned by the caller, and must be given back to Rust to be freed.
+ */
+RawVec_usize lengths(const RawVec_u8 *strings, uintptr_t count);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
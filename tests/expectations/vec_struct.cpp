@@ -0,0 +1,29 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Point {
+  float x;
+  float y;
+};
+
+template<typename T>
+struct RawVec {
+  T *ptr;
+  uintptr_t len;
+  uintptr_t cap;
+};
+
+extern "C" {
+
+/// The returned vector is owned by the caller, and must be given back to Rust to be freed.
+RawVec<Point> points(uintptr_t count);
+
+void free_points(RawVec<Point> points);
+
+/// The returned vector is owned by the caller, and must be given back to Rust to be freed.
+RawVec<uintptr_t> lengths(const RawVec<uint8_t> *strings, uintptr_t count);
+
+} // extern "C"
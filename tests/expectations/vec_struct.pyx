@@ -0,0 +1,34 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  ctypedef struct Point:
+    float x;
+    float y;
+
+  ctypedef struct RawVec_Point:
+    Point *ptr;
+    uintptr_t len;
+    uintptr_t cap;
+
+  ctypedef struct RawVec_usize:
+    uintptr_t *ptr;
+    uintptr_t len;
+    uintptr_t cap;
+
+  ctypedef struct RawVec_u8:
+    uint8_t *ptr;
+    uintptr_t len;
+    uintptr_t cap;
+
+  # The returned vector is owned by the caller, and must be given back to Rust to be freed.
+  RawVec_Point points(uintptr_t count);
+
+  void free_points(RawVec_Point points);
+
+  # The returned vector is owned by the caller, and must be given back to Rust to be freed.
+  RawVec_usize lengths(const RawVec_u8 *strings, uintptr_t count);
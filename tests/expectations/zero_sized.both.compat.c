@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Handle_u8 {
+  uint32_t id;
+  /* `marker` is zero-sized and omitted. */
+} Handle_u8;
+
+typedef struct Marker {
+  /* `unit` is zero-sized and omitted. */
+  /* `phantom` is zero-sized and omitted. */
+  uint8_t _dummy;
+} Marker;
+
+typedef struct Tuple {
+  uint16_t _0;
+  /* `.0` is zero-sized and omitted. */
+} Tuple;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Handle_u8 handle, struct Marker marker, struct Tuple tuple);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,22 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t id;
+  /* `marker` is zero-sized and omitted. */
+} Handle_u8;
+
+typedef struct {
+  /* `unit` is zero-sized and omitted. */
+  /* `phantom` is zero-sized and omitted. */
+  uint8_t _dummy;
+} Marker;
+
+typedef struct {
+  uint16_t _0;
+  /* `.0` is zero-sized and omitted. */
+} Tuple;
+
+void root(Handle_u8 handle, Marker marker, Tuple tuple);
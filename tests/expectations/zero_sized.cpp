@@ -0,0 +1,27 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+template<typename T>
+struct Handle {
+  uint32_t id;
+  /* `marker` is zero-sized and omitted. */
+};
+
+struct Marker {
+  /* `unit` is zero-sized and omitted. */
+  /* `phantom` is zero-sized and omitted. */
+};
+
+struct Tuple {
+  uint16_t _0;
+  /* `.0` is zero-sized and omitted. */
+};
+
+extern "C" {
+
+void root(Handle<uint8_t> handle, Marker marker, Tuple tuple);
+
+} // extern "C"
@@ -0,0 +1,22 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Handle_u8 {
+  uint32_t id;
+  /* `marker` is zero-sized and omitted. */
+};
+
+struct Marker {
+  /* `unit` is zero-sized and omitted. */
+  /* `phantom` is zero-sized and omitted. */
+  uint8_t _dummy;
+};
+
+struct Tuple {
+  uint16_t _0;
+  /* `.0` is zero-sized and omitted. */
+};
+
+void root(struct Handle_u8 handle, struct Marker marker, struct Tuple tuple);
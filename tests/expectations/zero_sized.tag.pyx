@@ -0,0 +1,22 @@
+from libc.stdint cimport int8_t, int16_t, int32_t, int64_t, intptr_t
+from libc.stdint cimport uint8_t, uint16_t, uint32_t, uint64_t, uintptr_t
+cdef extern from *:
+  ctypedef bint bool
+  ctypedef struct va_list
+
+cdef extern from *:
+
+  cdef struct Handle_u8:
+    uint32_t id;
+    # `marker` is zero-sized and omitted.
+
+  cdef struct Marker:
+    # `unit` is zero-sized and omitted.
+    # `phantom` is zero-sized and omitted.
+    pass
+
+  cdef struct Tuple:
+    uint16_t _0;
+    # `.0` is zero-sized and omitted.
+
+  void root(Handle_u8 handle, Marker marker, Tuple tuple);
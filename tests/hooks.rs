@@ -0,0 +1,49 @@
+use cbindgen::backend::{Item, PrimitiveType, Type};
+use cbindgen::{Builder, Language};
+
+#[test]
+fn filter_and_rewrite_items() {
+    let src = r#"
+        #[repr(C)]
+        pub struct pub_api_Point {
+            x: f32,
+            y: f32,
+        }
+
+        #[repr(C)]
+        pub struct Internal {
+            x: f32,
+        }
+
+        #[no_mangle]
+        pub extern "C" fn pub_api_origin() -> pub_api_Point {
+            unimplemented!()
+        }
+
+        #[no_mangle]
+        pub extern "C" fn internal(_: Internal) {}
+    "#;
+    let bindings = Builder::new()
+        .with_language(Language::Cxx)
+        .with_src_str("hooks.rs", src)
+        .with_item_filter(|item| item.name().starts_with("pub_api_"))
+        .with_item_rewriter(|parse| {
+            parse.structs.for_all_items_mut(|item| {
+                item.export_name = item.name().trim_start_matches("pub_api_").to_owned();
+                item.fields[0].ty = Type::Primitive(PrimitiveType::Double);
+                item.fields.truncate(1);
+            });
+        })
+        .generate()
+        .unwrap();
+
+    let mut out = Vec::new();
+    bindings.write(&mut out);
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.contains("struct Point {"), "{}", out);
+    assert!(out.contains("double x;"), "{}", out);
+    assert!(!out.contains(" y;"), "{}", out);
+    assert!(out.contains("Point pub_api_origin();"), "{}", out);
+    assert!(!out.contains("Internal"), "{}", out);
+    assert!(!out.contains("internal("), "{}", out);
+}
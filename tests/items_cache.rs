@@ -0,0 +1,100 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+const MANIFEST: &str = r#"
+[package]
+name = "cached_items"
+version = "0.1.0"
+edition = "2018"
+
+[dependencies]
+"#;
+
+const CONFIG: &str = r#"
+[parse]
+cache = true
+"#;
+
+const SOURCE: &str = r#"
+#[no_mangle]
+pub extern "C" fn cached() {}
+"#;
+
+fn setup(name: &str) -> String {
+    let dir = format!("{}/items cache {}", env!("CARGO_TARGET_TMPDIR"), name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(format!("{}/src", dir)).unwrap();
+    fs::write(format!("{}/Cargo.toml", dir), MANIFEST).unwrap();
+    fs::write(format!("{}/cbindgen.toml", dir), CONFIG).unwrap();
+    fs::write(format!("{}/src/lib.rs", dir), SOURCE).unwrap();
+    dir
+}
+
+fn cbindgen(dir: &str) -> Output {
+    let output = Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+        .env_remove("CARGO_TARGET_DIR")
+        .args(&["--lang", "c", "--config"])
+        .arg(format!("{}/cbindgen.toml", dir))
+        .arg(dir)
+        .output()
+        .expect("failed to execute cbindgen");
+    assert!(output.status.success(), "{:?}", output);
+    output
+}
+
+fn stdout(output: Output) -> String {
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn cache_files(dir: &str) -> Vec<PathBuf> {
+    match fs::read_dir(Path::new(dir).join("target").join("cbindgen")) {
+        Ok(entries) => entries.map(|entry| entry.unwrap().path()).collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+#[test]
+fn reuses_the_cached_items() {
+    let dir = setup("hit");
+    assert!(stdout(cbindgen(&dir)).contains("void cached(void);"));
+    let files = cache_files(&dir);
+    assert_eq!(files.len(), 1);
+
+    // Renaming the function in the cache shows that the items come from it.
+    let cached = fs::read_to_string(&files[0]).unwrap();
+    fs::write(&files[0], cached.replace("\"cached\"", "\"from_cache\"")).unwrap();
+    assert!(stdout(cbindgen(&dir)).contains("void from_cache(void);"));
+}
+
+#[test]
+fn parses_the_crate_again_when_a_source_changes() {
+    let dir = setup("miss");
+    cbindgen(&dir);
+    let files = cache_files(&dir);
+    let cached = fs::read_to_string(&files[0]).unwrap();
+    fs::write(&files[0], cached.replace("\"cached\"", "\"from_cache\"")).unwrap();
+
+    fs::write(
+        format!("{}/src/lib.rs", dir),
+        SOURCE.replace("cached", "changed"),
+    )
+    .unwrap();
+    assert!(stdout(cbindgen(&dir)).contains("void changed(void);"));
+    assert!(stdout(cbindgen(&dir)).contains("void changed(void);"));
+}
+
+#[test]
+fn does_not_cache_the_items_of_crates_with_warnings() {
+    let dir = setup("warning");
+    fs::write(
+        format!("{}/src/lib.rs", dir),
+        format!("{}\nconst NOT_PUB: u32 = 1;\n", SOURCE),
+    )
+    .unwrap();
+    let output = cbindgen(&dir);
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("NOT_PUB"));
+    assert!(cache_files(&dir).is_empty());
+}
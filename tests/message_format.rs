@@ -0,0 +1,78 @@
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn json_diagnostics() {
+    let dir = env!("CARGO_TARGET_TMPDIR");
+    let src = format!("{}/message_format.rs", dir);
+    fs::write(
+        &src,
+        "pub extern \"C\" fn not_mangled() {}\n\
+         \n\
+         #[no_mangle]\n\
+         pub extern \"C\" fn tuple(x: (u32, u32)) {}\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+        .args(&["--lang", "c", "--message-format=json", &src])
+        .output()
+        .expect("failed to execute cbindgen");
+
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let messages: Vec<serde_json::Value> = stderr
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(messages.len(), 2, "{}", stderr);
+
+    assert_eq!(messages[0]["level"], "warning");
+    assert_eq!(messages[0]["code"], "skipped_item");
+    // Source files are named relative to the current directory.
+    let file_name = messages[0]["spans"][0]["file_name"].as_str().unwrap();
+    assert!(src.ends_with(file_name), "{}", file_name);
+    assert_eq!(messages[0]["spans"][0]["line"], 1);
+    assert_eq!(messages[0]["spans"][0]["column"], 19);
+
+    assert_eq!(messages[1]["level"], "error");
+    assert_eq!(messages[1]["code"], "unsupported_type");
+    assert_eq!(messages[1]["spans"][0]["line"], 4);
+}
+
+#[test]
+fn json_diagnostics_of_ignored_annotations_and_cfgs() {
+    let dir = env!("CARGO_TARGET_TMPDIR");
+    let src = format!("{}/message_format_warnings.rs", dir);
+    fs::write(
+        &src,
+        "#[cfg(feature = \"extra\")]\n\
+         #[no_mangle]\n\
+         pub extern \"C\" fn extra() {}\n\
+         \n\
+         /// cbindgen:derive-eq\n\
+         #[repr(C)]\n\
+         pub struct Foo { x: u32 }\n\
+         \n\
+         /// cbindgen:derive-neq\n\
+         pub type Bar = Foo;\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+        .args(&["--lang", "c", "--message-format=json", &src])
+        .output()
+        .expect("failed to execute cbindgen");
+
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let messages: Vec<serde_json::Value> = stderr
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(messages.len(), 2, "{}", stderr);
+
+    assert_eq!(messages[0]["code"], "ignored_annotation");
+    assert_eq!(messages[0]["spans"][0]["line"], 7);
+    assert_eq!(messages[1]["code"], "missing_define");
+}
@@ -0,0 +1,121 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+const MANIFEST: &str = r#"
+[package]
+name = "cached"
+version = "0.1.0"
+edition = "2018"
+
+[dependencies]
+"#;
+
+const LOCK: &str = r#"# This file is automatically @generated by Cargo.
+# It is not intended for manual editing.
+version = 3
+
+[[package]]
+name = "cached"
+version = "0.1.0"
+"#;
+
+const SOURCE: &str = r#"
+#[no_mangle]
+pub extern "C" fn cached() {}
+"#;
+
+fn setup(name: &str, lock: bool) -> String {
+    let dir = format!("{}/metadata cache {}", env!("CARGO_TARGET_TMPDIR"), name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(format!("{}/src", dir)).unwrap();
+    fs::write(format!("{}/Cargo.toml", dir), MANIFEST).unwrap();
+    fs::write(format!("{}/src/lib.rs", dir), SOURCE).unwrap();
+    if lock {
+        fs::write(format!("{}/Cargo.lock", dir), LOCK).unwrap();
+    }
+    dir
+}
+
+/// Runs cbindgen on the crate in `dir`, with `cargo` as the cargo binary.
+fn cbindgen(dir: &str, cargo: &str, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+        .env("CARGO", cargo)
+        .env_remove("CARGO_TARGET_DIR")
+        .args(&["--lang", "c"])
+        .args(args)
+        .arg(dir)
+        .output()
+        .expect("failed to execute cbindgen")
+}
+
+fn cargo() -> String {
+    std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned())
+}
+
+fn cache_files(dir: &str) -> usize {
+    match fs::read_dir(Path::new(dir).join("target").join("cbindgen")) {
+        Ok(entries) => entries.count(),
+        Err(_) => 0,
+    }
+}
+
+#[test]
+fn reuses_the_cached_metadata() {
+    let dir = setup("hit", true);
+    let missed = cbindgen(&dir, &cargo(), &["--cache-metadata"]);
+    assert!(missed.status.success(), "{:?}", missed);
+    assert_eq!(cache_files(&dir), 1);
+
+    // A cargo that can't run shows that the metadata comes from the cache.
+    let hit = cbindgen(&dir, "/nonexistent/cargo", &["--cache-metadata"]);
+    assert!(hit.status.success(), "{:?}", hit);
+    assert_eq!(hit.stdout, missed.stdout);
+    assert!(String::from_utf8(hit.stdout)
+        .unwrap()
+        .contains("void cached(void);"));
+}
+
+#[test]
+fn runs_cargo_when_the_manifest_changes() {
+    let dir = setup("miss", true);
+    let first = cbindgen(&dir, &cargo(), &["--cache-metadata"]);
+    assert!(first.status.success(), "{:?}", first);
+
+    let manifest = format!("{}/Cargo.toml", dir);
+    fs::write(&manifest, MANIFEST.replace("0.1.0", "0.1.1")).unwrap();
+    fs::write(
+        format!("{}/Cargo.lock", dir),
+        LOCK.replace("0.1.0", "0.1.1"),
+    )
+    .unwrap();
+    let missed = cbindgen(&dir, "/nonexistent/cargo", &["--cache-metadata"]);
+    assert!(!missed.status.success(), "{:?}", missed);
+
+    let second = cbindgen(&dir, &cargo(), &["--cache-metadata"]);
+    assert!(second.status.success(), "{:?}", second);
+    assert_eq!(cache_files(&dir), 2);
+}
+
+#[test]
+fn does_not_cache_by_default() {
+    let dir = setup("default", true);
+    let output = cbindgen(&dir, &cargo(), &[]);
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(cache_files(&dir), 0);
+}
+
+#[test]
+fn frozen_does_not_update_the_lock_file() {
+    let dir = setup("frozen", false);
+    let frozen = cbindgen(&dir, &cargo(), &["--frozen"]);
+    assert!(!frozen.status.success(), "{:?}", frozen);
+    assert!(!Path::new(&format!("{}/Cargo.lock", dir)).exists());
+
+    let offline = cbindgen(&dir, &cargo(), &["--offline"]);
+    assert!(offline.status.success(), "{:?}", offline);
+    assert!(Path::new(&format!("{}/Cargo.lock", dir)).exists());
+
+    let frozen = cbindgen(&dir, &cargo(), &["--frozen"]);
+    assert!(frozen.status.success(), "{:?}", frozen);
+}
@@ -0,0 +1,15 @@
+pub const NAME_SIZE: usize = 16;
+
+pub type Digest = [u8; 32];
+
+#[repr(C)]
+pub struct Record {
+    id: u32,
+    name: [u8; NAME_SIZE],
+    samples: [[f32; 3]; 4],
+    #[cfg(feature = "checksum")]
+    checksum: [u8; 8],
+}
+
+#[no_mangle]
+pub extern "C" fn root(record: *const Record, digest: *const Digest) {}
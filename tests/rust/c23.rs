@@ -0,0 +1,34 @@
+#[repr(u8)]
+pub enum Mode {
+    Read,
+    Write,
+}
+
+#[repr(C)]
+pub struct Options {
+    mode: Mode,
+    verbose: bool,
+}
+
+pub const MAX_LEN: u32 = 64;
+pub const DEFAULT_NAME: *const u8 = std::ptr::null();
+
+#[no_mangle]
+#[must_use]
+pub extern "C" fn open_options(mode: Mode) -> Options {
+    Options { mode, verbose: false }
+}
+
+#[no_mangle]
+#[deprecated]
+pub extern "C" fn legacy_open() {}
+
+#[no_mangle]
+#[deprecated(since = "1.0.0", note = "use \"open_options\" instead")]
+pub extern "C" fn legacy_open_with(options: *const Options) {}
+
+#[no_mangle]
+#[must_use = "the options should be used to open something"]
+pub extern "C" fn default_options() -> Options {
+    Options { mode: Mode::Read, verbose: false }
+}
@@ -0,0 +1,25 @@
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+/// cbindgen:derive-c-helper-functions
+#[repr(C, u8)]
+pub enum Shape {
+    Empty,
+    Circle { center: Point, radius: f32 },
+    Polygon([Point; 3]),
+    Label(*const u8),
+}
+
+/// cbindgen:derive-c-helper-functions
+#[repr(u8)]
+pub enum Event {
+    Quit,
+    KeyPress(u32),
+    Move { dx: i32, dy: i32 },
+}
+
+#[no_mangle]
+pub extern "C" fn root(shape: Shape, event: Event) {}
@@ -0,0 +1,10 @@
+#[repr(C)]
+pub struct Version {
+    major: u32,
+    minor: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn version() -> Version {
+    Version { major: 1, minor: 2 }
+}
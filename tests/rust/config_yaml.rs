@@ -0,0 +1,14 @@
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[repr(u8)]
+pub enum Shape {
+    Circle,
+    Square,
+}
+
+#[no_mangle]
+pub extern "C" fn draw(point: Point, shape: Shape) {}
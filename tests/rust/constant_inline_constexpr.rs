@@ -0,0 +1,13 @@
+pub const CONSTANT_I64: i64 = 216;
+pub const CONSTANT_FLOAT32: f32 = 312.292;
+pub const DELIMITER: char = ':';
+
+#[repr(C)]
+pub struct Foo {
+    x: i32,
+}
+
+pub const SOME_FOO: Foo = Foo { x: 99 };
+
+#[no_mangle]
+pub extern "C" fn root(foo: Foo) {}
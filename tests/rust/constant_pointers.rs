@@ -0,0 +1,21 @@
+#[repr(C)]
+pub struct Node {
+    value: i32,
+    data: *mut u8,
+}
+
+pub const NO_NODE: *const Node = 0 as *const Node;
+pub const NO_DATA: *mut u8 = 0 as *mut u8;
+
+#[no_mangle]
+pub static ROOT: *const Node = 0 as *const Node;
+
+#[no_mangle]
+pub extern "C" fn node_first(nodes: *const *const Node) -> *const Node {
+    unsafe { *nodes }
+}
+
+#[no_mangle]
+pub extern "C" fn node_data(node: &Node) -> *const *mut u8 {
+    &node.data
+}
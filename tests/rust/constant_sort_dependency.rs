@@ -0,0 +1,4 @@
+pub const C: u8 = B + 1;
+pub const B: u8 = A + 1;
+pub const A: u8 = 0;
+pub const D: u8 = 4;
@@ -0,0 +1,30 @@
+/// Uses the style from the config.
+pub const MAX_ITEMS: u32 = 64;
+
+/// cbindgen:style=define
+pub const VERSION: &'static str = "1.0";
+
+/// cbindgen:style=enum
+pub const BUFFER_SIZE: i32 = 4096;
+
+/// Not an integer, so it falls back to the default style.
+/// cbindgen:style=enum
+pub const RATIO: f32 = 1.5;
+
+/// cbindgen:style=static_const
+pub const SCALE: f64 = 2.0;
+
+/// cbindgen:style=constexpr
+pub const FLAGS: u8 = 0x0F;
+
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+/// cbindgen:style=static_const
+pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+#[no_mangle]
+pub extern "C" fn root(point: Point) {}
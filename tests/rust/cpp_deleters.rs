@@ -0,0 +1,28 @@
+/// cbindgen:destructor=parser_free
+pub struct Parser {
+    input: String,
+}
+
+/// cbindgen:destructor=document_free
+#[cfg(feature = "documents")]
+pub struct Document {
+    root: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn parser_new() -> *mut Parser {
+    unimplemented!()
+}
+
+#[no_mangle]
+pub extern "C" fn parser_free(parser: *mut Parser) {}
+
+#[cfg(feature = "documents")]
+#[no_mangle]
+pub extern "C" fn parser_parse(parser: *mut Parser) -> *mut Document {
+    unimplemented!()
+}
+
+#[cfg(feature = "documents")]
+#[no_mangle]
+pub extern "C" fn document_free(document: *mut Document) {}
@@ -0,0 +1,23 @@
+#[repr(i32)]
+pub enum Status {
+    Ok,
+    NotFound,
+    Denied,
+}
+
+/// cbindgen:error-code
+#[no_mangle]
+pub extern "C" fn open_file(path: *const u8) -> i32 {
+    0
+}
+
+/// cbindgen:error-code=Status::Ok
+#[no_mangle]
+pub extern "C" fn check_access(user: u32) -> Status {
+    Status::Ok
+}
+
+#[no_mangle]
+pub extern "C" fn not_an_error_code() -> i32 {
+    0
+}
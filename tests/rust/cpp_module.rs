@@ -0,0 +1,18 @@
+pub const LIMIT: u32 = 10;
+
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[repr(u8)]
+pub enum Direction {
+    Up,
+    Down,
+}
+
+#[no_mangle]
+pub extern "C" fn step(point: Point, direction: Direction) -> Point {
+    point
+}
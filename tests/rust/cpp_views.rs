@@ -0,0 +1,27 @@
+use std::os::raw::c_char;
+
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+/// cbindgen:span=[[points; count]]
+#[no_mangle]
+pub extern "C" fn centroid(points: *const Point, count: usize) -> Point {
+    unimplemented!()
+}
+
+/// cbindgen:span=[[values; len]]
+#[no_mangle]
+pub extern "C" fn fill(values: *mut i32, len: u32, value: i32) {}
+
+/// cbindgen:string-view=[[name; name_len], [label; label_len]]
+#[no_mangle]
+pub extern "C" fn set_name(id: u64, name: *const c_char, name_len: usize, label: *const u8, label_len: usize) -> bool {
+    true
+}
+
+/// cbindgen:span=[[names; count]]
+#[no_mangle]
+pub extern "C" fn print_all(names: *const *const c_char, count: usize) {}
@@ -0,0 +1,14 @@
+extern crate dep;
+
+use dep::{Point, Unit};
+
+#[repr(C)]
+pub struct Line {
+    pub a: Point,
+    pub b: Point,
+}
+
+#[no_mangle]
+pub extern "C" fn line_length(line: &Line, unit: Unit) -> f32 {
+    0.0
+}
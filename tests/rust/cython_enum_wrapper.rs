@@ -0,0 +1,31 @@
+/// A shape.
+#[repr(C)]
+pub enum Shape {
+    Circle(f32),
+    Rect { w: f32, h: f32 },
+    Empty,
+    Custom(*const u8),
+}
+
+#[repr(u8)]
+pub enum Reading {
+    Level(u32),
+    Pair(i16, bool),
+    Off,
+}
+
+#[no_mangle]
+pub extern "C" fn shape_area(shape: Shape) -> f32 {
+    0.0
+}
+
+/// cbindgen:nogil
+#[no_mangle]
+pub extern "C" fn unit_circle() -> Shape {
+    Shape::Empty
+}
+
+#[no_mangle]
+pub extern "C" fn read(sensor: u32) -> Reading {
+    Reading::Off
+}
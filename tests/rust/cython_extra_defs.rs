@@ -0,0 +1,7 @@
+#[repr(C)]
+pub struct Buffer {
+    len: usize,
+}
+
+#[no_mangle]
+pub extern "C" fn buffer_clear(buffer: *mut Buffer) {}
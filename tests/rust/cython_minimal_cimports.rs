@@ -0,0 +1,17 @@
+#[repr(u8)]
+pub enum Kind {
+    Start,
+    End,
+}
+
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    offset: isize,
+    kind: Kind,
+}
+
+#[no_mangle]
+pub extern "C" fn measure(p: Point, n: u64) -> f32 {
+    0.0
+}
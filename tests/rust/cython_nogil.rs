@@ -0,0 +1,14 @@
+#[no_mangle]
+pub extern "C" fn compute(x: i32) -> i32 {
+    x
+}
+
+/// Calls back into Python code, so it needs the GIL.
+///
+/// cbindgen:nogil=false
+#[no_mangle]
+pub extern "C" fn run_callback(callback: extern "C" fn(i32)) {}
+
+/// cbindgen:nogil
+#[no_mangle]
+pub extern "C" fn reset() {}
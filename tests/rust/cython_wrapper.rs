@@ -0,0 +1,59 @@
+/// cbindgen:destructor=parser_free
+pub struct Parser;
+
+pub struct Token;
+
+/// The destructor of `Parser` can't free a `Lexer`.
+///
+/// cbindgen:destructor=parser_free
+pub struct Lexer;
+
+/// cbindgen:ownership=owned
+#[no_mangle]
+pub extern "C" fn parser_new(strict: bool) -> *mut Parser {
+    Box::into_raw(Box::new(Parser))
+}
+
+#[no_mangle]
+pub extern "C" fn parser_free(parser: *mut Parser) {}
+
+/// Parses the next token.
+///
+/// cbindgen:nogil
+#[no_mangle]
+pub extern "C" fn parser_next(parser: *mut Parser, limit: u32) -> *const Token {
+    std::ptr::null()
+}
+
+/// cbindgen:nogil
+#[no_mangle]
+pub extern "C" fn parser_reset(parser: *mut Parser) {}
+
+#[no_mangle]
+pub extern "C" fn token_len(token: &Token) -> usize {
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn token_text(token: &Token) -> *const std::os::raw::c_char {
+    std::ptr::null()
+}
+
+/// The token is borrowed from the parser, which must outlive it.
+#[no_mangle]
+pub extern "C" fn parser_current<'a>(parser: &'a mut Parser) -> &'a mut Token {
+    unimplemented!()
+}
+
+/// cbindgen:ownership=[[parser; owned], [return; owned]]
+#[no_mangle]
+pub extern "C" fn parser_into_lexer(parser: *mut Parser) -> *mut Lexer {
+    std::ptr::null_mut()
+}
+
+/// cbindgen:ownership=[[lexer; owned]]
+/// cbindgen:nogil
+#[no_mangle]
+pub extern "C" fn lexer_run(lexer: *mut Lexer, steps: u32) -> u32 {
+    0
+}
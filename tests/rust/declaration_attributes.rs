@@ -0,0 +1,27 @@
+/// cbindgen:weak
+#[no_mangle]
+pub extern "C" fn plugin_hook(value: i32) -> i32 {
+    value
+}
+
+/// cbindgen:visibility=hidden
+/// cbindgen:section=.text.startup
+#[no_mangle]
+pub extern "C" fn internal_helper() {}
+
+/// cbindgen:constructor
+#[no_mangle]
+pub extern "C" fn library_init() {}
+
+/// cbindgen:attribute=__attribute__((cold))
+#[no_mangle]
+pub extern "C" fn rarely_called(first_argument: i32, second_argument: i32, third_argument: i32) {}
+
+/// cbindgen:used
+/// cbindgen:section=.data.config
+#[no_mangle]
+pub static mut CONFIG_VERSION: u32 = 1;
+
+/// cbindgen:visibility="default"
+#[no_mangle]
+pub static EXPORTED_VALUE: i32 = 0;
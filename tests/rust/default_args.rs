@@ -0,0 +1,15 @@
+#[repr(C)]
+pub struct Canvas {
+    width: u32,
+    height: u32,
+}
+
+/// cbindgen:default-args=[[color; 0xFFFFFFFF], [scale; 1.0f]]
+#[no_mangle]
+pub extern "C" fn draw(canvas: &mut Canvas, x: i32, y: i32, color: u32, scale: f32) {}
+
+/// cbindgen:default-args=[[flags; 0]]
+#[no_mangle]
+pub extern "C" fn canvas_new(flags: u32) -> *mut Canvas {
+    std::ptr::null_mut()
+}
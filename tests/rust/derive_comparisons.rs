@@ -0,0 +1,30 @@
+#[repr(C)]
+pub struct Version {
+    major: u32,
+    minor: u32,
+    patch: u32,
+}
+
+#[repr(C)]
+pub struct Release {
+    version: Version,
+    date: i64,
+}
+
+#[repr(C)]
+pub struct Flags {
+    enabled: bool,
+    level: u8,
+}
+
+/// cbindgen:derive-comparisons=false
+#[repr(C)]
+pub struct Opaque {
+    handle: *mut u8,
+}
+
+#[repr(C)]
+pub struct Id(u64);
+
+#[no_mangle]
+pub extern "C" fn root(release: Release, flags: Flags, opaque: Opaque, id: Id) {}
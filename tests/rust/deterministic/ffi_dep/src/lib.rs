@@ -0,0 +1,5 @@
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
@@ -0,0 +1,6 @@
+mod shapes;
+
+#[no_mangle]
+pub extern "C" fn origin() -> ffi_dep::Point {
+    unimplemented!()
+}
@@ -0,0 +1,14 @@
+use ffi_dep::Point;
+
+#[repr(C)]
+pub struct Segment {
+    #[cfg(feature = "fast")]
+    length: f32,
+    start: Point,
+    end: Point,
+}
+
+#[no_mangle]
+pub extern "C" fn length(segment: Segment) -> f32 {
+    unimplemented!()
+}
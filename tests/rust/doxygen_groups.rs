@@ -0,0 +1,48 @@
+/// The version of the bindings.
+pub const VERSION: u32 = 1;
+
+pub mod shapes {
+    /// A point in the plane.
+    #[repr(C)]
+    pub struct Point {
+        pub x: f32,
+        pub y: f32,
+    }
+
+    pub const ORIGIN_X: f32 = 0.0;
+
+    #[no_mangle]
+    pub extern "C" fn point_length(point: Point) -> f32 {
+        (point.x * point.x + point.y * point.y).sqrt()
+    }
+
+    pub mod polygons {
+        use super::Point;
+
+        /// A triangle made of three points.
+        #[repr(C)]
+        pub struct Triangle {
+            pub points: [Point; 3],
+        }
+
+        #[no_mangle]
+        pub extern "C" fn triangle_area(triangle: Triangle) -> f32 {
+            0.0
+        }
+    }
+}
+
+pub mod colors {
+    #[repr(u8)]
+    pub enum Color {
+        Red,
+        Green,
+        Blue,
+    }
+
+    #[no_mangle]
+    pub static DEFAULT_COLOR: Color = Color::Red;
+}
+
+#[no_mangle]
+pub extern "C" fn root(color: colors::Color, triangle: shapes::polygons::Triangle) {}
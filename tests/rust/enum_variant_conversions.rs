@@ -0,0 +1,24 @@
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[repr(u8)]
+pub enum Shape {
+    Circle { center: Point, radius: f32 },
+    Rect(Point, Point),
+    Empty,
+}
+
+#[repr(C, u8)]
+pub enum Value {
+    Int(i64),
+    Float(f64),
+    Pair(i32, i32),
+    Null,
+    Undefined,
+}
+
+#[no_mangle]
+pub extern "C" fn root(shape: Shape, value: Value) {}
@@ -0,0 +1,21 @@
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[no_mangle]
+pub static mut POINT_COUNT: u32 = 0;
+
+#[no_mangle]
+pub static ORIGIN: Point = Point { x: 0, y: 0 };
+
+#[no_mangle]
+pub extern "C" fn point_new(x: i32, y: i32) -> Point {
+    Point { x, y }
+}
+
+#[no_mangle]
+pub extern "C" fn point_distance_squared(a: Point, b: Point) -> i32 {
+    (a.x - b.x) * (a.x - b.x) + (a.y - b.y) * (a.y - b.y)
+}
@@ -0,0 +1,14 @@
+#[no_mangle]
+pub static mut COUNTER: u32 = 0;
+
+#[no_mangle]
+pub extern "C" fn counter_increment() -> u32 {
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn counter_reset() {}
+
+extern "C" {
+    pub fn defined_elsewhere();
+}
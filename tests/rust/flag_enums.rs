@@ -0,0 +1,24 @@
+/// cbindgen:flags
+#[repr(u8)]
+pub enum Permissions {
+    Read = 1,
+    Write = 2,
+    Execute = 4,
+}
+
+/// cbindgen:flags
+#[repr(C)]
+pub enum Style {
+    Bold = 1,
+    Italic = 2,
+    Underline = 4,
+}
+
+#[repr(u8)]
+pub enum NotFlags {
+    A,
+    B,
+}
+
+#[no_mangle]
+pub extern "C" fn root(permissions: Permissions, style: Style, not_flags: NotFlags) {}
@@ -0,0 +1,30 @@
+#[repr(C)]
+pub struct Event {
+    code: u32,
+}
+
+pub type Handler = extern "C" fn(event: *const Event, user_data: *mut u8) -> bool;
+
+#[repr(C)]
+pub struct Dispatcher {
+    on_event: extern "C" fn(*const Event, *mut u8) -> bool,
+    on_close: Option<extern "C" fn()>,
+    fallback: Handler,
+}
+
+#[no_mangle]
+pub static mut DEFAULT_CLOSE: Option<extern "C" fn()> = None;
+
+#[no_mangle]
+pub extern "C" fn dispatcher_set_filter(
+    dispatcher: *mut Dispatcher,
+    filter: extern "C" fn(code: u32) -> bool,
+) {
+}
+
+#[no_mangle]
+pub extern "C" fn dispatcher_map(
+    dispatcher: *mut Dispatcher,
+    wrap: extern "C" fn(extern "C" fn(u32) -> bool) -> extern "C" fn(),
+) {
+}
@@ -0,0 +1,17 @@
+#[repr(C)]
+pub struct Normal {
+    x: i32,
+    y: f32,
+}
+
+extern "C" {
+    static COUNTER: u32;
+
+    static mut LAST: Normal;
+
+    #[link_name = "foreign_name"]
+    static RENAMED: *const u8;
+
+    #[link_name = "real_foo"]
+    fn foo() -> i32;
+}
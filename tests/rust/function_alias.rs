@@ -0,0 +1,11 @@
+#[export_name = "mylib_init_v2"]
+pub extern "C" fn init(flags: u32) -> i32 {
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn mylib_shutdown() {}
+
+#[cfg(feature = "legacy")]
+#[export_name = "mylib_reset_v2"]
+pub extern "C" fn reset() {}
@@ -0,0 +1,7 @@
+#[export_name = "mylib_init_v2"]
+pub extern "C" fn init(flags: u32) -> i32 {
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn mylib_shutdown() {}
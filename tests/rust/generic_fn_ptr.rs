@@ -0,0 +1,29 @@
+#[repr(C)]
+pub struct Foo {
+    a: i32,
+}
+
+pub type Callback<T> = extern "C" fn(*mut T);
+pub type Visitor<T> = extern "C" fn(Callback<T>, T) -> bool;
+
+#[repr(C)]
+pub struct Holder<T> {
+    callback: Callback<T>,
+    value: T,
+}
+
+#[no_mangle]
+pub static mut FOO_CALLBACK: Callback<Foo> = {
+    extern "C" fn noop(_: *mut Foo) {}
+    noop
+};
+
+#[no_mangle]
+pub extern "C" fn root(
+    a: Callback<u8>,
+    b: Visitor<i16>,
+    c: Holder<u32>,
+    d: *const Callback<Foo>,
+    e: extern "C" fn(Callback<i64>),
+) {
+}
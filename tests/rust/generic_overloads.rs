@@ -0,0 +1,61 @@
+#[repr(C)]
+pub struct Vec2 {
+    x: f32,
+    y: f32,
+}
+
+#[no_mangle]
+pub extern "C" fn magnitude_i32(value: i32) -> i32 {
+    value.abs()
+}
+
+#[no_mangle]
+pub extern "C" fn magnitude_i64(value: i64) -> i64 {
+    value.abs()
+}
+
+#[no_mangle]
+pub extern "C" fn magnitude_f64(value: f64) -> f64 {
+    value.abs()
+}
+
+#[no_mangle]
+pub extern "C" fn clamp_u8(value: u8, min: u8, max: u8) -> u8 {
+    value.max(min).min(max)
+}
+
+#[no_mangle]
+pub extern "C" fn clamp_f32(value: f32, min: f32, max: f32) -> f32 {
+    value.max(min).min(max)
+}
+
+/// cbindgen:generic-macro=length
+#[no_mangle]
+pub extern "C" fn length_vec2(v: *const Vec2) -> f32 {
+    0.0
+}
+
+/// cbindgen:generic-macro=length
+#[no_mangle]
+pub extern "C" fn length_str(s: *const u8) -> usize {
+    0
+}
+
+// Only one function named after a primitive type, so there's no macro.
+#[no_mangle]
+pub extern "C" fn lonely_u16(value: u16) -> u16 {
+    value
+}
+
+// C++ overloads may differ in their number of arguments.
+/// cbindgen:generic-macro=scale
+#[no_mangle]
+pub extern "C" fn scale_uniform(v: Vec2, factor: f32) -> Vec2 {
+    v
+}
+
+/// cbindgen:generic-macro=scale
+#[no_mangle]
+pub extern "C" fn scale_axes(v: Vec2, x: f32, y: f32) -> Vec2 {
+    v
+}
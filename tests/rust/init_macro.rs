@@ -0,0 +1,32 @@
+/// The settings of a window.
+///
+/// cbindgen:init-macro
+#[repr(C)]
+pub struct WindowConfig {
+    /// The width of the window, in pixels.
+    ///
+    /// cbindgen:default=640
+    pub width: u32,
+    /// cbindgen:default=480
+    pub height: u32,
+    /// cbindgen:default=true
+    pub resizable: bool,
+    pub title: *const u8,
+    /// cbindgen:default=1.0f
+    pub scale: f32,
+}
+
+/// cbindgen:init-macro
+#[repr(C)]
+pub struct Counter {
+    pub value: i32,
+}
+
+#[repr(C)]
+pub struct NoMacro {
+    /// cbindgen:default=1
+    pub value: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn create_window(config: WindowConfig, counter: Counter, no_macro: NoMacro) {}
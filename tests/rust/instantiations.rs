@@ -0,0 +1,15 @@
+#[repr(C)]
+pub struct Pair<T, U> {
+    first: T,
+    second: U,
+}
+
+#[repr(C)]
+pub struct Wrapper<T> {
+    value: T,
+}
+
+pub type Callback<T> = extern "C" fn(T) -> bool;
+
+#[no_mangle]
+pub extern "C" fn root(wrapper: Wrapper<u8>) {}
@@ -0,0 +1,10 @@
+#[repr(C)]
+pub struct Wide {
+    unsigned: u128,
+    signed: i128,
+}
+
+#[no_mangle]
+pub extern "C" fn root(w: Wide, x: u128) -> i128 {
+    0
+}
@@ -0,0 +1,22 @@
+#[repr(C, align(16))]
+pub struct Vector {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+
+#[repr(C, align(8))]
+pub union Bits {
+    value: u32,
+    bytes: [u8; 4],
+}
+
+#[repr(C)]
+pub struct Particle {
+    position: Vector,
+    velocity: Vector,
+    bits: Bits,
+}
+
+#[no_mangle]
+pub extern "C" fn root(particle: *mut Particle) {}
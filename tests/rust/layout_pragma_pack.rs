@@ -0,0 +1,21 @@
+#[repr(C, packed)]
+pub struct Header {
+    kind: u8,
+    length: u32,
+    checksum: u16,
+}
+
+#[repr(C, packed)]
+pub union Payload {
+    word: u32,
+    bytes: [u8; 3],
+}
+
+#[repr(C)]
+pub struct Message {
+    header: Header,
+    payload: Payload,
+}
+
+#[no_mangle]
+pub extern "C" fn root(message: *const Message) {}
@@ -0,0 +1,37 @@
+#[repr(C)]
+pub struct Entry<'a> {
+    name: &'a u8,
+}
+
+#[repr(C)]
+pub struct Map {
+    len: usize,
+}
+
+impl Map {
+    #[no_mangle]
+    pub extern "C" fn map_first(&self) -> &Entry {
+        unimplemented!()
+    }
+}
+
+/// Looks up a key.
+#[no_mangle]
+pub extern "C" fn map_get<'m>(map: &'m Map, key: &u8) -> Option<&'m Entry<'m>> {
+    None
+}
+
+#[no_mangle]
+pub extern "C" fn pick<'a>(first: &'a Map, second: &'a Map, flag: bool) -> &'a Map {
+    first
+}
+
+#[no_mangle]
+pub extern "C" fn identity(value: &u32) -> &u32 {
+    value
+}
+
+#[no_mangle]
+pub extern "C" fn global_map(map: &Map) -> &'static Map {
+    unimplemented!()
+}
@@ -0,0 +1,16 @@
+pub const LIMIT: u32 = 10;
+
+/// A point on the plane.
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+pub type Callback = extern "C" fn(Point);
+
+#[no_mangle]
+pub static mut ORIGIN: Point = Point { x: 0.0, y: 0.0 };
+
+#[no_mangle]
+pub extern "C" fn visit(point: Point, callback: Callback) {}
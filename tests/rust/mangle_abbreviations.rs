@@ -0,0 +1,13 @@
+#[repr(C)]
+pub struct Foo<T> {
+    a: T,
+}
+
+#[repr(C)]
+pub struct Bar<T, U> {
+    a: T,
+    b: U,
+}
+
+#[no_mangle]
+pub extern "C" fn root(x: Foo<u64>, y: Bar<Foo<i32>, u64>) {}
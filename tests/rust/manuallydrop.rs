@@ -20,3 +20,12 @@ pub extern "C" fn root(a: &Foo, with_manual_drop: &MyStruct) {}
 
 #[no_mangle]
 pub extern "C" fn take(with_manual_drop: std::mem::ManuallyDrop<Point>) {}
+
+#[repr(C)]
+pub union MyUnion {
+    point: std::mem::ManuallyDrop<Point>,
+    bits: u64,
+}
+
+#[no_mangle]
+pub extern "C" fn take_union(with_manual_drop: MyUnion) {}
@@ -11,3 +11,6 @@ pub type Foo<'a> = NotReprC<std::mem::MaybeUninit<&'a i32>>;
 
 #[no_mangle]
 pub extern "C" fn root<'a, 'b>(a: &'a Foo, with_maybe_uninit: &'b MyStruct) {}
+
+#[no_mangle]
+pub extern "C" fn init(out: *mut std::mem::MaybeUninit<MyStruct>, len: *mut std::mem::MaybeUninit<usize>) {}
@@ -0,0 +1,30 @@
+#[repr(C)]
+pub struct Counter {
+    value: i32,
+}
+
+/// cbindgen:method-of=Counter
+#[no_mangle]
+pub extern "C" fn counter_increment(counter: *mut Counter, by: i32) {}
+
+/// cbindgen:method-of=Counter
+#[no_mangle]
+pub extern "C" fn counter_get(counter: *const Counter) -> i32 {
+    0
+}
+
+/// cbindgen:method-of=Counter
+#[no_mangle]
+pub extern "C" fn counter_is_zero(counter: &Counter) -> bool {
+    true
+}
+
+/// cbindgen:method-of=Counter
+#[no_mangle]
+pub extern "C" fn reset_counter(counter: *mut Counter) {}
+
+/// cbindgen:method-of=Counter
+#[no_mangle]
+pub extern "C" fn counter_new(value: i32) -> Counter {
+    Counter { value }
+}
@@ -0,0 +1,8 @@
+#[repr(C)]
+pub struct Options {
+    verbose: bool,
+    level: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn configure(options: *const Options, len: usize) {}
@@ -0,0 +1,29 @@
+#[repr(C)]
+#[must_use = "leaks the handle unless it is closed"]
+pub struct Handle {
+    fd: i32,
+}
+
+#[repr(C)]
+#[must_use = "errors must be \"handled\""]
+pub enum Status {
+    Ok,
+    Failed,
+}
+
+#[repr(C, u8)]
+#[must_use = "might be an error"]
+pub enum Outcome {
+    Done(i32),
+    Error(Status),
+}
+
+#[no_mangle]
+#[must_use = "the handle must be closed"]
+pub extern "C" fn handle_open(path: *const u8) -> Handle {
+}
+
+#[no_mangle]
+#[must_use]
+pub extern "C" fn handle_read(handle: Handle) -> Outcome {
+}
@@ -0,0 +1,66 @@
+pub mod geometry {
+    #[repr(C)]
+    pub struct Point {
+        pub x: f32,
+        pub y: f32,
+    }
+
+    pub mod shapes {
+        use super::Point;
+
+        #[repr(C)]
+        pub struct Circle {
+            pub center: Point,
+            pub radius: f32,
+        }
+
+        #[repr(u8)]
+        pub enum Kind {
+            Circle,
+            Square,
+        }
+    }
+}
+
+pub mod wrappers {
+    #[repr(C)]
+    pub struct Wrapper<T> {
+        pub value: T,
+    }
+}
+
+#[repr(C)]
+pub struct Scene {
+    pub origin: geometry::Point,
+    pub circle: geometry::shapes::Circle,
+    pub kind: geometry::shapes::Kind,
+    pub scale: wrappers::Wrapper<f32>,
+}
+
+#[no_mangle]
+pub extern "C" fn draw(scene: *const Scene, center: geometry::Point) {}
+
+pub mod io {
+    use super::Scene;
+
+    pub const MAX_SCENES: u32 = 16;
+
+    #[no_mangle]
+    pub static mut SCENE_COUNT: u32 = 0;
+
+    /// cbindgen:destructor=free_buffer
+    pub struct Buffer(Vec<u8>);
+
+    #[no_mangle]
+    pub extern "C" fn find_scene(name: *const std::os::raw::c_char) -> Option<&'static Scene> {
+        None
+    }
+
+    #[no_mangle]
+    pub extern "C" fn new_buffer() -> *mut Buffer {
+        std::ptr::null_mut()
+    }
+
+    #[no_mangle]
+    pub extern "C" fn free_buffer(buffer: *mut Buffer) {}
+}
@@ -0,0 +1,26 @@
+use std::ptr::NonNull;
+
+#[repr(C)]
+pub struct Buffer {
+    data: *mut u8,
+    len: usize,
+}
+
+#[no_mangle]
+pub extern "C" fn buffer_fill(buffer: &mut Buffer, value: u8) {}
+
+#[no_mangle]
+pub extern "C" fn buffer_copy(dst: &mut Buffer, src: Option<&Buffer>, data: NonNull<u8>) {}
+
+#[no_mangle]
+pub extern "C" fn buffer_visit(buffer: *const Buffer, visitor: extern "C" fn(u8)) {}
+
+/// cbindgen:non-null-args=false
+#[no_mangle]
+pub extern "C" fn buffer_clear(buffer: &mut Buffer) {}
+
+/// cbindgen:weak
+#[no_mangle]
+pub extern "C" fn buffer_len(buffer: &Buffer) -> usize {
+    buffer.len
+}
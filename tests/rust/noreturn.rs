@@ -0,0 +1,19 @@
+#[no_mangle]
+pub extern "C" fn fatal_error(code: i32) -> ! {
+    std::process::exit(code)
+}
+
+#[no_mangle]
+pub extern "C" fn maybe_exit(code: i32) {
+    if code != 0 {
+        std::process::exit(code)
+    }
+}
+
+#[repr(C)]
+pub struct Callbacks {
+    on_fatal_error: extern "C" fn(i32) -> !,
+}
+
+#[no_mangle]
+pub extern "C" fn set_callbacks(callbacks: Callbacks) {}
@@ -0,0 +1,27 @@
+use std::ptr::NonNull;
+
+#[repr(C)]
+pub struct Entry {
+    key: u32,
+    value: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn find(entries: &[Entry; 4], key: u32) -> Option<&Entry> {
+    None
+}
+
+#[no_mangle]
+pub extern "C" fn first_mut(entries: *mut Entry) -> Option<NonNull<Entry>> {
+    None
+}
+
+#[no_mangle]
+pub extern "C" fn data(_: u32) -> *const u8 {
+    std::ptr::null()
+}
+
+#[no_mangle]
+pub extern "C" fn get(entries: &[Entry; 4]) -> &Entry {
+    &entries[0]
+}
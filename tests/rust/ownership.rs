@@ -0,0 +1,32 @@
+/// cbindgen:destructor=buffer_free
+pub struct Buffer {
+    data: Vec<u8>,
+}
+
+/// Creates a new buffer.
+///
+/// cbindgen:ownership=owned
+#[no_mangle]
+pub extern "C" fn buffer_new() -> *mut Buffer {
+    Box::into_raw(Box::new(Buffer { data: Vec::new() }))
+}
+
+/// cbindgen:ownership=[[buffer; owned]]
+#[no_mangle]
+pub unsafe extern "C" fn buffer_free(buffer: *mut Buffer) {}
+
+/// Returns the contents of the buffer.
+///
+/// cbindgen:ownership=[[buffer; borrowed], [return; borrowed]]
+#[no_mangle]
+pub extern "C" fn buffer_data(buffer: &Buffer, len: *mut usize) -> *const u8 {
+    buffer.data.as_ptr()
+}
+
+/// Appends `other` to `buffer`, returning the resulting buffer.
+///
+/// cbindgen:ownership=[[buffer; owned], [other; borrowed], [return; owned]]
+#[no_mangle]
+pub extern "C" fn buffer_append(buffer: *mut Buffer, other: &Buffer) -> *mut Buffer {
+    buffer
+}
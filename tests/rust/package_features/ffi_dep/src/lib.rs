@@ -0,0 +1,24 @@
+#[repr(C)]
+pub struct Options {
+    pub flags: u32,
+    #[cfg(feature = "extended")]
+    pub extra: u64,
+    #[cfg(feature = "legacy")]
+    pub legacy: u8,
+}
+
+#[cfg(feature = "extended")]
+#[no_mangle]
+pub extern "C" fn options_extended(options: Options) {}
+
+#[cfg(not(feature = "extended"))]
+#[no_mangle]
+pub extern "C" fn options_basic(options: Options) {}
+
+#[cfg(all(unix, feature = "extended"))]
+#[no_mangle]
+pub extern "C" fn options_unix(options: Options) {}
+
+#[cfg(any(windows, feature = "legacy"))]
+#[no_mangle]
+pub extern "C" fn options_windows(options: Options) {}
@@ -0,0 +1,12 @@
+extern crate ffi_dep;
+
+#[no_mangle]
+pub extern "C" fn init(options: ffi_dep::Options) {}
+
+#[cfg(feature = "logging")]
+#[no_mangle]
+pub extern "C" fn log_options(options: ffi_dep::Options) {}
+
+#[cfg(feature = "tracing")]
+#[no_mangle]
+pub extern "C" fn trace_options(options: ffi_dep::Options) {}
@@ -0,0 +1,10 @@
+#[repr(C)]
+pub struct Plugin {
+    pub alpha: Alpha,
+    pub version: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn plugin_version(plugin: *const Plugin) -> u32 {
+    0
+}
@@ -0,0 +1,4 @@
+#[repr(C)]
+pub struct Alpha {
+    pub a: u32,
+}
@@ -0,0 +1,4 @@
+#[repr(C)]
+pub struct Beta {
+    pub b: f32,
+}
@@ -0,0 +1,4 @@
+#[repr(C)]
+pub struct Internal {
+    pub i: u64,
+}
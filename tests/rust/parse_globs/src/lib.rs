@@ -0,0 +1,6 @@
+extern crate ffi_alpha;
+extern crate ffi_beta;
+extern crate ffi_internal;
+
+#[no_mangle]
+pub extern "C" fn combine(alpha: ffi_alpha::Alpha, beta: ffi_beta::Beta) {}
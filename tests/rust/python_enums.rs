@@ -0,0 +1,33 @@
+/// How shapes are filled.
+#[repr(u8)]
+pub enum FillRule {
+    /// The non-zero winding rule.
+    NonZero,
+    EvenOdd = 4,
+    None,
+}
+
+#[repr(i32)]
+pub enum Level {
+    Low = -1,
+    High = 1 << 4,
+    Higher,
+}
+
+/// cbindgen:flags
+#[repr(u8)]
+pub enum Permissions {
+    Read = 1,
+    Write = 2,
+    Execute = 4,
+}
+
+#[repr(C, u8)]
+pub enum Shape {
+    Circle(f32),
+    Square { side: f32 },
+    Nothing,
+}
+
+#[no_mangle]
+pub extern "C" fn root(a: FillRule, b: Level, c: Shape, d: Permissions) {}
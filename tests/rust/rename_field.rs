@@ -0,0 +1,34 @@
+#[repr(C)]
+pub struct Instruction {
+    /// cbindgen:rename=kind
+    /// The kind of the instruction.
+    r#type: u8,
+    /// cbindgen:rename=reg
+    register: u32,
+    operand: i64,
+}
+
+#[repr(C)]
+pub union Value {
+    /// cbindgen:rename=as_int
+    int: i64,
+    real: f64,
+}
+
+#[repr(C)]
+pub struct Pair(
+    /// cbindgen:rename=first
+    i32,
+    i32,
+);
+
+#[repr(C, u8)]
+pub enum Operand {
+    Immediate {
+        /// cbindgen:rename=value
+        default: i64,
+    },
+}
+
+#[no_mangle]
+pub extern "C" fn root(i: Instruction, v: Value, p: Pair, o: Operand) {}
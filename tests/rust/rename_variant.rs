@@ -0,0 +1,18 @@
+#[repr(u8)]
+pub enum Mode {
+    Read,
+    /// cbindgen:rename=MODE_WRITE_ALL
+    Write,
+    /// cbindgen:rename=Register
+    Register_,
+}
+
+#[repr(C, u8)]
+pub enum Event {
+    Start,
+    /// cbindgen:rename=Finish
+    Stop { code: i32 },
+}
+
+#[no_mangle]
+pub extern "C" fn root(m: Mode, e: Event) {}
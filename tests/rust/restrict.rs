@@ -0,0 +1,10 @@
+/// cbindgen:restrict=[dst, src]
+#[no_mangle]
+pub extern "C" fn copy_floats(dst: *mut f32, src: *const f32, len: usize) {}
+
+/// cbindgen:restrict
+#[no_mangle]
+pub extern "C" fn add_floats(out: *mut f32, lhs: *const f32, rhs: *const f32, len: usize) {}
+
+#[no_mangle]
+pub extern "C" fn scale_floats(values: *mut f32, factor: f32, len: usize) {}
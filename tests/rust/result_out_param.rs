@@ -0,0 +1,44 @@
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+pub struct Handle;
+
+#[repr(u8)]
+pub enum Error {
+    Ok = 0,
+    InvalidInput = 1,
+    OutOfMemory = 2,
+}
+
+/// Parses a point.
+///
+/// cbindgen:result-out-param
+/// cbindgen:error-code=Error::Ok
+#[no_mangle]
+pub extern "C" fn parse_point(input: *const u8, len: usize, out: *mut Point) -> Error {
+    Error::InvalidInput
+}
+
+/// cbindgen:result-out-param=handle
+#[no_mangle]
+pub extern "C" fn open(id: u32, handle: *mut *mut Handle) -> i32 {
+    -1
+}
+
+/// cbindgen:result-out-param=len
+/// cbindgen:error-code=Error::Ok
+#[no_mangle]
+pub extern "C" fn handle_len(handle: *const Handle, len: *mut u32) -> Error {
+    Error::Ok
+}
+
+/// Results have no C equivalent, so this is skipped.
+///
+/// cbindgen:result-out-param
+#[no_mangle]
+pub extern "C" fn parse_result(input: *const u8) -> Result<Point, Error> {
+    Err(Error::InvalidInput)
+}
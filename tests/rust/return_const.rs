@@ -0,0 +1,27 @@
+#[repr(C)]
+pub struct Table {
+    rows: *mut u32,
+    len: usize,
+}
+
+/// cbindgen:return-const
+#[no_mangle]
+pub extern "C" fn table_rows(table: *mut Table) -> *mut u32 {
+    unsafe { (*table).rows }
+}
+
+/// cbindgen:return-const
+#[no_mangle]
+pub extern "C" fn table_get(table: &mut Table) -> &mut Table {
+    table
+}
+
+#[no_mangle]
+pub extern "C" fn table_first(table: *const Table) -> *const u32 {
+    unsafe { (*table).rows }
+}
+
+#[no_mangle]
+pub extern "C" fn table_rows_mut(table: *mut Table) -> *mut u32 {
+    unsafe { (*table).rows }
+}
@@ -0,0 +1,21 @@
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+/// cbindgen:slices-as-structs
+#[no_mangle]
+pub extern "C" fn sum(values: &[u32]) -> u64 {
+    values.iter().map(|v| *v as u64).sum()
+}
+
+/// cbindgen:slices-as-structs
+#[no_mangle]
+pub extern "C" fn fill(points: &mut [Point], bytes: *const SliceRef<u8>) {}
+
+/// cbindgen:slices-as-structs
+#[no_mangle]
+pub extern "C" fn origins() -> &'static [Point] {
+    &[]
+}
@@ -0,0 +1,24 @@
+#[repr(C)]
+pub struct Zeta {
+    a: i32,
+}
+
+#[repr(C)]
+pub struct Alpha {
+    a: Zeta,
+}
+
+pub const LAST: i32 = FIRST + 1;
+pub const FIRST: i32 = 1;
+
+#[no_mangle]
+pub static mut ZULU: i32 = 0;
+
+#[no_mangle]
+pub static mut ALPHA: i32 = 0;
+
+#[no_mangle]
+pub extern "C" fn zulu(a: Alpha) {}
+
+#[no_mangle]
+pub extern "C" fn alpha(z: Zeta) {}
@@ -0,0 +1,31 @@
+pub mod shapes {
+    #[repr(C)]
+    pub struct Point {
+        pub x: f32,
+        pub y: f32,
+    }
+
+    #[repr(C)]
+    pub struct Circle {
+        pub center: Point,
+        pub radius: f32,
+    }
+}
+
+pub mod colors {
+    #[repr(u8)]
+    pub enum Color {
+        Red,
+        Green,
+        Blue,
+    }
+
+    #[repr(C)]
+    pub struct Fill {
+        pub color: Color,
+        pub shape: *const crate::shapes::Circle,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn draw(circle: shapes::Circle, fill: colors::Fill) {}
@@ -0,0 +1,53 @@
+#[repr(u8)]
+pub enum Kind {
+    A,
+    B,
+}
+
+#[repr(C)]
+pub enum Status {
+    Ok,
+    Failed,
+}
+
+#[repr(C)]
+pub union Value {
+    number: i32,
+    ptr: *mut u8,
+}
+
+#[repr(C)]
+pub struct Header {
+    kind: Kind,
+    len: u32,
+    status: Status,
+}
+
+#[repr(C)]
+pub struct Packet {
+    header: Header,
+    flags: u8,
+    payload: [u16; 3],
+    value: Value,
+    callback: Option<extern "C" fn(u32) -> bool>,
+}
+
+#[repr(C, packed)]
+pub struct Packed {
+    a: u8,
+    b: u64,
+}
+
+#[repr(C, align(16))]
+pub struct Aligned {
+    a: u8,
+}
+
+/// Not checked, as the size of `long` varies.
+#[repr(C)]
+pub struct Long {
+    a: std::os::raw::c_long,
+}
+
+#[no_mangle]
+pub extern "C" fn root(p: Packet, q: Packed, a: Aligned, l: Long) {}
@@ -0,0 +1,18 @@
+/// cbindgen:strings-as-structs
+#[no_mangle]
+pub extern "C" fn greet(name: &str) -> String {
+    format!("Hello, {}!", name)
+}
+
+/// cbindgen:strings-as-structs
+#[no_mangle]
+pub extern "C" fn version() -> &'static str {
+    "1.0"
+}
+
+/// cbindgen:strings-as-structs
+/// cbindgen:slices-as-structs
+#[no_mangle]
+pub extern "C" fn join(parts: &[&str], separator: *const &str) -> String {
+    String::new()
+}
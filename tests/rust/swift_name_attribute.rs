@@ -0,0 +1,43 @@
+/// cbindgen:swift-name=Point
+#[repr(C)]
+pub struct RustPoint {
+    x: i32,
+    y: i32,
+}
+
+/// cbindgen:swift-name=Value
+#[repr(C)]
+pub union RustValue {
+    integer: i32,
+    real: f32,
+}
+
+/// cbindgen:swift-name=Direction
+#[repr(C)]
+pub enum RustDirection {
+    Up,
+    Down,
+}
+
+/// cbindgen:swift-name=Shape
+#[repr(C, u8)]
+pub enum RustShape {
+    Circle(f32),
+    Square(f32),
+}
+
+impl RustPoint {
+    #[no_mangle]
+    pub extern "C" fn RustPoint_length(&self) -> f32 {
+        0.0
+    }
+}
+
+/// cbindgen:swift-name=Point.init(x:y:)
+#[no_mangle]
+pub extern "C" fn rust_point_new(x: i32, y: i32) -> RustPoint {
+    RustPoint { x, y }
+}
+
+#[no_mangle]
+pub extern "C" fn rust_describe(value: RustValue, direction: RustDirection, shape: RustShape) {}
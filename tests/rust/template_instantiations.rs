@@ -0,0 +1,44 @@
+#[repr(C)]
+pub struct Pair<T, U> {
+    first: T,
+    second: U,
+}
+
+#[repr(C)]
+pub union Either<T> {
+    value: T,
+    bits: u64,
+}
+
+#[repr(C, u8)]
+pub enum Maybe<T> {
+    Nothing,
+    Just(T),
+}
+
+#[repr(C)]
+pub struct Wrapper<T> {
+    pair: Pair<T, T>,
+}
+
+pub type IntPair = Pair<i32, i32>;
+
+#[cfg(feature = "extra")]
+#[repr(C)]
+pub struct Extra<T> {
+    value: T,
+}
+
+#[no_mangle]
+pub extern "C" fn root(
+    a: IntPair,
+    b: Either<f32>,
+    c: Maybe<u8>,
+    d: Wrapper<f64>,
+    e: Pair<u8, f32>,
+) {
+}
+
+#[cfg(feature = "extra")]
+#[no_mangle]
+pub extern "C" fn extra(a: Extra<i32>) {}
@@ -0,0 +1,33 @@
+#[repr(C)]
+pub struct Pair<T, U> {
+    first: T,
+    second: *const U,
+}
+
+#[repr(C)]
+pub struct Buffer<T, const N: usize> {
+    data: [T; N],
+    len: usize,
+}
+
+#[repr(C)]
+pub union Either<T> {
+    value: T,
+    bits: u64,
+}
+
+#[repr(C, u8)]
+pub enum Slot<T> {
+    Empty,
+    Full(T),
+    Many(*mut T, usize),
+}
+
+#[no_mangle]
+pub extern "C" fn root(
+    a: Pair<i32, f32>,
+    b: Buffer<u8, 4>,
+    c: Either<u32>,
+    d: Slot<i64>,
+) {
+}
@@ -0,0 +1,24 @@
+pub trait Plugin {
+    fn name(&self) -> &str;
+}
+
+pub trait Logger {
+    fn log(&self, message: &str);
+}
+
+#[repr(C)]
+pub struct Host {
+    logger: Box<dyn Logger + Send>,
+    version: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn plugin_create() -> Box<dyn Plugin> {
+    unimplemented!()
+}
+
+#[no_mangle]
+pub extern "C" fn plugin_destroy(plugin: Box<dyn Plugin>) {}
+
+#[no_mangle]
+pub extern "C" fn plugin_attach(plugin: &mut dyn Plugin, host: *const Host) {}
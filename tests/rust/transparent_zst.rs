@@ -0,0 +1,43 @@
+use std::marker::PhantomData;
+
+#[repr(C)]
+pub struct Marker;
+
+#[repr(C)]
+pub struct Empty {
+    markers: [Marker; 4],
+}
+
+#[repr(transparent)]
+pub struct Meters(Marker, f64);
+
+#[repr(transparent)]
+pub struct Handle<T> {
+    tag: [u8; 0],
+    id: u32,
+    kind: PhantomData<T>,
+}
+
+#[repr(transparent)]
+pub struct Tagged {
+    marker: Empty,
+    value: *const u8,
+}
+
+#[repr(transparent)]
+pub struct OnlyMarkers(PhantomData<u8>);
+
+impl Meters {
+    pub const ZERO: Meters = Meters(Marker, 0.0);
+}
+
+pub const FAR: Meters = Meters(Marker, 1000.0);
+
+#[no_mangle]
+pub extern "C" fn root(
+    meters: Meters,
+    handle: Handle<u8>,
+    tagged: Tagged,
+    markers: *const OnlyMarkers,
+) {
+}
@@ -0,0 +1,19 @@
+#[repr(C)]
+pub struct Point(pub f32, pub f32);
+
+impl Point {
+    pub const ORIGIN: Point = Point(0.0, 0.0);
+}
+
+#[repr(C)]
+pub struct Wrapper(pub *const Point, pub u32);
+
+#[repr(C, u8)]
+pub enum Shape {
+    Dot(Point),
+    Segment(Point, Point),
+    None,
+}
+
+#[no_mangle]
+pub extern "C" fn root(p: Point, w: Wrapper, s: Shape) {}
@@ -0,0 +1,31 @@
+use std::ffi::CStr;
+use a::Id as AId;
+use b::Id;
+
+pub struct Handle {
+    id: u64,
+}
+
+#[repr(C)]
+pub struct Wrapper {
+    pub id: my_crate::Id,
+    pub value: Option<Flags>,
+}
+
+#[repr(C)]
+pub struct Config {
+    pub name: *const CStr,
+    pub flags: Flags,
+    pub wrapper: Wrapper,
+    pub handle: *mut ExternalHandle,
+}
+
+#[repr(C)]
+pub struct Ids {
+    pub a: AId,
+    pub b: Id,
+    pub qualified: a::Id,
+}
+
+#[no_mangle]
+pub extern "C" fn root(config: *const Config, id: my_crate::Id, ids: Ids) -> Flags {}
@@ -0,0 +1,20 @@
+#[repr(C)]
+pub struct Zeta {
+    a: i32,
+}
+
+#[repr(C)]
+pub struct Alpha {
+    a: Zeta,
+}
+
+#[repr(C)]
+pub struct Mu {
+    a: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn root(m: Mu, z: Zeta) {}
+
+#[no_mangle]
+pub extern "C" fn branch(a: Alpha) {}
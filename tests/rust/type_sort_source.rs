@@ -0,0 +1,23 @@
+#[repr(C)]
+pub struct Delta {
+    a: Bravo,
+}
+
+#[repr(C)]
+pub struct Charlie {
+    a: i32,
+}
+
+#[repr(C)]
+pub struct Bravo {
+    a: i32,
+}
+
+#[repr(u8)]
+pub enum Alpha {
+    A,
+    B,
+}
+
+#[no_mangle]
+pub extern "C" fn root(c: Charlie, d: Delta, a: Alpha) {}
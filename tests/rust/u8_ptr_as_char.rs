@@ -0,0 +1,18 @@
+use std::os::raw::c_char;
+
+#[no_mangle]
+pub extern "C" fn name(buf: *mut u8, len: usize) -> *const u8 {
+    buf
+}
+
+#[no_mangle]
+pub extern "C" fn print(s: *const c_char, bytes: *const *const u8) {}
+
+#[repr(C)]
+pub struct Buffer {
+    data: *mut u8,
+    prefix: c_char,
+}
+
+#[no_mangle]
+pub extern "C" fn fill(b: *mut Buffer) {}
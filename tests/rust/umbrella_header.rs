@@ -0,0 +1,10 @@
+#[repr(C)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn point_origin() -> Point {
+    Point { x: 0, y: 0 }
+}
@@ -0,0 +1,9 @@
+/// cbindgen:utf8=[text, return]
+#[no_mangle]
+pub extern "C" fn utf8_copy(text: *const u8, len: usize, buffer: *mut u8) -> *const u8 {
+    text
+}
+
+/// cbindgen:utf8=[name]
+#[no_mangle]
+pub extern "C" fn utf8_greet(name: *const u8) {}
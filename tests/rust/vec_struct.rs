@@ -0,0 +1,21 @@
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+/// cbindgen:vecs-as-structs
+#[no_mangle]
+pub extern "C" fn points(count: usize) -> Vec<Point> {
+    Vec::with_capacity(count)
+}
+
+/// cbindgen:vecs-as-structs
+#[no_mangle]
+pub extern "C" fn free_points(points: Vec<Point>) {}
+
+/// cbindgen:vecs-as-structs
+#[no_mangle]
+pub extern "C" fn lengths(strings: *const Vec<u8>, count: usize) -> Vec<usize> {
+    Vec::new()
+}
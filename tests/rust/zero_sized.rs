@@ -0,0 +1,19 @@
+use std::marker::PhantomData;
+
+#[repr(C)]
+pub struct Handle<T> {
+    id: u32,
+    marker: PhantomData<T>,
+}
+
+#[repr(C)]
+pub struct Marker {
+    unit: (),
+    phantom: PhantomData<u8>,
+}
+
+#[repr(C)]
+pub struct Tuple(PhantomData<u8>, u16);
+
+#[no_mangle]
+pub extern "C" fn root(handle: Handle<u8>, marker: Marker, tuple: Tuple, unit: ()) {}
@@ -0,0 +1,25 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+#[test]
+fn bindings_of_stdin() {
+    let mut cbindgen = Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+        .args(&["--lang", "c", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to execute cbindgen");
+
+    let src = "#[no_mangle]\npub extern \"C\" fn from_stdin(x: u8) -> bool { x != 0 }\n";
+    cbindgen
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(src.as_bytes())
+        .unwrap();
+
+    let output = cbindgen.wait_with_output().unwrap();
+    assert!(output.status.success());
+    let bindings = String::from_utf8(output.stdout).unwrap();
+    assert!(bindings.contains("bool from_stdin(uint8_t x);"));
+}
@@ -0,0 +1,98 @@
+use std::fs;
+use std::process::Command;
+
+const SOURCE: &str = r#"
+#[cfg(unix)]
+#[no_mangle]
+pub extern "C" fn on_unix() {}
+
+#[cfg(target_os = "linux")]
+#[no_mangle]
+pub extern "C" fn on_linux() {}
+
+#[cfg(any(target_os = "freebsd", feature = "serde"))]
+#[no_mangle]
+pub extern "C" fn on_freebsd_or_serde() {}
+
+#[cfg(not(windows))]
+#[no_mangle]
+pub extern "C" fn not_on_windows() {}
+
+#[no_mangle]
+pub extern "C" fn everywhere() {}
+"#;
+
+const CONFIG: &str = r#"
+language = "C"
+
+[targets]
+freebsd = ["unix", "target_os = freebsd"]
+linux = ["unix", "target_os = linux"]
+windows = ["windows", "feature = serde"]
+"#;
+
+fn setup(name: &str) -> String {
+    let dir = format!("{}/targets {}", env!("CARGO_TARGET_TMPDIR"), name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(format!("{}/lib.rs", dir), SOURCE).unwrap();
+    fs::write(format!("{}/cbindgen.toml", dir), CONFIG).unwrap();
+    dir
+}
+
+#[test]
+fn writes_a_header_per_target() {
+    let dir = setup("output");
+    let output = Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+        .args(&["--config", &format!("{}/cbindgen.toml", dir)])
+        .args(&["--output", &format!("{}/bindings.h", dir)])
+        .arg(format!("{}/lib.rs", dir))
+        .output()
+        .expect("failed to execute cbindgen");
+    assert!(output.status.success(), "{:?}", output);
+
+    let functions = |target: &str| {
+        let header = fs::read_to_string(format!("{}/bindings_{}.h", dir, target)).unwrap();
+        assert!(!header.contains("#if"), "{}", header);
+        [
+            "on_unix",
+            "on_linux",
+            "on_freebsd_or_serde",
+            "not_on_windows",
+            "everywhere",
+        ]
+        .iter()
+        .filter(|function| header.contains(&format!("void {}(void);", function)))
+        .copied()
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        functions("freebsd"),
+        [
+            "on_unix",
+            "on_freebsd_or_serde",
+            "not_on_windows",
+            "everywhere"
+        ]
+    );
+    assert_eq!(
+        functions("linux"),
+        ["on_unix", "on_linux", "not_on_windows", "everywhere"]
+    );
+    assert_eq!(functions("windows"), ["on_freebsd_or_serde", "everywhere"]);
+    assert!(!std::path::Path::new(&format!("{}/bindings.h", dir)).exists());
+}
+
+#[test]
+fn rejects_stdout() {
+    let dir = setup("stdout");
+    let output = Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+        .args(&["--config", &format!("{}/cbindgen.toml", dir)])
+        .arg(format!("{}/lib.rs", dir))
+        .output()
+        .expect("failed to execute cbindgen");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("`[targets]`"), "{}", stderr);
+}
@@ -14,16 +14,23 @@ fn style_str(style: Style) -> &'static str {
     }
 }
 
+/// The bindings, and the companion files written next to them, like the C
+/// helpers file or the Cython wrapper, by name.
+type CbindgenOutput = (Vec<u8>, Vec<(String, Vec<u8>)>);
+
+/// Runs cbindgen with `--output` into `out_dir`, which must not exist, so that
+/// the companion files are written too.
 fn run_cbindgen(
     cbindgen_path: &'static str,
     path: &Path,
     output: &Path,
+    out_dir: &Path,
     language: Language,
     cpp_compat: bool,
     style: Option<Style>,
-) -> Vec<u8> {
+) -> CbindgenOutput {
     let program = Path::new(cbindgen_path);
-    let mut command = Command::new(&program);
+    let mut command = Command::new(program);
     match language {
         Language::Cxx => {}
         Language::C => {
@@ -42,11 +49,18 @@ fn run_cbindgen(
         command.arg("--style").arg(style_str(style));
     }
 
-    let config = path.with_extension("toml");
-    if config.exists() {
+    let config = ["toml", "json", "yaml"]
+        .iter()
+        .map(|extension| path.with_extension(extension))
+        .find(|config| config.exists());
+    if let Some(config) = config {
         command.arg("--config").arg(config);
     }
 
+    fs::create_dir_all(out_dir).unwrap();
+    let output_name = output.file_name().unwrap();
+    command.arg("--output").arg(out_dir.join(output_name));
+
     command.arg(path);
 
     println!("Running: {:?}", command);
@@ -57,34 +71,136 @@ fn run_cbindgen(
         output,
         str::from_utf8(&cbindgen_output.stderr).unwrap_or_default()
     );
-    cbindgen_output.stdout
+
+    let mut bindings = Vec::new();
+    let mut companions = Vec::new();
+    for entry in fs::read_dir(out_dir).unwrap() {
+        let entry = entry.unwrap();
+        let contents = fs::read(entry.path()).unwrap();
+        if entry.file_name() == output_name {
+            bindings = contents;
+        } else {
+            companions.push((entry.file_name().into_string().unwrap(), contents));
+        }
+    }
+    companions.sort();
+    (bindings, companions)
+}
+
+/// Checks the companion files written along with `generated_file` against
+/// the ones in `companions/<generated file name>` next to it, or updates them.
+fn check_companions(generated_file: &Path, companions: &[(String, Vec<u8>)]) {
+    let dir = generated_file
+        .parent()
+        .unwrap()
+        .join("companions")
+        .join(generated_file.file_name().unwrap());
+    if env::var_os("CBINDGEN_TEST_VERIFY").is_some() {
+        let mut expected: Vec<_> = match fs::read_dir(&dir) {
+            Ok(entries) => entries
+                .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+        expected.sort();
+        let names: Vec<_> = companions.iter().map(|(name, _)| name.clone()).collect();
+        assert_eq!(names, expected, "companions of {:?}", generated_file);
+        for (name, contents) in companions {
+            assert_eq!(*contents, fs::read(dir.join(name)).unwrap(), "{}", name);
+        }
+    } else {
+        if dir.exists() {
+            fs::remove_dir_all(&dir).unwrap();
+        }
+        if !companions.is_empty() {
+            fs::create_dir_all(&dir).unwrap();
+        }
+        for (name, contents) in companions {
+            fs::write(dir.join(name), contents).unwrap();
+        }
+    }
+}
+
+/// The standard an output is compiled with, when it isn't the default one of
+/// its language.
+#[derive(Clone, Copy)]
+enum Standard {
+    Default,
+    C23,
+    /// C++20 modules, with the flags the C++ compiler needs for them.
+    CppModule(&'static [&'static str]),
+}
+
+fn compiler(language: Language) -> String {
+    match language {
+        Language::Cxx => env::var("CXX").unwrap_or_else(|_| "g++".to_owned()),
+        Language::C => env::var("CC").unwrap_or_else(|_| "gcc".to_owned()),
+        Language::Cython => env::var("CYTHON").unwrap_or_else(|_| "cython".to_owned()),
+    }
 }
 
+/// Whether the compiler of `language` compiles `probe` with `flags`.
+fn compiler_supports(language: Language, tmp_dir: &Path, flags: &[&str], probe: &str) -> bool {
+    let source = tmp_dir.join(match language {
+        Language::Cxx => "probe.cpp",
+        _ => "probe.c",
+    });
+    fs::write(&source, probe).unwrap();
+    let output = Command::new(compiler(language))
+        .args(flags)
+        .arg("-o")
+        .arg(tmp_dir.join("probe.o"))
+        .arg("-c")
+        .arg(&source)
+        .current_dir(tmp_dir)
+        .output();
+    matches!(output, Ok(ref output) if output.status.success())
+}
+
+#[allow(clippy::too_many_arguments)]
 fn compile(
     cbindgen_output: &Path,
     tests_path: &Path,
+    out_dir: &Path,
     tmp_dir: &Path,
     language: Language,
     style: Option<Style>,
     skip_warning_as_error: bool,
+    standard: Standard,
 ) {
-    let cc = match language {
-        Language::Cxx => env::var("CXX").unwrap_or_else(|_| "g++".to_owned()),
-        Language::C => env::var("CC").unwrap_or_else(|_| "gcc".to_owned()),
-        Language::Cython => env::var("CYTHON").unwrap_or_else(|_| "cython".to_owned()),
-    };
+    let cc = compiler(language);
 
     let file_name = cbindgen_output
         .file_name()
         .expect("cbindgen output should be a file");
     let mut object = tmp_dir.join(file_name);
     object.set_extension("o");
+    // Companion headers are compiled through a file including them, as
+    // `#pragma once` warns in a main file.
+    let is_header = matches!(
+        cbindgen_output.extension().and_then(|e| e.to_str()),
+        Some("h") | Some("hpp")
+    );
+    let source = if is_header {
+        let mut source = tmp_dir.join(file_name);
+        source.set_extension("include.c");
+        fs::write(
+            &source,
+            format!("#include \"{}\"\n", cbindgen_output.display()),
+        )
+        .unwrap();
+        source
+    } else {
+        cbindgen_output.to_owned()
+    };
 
     let mut command = Command::new(cc);
     match language {
         Language::Cxx | Language::C => {
             command.arg("-D").arg("DEFINED");
             command.arg("-I").arg(tests_path);
+            // The companion headers are written next to the bindings.
+            command.arg("-I").arg(out_dir);
             command.arg("-Wall");
             if !skip_warning_as_error {
                 command.arg("-Werror");
@@ -97,16 +213,29 @@ fn compile(
             // be specialized, but they're not so it's fine).
             command.arg("-Wno-return-type-c-linkage");
             if let Language::Cxx = language {
-                // enum class is a c++11 extension which makes g++ on macos 10.14 error out
-                // inline variables are are a c++17 extension
-                command.arg("-std=c++17");
-                // Prevents warnings when compiling .c files as c++.
-                command.arg("-x").arg("c++");
+                if let Standard::CppModule(flags) = standard {
+                    command.arg("-std=c++20");
+                    command.args(flags);
+                    // gcc writes the compiled module interfaces in the
+                    // current directory.
+                    command.current_dir(tmp_dir);
+                } else {
+                    // enum class is a c++11 extension which makes g++ on macos 10.14 error out
+                    // inline variables are are a c++17 extension
+                    command.arg("-std=c++17");
+                    // Prevents warnings when compiling .c files as c++.
+                    command.arg("-x").arg("c++");
+                }
                 if let Ok(extra_flags) = env::var("CXXFLAGS") {
                     command.args(extra_flags.split_whitespace());
                 }
-            } else if let Ok(extra_flags) = env::var("CFLAGS") {
-                command.args(extra_flags.split_whitespace());
+            } else {
+                if let Standard::C23 = standard {
+                    command.arg("-std=c2x");
+                }
+                if let Ok(extra_flags) = env::var("CFLAGS") {
+                    command.args(extra_flags.split_whitespace());
+                }
             }
 
             if let Some(style) = style {
@@ -118,7 +247,7 @@ fn compile(
             }
 
             command.arg("-o").arg(&object);
-            command.arg("-c").arg(cbindgen_output);
+            command.arg("-c").arg(&source);
         }
         Language::Cython => {
             command.arg("-Wextra");
@@ -138,9 +267,67 @@ fn compile(
     if object.exists() {
         fs::remove_file(object).unwrap();
     }
+    if is_header {
+        fs::remove_file(source).unwrap();
+    }
 }
 
 const SKIP_WARNING_AS_ERROR_SUFFIX: &str = ".skip_warning_as_error";
+/// Tests of C23 output, whose C output is compiled as C23. They fail if the C
+/// compiler doesn't support the C23 features cbindgen uses, unless
+/// `CBINDGEN_TEST_NO_C23` is set.
+const C23_PREFIX: &str = "c23";
+const C23_PROBE: &str = "typedef enum E : unsigned char { A } E;\n\
+                         static constexpr int X = 1;\n\
+                         static void *const P = nullptr;\n";
+/// Tests of C++20 module interface units, whose C++ output is compiled as a
+/// module. They fail if the C++ compiler doesn't support modules with any of
+/// `CPP_MODULE_FLAGS`, unless `CBINDGEN_TEST_NO_CPP_MODULES` is set.
+const CPP_MODULE_PREFIX: &str = "cpp_module";
+const CPP_MODULE_PROBE: &str = "export module probe;\nexport int probe();\n";
+const CPP_MODULE_FLAGS: &[&[&str]] = &[
+    // gcc
+    &["-fmodules-ts", "-x", "c++"],
+    // clang
+    &["--precompile", "-x", "c++-module"],
+];
+
+/// The standard the `language` output of the test `name` is compiled with, or
+/// `None` if it can't be and `CBINDGEN_TEST_NO_*` allows not compiling it.
+fn standard(name: &str, language: Language, tmp_dir: &Path) -> Option<Standard> {
+    match language {
+        Language::C if name.starts_with(C23_PREFIX) => {
+            if compiler_supports(language, tmp_dir, &["-std=c2x"], C23_PROBE) {
+                return Some(Standard::C23);
+            }
+            assert!(
+                env::var_os("CBINDGEN_TEST_NO_C23").is_some(),
+                "{} doesn't support C23, set CC to a compiler which does, like gcc 13 or \
+                 clang 18, or set CBINDGEN_TEST_NO_C23 to not compile the C23 tests",
+                compiler(language)
+            );
+            None
+        }
+        Language::Cxx if name.starts_with(CPP_MODULE_PREFIX) => {
+            for flags in CPP_MODULE_FLAGS {
+                let mut probe_flags = vec!["-std=c++20"];
+                probe_flags.extend_from_slice(flags);
+                if compiler_supports(language, tmp_dir, &probe_flags, CPP_MODULE_PROBE) {
+                    return Some(Standard::CppModule(flags));
+                }
+            }
+            assert!(
+                env::var_os("CBINDGEN_TEST_NO_CPP_MODULES").is_some(),
+                "{} doesn't support C++20 modules, set CXX to a compiler which does, like \
+                 gcc 11 or clang 16, or set CBINDGEN_TEST_NO_CPP_MODULES to not compile the \
+                 module tests",
+                compiler(language)
+            );
+            None
+        }
+        _ => Some(Standard::Default),
+    }
+}
 
 #[allow(clippy::too_many_arguments)]
 fn run_compile_test(
@@ -182,10 +369,12 @@ fn run_compile_test(
 
     generated_file.push(source_file);
 
-    let cbindgen_output = run_cbindgen(
+    let out_dir = tmp_dir.join(generated_file.file_name().unwrap());
+    let (cbindgen_output, companions) = run_cbindgen(
         cbindgen_path,
         path,
         &generated_file,
+        &out_dir,
         language,
         cpp_compat,
         style,
@@ -198,7 +387,9 @@ fn run_compile_test(
         } else if generated_file.exists() {
             fs::remove_file(&generated_file).unwrap();
         }
+        check_companions(&generated_file, &[]);
     } else {
+        check_companions(&generated_file, &companions);
         if env::var_os("CBINDGEN_TEST_VERIFY").is_some() {
             let prev_cbindgen_output = fs::read(&generated_file).unwrap();
             assert_eq!(cbindgen_output, prev_cbindgen_output);
@@ -212,23 +403,55 @@ fn run_compile_test(
             return;
         }
 
-        compile(
-            &generated_file,
-            &tests_path,
-            tmp_dir,
-            language,
-            style,
-            skip_warning_as_error,
-        );
+        if let Some(standard) = standard(name, language, tmp_dir) {
+            compile(
+                &generated_file,
+                &tests_path,
+                &out_dir,
+                tmp_dir,
+                language,
+                style,
+                skip_warning_as_error,
+                standard,
+            );
+
+            for (name, _) in &companions {
+                let language = if name.ends_with(".cpp") || name.ends_with(".hpp") {
+                    Language::Cxx
+                } else if name.ends_with(".c") {
+                    Language::C
+                } else if name.ends_with(".h") {
+                    // Headers of C++ bindings may be C++ too.
+                    match language {
+                        Language::Cython => Language::C,
+                        language => language,
+                    }
+                } else {
+                    continue;
+                };
+                compile(
+                    &out_dir.join(name),
+                    &tests_path,
+                    &out_dir,
+                    tmp_dir,
+                    language,
+                    style,
+                    skip_warning_as_error,
+                    standard,
+                );
+            }
+        }
 
         if language == Language::C && cpp_compat {
             compile(
                 &generated_file,
                 &tests_path,
+                &out_dir,
                 tmp_dir,
                 Language::Cxx,
                 style,
                 skip_warning_as_error,
+                Standard::Default,
             );
         }
     }
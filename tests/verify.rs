@@ -0,0 +1,31 @@
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn verify_prints_diff() {
+    let dir = env!("CARGO_TARGET_TMPDIR");
+    let src = format!("{}/verify.rs", dir);
+    let header = format!("{}/verify.h", dir);
+    let diff = format!("{}/verify.diff", dir);
+    fs::write(
+        &src,
+        "#[no_mangle]\npub extern \"C\" fn new_name(x: u8) -> bool { x != 0 }\n",
+    )
+    .unwrap();
+    fs::write(&header, "bool old_name(uint8_t x);\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+        .args(&["--lang", "c", "--verify", "--verify-diff", &diff])
+        .args(&["--output", &header, &src])
+        .output()
+        .expect("failed to execute cbindgen");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("-bool old_name(uint8_t x);"));
+    assert!(stderr.contains("+bool new_name(uint8_t x);"));
+    assert_eq!(
+        fs::read_to_string(&diff).unwrap().lines().next(),
+        Some(&*format!("--- a/{}", header))
+    );
+}
@@ -0,0 +1,105 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Kills the watching cbindgen when the test ends, even if it fails.
+struct Watcher(Child);
+
+impl Drop for Watcher {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+fn watch(dir: &str, args: &[&str]) -> Watcher {
+    let child = Command::new(env!("CARGO_BIN_EXE_cbindgen"))
+        .current_dir(dir)
+        .arg("--watch")
+        .args(args)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to execute cbindgen");
+    Watcher(child)
+}
+
+/// Waits until `file` contains `text`, for at most a minute.
+fn wait_for(file: &str, text: &str) {
+    let start = Instant::now();
+    loop {
+        if let Ok(contents) = fs::read_to_string(file) {
+            if contents.contains(text) {
+                return;
+            }
+        }
+        assert!(
+            start.elapsed() < Duration::from_secs(60),
+            "{} doesn't contain {:?}: {:?}",
+            file,
+            text,
+            fs::read_to_string(file)
+        );
+        thread::sleep(Duration::from_millis(100));
+    }
+}
+
+fn write(path: &str, contents: &str) {
+    fs::create_dir_all(Path::new(path).parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+#[test]
+fn watches_path_dependencies_outside_the_crate() {
+    let dir = format!("{}/watch dependency", env!("CARGO_TARGET_TMPDIR"));
+    let _ = fs::remove_dir_all(&dir);
+    write(
+        &format!("{}/app/Cargo.toml", dir),
+        "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n\
+         [dependencies]\ndep = { path = \"../dep\" }\n\n[workspace]\n",
+    );
+    write(
+        &format!("{}/app/cbindgen.toml", dir),
+        "language = \"C\"\n\n[parse]\nparse_deps = true\ninclude = [\"dep\"]\n",
+    );
+    write(
+        &format!("{}/app/src/lib.rs", dir),
+        "#[no_mangle]\npub extern \"C\" fn root(thing: dep::Thing) {}\n",
+    );
+    write(
+        &format!("{}/dep/Cargo.toml", dir),
+        "[package]\nname = \"dep\"\nversion = \"0.1.0\"\n",
+    );
+    let dep_src = format!("{}/dep/src/lib.rs", dir);
+    write(
+        &dep_src,
+        "#[repr(C)]\npub struct Thing {\n    first: i32,\n}\n",
+    );
+
+    let header = format!("{}/app/bindings.h", dir);
+    let _watcher = watch(&format!("{}/app", dir), &["--output", "bindings.h", "."]);
+    wait_for(&header, "int32_t first;");
+
+    write(
+        &dep_src,
+        "#[repr(C)]\npub struct Thing {\n    first: i32,\n    second: u8,\n}\n",
+    );
+    wait_for(&header, "uint8_t second;");
+}
+
+#[test]
+fn keeps_watching_after_a_failure() {
+    let dir = format!("{}/watch failure", env!("CARGO_TARGET_TMPDIR"));
+    let _ = fs::remove_dir_all(&dir);
+    let src = format!("{}/lib.rs", dir);
+    write(&src, "#[no_mangle]\npub extern \"C\" fn root( {}\n");
+
+    let mut watcher = watch(&dir, &["--lang", "c", "--output", "bindings.h", "lib.rs"]);
+    thread::sleep(Duration::from_secs(1));
+    assert!(watcher.0.try_wait().unwrap().is_none(), "cbindgen exited");
+
+    write(&src, "#[no_mangle]\npub extern \"C\" fn root(x: u32) {}\n");
+    wait_for(&format!("{}/bindings.h", dir), "void root(uint32_t x);");
+}